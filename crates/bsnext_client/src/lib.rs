@@ -2,6 +2,8 @@ pub const DIR: &str = "dist";
 pub const UI_CSS: &str = include_str!("../../../ui/dist/index.css");
 pub const UI_JS: &str = include_str!("../../../ui/dist/index.js");
 const UI_HTML: &str = include_str!("../../../ui/index.html");
+const PROXY_ERROR_HTML: &str = include_str!("../../../ui/proxy-error.html");
+const WORDMARK_SVG: &str = include_str!("../../../ui/svg/wordmark-white.svg");
 pub const INJECT_JS: &str = include_str!("../../../inject/dist/index.js");
 pub const REPLACE_STR: &str = "window.$BSLIVE_INJECT_CONFIG$";
 pub const WS_PATH: &str = "/__bs_ws";
@@ -13,7 +15,91 @@ pub fn html_with_base(base_override: &str) -> String {
     replaced
 }
 
+/// Shown in place of a proxied response, when the target could not be reached
+pub fn proxy_error_html(base_override: &str, err: &bsnext_dto::ProxyErrorDTO) -> String {
+    let next = format!("<base href=\"{base_override}\" />");
+    // skip the xml prolog, so that the svg can be inlined
+    let wordmark = WORDMARK_SVG
+        .find("<svg")
+        .map(|start| &WORDMARK_SVG[start..])
+        .unwrap_or_default();
+    let html = PROXY_ERROR_HTML.replace("<base href=\"/\" />", next.as_str());
+    fill_placeholders(&html, |name| match name {
+        "wordmark" => Some(wordmark.to_string()),
+        "target" => Some(escape_html(&err.target)),
+        "kind" => Some(err.kind.as_str().to_string()),
+        "message" => Some(escape_html(&err.message)),
+        "attempts" => Some(err.attempts.to_string()),
+        _ => None,
+    })
+}
+
+/// Replaces each `{{name}}` in a single pass, so that values are never substituted again
+fn fill_placeholders(template: &str, value: impl Fn(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let placeholder = &rest[start..start + 2 + len + 2];
+        out.push_str(&rest[..start]);
+        match value(&placeholder[2..placeholder.len() - 2]) {
+            Some(value) => out.push_str(&value),
+            None => out.push_str(placeholder),
+        }
+        rest = &rest[start + placeholder.len()..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn escape_html(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
 pub fn inject_js_with_config(inject: bsnext_dto::InjectConfig) -> String {
     let json = serde_json::to_string(&inject).expect("its a known type");
     INJECT_JS.replace(REPLACE_STR, &json)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bsnext_dto::{ProxyErrorDTO, ProxyErrorKindDTO};
+
+    #[test]
+    fn test_fill_placeholders() {
+        let filled = fill_placeholders("{{a}} {{b}} {{missing}} {{", |name| match name {
+            "a" => Some("{{b}}".to_string()),
+            "b" => Some("2".to_string()),
+            _ => None,
+        });
+        assert_eq!(filled, "{{b}} 2 {{missing}} {{");
+    }
+
+    #[test]
+    fn test_proxy_error_html_substitutes_once() {
+        let err = ProxyErrorDTO {
+            path: "/".to_string(),
+            target: "http://localhost:9000".to_string(),
+            kind: ProxyErrorKindDTO::Connect,
+            message: "refused {{attempts}}".to_string(),
+            attempts: 3,
+        };
+        let html = proxy_error_html("/", &err);
+        assert!(html.contains("refused {{attempts}}"));
+        assert!(html.contains("<dd>3</dd>"));
+    }
+}
@@ -1,22 +1,25 @@
-use crate::match_json::{match_json_body, NeedsJsonGuard};
+use crate::match_json::{match_body, NeedsBodyGuard};
 use crate::route_effect::RouteEffect;
 use axum::body::Body;
 use axum::extract::Request;
 use bsnext_guards::route_guard::RouteGuard;
 use bsnext_input::route::Route;
-use http::Uri;
+use http::header::CONTENT_TYPE;
+use http::{HeaderMap, Method, Uri};
 use std::ops::ControlFlow;
 
 #[derive(Debug, Clone)]
 pub enum BodyMatch {
     Json,
+    Form,
+    Multipart { boundary: String },
 }
 
 impl RouteEffect for BodyMatch {
     fn new_opt(route: &Route, req: &Request, _uri: &Uri, outer_uri: &Uri) -> Option<Self> {
         route.when_body.as_ref().and_then(|body| {
-            if NeedsJsonGuard(body).accept_req(req, outer_uri) {
-                return Some(Self::Json);
+            if NeedsBodyGuard(body).accept_req(req, outer_uri) {
+                return BodyMatch::for_req(req.method(), req.headers());
             }
             None
         })
@@ -24,14 +27,34 @@ impl RouteEffect for BodyMatch {
 }
 
 impl BodyMatch {
+    /// Only methods that carry a body, with a content-type that we know how to read
+    pub fn for_req(method: &Method, headers: &HeaderMap) -> Option<Self> {
+        let has_body = [Method::POST, Method::PUT, Method::PATCH, Method::DELETE].contains(method);
+        if !has_body {
+            return None;
+        }
+        let content_type = headers.get(CONTENT_TYPE)?.to_str().ok()?;
+        let essence = content_type.split(';').next()?.trim();
+        match essence {
+            "application/x-www-form-urlencoded" => Some(Self::Form),
+            "multipart/form-data" => content_type
+                .split(';')
+                .filter_map(|param| param.trim().split_once('='))
+                .find(|(name, _)| name.eq_ignore_ascii_case("boundary"))
+                .map(|(_, boundary)| Self::Multipart {
+                    boundary: boundary.trim_matches('"').to_string(),
+                }),
+            json if json == "application/json" || json.ends_with("+json") => Some(Self::Json),
+            _ => None,
+        }
+    }
+
     pub async fn try_exec(
         &self,
         body: &mut Option<Body>,
         route: &Route,
     ) -> (Option<Body>, ControlFlow<()>) {
-        match &self {
-            BodyMatch::Json => match_json_body(body, route).await,
-        }
+        match_body(body, route, self).await
     }
 }
 
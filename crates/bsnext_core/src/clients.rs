@@ -0,0 +1,73 @@
+use bsnext_dto::ClientDTO;
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::sync::RwLock;
+
+///
+/// Every browser currently connected to a server over the websocket.
+/// Ids are only unique within a single server
+///
+#[derive(Debug, Default)]
+pub struct Clients {
+    next_id: AtomicU64,
+    connected: RwLock<BTreeMap<u64, ConnectedClient>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConnectedClient {
+    pub id: u64,
+    pub addr: SocketAddr,
+    pub user_agent: Option<String>,
+    /// Path, query and hash of the page the browser is on
+    pub url: Option<String>,
+    pub connected_at: DateTime<Utc>,
+}
+
+impl Clients {
+    pub async fn add(
+        &self,
+        addr: SocketAddr,
+        user_agent: Option<String>,
+        url: Option<String>,
+    ) -> ConnectedClient {
+        let client = ConnectedClient {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed) + 1,
+            addr,
+            user_agent,
+            url,
+            connected_at: Utc::now(),
+        };
+        self.connected
+            .write()
+            .await
+            .insert(client.id, client.clone());
+        client
+    }
+    pub async fn remove(&self, id: u64) -> Option<ConnectedClient> {
+        self.connected.write().await.remove(&id)
+    }
+    pub async fn set_url(&self, id: u64, url: String) {
+        if let Some(client) = self.connected.write().await.get_mut(&id) {
+            client.url = Some(url);
+        }
+    }
+    pub async fn list(&self) -> Vec<ConnectedClient> {
+        self.connected.read().await.values().cloned().collect()
+    }
+}
+
+impl From<&ConnectedClient> for ClientDTO {
+    fn from(value: &ConnectedClient) -> Self {
+        Self {
+            id: value.id.to_string(),
+            addr: value.addr.to_string(),
+            user_agent: value.user_agent.clone(),
+            url: value.url.clone(),
+            connected_at: value
+                .connected_at
+                .to_rfc3339_opts(SecondsFormat::Millis, true),
+        }
+    }
+}
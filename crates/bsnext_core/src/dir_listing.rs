@@ -0,0 +1,315 @@
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::{Html, IntoResponse, Response};
+use axum::Json;
+use bsnext_guards::OuterUri;
+use chrono::{DateTime, SecondsFormat, Utc};
+use http::header::ACCEPT;
+use http::{Method, StatusCode};
+use minijinja::{AutoEscape, Environment};
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS};
+use serde_json::json;
+use std::cmp::Ordering;
+use std::path::{Path, PathBuf};
+
+///
+/// When a folder has no `index.html`, list its contents instead of responding with a 404.
+///
+/// Browsers get an HTML page, and requests with `Accept: application/json` get the same
+/// entries as JSON. Both can be sorted with `?sort=name|size|mtime&order=asc|desc`
+///
+pub async fn listing_mw(State(root): State<PathBuf>, req: Request, next: Next) -> Response {
+    let is_read = req.method() == Method::GET || req.method() == Method::HEAD;
+    let local_path = req.uri().path().to_string();
+    let outer_uri = req
+        .extensions()
+        .get::<OuterUri>()
+        .map(|OuterUri(uri)| uri.clone())
+        .unwrap_or_else(|| req.uri().clone());
+    let wants_json = req
+        .headers()
+        .get(ACCEPT)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.contains("application/json"));
+
+    let res = next.run(req).await;
+    if !is_read || res.status() != StatusCode::NOT_FOUND {
+        return res;
+    }
+    let Some(dir) = local_dir(&root, &local_path) else {
+        return res;
+    };
+
+    let query: ListingQuery = outer_uri
+        .query()
+        .and_then(|q| serde_urlencoded::from_str(q).ok())
+        .unwrap_or_default();
+    let entries = match read_entries(&dir, &query).await {
+        Ok(entries) => entries,
+        Err(err) => {
+            tracing::error!(?err, ?dir, "could not read directory for listing");
+            return res;
+        }
+    };
+
+    if wants_json {
+        return Json(json!({ "path": outer_uri.path(), "entries": entries })).into_response();
+    }
+    match render_html(outer_uri.path(), &entries, &query) {
+        Ok(html) => Html(html).into_response(),
+        Err(err) => {
+            tracing::error!(?err, "could not render directory listing");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+enum SortBy {
+    #[default]
+    Name,
+    Size,
+    Mtime,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Order {
+    #[default]
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Default, serde::Deserialize)]
+struct ListingQuery {
+    #[serde(default)]
+    sort: SortBy,
+    #[serde(default)]
+    order: Order,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ListingEntry {
+    name: String,
+    kind: EntryKind,
+    size: u64,
+    /// RFC 3339, in UTC
+    modified: Option<String>,
+    href: String,
+    #[serde(skip)]
+    mtime: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+enum EntryKind {
+    Dir,
+    File,
+}
+
+/// Characters that can't appear in a single, relative path segment
+const SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// The folder on disk for a request path, which must end with `/` and stay within `root`
+fn local_dir(root: &Path, path: &str) -> Option<PathBuf> {
+    if !path.ends_with('/') {
+        return None;
+    }
+    let decoded = percent_decode_str(path).decode_utf8().ok()?;
+    let mut dir = root.to_path_buf();
+    for segment in decoded.split('/').filter(|s| !s.is_empty()) {
+        if segment == ".." || segment == "." || segment.contains('\\') {
+            return None;
+        }
+        dir.push(segment);
+    }
+    dir.is_dir().then_some(dir)
+}
+
+async fn read_entries(dir: &Path, query: &ListingQuery) -> std::io::Result<Vec<ListingEntry>> {
+    let mut entries = vec![];
+    let mut read_dir = tokio::fs::read_dir(dir).await?;
+    while let Some(entry) = read_dir.next_entry().await? {
+        let Ok(meta) = entry.metadata().await else {
+            continue;
+        };
+        let name = entry.file_name().to_string_lossy().to_string();
+        let kind = if meta.is_dir() {
+            EntryKind::Dir
+        } else {
+            EntryKind::File
+        };
+        let mut href = utf8_percent_encode(&name, SEGMENT).to_string();
+        if kind == EntryKind::Dir {
+            href.push('/');
+        }
+        let mtime = meta.modified().ok().map(DateTime::<Utc>::from);
+        entries.push(ListingEntry {
+            name,
+            size: if kind == EntryKind::Dir {
+                0
+            } else {
+                meta.len()
+            },
+            modified: mtime.map(|m| m.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            kind,
+            href,
+            mtime,
+        });
+    }
+    entries.sort_by(|a, b| compare(a, b, query));
+    Ok(entries)
+}
+
+/// Folders always come first, then entries are sorted by the chosen column
+fn compare(a: &ListingEntry, b: &ListingEntry, query: &ListingQuery) -> Ordering {
+    let dirs_first = (b.kind == EntryKind::Dir).cmp(&(a.kind == EntryKind::Dir));
+    let by_column = match query.sort {
+        SortBy::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        SortBy::Size => a.size.cmp(&b.size),
+        SortBy::Mtime => a.mtime.cmp(&b.mtime),
+    };
+    let by_column = match query.order {
+        Order::Asc => by_column,
+        Order::Desc => by_column.reverse(),
+    };
+    dirs_first.then(by_column).then_with(|| a.name.cmp(&b.name))
+}
+
+fn render_html(
+    path: &str,
+    entries: &[ListingEntry],
+    query: &ListingQuery,
+) -> Result<String, minijinja::Error> {
+    let mut crumbs = vec![json!({ "name": "~", "href": "/" })];
+    let mut href = String::from("/");
+    for segment in path.split('/').filter(|s| !s.is_empty()) {
+        href.push_str(segment);
+        href.push('/');
+        let name = percent_decode_str(segment).decode_utf8_lossy().to_string();
+        crumbs.push(json!({ "name": name, "href": href }));
+    }
+
+    let columns = [
+        (SortBy::Name, "Name"),
+        (SortBy::Size, "Size"),
+        (SortBy::Mtime, "Modified"),
+    ]
+    .map(|(sort, label)| {
+        let active = query.sort == sort;
+        let next_order = match (active, query.order) {
+            (true, Order::Asc) => Order::Desc,
+            _ => Order::Asc,
+        };
+        json!({ "sort": sort, "label": label, "active": active, "next_order": next_order })
+    });
+
+    let rows = entries
+        .iter()
+        .map(|entry| {
+            json!({
+                "name": entry.name,
+                "href": entry.href,
+                "is_dir": entry.kind == EntryKind::Dir,
+                "size": match entry.kind {
+                    EntryKind::Dir => "-".to_string(),
+                    EntryKind::File => human_size(entry.size),
+                },
+                "modified": entry
+                    .mtime
+                    .map(|m| m.format("%Y-%m-%d %H:%M").to_string())
+                    .unwrap_or_default(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut env = Environment::new();
+    env.set_auto_escape_callback(|_| AutoEscape::Html);
+    env.render_str(
+        LISTING_HTML,
+        json!({
+            "path": path,
+            "crumbs": crumbs,
+            "columns": columns,
+            "order": query.order,
+            "rows": rows,
+        }),
+    )
+}
+
+fn human_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+const LISTING_HTML: &str = r#"<!doctype html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>Index of {{ path }}</title>
+    <style>
+        body { font-family: system-ui, sans-serif; margin: 2rem; color: #222; }
+        nav { margin-bottom: 1rem; }
+        table { border-collapse: collapse; min-width: 40rem; }
+        th, td { text-align: left; padding: .3rem 1.5rem .3rem 0; }
+        th a { color: inherit; }
+        td:nth-child(2), td:nth-child(3) { color: #666; white-space: nowrap; }
+    </style>
+</head>
+<body>
+<nav>{% for crumb in crumbs %}<a href="{{ crumb.href }}">{{ crumb.name }}</a>{% if not loop.last %} / {% endif %}{% endfor %}</nav>
+<table>
+    <thead>
+    <tr>{% for col in columns %}<th><a href="?sort={{ col.sort }}&amp;order={{ col.next_order }}">{{ col.label }}{% if col.active %} {{ "▲" if order == "asc" else "▼" }}{% endif %}</a></th>{% endfor %}</tr>
+    </thead>
+    <tbody>
+    {% for row in rows %}<tr><td><a href="{{ row.href }}">{{ row.name }}{% if row.is_dir %}/{% endif %}</a></td><td>{{ row.size }}</td><td>{{ row.modified }}</td></tr>
+    {% endfor %}</tbody>
+</table>
+</body>
+</html>
+"#;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_local_dir() {
+        let root = std::env::current_dir().unwrap();
+        assert_eq!(local_dir(&root, "/src/"), Some(root.join("src")));
+        assert_eq!(local_dir(&root, "/src"), None);
+        assert_eq!(local_dir(&root, "/../"), None);
+        assert_eq!(local_dir(&root, "/%2e%2e/"), None);
+        assert_eq!(local_dir(&root, "/missing/"), None);
+    }
+
+    #[test]
+    fn test_human_size() {
+        assert_eq!(human_size(12), "12 B");
+        assert_eq!(human_size(1536), "1.5 KB");
+        assert_eq!(human_size(3 * 1024 * 1024), "3.0 MB");
+    }
+}
@@ -1,18 +1,28 @@
 use crate::runtime_ctx::RuntimeCtx;
-use crate::server::router::common::{into_state, uri_to_res_parts};
+use crate::server::router::common::into_state;
+use crate::server::router::make_router;
+use crate::server::state::ServerState;
 use crate::shared_args::LoggingOpts;
+use axum::body::Body;
+use axum::extract::Request;
 use bsnext_fs_helpers::{FsWriteError, WriteMode};
 use bsnext_input::route::{Route, RouteKind};
 use bsnext_input::server_config::ServerConfig;
+use bsnext_input::InputError;
 use bsnext_output::OutputWriterTrait;
+use bsnext_resp::inject_opts::InjectOpts;
 use bsnext_tracing::OutputFormat;
-use futures_util::future::join_all;
+use bytes::Bytes;
+use http::header::{CONTENT_TYPE, LOCATION};
 use http::response::Parts;
+use http_body_util::BodyExt;
 use std::clone::Clone;
+use std::collections::{HashSet, VecDeque};
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
-use std::time::Duration;
+use std::sync::Arc;
+use tower::ServiceExt;
 
 #[derive(Debug, serde::Serialize)]
 pub enum ExportEvent {
@@ -20,12 +30,22 @@ pub enum ExportEvent {
     DryRunFileCreate(PathBuf),
     DidCreateFile(PathBuf),
     DidCreateDir(PathBuf),
+    Excluded {
+        url_path: String,
+        reason: ExcludeReason,
+    },
+    /// Links were left unvisited because `--max-pages` was reached
+    MaxPagesReached(usize),
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum ExportError {
     #[error("{0}")]
     Fs(FsWriteError),
+    #[error("{0}")]
+    Input(Box<InputError>),
+    #[error("no servers were found in the input")]
+    NoServers,
 }
 
 impl OutputWriterTrait for ExportError {
@@ -33,6 +53,8 @@ impl OutputWriterTrait for ExportError {
         let error_string = self.to_string();
         let named = match self {
             ExportError::Fs(_) => "fs",
+            ExportError::Input(_) => "input",
+            ExportError::NoServers => "no_servers",
         };
         let v = serde_json::json!({
             "kind": named,
@@ -47,6 +69,12 @@ impl OutputWriterTrait for ExportError {
             ExportError::Fs(fs_write_error) => {
                 writeln!(sink, "[export]: Error! {fs_write_error}")?;
             }
+            ExportError::Input(input_error) => {
+                writeln!(sink, "[export]: Error! {input_error}")?;
+            }
+            ExportError::NoServers => {
+                writeln!(sink, "[export]: Error! {self}")?;
+            }
         }
         Ok(())
     }
@@ -75,6 +103,15 @@ impl OutputWriterTrait for ExportEvent {
             ExportEvent::DidCreateDir(file) => {
                 writeln!(sink, "[export]: did create dir {}", file.display())?;
             }
+            ExportEvent::Excluded { url_path, reason } => {
+                writeln!(sink, "[export]: skipped {url_path}, {reason}")?;
+            }
+            ExportEvent::MaxPagesReached(max_pages) => {
+                writeln!(
+                    sink,
+                    "[export]: stopped following links after {max_pages} pages, see --max-pages"
+                )?;
+            }
         }
         Ok(())
     }
@@ -83,26 +120,42 @@ impl OutputWriterTrait for ExportEvent {
 #[derive(Debug)]
 enum ExportType {
     Write {
+        url_path: String,
         export_result: ExportResult,
         filepath: PathBuf,
     },
     Excluded {
+        url_path: String,
         reason: ExcludeReason,
     },
 }
 
-#[derive(Debug)]
-enum ExcludeReason {
-    BadRequest,
+#[derive(Debug, serde::Serialize)]
+pub enum ExcludeReason {
+    /// Anything other than a 200 response is not written to disk
+    Status(u16),
+    /// The response body could not be read
+    Body(String),
+    /// An earlier url path was already written to the same file, eg: `/` and `/index.html`
+    SameFile(PathBuf),
+    /// The file could not be written, eg: it already exists and `--force` wasn't given
+    Write(String),
 }
 
-#[derive(Debug)]
-struct ExportRequest<'a> {
-    pub url_path: &'a str,
-    pub filepath: PathBuf,
+impl std::fmt::Display for ExcludeReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExcludeReason::Status(status) => write!(f, "status code {status}"),
+            ExcludeReason::Body(reason) => write!(f, "could not read body: {reason}"),
+            ExcludeReason::SameFile(filepath) => {
+                write!(f, "{} was already exported", filepath.display())
+            }
+            ExcludeReason::Write(reason) => write!(f, "{reason}"),
+        }
+    }
 }
 
-type ExportResult = (Parts, String, Duration);
+type ExportResult = (Parts, Bytes);
 
 #[derive(Debug, Clone, clap::Parser)]
 pub struct ExportCommand {
@@ -112,6 +165,12 @@ pub struct ExportCommand {
     /// When provided, just prints what might happen instead of actually causing side effects
     #[arg(long)]
     pub dry_run: bool,
+    /// Overwrite files that already exist in the output folder
+    #[arg(long)]
+    pub force: bool,
+    /// Stop following links once this many url paths have been requested
+    #[arg(long, default_value_t = 1000)]
+    pub max_pages: usize,
 
     /// logging options
     #[clap(flatten)]
@@ -125,65 +184,136 @@ pub struct ExportCommand {
     pub trailing: Vec<String>,
 }
 
+///
+/// Render every route of a server to disk. Starting from the paths of each route, any `href`
+/// or `src` links found in HTML responses are followed, which is how `dir` and `proxy`
+/// routes get crawled. Routes match nested paths, so relative links can go on forever -
+/// `--max-pages` caps the crawl.
+///
 pub async fn export_one_server(
     cwd: &PathBuf,
     server: ServerConfig,
     cmd: &ExportCommand,
     write_mode: WriteMode,
 ) -> Result<Vec<ExportEvent>, ExportError> {
+    let server = without_connector(server);
     let routes = server.combined_routes();
-    let state = into_state(server);
-
-    let raw_entries = routes.iter().filter_map(only_raw_entries);
-    let raw_entry_paths = raw_entries.clone().map(|r| r.filepath);
-    let async_requests = raw_entries.map(|req| uri_to_res_parts(state.clone(), req.url_path));
-
+    let state = Arc::new(into_state(server));
     let ctx = RuntimeCtx::new(cwd);
-    // let job_count = raw_entry_paths.len();
 
-    join_all(async_requests)
-        .await
-        .into_iter()
-        .zip(raw_entry_paths)
-        .map(to_export_type)
-        .try_fold(vec![], move |mut acc, ref ex_type| match cmd.dry_run {
-            true => match print_sink(ex_type, &cmd.out_dir, &ctx) {
-                Ok(evts) => {
-                    acc.extend(evts);
-                    Ok(acc)
+    let mut seen = HashSet::new();
+    let mut queue = routes
+        .iter()
+        .filter_map(entry_url_path)
+        .filter(|url_path| seen.insert(url_path.clone()))
+        .collect::<VecDeque<_>>();
+    let mut filepaths = HashSet::new();
+    let mut max_pages_reached = false;
+    let mut events = vec![];
+
+    while let Some(url_path) = queue.pop_front() {
+        let ex_type = match request_one(&state, &url_path).await {
+            Ok((parts, body)) => {
+                for link in links_to_follow(&url_path, &parts, &body) {
+                    if seen.contains(&link) {
+                        continue;
+                    }
+                    if seen.len() >= cmd.max_pages {
+                        max_pages_reached = true;
+                        continue;
+                    }
+                    seen.insert(link.clone());
+                    queue.push_back(link);
                 }
-                Err(e) => Err(e),
-            },
-            false => match fs_sink(ex_type, &cmd.out_dir, &ctx, &write_mode) {
-                Ok(evts) => {
-                    acc.extend(evts);
-                    Ok(acc)
+                match to_export_type(url_path.clone(), (parts, body)) {
+                    ExportType::Write { filepath, .. } if !filepaths.insert(filepath.clone()) => {
+                        ExportType::Excluded {
+                            url_path,
+                            reason: ExcludeReason::SameFile(filepath),
+                        }
+                    }
+                    ex_type => ex_type,
                 }
-                Err(e) => Err(e),
-            },
+            }
+            Err(reason) => ExportType::Excluded { url_path, reason },
+        };
+        let next = match cmd.dry_run {
+            true => print_sink(ex_type, &cmd.out_dir, &ctx),
+            false => fs_sink(ex_type, &cmd.out_dir, &ctx, &write_mode),
+        };
+        events.extend(next?);
+    }
+
+    if max_pages_reached {
+        events.push(ExportEvent::MaxPagesReached(cmd.max_pages));
+    }
+    Ok(events)
+}
+
+/// The bslive client would be useless in a static export, so only explicit injections are kept
+fn without_connector(mut server: ServerConfig) -> ServerConfig {
+    server.routes = server
+        .combined_routes()
+        .into_iter()
+        .map(|mut route| {
+            if route.opts.inject == InjectOpts::Bool(true) {
+                route.opts.inject = InjectOpts::Bool(false);
+            }
+            route
         })
+        .collect();
+    server.playground = None;
+    server
+}
+
+async fn request_one(
+    state: &Arc<ServerState>,
+    url_path: &str,
+) -> Result<ExportResult, ExcludeReason> {
+    let app = make_router(state);
+    let req = Request::get(url_path)
+        .body(Body::empty())
+        .map_err(|e| ExcludeReason::Body(e.to_string()))?;
+    let Ok(res) = app.oneshot(req).await;
+    let (parts, body) = res.into_parts();
+    let body = body
+        .collect()
+        .await
+        .map_err(|e| ExcludeReason::Body(e.to_string()))?;
+    Ok((parts, body.to_bytes()))
 }
 
 fn fs_sink(
-    ex_type: &ExportType,
+    ex_type: ExportType,
     out_dir: &PathBuf,
     ctx: &RuntimeCtx,
     write_mode: &WriteMode,
 ) -> Result<Vec<ExportEvent>, ExportError> {
     match ex_type {
         ExportType::Write {
+            url_path,
             export_result,
             filepath,
         } => {
             let filepath = ctx.cwd().join(out_dir).join(filepath);
-            write_one(export_result, &filepath, ctx, write_mode).map_err(ExportError::Fs)
+            // one page that can't be written shouldn't stop the rest of the crawl
+            let events =
+                write_one(&export_result, &filepath, ctx, write_mode).unwrap_or_else(|e| {
+                    vec![ExportEvent::Excluded {
+                        url_path,
+                        reason: ExcludeReason::Write(e.to_string()),
+                    }]
+                });
+            Ok(events)
+        }
+        ExportType::Excluded { url_path, reason } => {
+            Ok(vec![ExportEvent::Excluded { url_path, reason }])
         }
-        ExportType::Excluded { reason: _ } => Ok(vec![]),
     }
 }
 
 fn print_sink(
-    ex_type: &ExportType,
+    ex_type: ExportType,
     out_dir: &PathBuf,
     ctx: &RuntimeCtx,
 ) -> Result<Vec<ExportEvent>, ExportError> {
@@ -193,37 +323,157 @@ fn print_sink(
             let path = ctx.cwd().join(out_dir).join(filepath);
             events.push(ExportEvent::DryRunFileCreate(path));
         }
-        ExportType::Excluded { reason } => {
-            todo!("Ignoring {:?}", reason)
+        ExportType::Excluded { url_path, reason } => {
+            events.push(ExportEvent::Excluded { url_path, reason });
         }
     }
     Ok(events)
 }
 
-fn to_export_type((export_result, filepath): (ExportResult, PathBuf)) -> ExportType {
-    let (parts, _, _) = &export_result;
+fn to_export_type(url_path: String, export_result: ExportResult) -> ExportType {
+    let (parts, _) = &export_result;
     if parts.status.as_u16() == 200 {
+        let filepath = url_path_to_filepath(&url_path, is_html(parts));
         ExportType::Write {
+            url_path,
             export_result,
             filepath,
         }
     } else {
         ExportType::Excluded {
-            reason: ExcludeReason::BadRequest,
+            url_path,
+            reason: ExcludeReason::Status(parts.status.as_u16()),
         }
     }
 }
 
-fn only_raw_entries(route: &Route) -> Option<ExportRequest<'_>> {
+/// Routes with dynamic segments cannot be requested directly, they can only be reached via links
+fn entry_url_path(route: &Route) -> Option<String> {
+    let url_path = route.url_path();
+    if url_path.contains(['*', ':', '{']) {
+        return None;
+    }
     match &route.kind {
-        RouteKind::Raw(..) => Some(ExportRequest {
-            filepath: route.as_filepath(),
-            url_path: route.url_path(),
-        }),
-        _ => None,
+        RouteKind::Raw(..) | RouteKind::Proxy(..) | RouteKind::Dir(..) => {
+            Some(url_path.to_string())
+        }
     }
 }
 
+///
+/// Directory-like paths become `index.html`, as do extension-less HTML pages,
+/// so that the output can be served by any static file server.
+///
+pub(crate) fn url_path_to_filepath(url_path: &str, is_html: bool) -> PathBuf {
+    let trimmed = url_path.trim_start_matches('/');
+    let pb = PathBuf::from(trimmed);
+    if trimmed.is_empty() || url_path.ends_with('/') || (is_html && pb.extension().is_none()) {
+        pb.join("index.html")
+    } else {
+        pb
+    }
+}
+
+fn is_html(parts: &Parts) -> bool {
+    parts
+        .headers
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("text/html"))
+}
+
+fn links_to_follow(url_path: &str, parts: &Parts, body: &Bytes) -> Vec<String> {
+    if parts.status.is_redirection() {
+        return parts
+            .headers
+            .get(LOCATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|location| resolve_link(url_path, location))
+            .into_iter()
+            .collect();
+    }
+    if !parts.status.is_success() || !is_html(parts) {
+        return vec![];
+    }
+    let html = String::from_utf8_lossy(body);
+    html_links(&html)
+        .into_iter()
+        .filter_map(|link| resolve_link(url_path, link))
+        .collect()
+}
+
+/// Collect the values of `href` and `src` attributes, quoted with either `"` or `'`
+fn html_links(html: &str) -> Vec<&str> {
+    let mut links = vec![];
+    for attr in ["href=", "src="] {
+        let mut rest = html;
+        while let Some(index) = rest.find(attr) {
+            let preceded_by_space = rest[..index]
+                .chars()
+                .next_back()
+                .is_some_and(char::is_whitespace);
+            rest = &rest[index + attr.len()..];
+            let Some(quote) = rest.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+                continue;
+            };
+            let value = &rest[1..];
+            let Some(end) = value.find(quote) else {
+                break;
+            };
+            if preceded_by_space {
+                links.push(&value[..end]);
+            }
+            rest = &value[end..];
+        }
+    }
+    links
+}
+
+///
+/// Resolve a link relative to the page it was found on. External links, fragments
+/// and the built-in `/__bs*` paths are ignored
+///
+fn resolve_link(base: &str, link: &str) -> Option<String> {
+    let link = link.trim();
+    let link = link.split(['#', '?']).next().unwrap_or_default();
+    if link.is_empty() || link.starts_with("//") {
+        return None;
+    }
+    let before_path = link.split('/').next().unwrap_or_default();
+    if before_path.contains(':') {
+        return None;
+    }
+
+    let joined = if link.starts_with('/') {
+        link.to_string()
+    } else {
+        let dir = &base[..base.rfind('/').map(|i| i + 1).unwrap_or(0)];
+        format!("{dir}{link}")
+    };
+
+    let mut segments: Vec<&str> = vec![];
+    for segment in joined.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            other => segments.push(other),
+        }
+    }
+    let trailing = if joined.ends_with('/') && !segments.is_empty() {
+        "/"
+    } else {
+        ""
+    };
+    let resolved = format!("/{}{trailing}", segments.join("/"));
+
+    if resolved.starts_with("/__bs") {
+        return None;
+    }
+    Some(resolved)
+}
+
 fn write_one(
     export_result: &ExportResult,
     filepath: &Path,
@@ -233,10 +483,64 @@ fn write_one(
     let dir = filepath.parent();
     let mut events = vec![];
     if let Some(dir) = dir {
-        fs::create_dir_all(dir).map_err(FsWriteError::FailedDir)?;
+        if !dir.exists() {
+            fs::create_dir_all(dir).map_err(FsWriteError::FailedDir)?;
+            events.push(ExportEvent::DidCreateDir(dir.to_path_buf()));
+        }
     }
-    let (_, ref body, _) = export_result;
-    let pb = bsnext_fs_helpers::fs_write_str(ctx.cwd(), filepath, body, write_mode)?;
+    let (_, ref body) = export_result;
+    let pb = bsnext_fs_helpers::fs_write_bytes(ctx.cwd(), filepath, body, write_mode)?;
     events.push(ExportEvent::DidCreateFile(pb.clone()));
     Ok(events)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_resolve_link() {
+        let cases = [
+            ("/", "about.html", Some("/about.html")),
+            ("/blog/post.html", "img/a.png", Some("/blog/img/a.png")),
+            ("/blog/post.html", "../styles.css?v=1", Some("/styles.css")),
+            ("/blog/", "./", Some("/blog/")),
+            ("/", "/docs/#intro", Some("/docs/")),
+            ("/", "#top", None),
+            ("/", "https://example.com/a.js", None),
+            ("/", "//cdn.example.com/a.js", None),
+            ("/", "mailto:a@example.com", None),
+            ("/", "/__bs_js", None),
+        ];
+        for (base, link, expected) in cases {
+            assert_eq!(
+                resolve_link(base, link).as_deref(),
+                expected,
+                "{base} + {link}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_html_links() {
+        let html = r#"<a href="/a">a</a><img src='b.png'><div data-href="nope"></div>"#;
+        assert_eq!(html_links(html), vec!["/a", "b.png"]);
+    }
+
+    #[test]
+    fn test_url_path_to_filepath() {
+        assert_eq!(url_path_to_filepath("/", true), PathBuf::from("index.html"));
+        assert_eq!(
+            url_path_to_filepath("/about", true),
+            PathBuf::from("about/index.html")
+        );
+        assert_eq!(
+            url_path_to_filepath("/docs/", false),
+            PathBuf::from("docs/index.html")
+        );
+        assert_eq!(
+            url_path_to_filepath("/api.json", false),
+            PathBuf::from("api.json")
+        );
+    }
+}
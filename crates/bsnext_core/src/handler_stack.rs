@@ -1,12 +1,21 @@
-use crate::handlers::proxy::{proxy_handler, ProxyConfig, RewriteKind};
+use crate::dir_listing::listing_mw;
+use crate::handlers::proxy::{proxy_handler, ProxyClient, ProxyConfig, RewriteKind};
 use crate::raw_loader::serve_raw_one;
+use crate::route_auth::auth_mw;
 use crate::route_cache::cache_control_layer;
 use crate::route_candidate::RouteCandidate;
+use crate::route_chaos::chaos_mw;
 use crate::route_delay::delay_mw;
 use crate::route_marker::RouteMarker;
 use crate::route_match::RouteMatch;
+use crate::route_mirror::mirror_mw;
+use crate::route_replay::replay_mw;
 use crate::route_res_headers::set_str_headers;
+use crate::route_resources::RouteResources;
+use crate::route_status::{status_layer, DeliberateStatus};
+use crate::route_template::template_mw;
 use crate::runtime_ctx::RuntimeCtx;
+use crate::spa_fallback::spa_fallback_mw;
 use axum::body::Body;
 use axum::extract::{Request, State};
 use axum::handler::Handler;
@@ -15,12 +24,14 @@ use axum::response::IntoResponse;
 use axum::routing::{any, any_service, get_service, MethodRouter};
 use axum::{middleware, Extension, Router};
 use bsnext_guards::{uri_extension, OuterUri};
+use bsnext_input::path_def::PathParams;
 use bsnext_input::route::{Route, RouteKind};
 use bsnext_resp::{response_modifications_layer, InjectHandling};
 use http::request::Parts;
 use http::{StatusCode, Uri};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Arc;
 use tower::ServiceExt;
 use tower_http::decompression::DecompressionLayer;
 use tower_http::services::{ServeDir, ServeFile};
@@ -67,21 +78,43 @@ impl RouteMap {
 
 #[tracing::instrument(skip_all)]
 pub fn route_list_for_path(path: &str, routes: Vec<Route>, ctx: RuntimeCtx) -> Router {
-    // let r1 = from_fn_with_state((path.to_string(), routes, ctx), try_one);
-    let svc = any_service(try_one.with_state((path.to_string(), routes, ctx)));
+    let group = RouteGroup {
+        path: path.to_string(),
+        params: Arc::new(PathParams::new(path)),
+        resources: routes.iter().map(RouteResources::new).collect(),
+        routes,
+        ctx,
+    };
+    let svc = any_service(try_one.with_state(Arc::new(group)));
     tracing::trace!("nest_service");
     Router::new()
         .nest_service(path, svc)
         .layer(from_fn(uri_extension))
 }
 
+/// The routes that share a path, each with its resources at the same index
+pub struct RouteGroup {
+    path: String,
+    params: Arc<PathParams>,
+    routes: Vec<Route>,
+    resources: Vec<RouteResources>,
+    ctx: RuntimeCtx,
+}
+
 pub async fn try_one(
-    State((path, routes, ctx)): State<(String, Vec<Route>, RuntimeCtx)>,
+    State(group): State<Arc<RouteGroup>>,
     Extension(OuterUri(outer_uri)): Extension<OuterUri>,
     parts: Parts,
     uri: Uri,
     req: Request,
 ) -> impl IntoResponse {
+    let RouteGroup {
+        path,
+        params,
+        routes,
+        resources,
+        ctx,
+    } = &*group;
     let span = trace_span!("try_one", outer_uri = ?outer_uri, path = path, local_uri = ?uri);
     let _g = span.enter();
 
@@ -96,7 +129,7 @@ pub async fn try_one(
         .filter(|(index, route)| {
             let span = trace_span!("early filter for candidates", index = index);
             let _g = span.enter();
-            RouteMatch(route).route_match(&req, &outer_uri, &path, path_and_query, &parts)
+            RouteMatch(route).route_match(&req, &outer_uri, path, params, path_and_query, &parts)
         })
         .map(|(index, route)| RouteCandidate::for_route(index, route, &req, &uri, &outer_uri))
         .collect::<Vec<_>>();
@@ -122,7 +155,32 @@ pub async fn try_one(
 
         trace!(mirror = ?candidate.mirror);
 
-        let mut method_router = to_method_router(&path, &candidate.route.kind, &ctx);
+        let route_resources = &resources[candidate.index];
+        let mut method_router = to_method_router(
+            path,
+            &candidate.route.kind,
+            ctx,
+            route_resources.proxy_client.as_ref(),
+        );
+
+        // when a raw route's body is a template. This wraps the handler directly, so
+        // that only the route's own body is rendered
+        if candidate.template.is_some() {
+            method_router = method_router.layer(from_fn_with_state(params.clone(), template_mw));
+        }
+
+        // when a proxy should be mirrored to disk. This is the inner-most layer, so that
+        // the mirror only ever contains what the target sent
+        if let Some(mirror) = &candidate.mirror {
+            method_router = method_router.layer(from_fn_with_state(
+                (
+                    mirror.config().clone(),
+                    ctx.clone(),
+                    route_resources.mirror_globs.clone(),
+                ),
+                mirror_mw,
+            ));
+        }
 
         // when a proxy needs injections
         if let (Some(ref injections), true) = (&candidate.injections, candidate.will_proxy()) {
@@ -177,7 +235,38 @@ pub async fn try_one(
             ))
         }
 
+        if let Some(status) = &candidate.status {
+            method_router =
+                method_router.layer(map_response_with_state(status.status(), status_layer))
+        }
+
+        // The layers added last run first, so a request passes through auth, then chaos,
+        // then replay, before any of the layers above.
+
+        // when a proxy should be answered from a recording. Recordings capture responses
+        // after all the layers above, but without any chaos
+        if let (Some(replay), Some(store)) = (&candidate.replay, &route_resources.replay) {
+            method_router = method_router.layer(from_fn_with_state(
+                (replay.config().clone(), ctx.clone(), store.clone()),
+                replay_mw,
+            ))
+        }
+
+        // when a route should misbehave on purpose, which applies to recordings too
+        if let Some(chaos) = &candidate.chaos {
+            method_router = method_router.layer(from_fn_with_state(chaos.opts().clone(), chaos_mw));
+        }
+
+        // routes that need their own credentials
+        if let Some(auth) = &candidate.auth {
+            method_router = method_router.layer(from_fn_with_state(auth.opts().clone(), auth_mw));
+        }
+
         let req_clone = match candidate.route.kind {
+            RouteKind::Raw(_) if candidate.template.is_some() => {
+                let body = req_body.take().unwrap_or_else(Body::empty);
+                Request::from_parts(parts.clone(), body)
+            }
             RouteKind::Raw(_) => Request::from_parts(parts.clone(), Body::empty()),
             RouteKind::Proxy(_) => {
                 if let Some(body) = req_body.take() {
@@ -194,10 +283,18 @@ pub async fn try_one(
 
         match result {
             Ok(result) => match result.status().as_u16() {
-                // todo: this is way too simplistic, it should allow for a 404 being deliberately returned etc
+                // eg: `status: 404` is deliberate, so it's not a reason to keep looking
+                404 | 405 if result.extensions().get::<DeliberateStatus>().is_some() => {
+                    return result.into_response();
+                }
                 404 | 405 => {
                     if let Some(fallback) = &candidate.route.fallback {
-                        let method_router = to_method_router(&path, &fallback.kind, &ctx);
+                        let method_router = to_method_router(
+                            path,
+                            &fallback.kind,
+                            ctx,
+                            route_resources.fallback_proxy_client.as_ref(),
+                        );
                         let raw_fb = Request::from_parts(parts.clone(), Body::empty());
                         return method_router.oneshot(raw_fb).await.into_response();
                     }
@@ -222,7 +319,12 @@ pub async fn try_one(
     StatusCode::NOT_FOUND.into_response()
 }
 
-fn to_method_router(path: &str, route_kind: &RouteKind, ctx: &RuntimeCtx) -> MethodRouter {
+fn to_method_router(
+    path: &str,
+    route_kind: &RouteKind,
+    ctx: &RuntimeCtx,
+    proxy_client: Option<&ProxyClient>,
+) -> MethodRouter {
     match route_kind {
         RouteKind::Raw(raw) => any_service(serve_raw_one.with_state(raw.clone())),
         RouteKind::Proxy(proxy) => {
@@ -231,36 +333,32 @@ fn to_method_router(path: &str, route_kind: &RouteKind, ctx: &RuntimeCtx) -> Met
                 path: path.to_string(),
                 headers: proxy.proxy_headers.clone().unwrap_or_default(),
                 rewrite_kind: RewriteKind::from(proxy.rewrite_uri),
+                rewrite_links: proxy.rewrites_links(),
+                timeouts: proxy.timeouts.unwrap_or_default(),
+                retry: proxy.retry(),
+                client: proxy_client.cloned(),
             };
             let proxy_with_decompression = proxy_handler.layer(Extension(proxy_config.clone()));
             any(proxy_with_decompression)
         }
         RouteKind::Dir(dir_route) => {
             tracing::trace!(?dir_route);
-            match &dir_route.base {
-                Some(base_dir) => {
-                    tracing::trace!(
-                        "combining root: `{}` with given path: `{}`",
-                        base_dir.display(),
-                        dir_route.dir
-                    );
-                    get_service(ServeDir::new(base_dir.join(&dir_route.dir)))
-                }
-                None => {
-                    let pb = PathBuf::from(&dir_route.dir);
-                    if pb.is_file() {
-                        get_service(ServeFile::new(pb))
-                    } else if pb.is_absolute() {
-                        trace!("no root given, using `{}` directly", dir_route.dir);
-                        get_service(
-                            ServeDir::new(&dir_route.dir).append_index_html_on_directories(true),
-                        )
-                    } else {
-                        let joined = ctx.cwd().join(pb);
-                        trace!(?joined, "serving");
-                        get_service(ServeDir::new(joined).append_index_html_on_directories(true))
-                    }
+            let pb = PathBuf::from(&dir_route.dir);
+            if dir_route.base.is_none() && pb.is_file() {
+                return get_service(ServeFile::new(pb));
+            }
+            let root = dir_route.root(ctx.cwd());
+            trace!(?root, "serving");
+            let mut method_router =
+                get_service(ServeDir::new(&root).append_index_html_on_directories(true));
+            if dir_route.lists() {
+                method_router = method_router.layer(from_fn_with_state(root.clone(), listing_mw));
+            }
+            match dir_route.spa_index() {
+                Some(index) => {
+                    method_router.layer(from_fn_with_state(root.join(index), spa_fallback_mw))
                 }
+                None => method_router,
             }
         }
     }
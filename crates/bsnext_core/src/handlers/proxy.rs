@@ -1,18 +1,28 @@
+use crate::handlers::proxy_error::{proxy_error_response, ProxyFailure};
+use crate::servers_supervisor::get_servers_handler::IncomingEvents;
+use actix::Recipient;
 use anyhow::Context;
 use axum::body::Body;
 use axum::extract::Request;
 use axum::response::{IntoResponse, Response};
-use axum::routing::any;
 use axum::Extension;
+use bsnext_dto::external_events::ExternalEventsDTO;
+use bsnext_dto::{ProxyErrorKindDTO, ProxyWsDTO};
 use bsnext_guards::OuterUri;
+use bsnext_input::route::{ProxyTimeouts, RetryConfig};
+use bsnext_resp::rewrite_links::RewriteLinks;
+use http::header::{ACCEPT, CONNECTION, HOST, LOCATION, SET_COOKIE, UPGRADE};
 use http::uri::{Parts, PathAndQuery};
-use http::{HeaderName, HeaderValue, StatusCode, Uri};
+use http::{HeaderMap, HeaderName, HeaderValue, StatusCode, Uri};
+use http_body_util::BodyExt;
+use hyper::upgrade::OnUpgrade;
 use hyper_tls::HttpsConnector;
 use hyper_util::client::legacy::connect::HttpConnector;
 use hyper_util::client::legacy::Client;
+use hyper_util::rt::{TokioExecutor, TokioIo};
 use std::collections::BTreeMap;
 use std::str::FromStr;
-use tower::ServiceExt;
+use std::time::Duration;
 use tracing::{trace_span, Instrument};
 
 #[derive(Debug, Clone)]
@@ -21,6 +31,11 @@ pub struct ProxyConfig {
     pub path: String,
     pub headers: BTreeMap<String, String>,
     pub rewrite_kind: RewriteKind,
+    pub rewrite_links: bool,
+    pub timeouts: ProxyTimeouts,
+    pub retry: RetryConfig,
+    /// Set when the route has its own connect timeout, otherwise the server's client is used
+    pub client: Option<ProxyClient>,
 }
 
 #[derive(Debug, Clone)]
@@ -95,8 +110,30 @@ pub async fn proxy_handler(
 
     let (parts, body) = req.into_parts();
 
+    let local_host = parts
+        .headers
+        .get(HOST)
+        .and_then(|h| h.to_str().ok())
+        .map(ToOwned::to_owned);
+    let accepts_html = parts
+        .headers
+        .get(ACCEPT)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.contains("text/html"));
     let mut req = Request::from_parts(parts, body);
 
+    // websocket (or any other) upgrades are tunnelled, once the target agrees to switch protocols
+    let client_upgrade = if is_upgrade(req.headers()) {
+        req.extensions_mut().remove::<OnUpgrade>()
+    } else {
+        None
+    };
+    let events = req.extensions().get::<Recipient<IncomingEvents>>().cloned();
+    let ws_dto = ProxyWsDTO {
+        path: outer_uri.path().to_string(),
+        target: parsed.to_string(),
+    };
+
     let target = match (parsed.host(), parsed.port()) {
         (Some(host), Some(port)) => format!("{host}:{port}"),
         (Some(host), None) => host.to_owned(),
@@ -108,7 +145,7 @@ pub async fn proxy_handler(
     req.headers_mut().insert("host", host_header_value);
     req.headers_mut().remove("referer");
 
-    for (k, v) in config.headers {
+    for (k, v) in &config.headers {
         match (
             HeaderName::from_bytes(k.as_bytes()),
             HeaderValue::from_bytes(v.as_bytes()),
@@ -123,33 +160,205 @@ pub async fn proxy_handler(
         }
     }
 
-    let sv2 = any(serve_one_proxy_req);
-    Ok(sv2
-        .oneshot(req)
-        .instrument(span.clone())
-        .await
-        .into_response())
+    let mut res = match serve_proxy_req(req, &config).instrument(span.clone()).await {
+        Ok(res) => res,
+        Err(failure) => {
+            let dto = failure.into_dto(outer_uri.path(), &ws_dto.target);
+            publish(&events, ExternalEventsDTO::ProxyError(dto.clone()));
+            return Ok(proxy_error_response(dto, accepts_html));
+        }
+    };
+
+    // bodies are handled in `response_modifications_layer`, but headers are re-written here
+    if let (true, Some(local_host), Some(authority)) =
+        (config.rewrite_links, local_host, target_uri.authority())
+    {
+        let rewrite = RewriteLinks::new(authority.as_str(), local_host);
+        rewrite_headers(&rewrite, res.headers_mut());
+    }
+
+    if let (StatusCode::SWITCHING_PROTOCOLS, Some(client_upgrade)) = (res.status(), client_upgrade)
+    {
+        let target_upgrade = hyper::upgrade::on(&mut res);
+        tokio::spawn(tunnel(client_upgrade, target_upgrade, events, ws_dto));
+    }
+
+    Ok(res)
+}
+
+fn is_upgrade(headers: &HeaderMap) -> bool {
+    let connection_upgrade = headers
+        .get_all(CONNECTION)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .any(|v| v.trim().eq_ignore_ascii_case("upgrade"));
+    connection_upgrade && headers.contains_key(UPGRADE)
+}
+
+///
+/// Copies bytes in both directions until either side closes.
+/// Both ends of the tunnel are reported to the event stream
+///
+async fn tunnel(
+    client: OnUpgrade,
+    target: OnUpgrade,
+    events: Option<Recipient<IncomingEvents>>,
+    dto: ProxyWsDTO,
+) {
+    let (client, target) = match tokio::try_join!(client, target) {
+        Ok(upgraded) => upgraded,
+        Err(e) => {
+            tracing::error!(?e, "could not upgrade proxied connection");
+            return;
+        }
+    };
+
+    publish(&events, ExternalEventsDTO::ProxyWsOpened(dto.clone()));
+
+    let mut client = TokioIo::new(client);
+    let mut target = TokioIo::new(target);
+    match tokio::io::copy_bidirectional(&mut client, &mut target).await {
+        Ok((to_target, to_client)) => {
+            tracing::trace!(to_target, to_client, "proxied connection closed")
+        }
+        Err(e) => tracing::debug!(?e, "proxied connection closed with an error"),
+    }
+
+    publish(&events, ExternalEventsDTO::ProxyWsClosed(dto));
+}
+
+fn publish(events: &Option<Recipient<IncomingEvents>>, evt: ExternalEventsDTO) {
+    if let Some(events) = events {
+        events.do_send(IncomingEvents::External(evt));
+    }
 }
 
+fn rewrite_headers(rewrite: &RewriteLinks, headers: &mut HeaderMap) {
+    let location = headers
+        .get(LOCATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| rewrite.rewrite_location(v))
+        .and_then(|v| HeaderValue::from_str(&v).ok());
+    if let Some(location) = location {
+        headers.insert(LOCATION, location);
+    }
+
+    let cookies = headers
+        .get_all(SET_COOKIE)
+        .iter()
+        .map(|v| {
+            v.to_str()
+                .ok()
+                .and_then(|c| rewrite.rewrite_set_cookie(c))
+                .and_then(|c| HeaderValue::from_str(&c).ok())
+                .unwrap_or_else(|| v.clone())
+        })
+        .collect::<Vec<_>>();
+    if !cookies.is_empty() {
+        headers.remove(SET_COOKIE);
+        for cookie in cookies {
+            headers.append(SET_COOKIE, cookie);
+        }
+    }
+}
+
+pub type ProxyClient = Client<HttpsConnector<HttpConnector>, Body>;
+
+/// Connect timeouts belong to the connector, so they need a client of their own
+pub fn client_with_connect_timeout(connect_ms: u64) -> ProxyClient {
+    let mut http = HttpConnector::new();
+    http.enforce_http(false);
+    http.set_connect_timeout(Some(Duration::from_millis(connect_ms)));
+    Client::builder(TokioExecutor::new()).build(HttpsConnector::new_with_connector(http))
+}
+
+///
+/// Send the request to the target, trying again (with backoff) when configured.
+///
+/// Requests that never reached the target are always safe to retry, others are only
+/// retried when the method is idempotent
+///
 #[tracing::instrument(skip_all)]
-async fn serve_one_proxy_req(req: Request) -> Response {
-    tracing::trace!("serve_one_proxy_req {}", req.uri().to_string());
-    let client = {
-        req.extensions()
-            .get::<Client<HttpsConnector<HttpConnector>, Body>>()
+async fn serve_proxy_req(req: Request, config: &ProxyConfig) -> Result<Response, ProxyFailure> {
+    tracing::trace!("serve_proxy_req {}", req.uri().to_string());
+    let client = match &config.client {
+        Some(client) => client.clone(),
+        None => req
+            .extensions()
+            .get::<ProxyClient>()
             .expect("must have a client, move this to an extractor?")
+            .clone(),
     };
     tracing::trace!(req.headers = ?req.headers());
     tracing::trace!(req.method = ?req.method());
-    let client_c = client.clone();
-    let resp = client_c
-        .request(req)
+
+    if config.retry.count == 0 {
+        return send_once(&client, req, config)
+            .await
+            .map_err(|e| e.attempts(1));
+    }
+
+    // the body must be buffered, so that it can be sent more than once
+    let (parts, body) = req.into_parts();
+    let body = body
+        .collect()
         .await
-        .map_err(|_| StatusCode::BAD_REQUEST)
-        .into_response();
-    tracing::trace!(resp.status = resp.status().as_u16());
-    tracing::trace!(resp.headers = ?resp.headers());
-    resp
+        .map(|collected| collected.to_bytes())
+        .map_err(|e| ProxyFailure::new(ProxyErrorKindDTO::Other, e.to_string()))?;
+
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        let req = Request::from_parts(parts.clone(), Body::from(body.clone()));
+        let failure = match send_once(&client, req, config).await {
+            Ok(res) => return Ok(res),
+            Err(failure) => failure,
+        };
+        let can_retry = failure.before_sending() || parts.method.is_idempotent();
+        if attempt > config.retry.count || !can_retry {
+            return Err(failure.attempts(attempt));
+        }
+        let backoff = config
+            .retry
+            .backoff_ms
+            .saturating_mul(1 << (attempt - 1).min(16));
+        tracing::debug!(attempt, backoff, ?failure.kind, "retrying proxy request");
+        tokio::time::sleep(Duration::from_millis(backoff)).await;
+    }
+}
+
+async fn send_once(
+    client: &ProxyClient,
+    req: Request,
+    config: &ProxyConfig,
+) -> Result<Response, ProxyFailure> {
+    let pending = client.request(req);
+    let result = match config.timeouts.response_ms {
+        None => pending.await,
+        Some(response_ms) => {
+            match tokio::time::timeout(Duration::from_millis(response_ms), pending).await {
+                Ok(result) => result,
+                Err(_) => {
+                    return Err(ProxyFailure::new(
+                        ProxyErrorKindDTO::ResponseTimeout,
+                        format!("no response after {response_ms}ms"),
+                    ))
+                }
+            }
+        }
+    };
+    match result {
+        Ok(res) => {
+            tracing::trace!(resp.status = res.status().as_u16());
+            tracing::trace!(resp.headers = ?res.headers());
+            Ok(res.into_response())
+        }
+        Err(e) => {
+            tracing::debug!(?e, "proxy target could not be reached");
+            Err(ProxyFailure::from(e))
+        }
+    }
 }
 
 struct IntoTarget<'a> {
@@ -292,6 +501,10 @@ mod tests {
                 path: String::new(),
                 headers: BTreeMap::new(),
                 rewrite_kind: tc.rewrite,
+                rewrite_links: false,
+                timeouts: Default::default(),
+                retry: Default::default(),
+                client: None,
             };
 
             let args = IntoTarget {
@@ -0,0 +1,117 @@
+use axum::response::{IntoResponse, Response};
+use bsnext_client::proxy_error_html;
+use bsnext_dto::{ProxyErrorDTO, ProxyErrorKindDTO};
+use http::header::CONTENT_TYPE;
+use http::{HeaderValue, StatusCode};
+use mime_guess::mime;
+use std::error::Error;
+use std::io;
+
+/// Why the proxy target could not give a response
+#[derive(Debug)]
+pub struct ProxyFailure {
+    pub kind: ProxyErrorKindDTO,
+    pub message: String,
+    pub attempts: u32,
+}
+
+impl ProxyFailure {
+    pub fn new(kind: ProxyErrorKindDTO, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            attempts: 0,
+        }
+    }
+
+    pub fn attempts(self, attempts: u32) -> Self {
+        Self { attempts, ..self }
+    }
+
+    /// When the connection was never made, the target cannot have seen the request
+    pub fn before_sending(&self) -> bool {
+        matches!(
+            self.kind,
+            ProxyErrorKindDTO::Connect | ProxyErrorKindDTO::ConnectTimeout
+        )
+    }
+
+    pub fn into_dto(self, path: &str, target: &str) -> ProxyErrorDTO {
+        ProxyErrorDTO {
+            path: path.to_string(),
+            target: target.to_string(),
+            kind: self.kind,
+            message: self.message,
+            attempts: self.attempts,
+        }
+    }
+}
+
+impl From<hyper_util::client::legacy::Error> for ProxyFailure {
+    fn from(e: hyper_util::client::legacy::Error) -> Self {
+        let kind = match (e.is_connect(), is_timeout(&e)) {
+            (true, true) => ProxyErrorKindDTO::ConnectTimeout,
+            (true, false) => ProxyErrorKindDTO::Connect,
+            (false, _) => ProxyErrorKindDTO::Other,
+        };
+        Self::new(kind, error_chain(&e))
+    }
+}
+
+fn is_timeout(e: &(dyn Error + 'static)) -> bool {
+    let mut next = Some(e);
+    while let Some(e) = next {
+        if e.downcast_ref::<io::Error>()
+            .is_some_and(|io| io.kind() == io::ErrorKind::TimedOut)
+        {
+            return true;
+        }
+        next = e.source();
+    }
+    false
+}
+
+/// The top-level client errors are vague, like `client error (Connect)`, so include every cause
+fn error_chain(e: &(dyn Error + 'static)) -> String {
+    let mut messages = vec![e.to_string()];
+    let mut next = e.source();
+    while let Some(e) = next {
+        messages.push(e.to_string());
+        next = e.source();
+    }
+    messages.join(": ")
+}
+
+///
+/// Browsers get the branded error page, everything else gets a plain-text summary.
+///
+/// Timeouts are a `504`, any other failure is a `502`
+///
+pub fn proxy_error_response(dto: ProxyErrorDTO, accepts_html: bool) -> Response {
+    let status = match dto.kind {
+        ProxyErrorKindDTO::ConnectTimeout | ProxyErrorKindDTO::ResponseTimeout => {
+            StatusCode::GATEWAY_TIMEOUT
+        }
+        ProxyErrorKindDTO::Connect | ProxyErrorKindDTO::Other => StatusCode::BAD_GATEWAY,
+    };
+    if accepts_html {
+        let markup = proxy_error_html("/__bs_assets/ui/", &dto);
+        (
+            status,
+            [(
+                CONTENT_TYPE,
+                HeaderValue::from_static(mime::TEXT_HTML_UTF_8.as_ref()),
+            )],
+            markup,
+        )
+            .into_response()
+    } else {
+        let text = format!(
+            "could not reach {} ({}): {}",
+            dto.target,
+            dto.kind.as_str(),
+            dto.message
+        );
+        (status, text).into_response()
+    }
+}
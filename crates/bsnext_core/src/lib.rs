@@ -3,18 +3,24 @@ pub mod server;
 pub mod servers_supervisor;
 
 mod body_match;
+pub mod clients;
+mod dir_listing;
 pub mod dir_loader;
 pub mod export;
 pub mod handler_stack;
 pub mod handlers;
+mod match_form;
 mod match_json;
 pub mod meta;
 pub mod not_found;
 pub mod panic_handler;
 pub mod proxy_loader;
 pub mod raw_loader;
+pub mod record;
+pub mod route_auth;
 mod route_cache;
 mod route_candidate;
+mod route_chaos;
 mod route_compress;
 mod route_cors;
 mod route_delay;
@@ -23,8 +29,13 @@ pub mod route_injections;
 mod route_marker;
 mod route_match;
 mod route_mirror;
+mod route_replay;
 mod route_res_headers;
+mod route_resources;
+mod route_status;
+mod route_template;
 pub mod runtime_ctx;
 pub mod serve_dir;
 pub mod shared_args;
+mod spa_fallback;
 pub mod ws;
@@ -0,0 +1,121 @@
+use bytes::Bytes;
+
+/// Fields from an `application/x-www-form-urlencoded` body, in order
+pub fn parse_form(bytes: &Bytes) -> Vec<(String, String)> {
+    match serde_urlencoded::from_bytes::<Vec<(String, String)>>(bytes) {
+        Ok(fields) => fields,
+        Err(err) => {
+            tracing::error!(?err, "could not read form body");
+            vec![]
+        }
+    }
+}
+
+///
+/// Fields from a `multipart/form-data` body, in order.
+///
+/// Text fields give their content, whilst file uploads give their filename - the file
+/// content itself is never matched against
+///
+pub fn parse_multipart(bytes: &Bytes, boundary: &str) -> Vec<(String, String)> {
+    let delimiter = format!("--{boundary}");
+    let mut fields = vec![];
+    let parts = split(bytes, delimiter.as_bytes()).into_iter().skip(1);
+
+    for part in parts {
+        // the closing delimiter is followed by `--`
+        if part.starts_with(b"--") {
+            break;
+        }
+        let part = part.strip_prefix(b"\r\n").unwrap_or(part);
+        let part = part.strip_suffix(b"\r\n").unwrap_or(part);
+        let Some(split_at) = find(part, b"\r\n\r\n") else {
+            continue;
+        };
+        let (head, content) = (&part[..split_at], &part[split_at + 4..]);
+        let head = String::from_utf8_lossy(head);
+        let Some(disposition) = head.lines().find_map(|line| {
+            line.split_once(':')
+                .filter(|(name, _)| name.trim().eq_ignore_ascii_case("content-disposition"))
+                .map(|(_, value)| value.to_string())
+        }) else {
+            continue;
+        };
+        let Some(name) = disposition_param(&disposition, "name") else {
+            continue;
+        };
+        let value = match disposition_param(&disposition, "filename") {
+            Some(filename) => filename,
+            None => String::from_utf8_lossy(content).to_string(),
+        };
+        fields.push((name, value));
+    }
+
+    fields
+}
+
+fn disposition_param(disposition: &str, wanted: &str) -> Option<String> {
+    disposition
+        .split(';')
+        .filter_map(|param| param.trim().split_once('='))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case(wanted))
+        .map(|(_, value)| value.trim().trim_matches('"').to_string())
+}
+
+fn split<'a>(haystack: &'a [u8], needle: &[u8]) -> Vec<&'a [u8]> {
+    let mut out = vec![];
+    let mut rest = haystack;
+    while let Some(index) = find(rest, needle) {
+        out.push(&rest[..index]);
+        rest = &rest[index + needle.len()..];
+    }
+    out.push(rest);
+    out
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_multipart() {
+        let body = "preamble\r\n\
+            --XyZ\r\n\
+            Content-Disposition: form-data; name=\"title\"\r\n\
+            \r\n\
+            hello world\r\n\
+            --XyZ\r\n\
+            Content-Disposition: form-data; name=\"avatar\"; filename=\"me.png\"\r\n\
+            Content-Type: image/png\r\n\
+            \r\n\
+            \u{1}\u{2}\u{3}\r\n\
+            --XyZ--\r\n";
+        let fields = parse_multipart(&Bytes::from(body), "XyZ");
+        assert_eq!(
+            fields,
+            vec![
+                ("title".to_string(), "hello world".to_string()),
+                ("avatar".to_string(), "me.png".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_form() {
+        let fields = parse_form(&Bytes::from_static(b"name=kittie&tags=a+b&tags=c%26d"));
+        assert_eq!(
+            fields,
+            vec![
+                ("name".to_string(), "kittie".to_string()),
+                ("tags".to_string(), "a b".to_string()),
+                ("tags".to_string(), "c&d".to_string()),
+            ]
+        );
+    }
+}
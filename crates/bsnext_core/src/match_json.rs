@@ -1,42 +1,33 @@
+use crate::body_match::BodyMatch;
+use crate::match_form::{parse_form, parse_multipart};
 use axum::body::Body;
 use axum::extract::Request;
 use axum::response::Response;
 use bsnext_guards::route_guard::RouteGuard;
+use bsnext_input::json_path::select;
 use bsnext_input::route::{ListOrSingle, Route};
-use bsnext_input::when_guard::{JsonGuard, JsonPropGuard, WhenBodyGuard};
+use bsnext_input::when_guard::{JsonGuard, JsonPropGuard, NamedHasGuard, WhenBodyGuard};
 use bytes::Bytes;
-use http::header::CONTENT_TYPE;
-use http::{Method, Uri};
+use http::Uri;
 use serde_json::Value;
 use std::ops::ControlFlow;
 use tracing::trace;
 
-pub struct NeedsJsonGuard<'a>(pub &'a ListOrSingle<WhenBodyGuard>);
-impl RouteGuard for NeedsJsonGuard<'_> {
-    #[tracing::instrument(skip_all, name = "NeedsJsonGuard.accept_req")]
+pub struct NeedsBodyGuard<'a>(pub &'a ListOrSingle<WhenBodyGuard>);
+impl RouteGuard for NeedsBodyGuard<'_> {
+    #[tracing::instrument(skip_all, name = "NeedsBodyGuard.accept_req")]
     fn accept_req(&self, req: &Request, _outer_uri: &Uri) -> bool {
         let exec = match self.0 {
-            ListOrSingle::WhenOne(WhenBodyGuard::Json { .. }) => true,
-            ListOrSingle::WhenMany(items) => items
-                .iter()
-                .any(|item| matches!(item, WhenBodyGuard::Json { .. })),
-            _ => false,
+            ListOrSingle::WhenOne(one) => one.needs_body(),
+            ListOrSingle::WhenMany(items) => items.iter().any(WhenBodyGuard::needs_body),
         };
         trace!(?exec);
         if !exec {
             return false;
         }
-        let headers = req.headers();
-        let method = req.method();
-        let json = headers.get(CONTENT_TYPE).is_some_and(|header| {
-            header
-                .to_str()
-                .ok()
-                .map(|h| h.contains("application/json"))
-                .unwrap_or(false)
-        });
-        trace!(?json, ?method, ?headers);
-        json && method == Method::POST
+        let readable = BodyMatch::for_req(req.method(), req.headers());
+        trace!(?readable, method = ?req.method(), headers = ?req.headers());
+        readable.is_some()
     }
 
     fn accept_res<T>(&self, _res: &Response<T>, _outer_uri: &Uri) -> bool {
@@ -44,61 +35,86 @@ impl RouteGuard for NeedsJsonGuard<'_> {
     }
 }
 
-impl NeedsJsonGuard<'_> {
-    pub fn match_body(&self, value: &Value) -> bool {
+/// A request body, read according to its content-type
+#[derive(Debug)]
+pub enum BodyValue {
+    Json(Value),
+    Form(Vec<(String, String)>),
+    Multipart(Vec<(String, String)>),
+}
+
+impl NeedsBodyGuard<'_> {
+    pub fn match_body(&self, value: &BodyValue) -> bool {
         let matches: Vec<(&'_ WhenBodyGuard, bool)> = match self.0 {
-            ListOrSingle::WhenOne(one) => vec![(one, match_one_json(value, one))],
+            ListOrSingle::WhenOne(one) => vec![(one, match_one_body(value, one))],
             ListOrSingle::WhenMany(many) => many
                 .iter()
-                .map(|guard| (guard, match_one_json(value, guard)))
+                .map(|guard| (guard, match_one_body(value, guard)))
                 .collect(),
         };
         matches.iter().all(|(_item, result)| *result)
     }
 }
 
-fn match_one_json(value: &Value, when_body_guard: &WhenBodyGuard) -> bool {
-    match when_body_guard {
-        WhenBodyGuard::Json { json } => match json {
-            JsonGuard::ArrayLast { items, last } => match value.pointer(items) {
-                Some(Value::Array(arr)) => match arr.last() {
-                    None => false,
-                    Some(last_item) => last.iter().all(|prop_guard| match prop_guard {
-                        JsonPropGuard::PathIs { path, is } => match last_item.pointer(path) {
-                            Some(Value::String(val_string)) => val_string == is,
-                            _ => false,
-                        },
-                        JsonPropGuard::PathHas { path, has } => match last_item.pointer(path) {
-                            Some(Value::String(val_string)) => val_string.contains(has),
-                            _ => false,
-                        },
-                    }),
-                },
-                _ => false,
-            },
-            JsonGuard::ArrayAny { items, any } => match value.pointer(items) {
-                Some(Value::Array(arr)) if arr.is_empty() => false,
-                Some(Value::Array(val)) => val
-                    .iter()
-                    .any(|val| any.iter().any(|prop_guard| match_prop(val, prop_guard))),
-                _ => false,
-            },
-            JsonGuard::ArrayAll { items, all } => match value.pointer(items) {
-                Some(Value::Array(arr)) if arr.is_empty() => false,
-                Some(Value::Array(arr)) => arr
+fn match_one_body(value: &BodyValue, when_body_guard: &WhenBodyGuard) -> bool {
+    match (when_body_guard, value) {
+        (WhenBodyGuard::Json { json }, BodyValue::Json(value)) => match_one_json(value, json),
+        (WhenBodyGuard::Form { form }, BodyValue::Form(fields)) => match_field(fields, form),
+        (WhenBodyGuard::Multipart { multipart }, BodyValue::Multipart(fields)) => {
+            match_field(fields, multipart)
+        }
+        (WhenBodyGuard::Any { any }, _) => any.iter().any(|guard| match_one_body(value, guard)),
+        (WhenBodyGuard::All { all }, _) => all.iter().all(|guard| match_one_body(value, guard)),
+        (WhenBodyGuard::Not { not }, _) => !match_one_body(value, not),
+        // a guard for a different kind of body, or `Never`
+        _ => false,
+    }
+}
+
+fn match_field(fields: &[(String, String)], guard: &NamedHasGuard) -> bool {
+    fields
+        .iter()
+        .filter(|(name, _)| *name == guard.name)
+        .any(|(_, value)| guard.guard.test(value))
+}
+
+fn match_one_json(value: &Value, json: &JsonGuard) -> bool {
+    match json {
+        JsonGuard::ArrayLast { items, last } => match first(value, items) {
+            Some(Value::Array(arr)) => match arr.last() {
+                None => false,
+                Some(last_item) => last
                     .iter()
-                    .any(|one_val| all.iter().all(|guard| match_prop(one_val, guard))),
-                _ => false,
+                    .all(|prop_guard| match_prop(last_item, prop_guard)),
             },
-            JsonGuard::Path(pg) => match_prop(value, pg),
+            _ => false,
+        },
+        JsonGuard::ArrayAny { items, any } => match first(value, items) {
+            Some(Value::Array(arr)) if arr.is_empty() => false,
+            Some(Value::Array(val)) => val
+                .iter()
+                .any(|val| any.iter().any(|prop_guard| match_prop(val, prop_guard))),
+            _ => false,
+        },
+        JsonGuard::ArrayAll { items, all } => match first(value, items) {
+            Some(Value::Array(arr)) if arr.is_empty() => false,
+            Some(Value::Array(arr)) => arr
+                .iter()
+                .any(|one_val| all.iter().all(|guard| match_prop(one_val, guard))),
+            _ => false,
         },
-        WhenBodyGuard::Never => false,
+        JsonGuard::Path(pg) => match_prop(value, pg),
     }
 }
 
-pub async fn match_json_body(
+fn first<'a>(value: &'a Value, selector: &str) -> Option<&'a Value> {
+    select(value, selector).into_iter().next()
+}
+
+pub async fn match_body(
     body: &mut Option<Body>,
     route: &Route,
+    kind: &BodyMatch,
 ) -> (Option<Body>, ControlFlow<()>) {
     use http_body_util::BodyExt;
     if let Some(inner_body) = body.take() {
@@ -113,29 +129,35 @@ pub async fn match_json_body(
 
         trace!("did collect {} bytes", bytes.len());
 
-        match serde_json::from_slice(bytes.iter().as_slice()) {
-            Ok(value) => {
-                let result = route
-                    .when_body
-                    .as_ref()
-                    .map(|when_body| NeedsJsonGuard(when_body).match_body(&value));
-                if result.is_some_and(|res| !res) {
-                    trace!("ignoring, `when_body` was present, but didn't match the guards");
-                    trace!("restoring body from clone");
-                    (Some(Body::from(bytes)), ControlFlow::Break(()))
-                } else {
-                    if result.is_some() {
-                        trace!("✅ when_body produced a valid match");
-                    } else {
-                        trace!("when_body didn't produce a value");
-                    }
-                    (Some(Body::from(bytes)), ControlFlow::Continue(()))
+        let value = match kind {
+            BodyMatch::Json => match serde_json::from_slice(bytes.iter().as_slice()) {
+                Ok(value) => BodyValue::Json(value),
+                Err(err) => {
+                    tracing::error!(?err, "could not deserialize into Value");
+                    return (Some(Body::from(bytes)), ControlFlow::Continue(()));
                 }
+            },
+            BodyMatch::Form => BodyValue::Form(parse_form(&bytes)),
+            BodyMatch::Multipart { boundary } => {
+                BodyValue::Multipart(parse_multipart(&bytes, boundary))
             }
-            Err(err) => {
-                tracing::error!(?err, "could not deserialize into Value");
-                (Some(Body::from(bytes)), ControlFlow::Continue(()))
+        };
+
+        let result = route
+            .when_body
+            .as_ref()
+            .map(|when_body| NeedsBodyGuard(when_body).match_body(&value));
+        if result.is_some_and(|res| !res) {
+            trace!("ignoring, `when_body` was present, but didn't match the guards");
+            trace!("restoring body from clone");
+            (Some(Body::from(bytes)), ControlFlow::Break(()))
+        } else {
+            if result.is_some() {
+                trace!("✅ when_body produced a valid match");
+            } else {
+                trace!("when_body didn't produce a value");
             }
+            (Some(Body::from(bytes)), ControlFlow::Continue(()))
         }
     } else {
         trace!("could not .take() body");
@@ -144,14 +166,35 @@ pub async fn match_json_body(
 }
 
 pub fn match_prop(value: &Value, prop_guard: &JsonPropGuard) -> bool {
+    let selected = select(value, prop_guard.path());
     match prop_guard {
-        JsonPropGuard::PathIs { path, is } => match value.pointer(path) {
-            Some(Value::String(val_string)) => val_string == is,
-            _ => false,
-        },
-        JsonPropGuard::PathHas { path, has } => match value.pointer(path) {
-            Some(Value::String(val_string)) => val_string.contains(has),
+        JsonPropGuard::PathIs { is, .. } => selected
+            .iter()
+            .any(|v| scalar_string(v).is_some_and(|s| s == *is)),
+        JsonPropGuard::PathHas { has, .. } => selected.iter().any(|v| match v {
+            Value::String(val_string) => val_string.contains(has),
             _ => false,
+        }),
+        JsonPropGuard::PathExists { exists, .. } => selected.is_empty() != *exists,
+        JsonPropGuard::PathMatches { matches, .. } => match matches.regex() {
+            Ok(re) => selected
+                .iter()
+                .any(|v| scalar_string(v).is_some_and(|s| re.is_match(&s))),
+            Err(_) => false,
         },
+        JsonPropGuard::PathNumber { number, .. } => selected
+            .iter()
+            .filter_map(|v| v.as_f64())
+            .any(|n| number.test(n)),
+    }
+}
+
+/// Numbers and booleans are compared by how they'd be written in JSON
+fn scalar_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
     }
 }
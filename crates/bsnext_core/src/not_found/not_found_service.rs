@@ -8,6 +8,7 @@ use http::{HeaderValue, StatusCode};
 use mime_guess::mime;
 
 use crate::handlers::proxy::AnyAppError;
+use crate::route_status::DeliberateStatus;
 
 use bsnext_client::html_with_base;
 use tracing::{span, Level};
@@ -19,7 +20,7 @@ pub async fn not_found_loader(req: Request, next: Next) -> Result<Response, AnyA
     tracing::trace!("not_found->");
     let r = next.run(req).await;
     tracing::trace!("<-not_found");
-    if r.status().as_u16() != 404 {
+    if r.status().as_u16() != 404 || r.extensions().get::<DeliberateStatus>().is_some() {
         return Ok(r);
     };
 
@@ -2,7 +2,7 @@ use axum::extract::{Request, State};
 use axum::middleware::Next;
 use axum::response::{Html, IntoResponse, Response, Sse};
 use axum::{Extension, Json};
-use http::header::CONTENT_TYPE;
+use http::header::{CONTENT_TYPE, LOCATION};
 use std::convert::Infallible;
 use std::fs;
 
@@ -35,6 +35,15 @@ pub async fn raw_resp_for(outer_uri: OuterUri, route: &RawRoute) -> impl IntoRes
         }
         RawRoute::Json { json } => Json(&json.0).into_response(),
         RawRoute::Raw { raw } => text_asset_response(uri.path(), raw).into_response(),
+        RawRoute::Redirect { redirect } => {
+            let status = StatusCode::from_u16(redirect.status()).unwrap_or_else(|err| {
+                tracing::error!(?err, "invalid redirect status, using 302");
+                StatusCode::FOUND
+            });
+            (status, [(LOCATION, redirect.to.clone())]).into_response()
+        }
+        RawRoute::NoContent { no_content: true } => StatusCode::NO_CONTENT.into_response(),
+        RawRoute::NoContent { no_content: false } => StatusCode::OK.into_response(),
         RawRoute::Sse {
             sse: SseOpts { body, throttle_ms },
         } => {
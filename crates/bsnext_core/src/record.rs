@@ -0,0 +1,419 @@
+use crate::server::state::ServerState;
+use axum::body::Body;
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::Response;
+use base64::Engine;
+use bsnext_input::server_config::Scheme;
+use bytes::Bytes;
+use chrono::{DateTime, SecondsFormat, Utc};
+use http::header::{CONTENT_TYPE, HOST, LOCATION};
+use http::{HeaderMap, Method, StatusCode, Uri, Version};
+use http_body_util::BodyExt;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+/// Only the most recent entries are kept, so that a long-running server doesn't grow forever
+const MAX_ENTRIES: usize = 1000;
+
+#[derive(Debug)]
+pub struct Recorder {
+    enabled: AtomicBool,
+    scheme: Scheme,
+    entries: RwLock<VecDeque<RecordedEntry>>,
+    /// Set once the oldest entries start being dropped, so that it's only reported once
+    dropping: AtomicBool,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordedEntry {
+    pub started: DateTime<Utc>,
+    pub duration: Duration,
+    pub method: Method,
+    pub url: String,
+    pub version: Version,
+    pub req_headers: HeaderMap,
+    pub req_body: Bytes,
+    pub status: StatusCode,
+    pub res_headers: HeaderMap,
+    /// `None` for streaming responses (like `text/event-stream`), since they are never buffered
+    pub res_body: Option<Bytes>,
+}
+
+impl Recorder {
+    pub fn new(enabled: bool, scheme: Scheme) -> Self {
+        Self {
+            enabled: AtomicBool::new(enabled),
+            scheme,
+            entries: Default::default(),
+            dropping: AtomicBool::new(false),
+        }
+    }
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed)
+    }
+    pub async fn push(&self, entry: RecordedEntry) {
+        let mut entries = self.entries.write().await;
+        if entries.len() == MAX_ENTRIES {
+            entries.pop_front();
+            if !self.dropping.swap(true, Ordering::Relaxed) {
+                tracing::warn!(
+                    "recorded {MAX_ENTRIES} requests, older entries will now be dropped from the HAR"
+                );
+            }
+        }
+        entries.push_back(entry);
+    }
+    pub async fn entries(&self) -> Vec<RecordedEntry> {
+        self.entries.read().await.iter().cloned().collect()
+    }
+    pub async fn clear(&self) {
+        self.entries.write().await.clear();
+        self.dropping.store(false, Ordering::Relaxed);
+    }
+    pub async fn to_har(&self) -> Har {
+        Har::from_entries(&self.entries().await)
+    }
+}
+
+/// Requests only carry the path, so the full url is re-created from the `host` header
+pub fn full_url(scheme: Scheme, headers: &HeaderMap, uri: &Uri) -> String {
+    let host = headers
+        .get(HOST)
+        .and_then(|h| h.to_str().ok())
+        .or(uri.authority().map(|a| a.as_str()))
+        .unwrap_or("localhost");
+    let pq = uri.path_and_query().map(|pq| pq.as_str()).unwrap_or("/");
+    format!("{scheme}://{host}{pq}")
+}
+
+///
+/// Captures every request/response pair that goes through the dynamic routes,
+/// when `record` is enabled for the server
+///
+pub async fn record_layer(
+    State(app): State<Arc<ServerState>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let recorder = app.recorder.clone();
+    if !recorder.is_enabled() {
+        return next.run(req).await;
+    }
+
+    let started = Utc::now();
+    let start = Instant::now();
+    let (parts, body) = req.into_parts();
+    let req_body = match body.collect().await {
+        Ok(collected) => collected.to_bytes(),
+        Err(e) => {
+            tracing::error!(?e, "could not buffer request body for recording");
+            Bytes::new()
+        }
+    };
+
+    let method = parts.method.clone();
+    let url = full_url(recorder.scheme, &parts.headers, &parts.uri);
+    let version = parts.version;
+    let req_headers = parts.headers.clone();
+    let req = Request::from_parts(parts, Body::from(req_body.clone()));
+
+    let res = next.run(req).await;
+    let (parts, body) = res.into_parts();
+
+    let (res_body, body) = if is_event_stream(&parts.headers) {
+        (None, body)
+    } else {
+        match body.collect().await {
+            Ok(collected) => {
+                let bytes = collected.to_bytes();
+                (Some(bytes.clone()), Body::from(bytes))
+            }
+            Err(e) => {
+                tracing::error!(?e, "could not buffer response body for recording");
+                (None, Body::empty())
+            }
+        }
+    };
+
+    recorder
+        .push(RecordedEntry {
+            started,
+            duration: start.elapsed(),
+            method,
+            url,
+            version,
+            req_headers,
+            req_body,
+            status: parts.status,
+            res_headers: parts.headers.clone(),
+            res_body,
+        })
+        .await;
+
+    Response::from_parts(parts, body)
+}
+
+fn is_event_stream(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("text/event-stream"))
+}
+
+///
+/// The subset of HAR 1.2 that we produce and read back - see http://www.softwareishard.com/blog/har-12-spec/
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct Har {
+    pub log: HarLog,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct HarLog {
+    pub version: String,
+    pub creator: HarCreator,
+    pub entries: Vec<HarEntry>,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct HarCreator {
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct HarEntry {
+    pub started_date_time: String,
+    pub time: f64,
+    pub request: HarRequest,
+    pub response: HarResponse,
+    pub cache: serde_json::Value,
+    pub timings: HarTimings,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct HarRequest {
+    pub method: String,
+    pub url: String,
+    pub http_version: String,
+    pub cookies: Vec<HarNameValue>,
+    pub headers: Vec<HarNameValue>,
+    pub query_string: Vec<HarNameValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_data: Option<HarPostData>,
+    pub headers_size: i64,
+    pub body_size: i64,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct HarResponse {
+    pub status: u16,
+    pub status_text: String,
+    pub http_version: String,
+    pub cookies: Vec<HarNameValue>,
+    pub headers: Vec<HarNameValue>,
+    pub content: HarContent,
+    #[serde(rename = "redirectURL")]
+    pub redirect_url: String,
+    pub headers_size: i64,
+    pub body_size: i64,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct HarNameValue {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct HarPostData {
+    pub mime_type: String,
+    pub text: String,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct HarContent {
+    pub size: i64,
+    pub mime_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct HarTimings {
+    pub send: f64,
+    pub wait: f64,
+    pub receive: f64,
+}
+
+impl Har {
+    pub fn from_entries(entries: &[RecordedEntry]) -> Self {
+        Self {
+            log: HarLog {
+                version: "1.2".to_string(),
+                creator: HarCreator {
+                    name: "Browsersync".to_string(),
+                    version: env!("CARGO_PKG_VERSION").to_string(),
+                },
+                entries: entries.iter().map(HarEntry::from).collect(),
+            },
+        }
+    }
+}
+
+impl From<&RecordedEntry> for HarEntry {
+    fn from(entry: &RecordedEntry) -> Self {
+        let time = entry.duration.as_secs_f64() * 1000.0;
+        let post_data = (!entry.req_body.is_empty()).then(|| HarPostData {
+            mime_type: mime_type(&entry.req_headers),
+            text: String::from_utf8_lossy(&entry.req_body).to_string(),
+        });
+        let (text, encoding) = match &entry.res_body {
+            None => (None, None),
+            Some(body) => match std::str::from_utf8(body) {
+                Ok(text) => (Some(text.to_string()), None),
+                Err(_) => (
+                    Some(base64::engine::general_purpose::STANDARD.encode(body)),
+                    Some("base64".to_string()),
+                ),
+            },
+        };
+        let res_size = entry
+            .res_body
+            .as_ref()
+            .map(|b| b.len() as i64)
+            .unwrap_or(-1);
+        let query_string = Uri::try_from(entry.url.as_str())
+            .ok()
+            .and_then(|uri| uri.query().map(query_pairs))
+            .unwrap_or_default();
+
+        HarEntry {
+            started_date_time: entry.started.to_rfc3339_opts(SecondsFormat::Millis, true),
+            time,
+            request: HarRequest {
+                method: entry.method.to_string(),
+                url: entry.url.clone(),
+                http_version: format!("{:?}", entry.version),
+                cookies: vec![],
+                headers: name_values(&entry.req_headers),
+                query_string,
+                post_data,
+                headers_size: -1,
+                body_size: entry.req_body.len() as i64,
+            },
+            response: HarResponse {
+                status: entry.status.as_u16(),
+                status_text: entry
+                    .status
+                    .canonical_reason()
+                    .unwrap_or_default()
+                    .to_string(),
+                http_version: format!("{:?}", entry.version),
+                cookies: vec![],
+                headers: name_values(&entry.res_headers),
+                content: HarContent {
+                    size: res_size,
+                    mime_type: mime_type(&entry.res_headers),
+                    text,
+                    encoding,
+                },
+                redirect_url: entry
+                    .res_headers
+                    .get(LOCATION)
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string(),
+                headers_size: -1,
+                body_size: res_size,
+            },
+            cache: serde_json::json!({}),
+            timings: HarTimings {
+                send: 0.0,
+                wait: time,
+                receive: 0.0,
+            },
+        }
+    }
+}
+
+fn name_values(headers: &HeaderMap) -> Vec<HarNameValue> {
+    headers
+        .iter()
+        .map(|(k, v)| HarNameValue {
+            name: k.to_string(),
+            value: String::from_utf8_lossy(v.as_bytes()).to_string(),
+        })
+        .collect()
+}
+
+fn query_pairs(query: &str) -> Vec<HarNameValue> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            HarNameValue {
+                name: name.to_string(),
+                value: value.to_string(),
+            }
+        })
+        .collect()
+}
+
+fn mime_type(headers: &HeaderMap) -> String {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_har_entry() {
+        let mut res_headers = HeaderMap::new();
+        res_headers.insert(CONTENT_TYPE, "image/png".parse().unwrap());
+        let entry = RecordedEntry {
+            started: Utc::now(),
+            duration: Duration::from_millis(12),
+            method: Method::POST,
+            url: "http://127.0.0.1:3000/api?a=1&b".to_string(),
+            version: Version::HTTP_11,
+            req_headers: HeaderMap::new(),
+            req_body: Bytes::from_static(b"{}"),
+            status: StatusCode::OK,
+            res_headers,
+            res_body: Some(Bytes::from_static(&[0x89, 0x50, 0xff])),
+        };
+        let har = HarEntry::from(&entry);
+        assert_eq!(har.request.http_version, "HTTP/1.1");
+        assert_eq!(har.request.query_string.len(), 2);
+        assert_eq!(har.request.post_data.map(|p| p.text), Some("{}".into()));
+        assert_eq!(har.response.content.encoding.as_deref(), Some("base64"));
+        assert_eq!(har.response.content.text.as_deref(), Some("iVD/"));
+        assert_eq!(har.response.status_text, "OK");
+    }
+}
@@ -0,0 +1,249 @@
+use crate::route_effect::RouteEffect;
+use crate::server::state::ServerState;
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use bsnext_input::route::{AuthOpts, Route};
+use http::header::{
+    ACCESS_CONTROL_REQUEST_METHOD, AUTHORIZATION, COOKIE, SET_COOKIE, WWW_AUTHENTICATE,
+};
+use http::{HeaderMap, HeaderValue, Method, StatusCode, Uri};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Arc;
+
+const TOKEN_PARAM: &str = "bslive.token";
+const TOKEN_COOKIE: &str = "bslive_token";
+
+#[derive(Debug, Clone)]
+pub struct Auth {
+    opts: AuthOpts,
+}
+
+impl Auth {
+    pub fn opts(&self) -> &AuthOpts {
+        &self.opts
+    }
+}
+
+impl RouteEffect for Auth {
+    fn new_opt(route: &Route, _req: &Request, _uri: &Uri, _outer_uri: &Uri) -> Option<Self> {
+        let opts = route.opts.auth.clone()?;
+        (!opts.is_empty()).then_some(Self { opts })
+    }
+}
+
+///
+/// The token a request was allowed through with, so that handlers (like the connector
+/// script) can hand it on to the websocket
+///
+#[derive(Debug, Clone)]
+pub struct AuthToken(pub String);
+
+#[derive(Debug, PartialEq)]
+enum Authorized {
+    Basic,
+    Token { token: String, from_query: bool },
+}
+
+pub async fn auth_mw(State(opts): State<AuthOpts>, req: Request, next: Next) -> Response {
+    check(&opts, req, next).await
+}
+
+/// Server-wide `auth`, read from state each time so that it can be changed without a restart
+pub async fn server_auth_mw(
+    State(app): State<Arc<ServerState>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let opts = app.auth.read().await.clone();
+    match opts {
+        Some(opts) if !opts.is_empty() => check(&opts, req, next).await,
+        _ => next.run(req).await,
+    }
+}
+
+async fn check(opts: &AuthOpts, mut req: Request, next: Next) -> Response {
+    // browsers never send credentials with CORS preflight requests
+    let is_preflight = req.method() == Method::OPTIONS
+        && req.headers().contains_key(ACCESS_CONTROL_REQUEST_METHOD);
+    if is_preflight {
+        return next.run(req).await;
+    }
+
+    match authorize(opts, req.headers(), req.uri()) {
+        Some(Authorized::Basic) => next.run(req).await,
+        Some(Authorized::Token { token, from_query }) => {
+            req.extensions_mut().insert(AuthToken(token.clone()));
+            let mut res = next.run(req).await;
+            if from_query {
+                let name = token_cookie(&token);
+                let cookie = format!("{name}={token}; Path=/; HttpOnly; SameSite=Lax");
+                if let Ok(cookie) = HeaderValue::from_str(&cookie) {
+                    res.headers_mut().append(SET_COOKIE, cookie);
+                }
+            }
+            res
+        }
+        None => {
+            tracing::debug!(path = req.uri().path(), "auth: rejecting request");
+            let challenge = match opts.basic {
+                Some(_) => r#"Basic realm="bslive", charset="UTF-8""#,
+                None => r#"Bearer realm="bslive""#,
+            };
+            (
+                StatusCode::UNAUTHORIZED,
+                [(WWW_AUTHENTICATE, HeaderValue::from_static(challenge))],
+                "bslive: authentication required",
+            )
+                .into_response()
+        }
+    }
+}
+
+fn authorize(opts: &AuthOpts, headers: &HeaderMap, uri: &Uri) -> Option<Authorized> {
+    let authorization = headers
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.split_once(' '));
+
+    let scheme = |name: &str| authorization.filter(|(scheme, _)| scheme.eq_ignore_ascii_case(name));
+
+    if let (Some(basic), Some((_, encoded))) = (&opts.basic, scheme("Basic")) {
+        let matches = STANDARD
+            .decode(encoded.trim())
+            .ok()
+            .and_then(|decoded| String::from_utf8(decoded).ok())
+            .is_some_and(|decoded| match decoded.split_once(':') {
+                Some((username, password)) => {
+                    same(username, &basic.username) && same(password, &basic.password)
+                }
+                None => false,
+            });
+        if matches {
+            return Some(Authorized::Basic);
+        }
+    }
+
+    let expected = opts.token.as_deref()?;
+    let from_header = scheme("Bearer").map(|(_, token)| token.trim().to_string());
+    let from_query = uri
+        .query()
+        .and_then(|q| serde_urlencoded::from_str::<Vec<(String, String)>>(q).ok())
+        .and_then(|pairs| pairs.into_iter().find(|(k, _)| k == TOKEN_PARAM))
+        .map(|(_, v)| v);
+    let from_cookie = headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(name, _)| *name == token_cookie(expected))
+        .map(|(_, v)| v.to_string());
+
+    [
+        (from_header, false),
+        (from_query, true),
+        (from_cookie, false),
+    ]
+    .into_iter()
+    .find_map(|(token, from_query)| {
+        token
+            .filter(|token| same(token, expected))
+            .map(|token| Authorized::Token { token, from_query })
+    })
+}
+
+///
+/// The server and its routes can each have a token, so every token is kept in a cookie of its
+/// own - a single cookie would be replaced when signing in to a different scope
+///
+fn token_cookie(token: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    token.hash(&mut hasher);
+    format!("{TOKEN_COOKIE}_{:016x}", hasher.finish())
+}
+
+/// Compares without returning early, so that timing doesn't leak how much of a secret matched
+fn same(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0, |acc, (x, y)| acc | (x ^ y))
+            == 0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bsnext_input::route::BasicAuth;
+
+    fn opts() -> AuthOpts {
+        AuthOpts {
+            basic: Some(BasicAuth {
+                username: "admin".into(),
+                password: "secret".into(),
+            }),
+            token: Some("abc123".into()),
+        }
+    }
+
+    fn headers(pairs: &[(http::HeaderName, &str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.clone(), HeaderValue::from_str(v).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_authorize() {
+        let uri = Uri::from_static("/");
+        let basic = headers(&[(AUTHORIZATION, "Basic YWRtaW46c2VjcmV0")]);
+        assert_eq!(authorize(&opts(), &basic, &uri), Some(Authorized::Basic));
+
+        let wrong = headers(&[(AUTHORIZATION, "Basic YWRtaW46bm9wZQ==")]);
+        assert_eq!(authorize(&opts(), &wrong, &uri), None);
+
+        let bearer = headers(&[(AUTHORIZATION, "Bearer abc123")]);
+        assert_eq!(
+            authorize(&opts(), &bearer, &uri),
+            Some(Authorized::Token {
+                token: "abc123".into(),
+                from_query: false
+            })
+        );
+
+        let query = Uri::from_static("/?a=b&bslive.token=abc123");
+        assert_eq!(
+            authorize(&opts(), &HeaderMap::new(), &query),
+            Some(Authorized::Token {
+                token: "abc123".into(),
+                from_query: true
+            })
+        );
+
+        let lowercase = headers(&[(AUTHORIZATION, "bearer abc123")]);
+        assert!(authorize(&opts(), &lowercase, &uri).is_some());
+        let lowercase = headers(&[(AUTHORIZATION, "BASIC YWRtaW46c2VjcmV0")]);
+        assert_eq!(
+            authorize(&opts(), &lowercase, &uri),
+            Some(Authorized::Basic)
+        );
+
+        let own = format!("other=1; {}=abc123", token_cookie("abc123"));
+        let cookie = headers(&[(COOKIE, own.as_str())]);
+        assert!(authorize(&opts(), &cookie, &uri).is_some());
+        let other_scope = format!("{}=abc123", token_cookie("top-secret"));
+        let cookie = headers(&[(COOKIE, other_scope.as_str())]);
+        assert_eq!(authorize(&opts(), &cookie, &uri), None);
+        assert_eq!(authorize(&opts(), &HeaderMap::new(), &uri), None);
+    }
+
+    #[test]
+    fn test_same() {
+        assert!(same("abc", "abc"));
+        assert!(!same("abc", "abd"));
+        assert!(!same("abc", "abcd"));
+    }
+}
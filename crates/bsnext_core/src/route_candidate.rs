@@ -1,12 +1,17 @@
 use crate::body_match::BodyMatch;
+use crate::route_auth::Auth;
 use crate::route_cache::CachePrevent;
+use crate::route_chaos::Chaos;
 use crate::route_compress::Compress;
 use crate::route_cors::Cors;
 use crate::route_delay::Delay;
 use crate::route_effect::RouteEffect;
 use crate::route_injections::Injections;
 use crate::route_mirror::Mirror;
+use crate::route_replay::Replay;
 use crate::route_res_headers::ResHeaders;
+use crate::route_status::Status;
+use crate::route_template::Template;
 use axum::body::Body;
 use axum::extract::Request;
 use bsnext_input::route::{Route, RouteKind};
@@ -26,6 +31,11 @@ pub struct RouteCandidate<'a> {
     pub cache_prevent: Option<CachePrevent>,
     pub cors: Option<Cors>,
     pub res_headers: Option<ResHeaders>,
+    pub replay: Option<Replay>,
+    pub template: Option<Template>,
+    pub status: Option<Status>,
+    pub chaos: Option<Chaos>,
+    pub auth: Option<Auth>,
 }
 
 impl<'a> RouteCandidate<'a> {
@@ -44,6 +54,11 @@ impl<'a> RouteCandidate<'a> {
         let cache_prevent = CachePrevent::new_opt(route, req, uri, outer_uri);
         let cors = Cors::new_opt(route, req, uri, outer_uri);
         let res_headers = ResHeaders::new_opt(route, req, uri, outer_uri);
+        let replay = Replay::new_opt(route, req, uri, outer_uri);
+        let template = Template::new_opt(route, req, uri, outer_uri);
+        let status = Status::new_opt(route, req, uri, outer_uri);
+        let chaos = Chaos::new_opt(route, req, uri, outer_uri);
+        let auth = Auth::new_opt(route, req, uri, outer_uri);
 
         RouteCandidate {
             index,
@@ -56,6 +71,11 @@ impl<'a> RouteCandidate<'a> {
             cache_prevent,
             cors,
             res_headers,
+            replay,
+            template,
+            status,
+            chaos,
+            auth,
         }
     }
 }
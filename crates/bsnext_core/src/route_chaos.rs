@@ -0,0 +1,234 @@
+use crate::route_effect::RouteEffect;
+use axum::body::Body;
+use axum::extract::{Query, Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use bsnext_input::route::{ChaosOpts, LatencyOpts, Route};
+use bsnext_query::dynamic_query_params::DynamicQueryParams;
+use bytes::Bytes;
+use futures::channel::mpsc::channel;
+use futures::SinkExt;
+use http::header::CONTENT_LENGTH;
+use http::{StatusCode, Uri};
+use rand::Rng;
+use std::io;
+use std::time::Duration;
+use tokio::time::sleep;
+use tokio_stream::StreamExt;
+
+#[derive(Debug, Clone)]
+pub struct Chaos {
+    opts: ChaosOpts,
+}
+
+impl Chaos {
+    pub fn opts(&self) -> &ChaosOpts {
+        &self.opts
+    }
+}
+
+impl RouteEffect for Chaos {
+    fn new_opt(
+        Route { opts, .. }: &Route,
+        req: &Request,
+        _uri: &Uri,
+        _outer_uri: &Uri,
+    ) -> Option<Self> {
+        let url_params: Result<Query<DynamicQueryParams>, _> = Query::try_from_uri(req.uri());
+        let from_url = url_params.ok().map(|Query(params)| from_params(&params));
+        let from_route = opts.chaos.clone();
+
+        // URL takes precedence over route
+        let opts = match (from_url, from_route) {
+            (Some(from_url), Some(from_route)) => from_url.or(&from_route),
+            (Some(from_url), None) => from_url,
+            (None, Some(from_route)) => from_route,
+            (None, None) => return None,
+        };
+        (opts != ChaosOpts::default()).then_some(Self { opts })
+    }
+}
+
+fn from_params(params: &DynamicQueryParams) -> ChaosOpts {
+    let latency = [
+        params.chaos_latency_min_ms,
+        params.chaos_latency_max_ms,
+        params.chaos_latency_jitter_ms,
+    ]
+    .iter()
+    .any(Option::is_some)
+    .then(|| LatencyOpts {
+        min_ms: params.chaos_latency_min_ms.unwrap_or_default(),
+        max_ms: params.chaos_latency_max_ms,
+        jitter_ms: params.chaos_latency_jitter_ms,
+    });
+    ChaosOpts {
+        error_rate: params.chaos_error_rate,
+        error_status: params.chaos_error_status,
+        latency,
+        reset_rate: params.chaos_reset_rate,
+        bytes_per_sec: params.chaos_bytes_per_sec,
+    }
+}
+
+pub async fn chaos_mw(State(opts): State<ChaosOpts>, req: Request, next: Next) -> Response {
+    if let Some(latency) = &opts.latency {
+        let ms = latency_ms(latency);
+        tracing::trace!(ms, "chaos: adding latency");
+        sleep(Duration::from_millis(ms)).await;
+    }
+
+    if chance(opts.error_rate) {
+        let status =
+            StatusCode::from_u16(opts.error_status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        tracing::debug!(%status, path = req.uri().path(), "chaos: failing request");
+        return (status, "bslive chaos: this request failed on purpose").into_response();
+    }
+
+    let res = next.run(req).await;
+    let reset = chance(opts.reset_rate);
+    if !reset && opts.bytes_per_sec.is_none() {
+        return res;
+    }
+
+    let content_length = res
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok());
+    let (parts, body) = res.into_parts();
+    let body = disrupt_body(body, reset.then_some(content_length), opts.bytes_per_sec);
+    Response::from_parts(parts, body)
+}
+
+fn chance(rate: Option<f64>) -> bool {
+    match rate {
+        Some(rate) if rate > 0.0 => rand::thread_rng().gen_bool(rate.min(1.0)),
+        _ => false,
+    }
+}
+
+/// Latency can be set from the url, so it's capped rather than trusting any value given
+const MAX_LATENCY_MS: u64 = 5 * 60 * 1000;
+
+fn latency_ms(latency: &LatencyOpts) -> u64 {
+    let mut rng = rand::thread_rng();
+    let min = latency.min_ms.min(MAX_LATENCY_MS);
+    let max = latency.max_ms.unwrap_or(min).clamp(min, MAX_LATENCY_MS);
+    let ms = rng.gen_range(min..=max);
+    match latency.jitter_ms.map(|jitter| jitter.min(MAX_LATENCY_MS)) {
+        Some(jitter) if jitter > 0 => {
+            let offset = rng.gen_range(0..=jitter.saturating_mul(2));
+            ms.saturating_add(offset)
+                .saturating_sub(jitter)
+                .min(MAX_LATENCY_MS)
+        }
+        _ => ms,
+    }
+}
+
+///
+/// Re-streams a body, throttled to `bytes_per_sec`. When `reset` is given, the body is cut off
+/// with an error at a random point (which drops the connection) - anywhere within the content
+/// length when it's known, or else within the first chunk
+///
+fn disrupt_body(body: Body, reset: Option<Option<u64>>, bytes_per_sec: Option<u64>) -> Body {
+    let (mut tx, rx) = channel::<Result<Bytes, io::Error>>(1);
+    let bytes_per_sec = bytes_per_sec.filter(|bps| *bps > 0);
+    let piece_size = bytes_per_sec.map_or(usize::MAX, |bps| (bps / 10).max(1) as usize);
+
+    tokio::spawn(async move {
+        let mut stream = body.into_data_stream();
+        let mut sent: u64 = 0;
+        let mut reset_at = reset.flatten().map(random_below);
+        while let Some(chunk) = stream.next().await {
+            let mut chunk = match chunk {
+                Ok(chunk) => chunk,
+                Err(err) => {
+                    let _ = tx.send(Err(io::Error::other(err))).await;
+                    return;
+                }
+            };
+            if reset.is_some() && reset_at.is_none() {
+                reset_at = Some(sent + random_below(chunk.len() as u64));
+            }
+            while !chunk.is_empty() {
+                let mut piece = chunk.split_to(piece_size.min(chunk.len()));
+                let cut = reset_at.filter(|at| sent + piece.len() as u64 >= *at);
+                if let Some(at) = cut {
+                    piece.truncate((at - sent) as usize);
+                }
+                let len = piece.len() as u64;
+                sent += len;
+                if len > 0 && tx.send(Ok(piece)).await.is_err() {
+                    return;
+                }
+                if cut.is_some() {
+                    tracing::debug!(sent, "chaos: resetting connection");
+                    let reset = io::Error::new(io::ErrorKind::ConnectionReset, "bslive chaos");
+                    let _ = tx.send(Err(reset)).await;
+                    return;
+                }
+                if let Some(bps) = bytes_per_sec {
+                    sleep(Duration::from_millis(len * 1000 / bps)).await;
+                }
+            }
+        }
+    });
+
+    Body::from_stream(rx)
+}
+
+fn random_below(n: u64) -> u64 {
+    rand::thread_rng().gen_range(0..n.max(1))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_latency_ms() {
+        let fixed = LatencyOpts {
+            min_ms: 100,
+            max_ms: None,
+            jitter_ms: None,
+        };
+        assert_eq!(latency_ms(&fixed), 100);
+        let ranged = LatencyOpts {
+            min_ms: 100,
+            max_ms: Some(200),
+            jitter_ms: Some(50),
+        };
+        for _ in 0..100 {
+            let ms = latency_ms(&ranged);
+            assert!((50..=250).contains(&ms), "{ms}");
+        }
+    }
+
+    #[test]
+    fn test_latency_ms_capped() {
+        let huge = LatencyOpts {
+            min_ms: u64::MAX,
+            max_ms: Some(u64::MAX),
+            jitter_ms: Some(u64::MAX),
+        };
+        for _ in 0..100 {
+            assert!(latency_ms(&huge) <= MAX_LATENCY_MS);
+        }
+        let url: Uri = "/?bslive.chaos.latency.jitter_ms=18446744073709551615"
+            .parse()
+            .unwrap();
+        let Query(params) = Query::<DynamicQueryParams>::try_from_uri(&url).unwrap();
+        let latency = from_params(&params).latency.unwrap();
+        assert!(latency_ms(&latency) <= MAX_LATENCY_MS);
+    }
+
+    #[test]
+    fn test_chance() {
+        assert!(!chance(None));
+        assert!(!chance(Some(0.0)));
+        assert!(chance(Some(1.0)));
+        assert!(chance(Some(5.0)), "rates are capped at 1");
+    }
+}
@@ -1,8 +1,11 @@
 use crate::route_effect::RouteEffect;
 use axum::extract::Request;
-use bsnext_input::route::{CorsOpts, Route};
-use http::Uri;
-use tower_http::cors::CorsLayer;
+use bsnext_input::route::{CorsConfig, CorsOpts, Route};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use http::{HeaderName, HeaderValue, Method, Uri};
+use std::str::FromStr;
+use std::time::Duration;
+use tower_http::cors::{AllowHeaders, AllowMethods, AllowOrigin, CorsLayer, ExposeHeaders};
 
 #[derive(Debug, Clone)]
 pub struct Cors {
@@ -18,16 +21,98 @@ impl RouteEffect for Cors {
     ) -> Option<Self> {
         opts.cors
             .as_ref()
-            .filter(|v| **v == CorsOpts::Cors(true))
+            .filter(|v| **v != CorsOpts::Cors(false))
             .map(|opts| Cors { opts: opts.clone() })
     }
 }
 
 impl Cors {
     pub fn as_layer(&self) -> CorsLayer {
-        match self.opts {
+        match &self.opts {
             CorsOpts::Cors(true) => CorsLayer::permissive(),
-            CorsOpts::Cors(false) => todo!("unreachable"),
+            CorsOpts::Cors(false) => CorsLayer::new(),
+            CorsOpts::Config(config) => config_layer(config),
         }
     }
 }
+
+///
+/// Browsers reject `*` alongside credentials, so in that case anything left out is mirrored
+/// from the request instead
+///
+fn config_layer(config: &CorsConfig) -> CorsLayer {
+    let credentials = config.credentials.unwrap_or(false);
+
+    let allow_origin = match without_wildcard(&config.origins) {
+        None if credentials => AllowOrigin::mirror_request(),
+        None => AllowOrigin::any(),
+        Some(origins) if origins.iter().any(|o| o.contains('*')) => origin_patterns(origins),
+        Some(origins) => AllowOrigin::list(parse_all(origins, HeaderValue::from_str)),
+    };
+    let allow_methods = match without_wildcard(&config.methods) {
+        None if credentials => AllowMethods::mirror_request(),
+        None => AllowMethods::any(),
+        Some(methods) => {
+            AllowMethods::list(parse_all(methods, |m| Method::from_str(&m.to_uppercase())))
+        }
+    };
+    let allow_headers = match without_wildcard(&config.headers) {
+        None if credentials => AllowHeaders::mirror_request(),
+        None => AllowHeaders::any(),
+        Some(headers) => AllowHeaders::list(parse_all(headers, HeaderName::from_str)),
+    };
+    let expose_headers = match without_wildcard(&config.expose_headers) {
+        None if credentials => ExposeHeaders::list([]),
+        None => ExposeHeaders::any(),
+        Some(headers) => ExposeHeaders::list(parse_all(headers, HeaderName::from_str)),
+    };
+
+    let layer = CorsLayer::new()
+        .allow_origin(allow_origin)
+        .allow_methods(allow_methods)
+        .allow_headers(allow_headers)
+        .expose_headers(expose_headers)
+        .allow_credentials(credentials);
+
+    match config.max_age {
+        Some(secs) => layer.max_age(Duration::from_secs(secs)),
+        None => layer,
+    }
+}
+
+/// A list containing `*` is treated the same as no list at all
+fn without_wildcard(list: &Option<Vec<String>>) -> Option<&[String]> {
+    list.as_deref()
+        .filter(|items| !items.iter().any(|i| i == "*"))
+}
+
+fn parse_all<T, E: std::fmt::Debug>(
+    items: &[String],
+    parse: impl Fn(&str) -> Result<T, E>,
+) -> Vec<T> {
+    items
+        .iter()
+        .filter_map(|item| match parse(item) {
+            Ok(parsed) => Some(parsed),
+            Err(err) => {
+                tracing::error!(?err, item, "invalid cors value, ignoring it");
+                None
+            }
+        })
+        .collect()
+}
+
+fn origin_patterns(origins: &[String]) -> AllowOrigin {
+    let mut builder = GlobSetBuilder::new();
+    for origin in origins {
+        // `*` shouldn't reach across a `/`, so patterns can only match within the host
+        match GlobBuilder::new(origin).literal_separator(true).build() {
+            Ok(glob) => {
+                builder.add(glob);
+            }
+            Err(err) => tracing::error!(?err, origin, "invalid cors origin pattern, ignoring it"),
+        }
+    }
+    let set = builder.build().unwrap_or_else(|_| GlobSet::empty());
+    AllowOrigin::predicate(move |origin, _| origin.to_str().is_ok_and(|o| set.is_match(o)))
+}
@@ -2,10 +2,12 @@ use crate::route_effect::RouteEffect;
 use axum::extract::{Query, Request};
 use bsnext_guards::path_matcher::PathMatcher;
 use bsnext_guards::MatcherList;
-use bsnext_input::route::Route;
+use bsnext_input::route::{Route, RouteKind};
 use bsnext_query::dynamic_query_params::{DynamicQueryParams, InjectParam};
 use bsnext_resp::builtin_strings::{BuiltinStringDef, BuiltinStrings};
 use bsnext_resp::inject_opts::{InjectOpts, Injection, InjectionItem};
+use bsnext_resp::rewrite_links::RewriteLinks;
+use http::header::HOST;
 use http::Uri;
 
 #[derive(Debug, PartialEq)]
@@ -14,7 +16,7 @@ pub struct Injections {
 }
 
 impl RouteEffect for Injections {
-    fn new_opt(route: &Route, _req: &Request, uri: &Uri, _outer_uri: &Uri) -> Option<Self> {
+    fn new_opt(route: &Route, req: &Request, uri: &Uri, _outer_uri: &Uri) -> Option<Self> {
         let mut items = match &route.opts.inject {
             InjectOpts::Bool(true) => {
                 vec![InjectionItem {
@@ -50,6 +52,12 @@ impl RouteEffect for Injections {
                 InjectParam::Other(_) => todo!("other?"),
             }
         }
+        if let Some(rewrite) = rewrite_links(route, req) {
+            items.push(InjectionItem {
+                inner: Injection::RewriteLinks(rewrite),
+                only: None,
+            })
+        }
         if items.is_empty() {
             return None;
         }
@@ -57,6 +65,20 @@ impl RouteEffect for Injections {
     }
 }
 
+/// The local origin comes from the `host` header, so it's whatever the browser used to reach us
+fn rewrite_links(route: &Route, req: &Request) -> Option<RewriteLinks> {
+    let RouteKind::Proxy(proxy) = &route.kind else {
+        return None;
+    };
+    if !proxy.rewrites_links() {
+        return None;
+    }
+    let target = Uri::try_from(&proxy.proxy).ok()?;
+    let from = target.authority()?.as_str();
+    let to = req.headers().get(HOST)?.to_str().ok()?;
+    Some(RewriteLinks::new(from, to))
+}
+
 impl Injections {
     pub fn items(&self) -> Vec<InjectionItem> {
         self.items.clone()
@@ -1,9 +1,11 @@
-use crate::match_json::NeedsJsonGuard;
+use crate::match_json::NeedsBodyGuard;
 use axum::extract::Request;
 use axum::response::Response;
 use bsnext_guards::route_guard::RouteGuard;
+use bsnext_input::path_def::PathParams;
 use bsnext_input::route::{ListOrSingle, Route};
-use bsnext_input::when_guard::{HasGuard, WhenGuard};
+use bsnext_input::when_guard::{HasGuard, NamedHasGuard, WhenGuard};
+use http::header::COOKIE;
 use http::request::Parts;
 use http::uri::PathAndQuery;
 use http::Uri;
@@ -17,6 +19,7 @@ impl RouteMatch<'_> {
         req: &Request,
         outer_uri: &Uri,
         path: &str,
+        params: &PathParams,
         pq: Option<&PathAndQuery>,
         parts: &Parts,
     ) -> bool {
@@ -28,19 +31,19 @@ impl RouteMatch<'_> {
             .when
             .as_ref()
             .map(|when| match &when {
-                ListOrSingle::WhenOne(when) => match_one(when, outer_uri, path, pq, parts),
+                ListOrSingle::WhenOne(when) => match_one(when, outer_uri, path, params, pq, parts),
                 ListOrSingle::WhenMany(many) => many
                     .iter()
-                    .all(|when| match_one(when, outer_uri, path, pq, parts)),
+                    .all(|when| match_one(when, outer_uri, path, params, pq, parts)),
             })
             .unwrap_or(true);
 
-        // if this routes wants to inspect the body, check it was a POST
+        // if this routes wants to inspect the body, check it was sent with one
         let can_consume = match &route.when_body {
             None => true,
             Some(when_body) => {
-                let consuming = NeedsJsonGuard(when_body).accept_req(req, outer_uri);
-                trace!(route.when_body.json = consuming);
+                let consuming = NeedsBodyGuard(when_body).accept_req(req, outer_uri);
+                trace!(route.when_body = consuming);
                 consuming
             }
         };
@@ -60,6 +63,7 @@ fn match_one(
     when_guard: &WhenGuard,
     outer_uri: &Uri,
     path: &str,
+    params: &PathParams,
     pq: Option<&PathAndQuery>,
     parts: &Parts,
 ) -> bool {
@@ -70,6 +74,20 @@ fn match_one(
         WhenGuard::ExactUri { exact_uri: false } => path != pq.map(|pq| pq.as_str()).unwrap_or("/"),
         WhenGuard::Query { query } => QueryHasGuard(query).accept_req_parts(parts, outer_uri),
         WhenGuard::Accept { accept } => AcceptHasGuard(accept).accept_req_parts(parts, outer_uri),
+        WhenGuard::Method { method } => method.test(parts.method.as_str()),
+        WhenGuard::Header { header } => HeaderHasGuard(header).accept_req_parts(parts, outer_uri),
+        WhenGuard::Cookie { cookie } => CookieHasGuard(cookie).accept_req_parts(parts, outer_uri),
+        WhenGuard::Param { param } => match params.get(outer_uri.path(), &param.name) {
+            Some(value) => param.guard.test(&value),
+            None => false,
+        },
+        WhenGuard::Any { any } => any
+            .iter()
+            .any(|when| match_one(when, outer_uri, path, params, pq, parts)),
+        WhenGuard::All { all } => all
+            .iter()
+            .all(|when| match_one(when, outer_uri, path, params, pq, parts)),
+        WhenGuard::Not { not } => !match_one(not, outer_uri, path, params, pq, parts),
     }
 }
 
@@ -121,3 +139,49 @@ impl RouteGuard for AcceptHasGuard<'_> {
         }
     }
 }
+
+struct HeaderHasGuard<'a>(pub &'a NamedHasGuard);
+
+impl RouteGuard for HeaderHasGuard<'_> {
+    fn accept_req(&self, _req: &Request, _outer_uri: &Uri) -> bool {
+        true
+    }
+
+    fn accept_res<T>(&self, _res: &Response<T>, _outer_uri: &Uri) -> bool {
+        true
+    }
+
+    /// Repeated headers are each tested, any one of them can match
+    fn accept_req_parts(&self, parts: &Parts, _outer_uri: &Uri) -> bool {
+        parts
+            .headers
+            .get_all(self.0.name.as_str())
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .any(|value| self.0.guard.test(value))
+    }
+}
+
+struct CookieHasGuard<'a>(pub &'a NamedHasGuard);
+
+impl RouteGuard for CookieHasGuard<'_> {
+    fn accept_req(&self, _req: &Request, _outer_uri: &Uri) -> bool {
+        true
+    }
+
+    fn accept_res<T>(&self, _res: &Response<T>, _outer_uri: &Uri) -> bool {
+        true
+    }
+
+    fn accept_req_parts(&self, parts: &Parts, _outer_uri: &Uri) -> bool {
+        parts
+            .headers
+            .get_all(COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(';'))
+            .filter_map(|pair| pair.trim().split_once('='))
+            .find(|(name, _)| *name == self.0.name)
+            .is_some_and(|(_, value)| self.0.guard.test(value))
+    }
+}
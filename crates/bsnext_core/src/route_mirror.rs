@@ -1,99 +1,296 @@
+use crate::export::url_path_to_filepath;
 use crate::route_effect::RouteEffect;
+use crate::runtime_ctx::RuntimeCtx;
 use axum::body::Body;
 use axum::extract::{Request, State};
 use axum::middleware::Next;
 use axum::response::{IntoResponse, Response};
-use bsnext_input::route::{Route, RouteKind};
+use bsnext_guards::OuterUri;
+use bsnext_input::route::{MirrorConfig, Route, RouteKind};
 use bytes::Bytes;
-use http::header::ACCEPT;
-use http::Uri;
-use std::ffi::OsStr;
+use globset::GlobSet;
+use http::header::{ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_TYPE};
+use http::{Method, StatusCode, Uri};
 use std::io;
-use std::path::{Path, PathBuf};
-use tokio::fs::{create_dir_all, File};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use tokio::fs::{create_dir_all, rename, File};
 use tokio::io::{AsyncWriteExt, BufWriter};
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use tokio_stream::StreamExt;
+use tower::ServiceExt;
+use tower_http::services::ServeDir;
 use tracing::error;
 
 #[derive(Debug, Clone)]
 pub struct Mirror {
-    #[allow(dead_code)]
-    path: PathBuf,
+    config: MirrorConfig,
 }
 
-impl RouteEffect for Mirror {
-    fn new_opt(route: &Route, req: &Request, _uri: &Uri, _outer_uri: &Uri) -> Option<Self> {
-        let css_req = req
-            .headers()
-            .get(ACCEPT)
-            .and_then(|h| h.to_str().ok())
-            .map(|c| c.contains("text/css"))
-            .unwrap_or(false);
-
-        let js_req = Path::new(req.uri().path())
-            .extension()
-            .is_some_and(|ext| ext == OsStr::new("js"));
-
-        (js_req || css_req)
-            .then(|| Mirror::from_route(&route.kind))
-            .flatten()
+impl Mirror {
+    pub fn config(&self) -> &MirrorConfig {
+        &self.config
     }
 }
 
-impl Mirror {
-    fn from_route(r: &RouteKind) -> Option<Self> {
-        match r {
-            RouteKind::Proxy(proxy) => proxy.mirror().map(|path| Self { path }),
+impl RouteEffect for Mirror {
+    fn new_opt(route: &Route, req: &Request, _uri: &Uri, _outer_uri: &Uri) -> Option<Self> {
+        if req.method() != Method::GET {
+            return None;
+        }
+        match &route.kind {
+            RouteKind::Proxy(proxy) => proxy.mirror().map(|config| Self { config }),
             RouteKind::Raw(_) => None,
             RouteKind::Dir(_) => None,
         }
     }
 }
 
-#[allow(dead_code)]
-async fn mirror_handler(
-    State(path): State<PathBuf>,
-    req: Request,
+///
+/// Streams proxied responses to the client, whilst writing them into the mirror directory.
+/// When `offline` is set and the proxy target cannot be reached, the mirror is served instead
+///
+pub async fn mirror_mw(
+    State((config, ctx, globs)): State<(MirrorConfig, RuntimeCtx, Option<Arc<GlobSet>>)>,
+    mut req: Request,
     next: Next,
-) -> impl IntoResponse {
+) -> Response {
+    let outer_uri = req
+        .extensions()
+        .get::<OuterUri>()
+        .map(|OuterUri(uri)| uri.clone())
+        .unwrap_or_else(|| req.uri().clone());
+    let dir = ctx.cwd().join(&config.dir);
+
+    // mirrored files are written as-is, so ask the target for an uncompressed body
+    req.headers_mut().remove(ACCEPT_ENCODING);
+
+    let offline_req = config.offline.then(|| {
+        let mut offline_req = Request::new(Body::empty());
+        *offline_req.uri_mut() = outer_uri.clone();
+        *offline_req.headers_mut() = req.headers().clone();
+        offline_req
+    });
+
+    let res = next.run(req).await;
+
+    let unreachable = matches!(
+        res.status(),
+        StatusCode::BAD_GATEWAY | StatusCode::GATEWAY_TIMEOUT
+    );
+    if unreachable {
+        if let Some(offline_req) = offline_req {
+            return match serve_mirror(&dir, offline_req).await {
+                Some(mirrored) => mirrored,
+                None => res,
+            };
+        }
+    }
+
+    if res.status() != StatusCode::OK || res.headers().contains_key(CONTENT_ENCODING) {
+        return res;
+    }
+
+    let url_path = percent_decode(outer_uri.path());
+    let content_type = res
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok());
+    if !should_mirror(&config, globs.as_deref(), &url_path, content_type) {
+        return res;
+    }
+
+    let is_html = content_type.is_some_and(|ct| ct.starts_with("text/html"));
+    let Some(filepath) = mirror_filepath(&dir, &url_path, is_html) else {
+        tracing::debug!(?url_path, "cannot mirror this path");
+        return res;
+    };
+
+    write_through(res, filepath).await
+}
+
+async fn serve_mirror(dir: &Path, req: Request) -> Option<Response> {
+    let serve_dir = ServeDir::new(dir).append_index_html_on_directories(true);
+    match serve_dir.oneshot(req).await {
+        Ok(res) if res.status() == StatusCode::OK => {
+            tracing::debug!("target unreachable, serving from mirror");
+            Some(res.into_response())
+        }
+        _ => None,
+    }
+}
+
+///
+/// The body is forwarded to the client chunk by chunk, and only moved into place
+/// once it's complete - so a dropped connection never leaves a partial file in the mirror
+///
+async fn write_through(res: Response, filepath: PathBuf) -> Response {
+    let mut partial = filepath.clone().into_os_string();
+    partial.push(".bslive-partial");
+    let partial = PathBuf::from(partial);
+    let file = match prepare_file(&partial).await {
+        Ok(file) => file,
+        Err(e) => {
+            error!(?e, "could not create mirror file {}", partial.display());
+            return res;
+        }
+    };
+
     let (sender, receiver) = tokio::sync::mpsc::unbounded_channel::<Result<Bytes, io::Error>>();
     let as_stream = UnboundedReceiverStream::from(receiver);
-    let c = req.uri().clone();
-    let p = path.join(c.path().strip_prefix("/").unwrap());
-    let r = next.run(req).await;
-    let (parts, body) = r.into_parts();
+    let (parts, body) = res.into_parts();
     let s = body.into_data_stream();
 
     tokio::spawn(async move {
-        // let s = s.throttle(Duration::from_millis(10));
         tokio::pin!(s);
-        create_dir_all(&p.parent().unwrap()).await.unwrap();
-        let mut file = BufWriter::new(File::create(p).await.unwrap());
+        let mut file = BufWriter::new(file);
+        let mut complete = true;
 
-        while let Some(Ok(b)) = s.next().await {
-            match file.write(&b).await {
-                Ok(_) => {}
-                Err(e) => error!(?e, "could not write"),
+        while let Some(chunk) = s.next().await {
+            let Ok(b) = chunk else {
+                complete = false;
+                break;
             };
-            // match file.write("\n".as_bytes()).await {
-            //     Ok(_) => {}
-            //     Err(e) => error!(?e, "could not new line"),
-            // };
-            match file.flush().await {
-                Ok(_) => {}
-                Err(e) => error!(?e, "could not flush"),
-            };
-            match sender.send(Ok(b)) {
-                Ok(_) => {}
-                Err(e) => {
-                    error!(?e, "sender was dropped before reading was finished");
-                    error!("will break");
-                    break;
-                }
+            if let Err(e) = file.write_all(&b).await {
+                error!(?e, "could not write");
+                complete = false;
+            }
+            if sender.send(Ok(b)).is_err() {
+                error!("sender was dropped before reading was finished");
+                complete = false;
+                break;
             };
         }
+
+        let flushed = file.flush().await;
+        let result = match (complete, flushed) {
+            (true, Ok(_)) => rename(&partial, &filepath).await,
+            (_, flushed) => tokio::fs::remove_file(&partial).await.and(flushed),
+        };
+        match result {
+            Ok(_) => tracing::trace!(complete, "mirror finished {}", filepath.display()),
+            Err(e) => error!(?e, "could not finish mirror file {}", filepath.display()),
+        }
     });
 
     Response::from_parts(parts, Body::from_stream(as_stream))
 }
+
+async fn prepare_file(path: &Path) -> io::Result<File> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent).await?;
+    }
+    File::create(path).await
+}
+
+/// Without any `mime` or `glob` filters, everything is mirrored
+fn should_mirror(
+    config: &MirrorConfig,
+    globs: Option<&GlobSet>,
+    url_path: &str,
+    content_type: Option<&str>,
+) -> bool {
+    if config.mime.is_empty() && config.glob.is_empty() {
+        return true;
+    }
+    let essence = content_type
+        .and_then(|ct| ct.split(';').next())
+        .map(str::trim)
+        .unwrap_or_default();
+    let mime_match = !essence.is_empty()
+        && config
+            .mime
+            .iter()
+            .any(|mime| match mime.strip_suffix("/*") {
+                Some(top_level) => essence
+                    .split_once('/')
+                    .is_some_and(|(t, _)| t.eq_ignore_ascii_case(top_level)),
+                None => essence.eq_ignore_ascii_case(mime),
+            });
+    mime_match || globs.is_some_and(|set| set.is_match(url_path.trim_start_matches('/')))
+}
+
+///
+/// Globs are checked when the input is loaded, so they're compiled once here when the
+/// router is built, rather than for every response
+///
+pub fn mirror_globs(route: &Route) -> Option<Arc<GlobSet>> {
+    let RouteKind::Proxy(proxy) = &route.kind else {
+        return None;
+    };
+    let config = proxy.mirror()?;
+    config
+        .glob_set()
+        .inspect_err(|err| error!(?err, "invalid mirror globs {:?}", config.glob))
+        .ok()
+        .map(Arc::new)
+}
+
+/// Only plain segments are allowed, so that nothing can be written outside the mirror dir
+fn mirror_filepath(dir: &Path, url_path: &str, is_html: bool) -> Option<PathBuf> {
+    let relative = url_path_to_filepath(url_path, is_html);
+    relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(..)))
+        .then(|| dir.join(relative))
+}
+
+fn percent_decode(path: &str) -> String {
+    percent_encoding::percent_decode_str(path)
+        .decode_utf8_lossy()
+        .to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn config(mime: &[&str], glob: &[&str]) -> MirrorConfig {
+        MirrorConfig {
+            dir: "mirror".to_string(),
+            mime: mime.iter().map(ToString::to_string).collect(),
+            glob: glob.iter().map(ToString::to_string).collect(),
+            offline: false,
+        }
+    }
+
+    fn mirrors(config: &MirrorConfig, url_path: &str, content_type: Option<&str>) -> bool {
+        should_mirror(
+            config,
+            config.glob_set().ok().as_ref(),
+            url_path,
+            content_type,
+        )
+    }
+
+    #[test]
+    fn test_should_mirror() {
+        let everything = config(&[], &[]);
+        assert!(mirrors(&everything, "/", Some("text/html")));
+
+        let images = config(&["image/*", "text/css"], &[]);
+        assert!(mirrors(&images, "/a.png", Some("image/png")));
+        assert!(mirrors(&images, "/a.css", Some("text/css; charset=utf-8")));
+        assert!(!mirrors(&images, "/a.js", Some("text/javascript")));
+        assert!(!mirrors(&images, "/a.png", None));
+
+        let assets = config(&[], &["assets/**", "/**/*.js"]);
+        assert!(mirrors(&assets, "/assets/img/a.png", None));
+        assert!(mirrors(&assets, "/js/app.js", None));
+        assert!(!mirrors(&assets, "/index.html", None));
+    }
+
+    #[test]
+    fn test_mirror_filepath() {
+        let dir = Path::new("/tmp/mirror");
+        assert_eq!(
+            mirror_filepath(dir, "/", true),
+            Some(PathBuf::from("/tmp/mirror/index.html"))
+        );
+        assert_eq!(
+            mirror_filepath(dir, "/css/app.css", false),
+            Some(PathBuf::from("/tmp/mirror/css/app.css"))
+        );
+        assert_eq!(mirror_filepath(dir, "/../etc/passwd", false), None);
+    }
+}
@@ -0,0 +1,406 @@
+use crate::record::{full_url, Har, HarEntry, RecordedEntry};
+use crate::route_effect::RouteEffect;
+use crate::runtime_ctx::RuntimeCtx;
+use axum::body::Body;
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use base64::Engine;
+use bsnext_guards::OuterUri;
+use bsnext_input::route::{ReplayConfig, ReplayMode, Route, RouteKind};
+use bsnext_input::server_config::Scheme;
+use bytes::Bytes;
+use chrono::Utc;
+use http::header::{CONNECTION, CONTENT_LENGTH, TRANSFER_ENCODING};
+use http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode, Uri};
+use http_body_util::BodyExt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Instant, SystemTime};
+use tokio::sync::Mutex;
+
+///
+/// The recording behind one replay route. It lives as long as the route does, so a changed
+/// input starts from a fresh read
+///
+#[derive(Debug, Default)]
+pub struct ReplayStore {
+    /// Appending is a read-modify-write of the whole file, so concurrent misses must take turns
+    append_lock: Mutex<()>,
+    /// The parsed recording, only read again from disk when the file's mtime changes
+    cached: std::sync::Mutex<Option<CachedHar>>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedHar {
+    file: PathBuf,
+    modified: SystemTime,
+    har: Arc<Har>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Replay {
+    config: ReplayConfig,
+}
+
+impl Replay {
+    pub fn config(&self) -> &ReplayConfig {
+        &self.config
+    }
+}
+
+impl RouteEffect for Replay {
+    fn new_opt(route: &Route, _req: &Request, _uri: &Uri, _outer_uri: &Uri) -> Option<Self> {
+        match &route.kind {
+            RouteKind::Proxy(proxy) => proxy.replay.as_ref().map(|replay| Self {
+                config: replay.config(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+pub async fn replay_mw(
+    State((config, ctx, store)): State<(ReplayConfig, RuntimeCtx, Arc<ReplayStore>)>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let outer_uri = req
+        .extensions()
+        .get::<OuterUri>()
+        .map(|OuterUri(uri)| uri.clone())
+        .unwrap_or_else(|| req.uri().clone());
+    let (parts, body) = req.into_parts();
+    let req_body = match body.collect().await {
+        Ok(collected) => collected.to_bytes(),
+        Err(e) => {
+            tracing::error!(?e, "could not buffer request body for replay");
+            Bytes::new()
+        }
+    };
+
+    let file = ctx.cwd().join(&config.file);
+    let har = match store.read_har(&file).await {
+        Ok(har) => har,
+        Err(e) => {
+            tracing::error!(?e, "could not read replay file {}", file.display());
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("could not read replay file {}: {e}", file.display()),
+            )
+                .into_response();
+        }
+    };
+
+    let pq = outer_uri
+        .path_and_query()
+        .map(|pq| pq.as_str())
+        .unwrap_or("/");
+    let found = har
+        .log
+        .entries
+        .iter()
+        .find(|entry| is_match(entry, &config, &parts.method, pq, &parts.headers, &req_body));
+
+    if let Some(entry) = found {
+        tracing::trace!(method = ?parts.method, pq, "replaying recorded response");
+        return to_response(entry);
+    }
+
+    match config.mode {
+        ReplayMode::Strict => {
+            tracing::debug!(method = ?parts.method, pq, "no recorded response");
+            (
+                StatusCode::NOT_FOUND,
+                format!("no recorded response for {} {pq}", parts.method),
+            )
+                .into_response()
+        }
+        ReplayMode::Passthrough => {
+            next.run(Request::from_parts(parts, Body::from(req_body)))
+                .await
+        }
+        ReplayMode::RecordMissing => {
+            let started = Utc::now();
+            let start = Instant::now();
+            let method = parts.method.clone();
+            let version = parts.version;
+            let req_headers = parts.headers.clone();
+            let url = full_url(Scheme::default(), &parts.headers, &outer_uri);
+            let req = Request::from_parts(parts, Body::from(req_body.clone()));
+
+            let (res_parts, res_body) = next.run(req).await.into_parts();
+            let res_bytes = match res_body.collect().await {
+                Ok(collected) => collected.to_bytes(),
+                Err(e) => {
+                    tracing::error!(?e, "could not buffer response body for replay");
+                    return StatusCode::BAD_GATEWAY.into_response();
+                }
+            };
+
+            let entry = RecordedEntry {
+                started,
+                duration: start.elapsed(),
+                method,
+                url,
+                version,
+                req_headers,
+                req_body,
+                status: res_parts.status,
+                res_headers: res_parts.headers.clone(),
+                res_body: Some(res_bytes.clone()),
+            };
+            // a failing upstream would otherwise be replayed as the answer from now on
+            if entry.status.is_server_error() {
+                tracing::debug!(status = %entry.status, pq, "not recording a server error");
+            } else if let Err(e) = store.append_entry(&file, HarEntry::from(&entry)).await {
+                tracing::error!(?e, "could not append to replay file {}", file.display());
+            }
+
+            Response::from_parts(res_parts, Body::from(res_bytes))
+        }
+    }
+}
+
+impl ReplayStore {
+    /// A missing file is treated as an empty recording, so that `record-missing` can create it
+    async fn read_har(&self, file: &Path) -> anyhow::Result<Arc<Har>> {
+        let modified = match tokio::fs::metadata(file).await {
+            Ok(meta) => meta.modified()?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(Arc::new(Har::from_entries(&[])))
+            }
+            Err(e) => return Err(e.into()),
+        };
+        if let Some(cached) = self.cached_har(file, modified) {
+            return Ok(cached);
+        }
+        let har: Arc<Har> = Arc::new(serde_json::from_slice(&tokio::fs::read(file).await?)?);
+        self.cache_har(file, modified, &har);
+        Ok(har)
+    }
+
+    fn cached_har(&self, file: &Path, modified: SystemTime) -> Option<Arc<Har>> {
+        let cached = self.cached.lock().expect("replay cache poisoned");
+        cached
+            .as_ref()
+            .filter(|cached| cached.file == file && cached.modified == modified)
+            .map(|cached| cached.har.clone())
+    }
+
+    fn cache_har(&self, file: &Path, modified: SystemTime, har: &Arc<Har>) {
+        let mut cached = self.cached.lock().expect("replay cache poisoned");
+        *cached = Some(CachedHar {
+            file: file.to_path_buf(),
+            modified,
+            har: har.clone(),
+        });
+    }
+
+    async fn append_entry(&self, file: &PathBuf, entry: HarEntry) -> anyhow::Result<()> {
+        let _guard = self.append_lock.lock().await;
+        let mut har = Har::clone(&*self.read_har(file).await?);
+        har.log.entries.push(entry);
+        if let Some(dir) = file.parent() {
+            tokio::fs::create_dir_all(dir).await?;
+        }
+        tokio::fs::write(file, serde_json::to_vec_pretty(&har)?).await?;
+        // the cache is updated directly, a second write within the mtime resolution looks unchanged
+        let modified = tokio::fs::metadata(file).await?.modified()?;
+        self.cache_har(file, modified, &Arc::new(har));
+        Ok(())
+    }
+}
+
+///
+/// Entries match on method + path + query, plus any headers listed in `match_headers`,
+/// and the body when `match_body` is set
+///
+fn is_match(
+    entry: &HarEntry,
+    config: &ReplayConfig,
+    method: &Method,
+    pq: &str,
+    headers: &HeaderMap,
+    body: &Bytes,
+) -> bool {
+    let entry_pq = Uri::try_from(entry.request.url.as_str())
+        .ok()
+        .and_then(|uri| uri.path_and_query().map(|pq| pq.as_str().to_string()));
+
+    if entry.request.method != method.as_str() || entry_pq.as_deref() != Some(pq) {
+        return false;
+    }
+
+    let headers_match = config.match_headers.iter().all(|name| {
+        let recorded = entry
+            .request
+            .headers
+            .iter()
+            .find(|h| h.name.eq_ignore_ascii_case(name))
+            .map(|h| h.value.as_str());
+        let incoming = headers.get(name.as_str()).and_then(|v| v.to_str().ok());
+        recorded == incoming
+    });
+
+    let body_match = !config.match_body
+        || entry
+            .request
+            .post_data
+            .as_ref()
+            .map(|post_data| post_data.text.as_bytes())
+            .unwrap_or_default()
+            == body.as_ref();
+
+    headers_match && body_match
+}
+
+fn to_response(entry: &HarEntry) -> Response {
+    let content = &entry.response.content;
+    let body = match (&content.text, content.encoding.as_deref()) {
+        (None, _) => Bytes::new(),
+        (Some(text), Some("base64")) => {
+            match base64::engine::general_purpose::STANDARD.decode(text) {
+                Ok(bytes) => Bytes::from(bytes),
+                Err(e) => {
+                    tracing::error!(?e, "could not decode recorded body");
+                    Bytes::new()
+                }
+            }
+        }
+        (Some(text), _) => Bytes::from(text.clone()),
+    };
+
+    let mut res = Response::new(Body::from(body));
+    *res.status_mut() = StatusCode::from_u16(entry.response.status).unwrap_or(StatusCode::OK);
+    for header in &entry.response.headers {
+        match (
+            HeaderName::from_bytes(header.name.as_bytes()),
+            HeaderValue::from_str(&header.value),
+        ) {
+            (Ok(name), Ok(value)) => {
+                // the body is sent in full, so framing headers from the recording are dropped
+                if name == CONTENT_LENGTH || name == TRANSFER_ENCODING || name == CONNECTION {
+                    continue;
+                }
+                res.headers_mut().append(name, value);
+            }
+            _ => tracing::debug!(?header.name, "skipping invalid recorded header"),
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::record::{HarNameValue, HarPostData, HarRequest};
+
+    #[tokio::test]
+    async fn test_read_har_cached() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let file = dir.path().join("api.har");
+        let store = ReplayStore::default();
+        store.append_entry(&file, HarEntry::default()).await?;
+
+        let first = store.read_har(&file).await?;
+        let second = store.read_har(&file).await?;
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(first.log.entries.len(), 1);
+
+        store.append_entry(&file, HarEntry::default()).await?;
+        assert_eq!(store.read_har(&file).await?.log.entries.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_match() {
+        let entry = HarEntry {
+            request: HarRequest {
+                method: "POST".to_string(),
+                url: "http://localhost:3000/api/users?page=2".to_string(),
+                headers: vec![HarNameValue {
+                    name: "Authorization".to_string(),
+                    value: "Bearer abc".to_string(),
+                }],
+                post_data: Some(HarPostData {
+                    mime_type: "application/json".to_string(),
+                    text: r#"{"name":"kittie"}"#.to_string(),
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut config = ReplayConfig {
+            file: PathBuf::from("api.har"),
+            mode: ReplayMode::Strict,
+            match_headers: vec![],
+            match_body: false,
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert("authorization", HeaderValue::from_static("Bearer abc"));
+        let body = Bytes::from_static(br#"{"name":"kittie"}"#);
+        let other_body = Bytes::from_static(b"{}");
+
+        assert!(is_match(
+            &entry,
+            &config,
+            &Method::POST,
+            "/api/users?page=2",
+            &headers,
+            &other_body
+        ));
+        assert!(!is_match(
+            &entry,
+            &config,
+            &Method::GET,
+            "/api/users?page=2",
+            &headers,
+            &body
+        ));
+        assert!(!is_match(
+            &entry,
+            &config,
+            &Method::POST,
+            "/api/users",
+            &headers,
+            &body
+        ));
+
+        config.match_body = true;
+        assert!(!is_match(
+            &entry,
+            &config,
+            &Method::POST,
+            "/api/users?page=2",
+            &headers,
+            &other_body
+        ));
+        assert!(is_match(
+            &entry,
+            &config,
+            &Method::POST,
+            "/api/users?page=2",
+            &headers,
+            &body
+        ));
+
+        config.match_headers = vec!["authorization".to_string()];
+        assert!(is_match(
+            &entry,
+            &config,
+            &Method::POST,
+            "/api/users?page=2",
+            &headers,
+            &body
+        ));
+        assert!(!is_match(
+            &entry,
+            &config,
+            &Method::POST,
+            "/api/users?page=2",
+            &HeaderMap::new(),
+            &body
+        ));
+    }
+}
@@ -0,0 +1,45 @@
+use crate::handlers::proxy::{client_with_connect_timeout, ProxyClient};
+use crate::route_mirror::mirror_globs;
+use crate::route_replay::ReplayStore;
+use bsnext_input::route::{Route, RouteKind};
+use globset::GlobSet;
+use std::sync::Arc;
+
+///
+/// Anything a route needs that's costly to build. It's made once, along with the router,
+/// so it's dropped again when the routes change
+///
+#[derive(Clone, Default)]
+pub struct RouteResources {
+    pub proxy_client: Option<ProxyClient>,
+    pub fallback_proxy_client: Option<ProxyClient>,
+    pub replay: Option<Arc<ReplayStore>>,
+    pub mirror_globs: Option<Arc<GlobSet>>,
+}
+
+impl RouteResources {
+    pub fn new(route: &Route) -> Self {
+        Self {
+            proxy_client: proxy_client(&route.kind),
+            fallback_proxy_client: route
+                .fallback
+                .as_ref()
+                .and_then(|fallback| proxy_client(&fallback.kind)),
+            replay: match &route.kind {
+                RouteKind::Proxy(proxy) if proxy.replay.is_some() => Some(Default::default()),
+                _ => None,
+            },
+            mirror_globs: mirror_globs(route),
+        }
+    }
+}
+
+fn proxy_client(kind: &RouteKind) -> Option<ProxyClient> {
+    match kind {
+        RouteKind::Proxy(proxy) => proxy
+            .timeouts
+            .and_then(|timeouts| timeouts.connect_ms)
+            .map(client_with_connect_timeout),
+        RouteKind::Raw(_) | RouteKind::Dir(_) => None,
+    }
+}
@@ -0,0 +1,48 @@
+use crate::route_effect::RouteEffect;
+use axum::extract::{Request, State};
+use axum::response::Response;
+use bsnext_input::route::Route;
+use http::{StatusCode, Uri};
+
+#[derive(Debug, Clone)]
+pub struct Status {
+    status: StatusCode,
+}
+
+impl Status {
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+}
+
+impl RouteEffect for Status {
+    fn new_opt(
+        Route { opts, .. }: &Route,
+        _req: &Request,
+        _uri: &Uri,
+        _outer_uri: &Uri,
+    ) -> Option<Self> {
+        let code = opts.status?;
+        match StatusCode::from_u16(code) {
+            Ok(status) => Some(Status { status }),
+            Err(err) => {
+                tracing::error!(?err, "invalid status code `{}`", code);
+                None
+            }
+        }
+    }
+}
+
+/// Marks a response whose status was set with `status: ...`, so that a deliberate `404`
+/// is sent as-is, rather than falling through to the next route or the not-found page
+#[derive(Debug, Clone, Copy)]
+pub struct DeliberateStatus;
+
+/// Only successful responses are changed, errors from the route itself are kept as-is
+pub async fn status_layer(State(status): State<StatusCode>, mut res: Response) -> Response {
+    if res.status().is_success() {
+        *res.status_mut() = status;
+        res.extensions_mut().insert(DeliberateStatus);
+    }
+    res
+}
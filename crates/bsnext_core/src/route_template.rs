@@ -0,0 +1,265 @@
+use crate::route_effect::RouteEffect;
+use axum::body::Body;
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use bsnext_guards::OuterUri;
+use bsnext_input::path_def::PathParams;
+use bsnext_input::route::{RawRoute, Route, RouteKind};
+use chrono::format::{Item, StrftimeItems};
+use chrono::Utc;
+use http::header::{CONTENT_LENGTH, CONTENT_TYPE};
+use http::StatusCode;
+use http::Uri;
+use http_body_util::BodyExt;
+use minijinja::{AutoEscape, Environment, ErrorKind, Value};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+///
+/// Renders `html`, `json` and `raw` bodies with [minijinja](https://docs.rs/minijinja), when
+/// `template: true` is set on a route.
+///
+/// Templates can read from `params` (from the route path, eg: `/users/:id`), `query`, `headers`,
+/// `method`, `path` and `body` (the request body, when it's JSON), and call the
+/// helpers `now()`, `uuid()` and `faker(kind)`
+///
+/// For `json` routes, each string inside the JSON is rendered on its own, so the output is
+/// always valid JSON
+///
+#[derive(Debug, Clone)]
+pub struct Template;
+
+impl RouteEffect for Template {
+    fn new_opt(route: &Route, _req: &Request, _uri: &Uri, _outer_uri: &Uri) -> Option<Self> {
+        match &route.kind {
+            RouteKind::Raw(
+                RawRoute::Sse { .. } | RawRoute::Redirect { .. } | RawRoute::NoContent { .. },
+            ) => None,
+            RouteKind::Raw(_) => route.opts.template.is_some_and(|t| t).then_some(Template),
+            RouteKind::Proxy(_) | RouteKind::Dir(_) => None,
+        }
+    }
+}
+
+pub async fn template_mw(
+    State(path_params): State<Arc<PathParams>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let outer_uri = req
+        .extensions()
+        .get::<OuterUri>()
+        .map(|OuterUri(uri)| uri.clone())
+        .unwrap_or_else(|| req.uri().clone());
+    let (parts, body) = req.into_parts();
+    let req_body = match body.collect().await {
+        Ok(collected) => collected.to_bytes(),
+        Err(e) => {
+            tracing::error!(?e, "could not read request body for template");
+            Default::default()
+        }
+    };
+
+    let query: BTreeMap<String, String> = outer_uri
+        .query()
+        .and_then(|q| serde_urlencoded::from_str(q).ok())
+        .unwrap_or_default();
+    let headers: BTreeMap<String, String> = parts
+        .headers
+        .iter()
+        .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+        .collect();
+    let params: BTreeMap<String, String> = path_params.all(outer_uri.path()).into_iter().collect();
+    let body = serde_json::from_slice::<serde_json::Value>(&req_body).unwrap_or_default();
+    let ctx = json!({
+        "params": params,
+        "query": query,
+        "headers": headers,
+        "method": parts.method.as_str(),
+        "path": outer_uri.path(),
+        "body": body,
+    });
+
+    let res = next
+        .run(Request::from_parts(parts, Body::from(req_body)))
+        .await;
+    let (mut res_parts, res_body) = res.into_parts();
+    let bytes = match res_body.collect().await {
+        Ok(collected) => collected.to_bytes(),
+        Err(e) => {
+            tracing::error!(?e, "could not read response body for template");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    let Ok(src) = std::str::from_utf8(&bytes) else {
+        return Response::from_parts(res_parts, Body::from(bytes));
+    };
+
+    let content_type = res_parts
+        .headers
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    let rendered = if content_type.starts_with("application/json") {
+        serde_json::from_str::<serde_json::Value>(src)
+            .map_err(|e| e.to_string())
+            .and_then(|value| render_json(&env(false), value, &ctx))
+            .map(|value| value.to_string())
+    } else {
+        let escape = content_type.starts_with("text/html");
+        env(escape).render_str(src, &ctx).map_err(|e| e.to_string())
+    };
+
+    match rendered {
+        Ok(rendered) => {
+            res_parts.headers.remove(CONTENT_LENGTH);
+            Response::from_parts(res_parts, Body::from(rendered))
+        }
+        Err(e) => {
+            tracing::error!(?e, "could not render template");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("could not render template: {e}"),
+            )
+                .into_response()
+        }
+    }
+}
+
+fn render_json(
+    env: &Environment,
+    value: serde_json::Value,
+    ctx: &serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    use serde_json::Value as J;
+    Ok(match value {
+        J::String(s) => J::String(env.render_str(&s, ctx).map_err(|e| e.to_string())?),
+        J::Array(items) => J::Array(
+            items
+                .into_iter()
+                .map(|item| render_json(env, item, ctx))
+                .collect::<Result<_, _>>()?,
+        ),
+        J::Object(map) => J::Object(
+            map.into_iter()
+                .map(|(k, v)| Ok((k, render_json(env, v, ctx)?)))
+                .collect::<Result<_, String>>()?,
+        ),
+        other => other,
+    })
+}
+
+fn env(escape_html: bool) -> Environment<'static> {
+    let mut env = Environment::new();
+    if escape_html {
+        env.set_auto_escape_callback(|_| AutoEscape::Html);
+    }
+    env.add_function("now", now);
+    env.add_function("uuid", || uuid::Uuid::new_v4().to_string());
+    env.add_function("faker", faker);
+    env
+}
+
+/// The current time as RFC 3339, or in the given `strftime` format
+fn now(format: Option<String>) -> Result<String, minijinja::Error> {
+    let now = Utc::now();
+    let Some(format) = format else {
+        return Ok(now.to_rfc3339());
+    };
+    // chrono panics while displaying an invalid format, so it's checked up front
+    let items = StrftimeItems::new(&format).collect::<Vec<_>>();
+    if items.contains(&Item::Error) {
+        return Err(minijinja::Error::new(
+            ErrorKind::InvalidOperation,
+            format!("invalid format `{format}` for now()"),
+        ));
+    }
+    Ok(now.format_with_items(items.iter()).to_string())
+}
+
+const FIRST_NAMES: &str =
+    "Ada Alan Grace Linus Margaret Ken Barbara Dennis Radia Tim Katherine Guido";
+const LAST_NAMES: &str =
+    "Lovelace Turing Hopper Torvalds Hamilton Thompson Liskov Ritchie Perlman Johnson";
+const WORDS: &str = "lorem ipsum dolor sit amet consectetur adipiscing elit sed do eiusmod tempor";
+const CITIES: &str = "London Paris Tokyo Lagos Lima Toronto Sydney Berlin";
+const COMPANIES: &str = "Acme Globex Initech Umbrella Hooli Soylent";
+
+fn pick(list: &'static str) -> &'static str {
+    let words = list.split_whitespace().collect::<Vec<_>>();
+    words
+        .choose(&mut rand::thread_rng())
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Random, realistic-looking values - `name`, `first_name`, `last_name`, `email`, `word`,
+/// `sentence`, `city`, `company`, `phone`, `number` or `bool`
+fn faker(kind: &str) -> Result<Value, minijinja::Error> {
+    let mut rng = rand::thread_rng();
+    let value = match kind {
+        "first_name" => Value::from(pick(FIRST_NAMES)),
+        "last_name" => Value::from(pick(LAST_NAMES)),
+        "name" => Value::from(format!("{} {}", pick(FIRST_NAMES), pick(LAST_NAMES))),
+        "email" => Value::from(format!(
+            "{}.{}@example.com",
+            pick(FIRST_NAMES).to_lowercase(),
+            pick(LAST_NAMES).to_lowercase()
+        )),
+        "word" => Value::from(pick(WORDS)),
+        "sentence" => {
+            let words = (0..6).map(|_| pick(WORDS)).collect::<Vec<_>>().join(" ");
+            let mut chars = words.chars();
+            let first = chars.next().map(|c| c.to_uppercase().to_string());
+            Value::from(format!("{}{}.", first.unwrap_or_default(), chars.as_str()))
+        }
+        "city" => Value::from(pick(CITIES)),
+        "company" => Value::from(format!("{} Inc", pick(COMPANIES))),
+        "phone" => Value::from(format!("555-{:04}", rng.gen_range(0..10000))),
+        "number" => Value::from(rng.gen_range(0..1000)),
+        "bool" => Value::from(rng.gen_bool(0.5)),
+        other => {
+            return Err(minijinja::Error::new(
+                minijinja::ErrorKind::InvalidOperation,
+                format!("unknown faker kind `{other}`"),
+            ))
+        }
+    };
+    Ok(value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_render_json() {
+        let ctx = json!({ "params": { "id": "12" } });
+        let value = json!({ "id": "{{ params.id }}", "tags": ["{{ params.id | upper }}"], "n": 1 });
+        let rendered = render_json(&env(false), value, &ctx).unwrap();
+        assert_eq!(rendered, json!({ "id": "12", "tags": ["12"], "n": 1 }));
+    }
+
+    #[test]
+    fn test_helpers() {
+        let ctx = json!({});
+        let out = env(false)
+            .render_str("{{ uuid() | length }} {{ faker('email') }}", &ctx)
+            .unwrap();
+        let (len, email) = out.split_once(' ').unwrap();
+        assert_eq!(len, "36");
+        assert!(email.ends_with("@example.com"));
+        assert!(env(false).render_str("{{ faker('nope') }}", &ctx).is_err());
+        assert_eq!(
+            env(false)
+                .render_str("{{ now('%Y') | length }}", &ctx)
+                .unwrap(),
+            "4"
+        );
+        assert!(env(false).render_str("{{ now('%Q') }}", &ctx).is_err());
+    }
+}
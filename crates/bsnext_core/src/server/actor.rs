@@ -1,3 +1,4 @@
+use crate::server::handler_change::ServedDirs;
 use crate::server::handler_stop::Stop;
 use crate::server::signals::ServerSignals;
 use crate::server::state::ServerState;
@@ -15,6 +16,7 @@ use tracing::{span, Level};
 pub struct ServerActor {
     pub config: ServerConfig,
     pub routes_manifest: RoutesManifest,
+    pub served_dirs: ServedDirs,
     pub signals: Option<ServerSignals>,
     pub app_state: Option<Arc<ServerState>>,
     pub addr: Option<SocketAddr>,
@@ -29,6 +31,7 @@ impl ServerActor {
             app_state: None,
             addr: None,
             routes_manifest,
+            served_dirs: ServedDirs::default(),
         }
     }
     pub fn install_signals(&mut self) -> (Sender<()>, Handle, broadcast::Sender<ClientEvent>) {
@@ -1,6 +1,7 @@
 use crate::server::actor::ServerActor;
 
-use bsnext_dto::{ChangeDTO, ChangeKind, ClientEvent};
+use bsnext_dto::{ChangeDTO, ChangeEffect, ChangeKind, ClientEvent};
+use bsnext_input::route::{Route, RouteKind};
 use std::path::{Path, PathBuf};
 
 #[derive(Clone, Debug)]
@@ -56,12 +57,113 @@ impl Change {
     }
 }
 
+impl Change {
+    /// Like the plain conversion, but also decides what browsers should do about each file
+    /// and which urls the `dir` routes serve it at. Relative paths are resolved against `cwd`
+    pub fn classify(&self, dirs: &ServedDirs, cwd: &Path) -> ChangeDTO {
+        match self {
+            Change::Fs { path, change_kind } => {
+                let urls = dirs.urls_for(&cwd.join(path));
+                ChangeDTO::Fs {
+                    path: path.to_string_lossy().to_string(),
+                    change_kind: change_kind.clone(),
+                    effect: Some(effect_for(path)),
+                    urls: (!urls.is_empty()).then_some(urls),
+                }
+            }
+            Change::FsMany(changes) => ChangeDTO::FsMany(
+                changes
+                    .iter()
+                    .map(|change| change.classify(dirs, cwd))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Stylesheets and images can be swapped in place, anything else needs a full reload
+fn effect_for(path: &Path) -> ChangeEffect {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default();
+    match name.rsplit_once('.').map(|(_, ext)| ext) {
+        Some("css") => ChangeEffect::InjectCss,
+        Some("map") if name.ends_with(".css.map") => ChangeEffect::InjectCss,
+        Some("jpg" | "jpeg" | "png" | "gif" | "svg") => ChangeEffect::SwapImage,
+        _ => ChangeEffect::ReloadPage,
+    }
+}
+
+///
+/// The `dir` routes of a server, with their roots resolved when the routes are loaded,
+/// so that classifying a change doesn't need to touch the file system
+///
+#[derive(Debug, Default, Clone)]
+pub struct ServedDirs(Vec<ServedDir>);
+
+#[derive(Debug, Clone)]
+struct ServedDir {
+    route_path: String,
+    root: PathBuf,
+    // watchers can report the resolved path, eg: `/private/var` on macOS
+    canonical: Option<PathBuf>,
+}
+
+impl ServedDirs {
+    pub fn new(routes: &[Route], cwd: &Path) -> Self {
+        let dirs = routes
+            .iter()
+            .filter_map(|route| {
+                let RouteKind::Dir(dir_route) = &route.kind else {
+                    return None;
+                };
+                let root = dir_route.root(cwd);
+                let canonical = root.canonicalize().ok().filter(|c| *c != root);
+                Some(ServedDir {
+                    route_path: route.path.as_str().to_string(),
+                    root,
+                    canonical,
+                })
+            })
+            .collect();
+        Self(dirs)
+    }
+
+    fn urls_for(&self, path: &Path) -> Vec<String> {
+        self.0
+            .iter()
+            .filter_map(|dir| {
+                let relative = path.strip_prefix(&dir.root).ok().or_else(|| {
+                    let canonical = dir.canonical.as_ref()?;
+                    path.strip_prefix(canonical).ok()
+                })?;
+                let segments = relative
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>();
+                let base = dir.route_path.as_str();
+                if segments.is_empty() {
+                    return Some(base.to_string());
+                }
+                Some(format!(
+                    "{}/{}",
+                    base.trim_end_matches('/'),
+                    segments.join("/")
+                ))
+            })
+            .collect()
+    }
+}
+
 impl From<&Change> for ChangeDTO {
     fn from(value: &Change) -> Self {
         match value {
             Change::Fs { path, change_kind } => Self::Fs {
                 path: path.to_string_lossy().to_string(),
                 change_kind: change_kind.clone(),
+                effect: None,
+                urls: None,
             },
             Change::FsMany(changes) => Self::FsMany(
                 changes
@@ -70,6 +172,8 @@ impl From<&Change> for ChangeDTO {
                         Change::Fs { path, change_kind } => Self::Fs {
                             path: path.to_string_lossy().to_string(),
                             change_kind: change_kind.clone(),
+                            effect: None,
+                            urls: None,
                         },
                         Change::FsMany(_) => unreachable!("recursive not supported"),
                     })
@@ -111,23 +215,68 @@ mod test {
         print!("{json}");
         Ok(())
     }
+
+    #[test]
+    fn test_classify() -> anyhow::Result<()> {
+        let routes: Vec<Route> = serde_yaml::from_str(
+            r#"
+- path: /
+  dir: public
+- path: /assets
+  dir: src/styles
+- path: /favicon.ico
+  dir: /static/favicon.ico
+- path: /api
+  raw: ok
+"#,
+        )?;
+        let cwd = Path::new("/project");
+        let dirs = ServedDirs::new(&routes, cwd);
+        let change = Change::fs_many(&[
+            "/project/src/styles/core/main.css",
+            "/project/public/logo.PNG",
+            "/static/favicon.ico",
+            "src/styles/main.css.map",
+            "/elsewhere/index.html",
+        ]);
+        let json = serde_json::to_value(change.classify(&dirs, cwd))?;
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "kind": "FsMany",
+                "payload": [
+                    {"kind": "Fs", "payload": {"path": "/project/src/styles/core/main.css", "change_kind": "Changed", "effect": "InjectCss", "urls": ["/assets/core/main.css"]}},
+                    {"kind": "Fs", "payload": {"path": "/project/public/logo.PNG", "change_kind": "Changed", "effect": "SwapImage", "urls": ["/logo.PNG"]}},
+                    {"kind": "Fs", "payload": {"path": "/static/favicon.ico", "change_kind": "Changed", "effect": "ReloadPage", "urls": ["/favicon.ico"]}},
+                    {"kind": "Fs", "payload": {"path": "src/styles/main.css.map", "change_kind": "Changed", "effect": "InjectCss", "urls": ["/assets/main.css.map"]}},
+                    {"kind": "Fs", "payload": {"path": "/elsewhere/index.html", "change_kind": "Changed", "effect": "ReloadPage"}},
+                ]
+            })
+        );
+        Ok(())
+    }
 }
 
 impl actix::Handler<ChangeWithSpan> for ServerActor {
     type Result = ();
 
+    // handled in place (rather than in a future) so that changes reach browsers in order
     fn handle(&mut self, msg: ChangeWithSpan, _ctx: &mut Self::Context) -> Self::Result {
-        if let Some(client_sender) = self.signals.as_ref().and_then(|s| s.client_sender.as_ref()) {
-            // todo: what messages are the clients expecting?
-            tracing::info!("forwarding `Change` event to connected web socket clients");
-            match client_sender.send(ClientEvent::Change((&msg.evt).into())) {
-                Ok(_) => {
-                    tracing::trace!("change event sent to clients");
-                }
-                Err(_) => tracing::error!("not sent to client_sender"),
-            };
-        } else {
-            tracing::debug!("signals not ready, should they be?");
-        }
+        let Some(client_sender) = self.client_sender() else {
+            return tracing::debug!("signals not ready, should they be?");
+        };
+        let change = match &self.app_state {
+            Some(app_state) => msg
+                .evt
+                .classify(&self.served_dirs, app_state.runtime_ctx.cwd()),
+            None => (&msg.evt).into(),
+        };
+        tracing::info!("forwarding `Change` event to connected web socket clients");
+        match client_sender.send(ClientEvent::Change(change)) {
+            Ok(_) => {
+                tracing::trace!("change event sent to clients");
+            }
+            Err(_) => tracing::error!("not sent to client_sender"),
+        };
     }
 }
@@ -1,17 +1,22 @@
+use crate::clients::Clients;
 use crate::handler_stack::RouteMap;
+use crate::record::Recorder;
 use crate::runtime_ctx::RuntimeCtx;
 use crate::server::actor::ServerActor;
+use crate::server::handler_change::ServedDirs;
 use crate::server::router::make_router;
 use crate::server::state::ServerState;
+use crate::server::tls::rustls_config;
 use crate::servers_supervisor::get_servers_handler::{GetActiveServers, IncomingEvents};
+use crate::ws::GHOST_CAPACITY;
 use actix::{Recipient, ResponseFuture};
 use actix_rt::Arbiter;
 use bsnext_dto::internal::ServerError;
-use bsnext_input::server_config::ServerIdentity;
+use bsnext_input::server_config::{RecordConfig, ServerIdentity};
 use std::io::ErrorKind;
 use std::net::{SocketAddr, TcpListener};
 use std::sync::Arc;
-use tokio::sync::{oneshot, RwLock};
+use tokio::sync::{broadcast, oneshot, RwLock};
 
 #[derive(actix::Message)]
 #[rtype(result = "Result<SocketAddr, ServerError>")]
@@ -26,6 +31,8 @@ impl actix::Handler<Listen> for ServerActor {
 
     fn handle(&mut self, msg: Listen, _ctx: &mut Self::Context) -> Self::Result {
         let identity = self.config.identity.clone();
+        let tls = self.config.tls.clone();
+        let cwd = msg.runtime_ctx.cwd().clone();
         tracing::trace!("actor started for {:?}", identity);
         let (send_complete, handle, client_sender) = self.install_signals();
         let (oneshot_send, oneshot_rec) = oneshot::channel();
@@ -34,6 +41,7 @@ impl actix::Handler<Listen> for ServerActor {
 
         let router =
             RouteMap::new_from_routes(&self.config.combined_routes()).into_router(&msg.runtime_ctx);
+        self.served_dirs = ServedDirs::new(&self.config.combined_routes(), &cwd);
 
         let app_state = Arc::new(ServerState {
             // parent: ,
@@ -45,6 +53,16 @@ impl actix::Handler<Listen> for ServerActor {
             parent: Some(msg.parent.clone()),
             evt_receiver: Some(msg.evt_receiver.clone()),
             client_sender: Arc::new(client_sender),
+            ghost_sender: Arc::new(broadcast::channel(GHOST_CAPACITY).0),
+            recorder: Arc::new(Recorder::new(
+                self.config
+                    .record
+                    .as_ref()
+                    .is_some_and(RecordConfig::is_enabled),
+                self.config.scheme(),
+            )),
+            auth: Arc::new(RwLock::new(self.config.auth.clone())),
+            clients: Arc::new(Clients::default()),
         });
 
         self.app_state = Some(app_state.clone());
@@ -84,13 +102,45 @@ impl actix::Handler<Listen> for ServerActor {
                 return;
             };
 
-            tracing::trace!("trying to listen on {:?}", socket_addr);
+            let tls_config = match tls {
+                None => Ok(None),
+                Some(tls) => rustls_config(&tls, &cwd, &socket_addr).await,
+            };
+
+            let tls_config = match tls_config {
+                Ok(tls_config) => tls_config,
+                Err(server_error) => {
+                    tracing::debug!("{:?} [❌ NOT started] {}", identity, server_error);
+                    if oneshot_send.send(Err(server_error)).is_err() {
+                        tracing::debug!("❌ oneshot send failed")
+                    }
+                    return;
+                }
+            };
 
-            let server = axum_server::bind(socket_addr)
-                .handle(h1)
-                .serve(router.into_make_service_with_connect_info::<SocketAddr>());
+            tracing::trace!(
+                tls = tls_config.is_some(),
+                "trying to listen on {:?}",
+                socket_addr
+            );
+
+            let make_service = router.into_make_service_with_connect_info::<SocketAddr>();
+            let server = match tls_config {
+                Some(tls_config) => {
+                    axum_server::bind_rustls(socket_addr, tls_config)
+                        .handle(h1)
+                        .serve(make_service)
+                        .await
+                }
+                None => {
+                    axum_server::bind(socket_addr)
+                        .handle(h1)
+                        .serve(make_service)
+                        .await
+                }
+            };
 
-            let result: Result<_, ServerError> = match server.await {
+            let result: Result<_, ServerError> = match server {
                 Ok(_) => {
                     tracing::debug!("{:?} [started] Server all done", identity);
                     if send_complete.send(()).is_err() {
@@ -1,9 +1,10 @@
 use crate::handler_stack::RouteMap;
 use crate::server::actor::ServerActor;
+use crate::server::handler_change::ServedDirs;
 use actix::ResponseFuture;
 use bsnext_input::client_config::ClientConfigChangeSet;
 use bsnext_input::route_manifest::{RouteChangeSet, RoutesManifest};
-use bsnext_input::server_config::ServerConfig;
+use bsnext_input::server_config::{RecordConfig, ServerConfig};
 
 #[derive(actix::Message, Clone)]
 #[rtype(result = "anyhow::Result<(RouteChangeSet, ClientConfigChangeSet)>")]
@@ -27,6 +28,20 @@ impl actix::Handler<Patch> for ServerActor {
         let next_manifest = RoutesManifest::new(&routes);
         let changeset = self.routes_manifest.changeset_for(&next_manifest);
         self.routes_manifest = RoutesManifest::new(&routes);
+        self.served_dirs = ServedDirs::new(&routes, app_state.runtime_ctx.cwd());
+
+        // Recording can be toggled without a restart
+        let recording = msg
+            .server_config
+            .record
+            .as_ref()
+            .is_some_and(RecordConfig::is_enabled);
+        app_state.recorder.set_enabled(recording);
+        self.config.record = msg.server_config.record.clone();
+
+        // Auth can be changed without a restart too
+        let auth = msg.server_config.auth.clone();
+        self.config.auth = auth.clone();
 
         // Process client configuration changes
         let client_config = msg.server_config.clients.clone();
@@ -60,6 +75,11 @@ impl actix::Handler<Patch> for ServerActor {
                 drop(mut_client_config);
                 tracing::trace!("did update client_config");
 
+                let mut mut_auth = app_state_clone.auth.write().await;
+                *mut_auth = auth;
+                drop(mut_auth);
+                tracing::trace!("did update auth");
+
                 Ok((changeset, client_config_change_set))
             }
         })
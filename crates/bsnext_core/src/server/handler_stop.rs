@@ -1,7 +1,10 @@
 use crate::server::actor::ServerActor;
+use crate::server::state::ServerState;
 use actix::ActorContext;
 use std::future::Future;
+use std::path::PathBuf;
 use std::pin::Pin;
+use std::sync::Arc;
 
 #[derive(actix::Message)]
 #[rtype(result = "()")]
@@ -23,6 +26,15 @@ impl actix::Handler<Stop> for ServerActor {
         tracing::trace!("{:?} using handle to shutdown", self.config.identity);
         handle.shutdown();
         ctx.stop();
+
+        let har_path = self
+            .config
+            .record
+            .as_ref()
+            .and_then(|record| record.har_path())
+            .cloned();
+        let app_state = self.app_state.clone();
+
         if let Some(complete_msg_receiver) = signals.complete_mdg_receiver {
             tracing::debug!("{:?} confirmed closed via signal", self.addr);
             Box::pin(async move {
@@ -30,9 +42,28 @@ impl actix::Handler<Stop> for ServerActor {
                     Ok(_) => {}
                     Err(e) => tracing::debug!("failed to get complete message {e}"),
                 }
+                if let (Some(har_path), Some(app_state)) = (har_path, app_state) {
+                    write_har(&har_path, &app_state).await;
+                }
             })
         } else {
             todo!("cannot get here?")
         }
     }
 }
+
+async fn write_har(har_path: &PathBuf, app_state: &Arc<ServerState>) {
+    let har = app_state.recorder.to_har().await;
+    let path = app_state.runtime_ctx.cwd().join(har_path);
+    let result = serde_json::to_vec_pretty(&har)
+        .map_err(anyhow::Error::from)
+        .and_then(|json| std::fs::write(&path, json).map_err(anyhow::Error::from));
+    match result {
+        Ok(_) => tracing::info!(
+            "wrote {} recorded requests to {}",
+            har.log.entries.len(),
+            path.display()
+        ),
+        Err(e) => tracing::error!(?e, "could not write HAR file {}", path.display()),
+    }
+}
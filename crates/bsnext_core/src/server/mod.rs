@@ -10,3 +10,4 @@ pub mod handler_stop;
 pub mod router;
 pub mod signals;
 pub mod state;
+pub mod tls;
@@ -2,20 +2,24 @@ use crate::server::router::make_router;
 use crate::server::state::ServerState;
 use std::net::SocketAddr;
 
+use crate::clients::Clients;
 use crate::handler_stack::RouteMap;
+use crate::record::Recorder;
 use crate::runtime_ctx::RuntimeCtx;
+use crate::ws::GHOST_CAPACITY;
 use axum::body::Body;
 use axum::extract::Request;
 use axum::middleware::{from_fn, Next};
 use axum::response::{IntoResponse, Response};
 use axum::Router;
 use bsnext_dto::ClientEvent;
-use bsnext_input::server_config::ServerConfig;
+use bsnext_input::server_config::{RecordConfig, ServerConfig};
 use bsnext_input::Input;
 use http::header::ACCEPT;
 use http::response::Parts;
 use http::HeaderValue;
 use mime_guess::mime::TEXT_HTML;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
@@ -35,6 +39,13 @@ pub fn into_state(val: ServerConfig) -> ServerState {
         parent: None,
         evt_receiver: None,
         client_sender: Arc::new(sender),
+        ghost_sender: Arc::new(tokio::sync::broadcast::channel(GHOST_CAPACITY).0),
+        recorder: Arc::new(Recorder::new(
+            val.record.as_ref().is_some_and(RecordConfig::is_enabled),
+            val.scheme(),
+        )),
+        auth: Arc::new(RwLock::new(val.auth.clone())),
+        clients: Arc::new(Clients::default()),
     }
 }
 
@@ -101,6 +112,36 @@ pub fn from_yaml_blocking(yaml: &str, uri: &str) -> anyhow::Result<(Parts, Strin
         .block_on(async { Ok(uri_to_res_parts(state.clone(), uri).await) })
 }
 
+///
+/// A GET to one of the named servers in an example input. Paths in the examples are
+/// relative to the repo root, so that's used as the cwd
+///
+pub async fn example_req(
+    yaml: &str,
+    server: &str,
+    uri: &str,
+    accept: Option<&str>,
+) -> anyhow::Result<(Parts, String)> {
+    let input: Input = serde_yaml::from_str(yaml)?;
+    let server = input
+        .servers
+        .iter()
+        .find(|x| x.identity.is_named(server))
+        .expect("server in example");
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(2)
+        .expect("repo root");
+    let ctx = RuntimeCtx::new(root);
+    let router = RouteMap::new_from_routes(&server.routes).into_router(&ctx);
+    let mut req = Request::get(uri);
+    if let Some(accept) = accept {
+        req = req.header(ACCEPT, accept);
+    }
+    let res = router.oneshot(req.body(Body::empty())?).await?;
+    Ok(to_resp_parts_and_body(res).await)
+}
+
 pub fn header_pairs(parts: &Parts) -> Vec<(String, String)> {
     parts
         .headers
@@ -10,6 +10,8 @@ use axum::{http, middleware, Extension, Router};
 
 use crate::meta::MetaData;
 use crate::not_found::not_found_service::not_found_loader;
+use crate::record::record_layer;
+use crate::route_auth::{server_auth_mw, AuthToken};
 use crate::server::router::assets::pub_ui_assets;
 use crate::server::router::pub_api::pub_api;
 use crate::server::state::ServerState;
@@ -41,13 +43,21 @@ pub fn make_router(state: &Arc<ServerState>) -> Router {
 
     let router = Router::new()
         .merge(built_ins(state.clone()).layer(CorsLayer::permissive()))
-        .merge(dynamic_loaders(state.clone()));
+        .merge(dynamic_loaders(state.clone()))
+        // server-wide auth covers the built-ins too, so the api and websocket aren't left open
+        .layer(from_fn_with_state(state.clone(), server_auth_mw));
 
-    router
+    let router = router
         .layer(TraceLayer::new_for_http())
-        .layer(Extension(client))
+        .layer(Extension(client));
     // todo: When to add this compression back in?
     // .layer(CompressionLayer::new())
+
+    // lets handlers that live outside of the state (like proxies) raise events
+    match &state.evt_receiver {
+        Some(evt_receiver) => router.layer(Extension(evt_receiver.clone())),
+        None => router,
+    }
 }
 
 pub fn built_ins(state: Arc<ServerState>) -> Router {
@@ -70,11 +80,13 @@ pub fn built_ins(state: Arc<ServerState>) -> Router {
     }
     async fn js_handler(_uri: Uri, req: Request) -> impl IntoResponse {
         let host = req.headers().get(HOST);
+        let token = req.extensions().get::<AuthToken>();
         let inject = InjectConfig {
             ctx_message: "This InjectConfig was created in the Browsersync LIVE js_handler".into(),
             connect: ConnectInfo {
                 ws_path: WS_PATH.into(),
                 host: host.and_then(|x| x.to_str().ok().map(ToOwned::to_owned)),
+                token: token.map(|AuthToken(token)| token.clone()),
             },
         };
         (
@@ -103,6 +115,7 @@ pub fn dynamic_loaders(state: Arc<ServerState>) -> Router {
     Router::new()
         .layer(
             ServiceBuilder::new()
+                .layer(from_fn_with_state(state.clone(), record_layer))
                 .layer(from_fn_with_state(state.clone(), tagging_layer))
                 .layer(from_fn_with_state(state.clone(), not_found_loader))
                 .layer(from_fn_with_state(state.clone(), dynamic_router)),
@@ -1,14 +1,21 @@
 use crate::server::state::ServerState;
 use crate::servers_supervisor::file_changed_handler::FilesChanged;
 use crate::servers_supervisor::get_servers_handler::{GetActiveServers, IncomingEvents};
-use axum::extract::State;
+use crate::ws::forward_console;
+use axum::extract::{ConnectInfo, State};
 use axum::response::IntoResponse;
 use axum::routing::{get, post};
 use axum::{Json, Router};
-use bsnext_dto::{ChangeDTO, ClientEvent, GetActiveServersResponseDTO, RouteDTO, ServerDesc};
+use bsnext_dto::{
+    ChangeDTO, ClientDTO, ClientEvent, ConnectedClientsDTO, GetActiveServersResponseDTO, RouteDTO,
+    ServerDesc,
+};
 use bsnext_fs::FsEventContext;
-use http::{StatusCode, Uri};
+use bsnext_input::client_config::ClientConfig;
+use http::header::USER_AGENT;
+use http::{HeaderMap, StatusCode, Uri};
 use serde_json::json;
+use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -29,6 +36,22 @@ async fn all_servers_handler(State(app): State<Arc<ServerState>>, _uri: Uri) ->
     }
 }
 
+async fn get_requests(State(app): State<Arc<ServerState>>) -> impl IntoResponse {
+    Json(app.recorder.to_har().await)
+}
+
+async fn delete_requests(State(app): State<Arc<ServerState>>) -> impl IntoResponse {
+    app.recorder.clear().await;
+    Json(json!({"ok": true}))
+}
+
+async fn get_clients(State(app): State<Arc<ServerState>>) -> impl IntoResponse {
+    let clients = app.clients.list().await;
+    Json(ConnectedClientsDTO {
+        clients: clients.iter().map(ClientDTO::from).collect(),
+    })
+}
+
 async fn get_current_server(State(app): State<Arc<ServerState>>, _uri: Uri) -> impl IntoResponse {
     let routes = app.routes.read().await;
     let dto = ServerDesc {
@@ -43,49 +66,161 @@ pub fn pub_api(state: Arc<ServerState>) -> Router<Arc<ServerState>> {
         .route("/servers", get(all_servers_handler))
         .route("/me", get(get_current_server))
         .route("/events", post(post_events))
+        .route("/clients", get(get_clients))
+        .route("/requests", get(get_requests).delete(delete_requests))
         .with_state(state.clone())
 }
 
+///
+/// A control API for editor plugins and scripts. Anything a server would normally
+/// send to browsers can be posted here, along with a few commands
+///
 async fn post_events(
     State(app): State<Arc<ServerState>>,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    headers: HeaderMap,
     Json(payload): Json<ClientEvent>,
 ) -> impl IntoResponse {
     tracing::trace!("Got post event: {:?}", payload);
-    match &app.evt_receiver {
-        None => unreachable!("should be unreachable?"),
-        Some(recv) => {
-            let paths = match payload {
-                ClientEvent::Change(ChangeDTO::Fs { path, .. }) => {
-                    vec![PathBuf::from(path)]
-                }
-                ClientEvent::Change(ChangeDTO::FsMany(changes)) => changes
-                    .into_iter()
-                    .map(|x| match x {
-                        ChangeDTO::Fs { path, .. } => PathBuf::from(path),
-                        ChangeDTO::FsMany(..) => todo!("recursion..."),
-                    })
-                    .collect::<Vec<_>>(),
-                ClientEvent::Config(_) => {
-                    todo!("handle ClientEvent::Config  in incoming event handler...")
-                }
-                ClientEvent::WsConnection(_) => {
-                    todo!("handle ClientEvent::WsConnection  in incoming event handler?")
-                }
-                ClientEvent::DisplayMessage(_) => {
-                    todo!("handle ClientEvent::DisplayMessage  in incoming event handler...")
-                }
-            };
-            match recv
-                .send(IncomingEvents::FilesChanged(FilesChanged {
-                    paths,
-                    ctx: FsEventContext::new(app.id, app.id),
-                }))
-                .await
-            {
-                Ok(_) => {}
-                Err(err) => tracing::error!(?err),
+    let user_agent = headers
+        .get(USER_AGENT)
+        .and_then(|v| v.to_str().ok())
+        .map(ToOwned::to_owned);
+    let addr = connect_info
+        .map(|ConnectInfo(addr)| addr.to_string())
+        .unwrap_or_default();
+    match handle_event(&app, payload, user_agent, addr).await {
+        Ok(()) => Json(json!({"ok": true})).into_response(),
+        Err((status, error)) => {
+            tracing::debug!(?status, error, "rejected posted event");
+            (status, Json(json!({"ok": false, "error": error}))).into_response()
+        }
+    }
+}
+
+type EventRejection = (StatusCode, String);
+
+async fn handle_event(
+    app: &ServerState,
+    event: ClientEvent,
+    user_agent: Option<String>,
+    addr: String,
+) -> Result<(), EventRejection> {
+    let location = match &event {
+        ClientEvent::Addressed(addressed) => match addressed.event.as_ref() {
+            ClientEvent::Location(location) => Some(location),
+            _ => None,
+        },
+        ClientEvent::Location(location) => Some(location),
+        _ => None,
+    };
+    if location.is_some_and(|location| !location.is_same_origin()) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "`Location` paths must start with a single `/`".into(),
+        ));
+    }
+    match event {
+        ClientEvent::Change(change) => {
+            let mut paths = vec![];
+            flatten_change(change.clone(), &mut paths);
+            if paths.is_empty() {
+                return Err((StatusCode::BAD_REQUEST, "no paths in change".into()));
+            }
+            match &app.evt_receiver {
+                // goes through the same pipeline as file watching
+                Some(recv) => recv
+                    .send(IncomingEvents::FilesChanged(FilesChanged {
+                        paths,
+                        ctx: FsEventContext::new(app.id, app.id),
+                    }))
+                    .await
+                    .map_err(|err| (StatusCode::SERVICE_UNAVAILABLE, err.to_string())),
+                // servers without a supervisor can only tell browsers directly
+                None => send_to_browsers(app, ClientEvent::Change(change)),
+            }
+        }
+        ClientEvent::Config(dto) => {
+            let next = ClientConfig::from(&dto);
+            let mut current = app.client_config.write().await;
+            let change_set = current.changeset_for(&next);
+            let Some(changed) = change_set.changed.first() else {
+                tracing::trace!("posted config did not change anything");
+                return Ok(());
             };
+            *current = changed.clone();
+            let event = ClientEvent::Config(changed.into());
+            drop(current);
+            send_to_browsers(app, event)
+        }
+        ClientEvent::WsConnection(..) => Err((
+            StatusCode::BAD_REQUEST,
+            "`WsConnection` is only sent by the server, use `Config` to change the client config"
+                .into(),
+        )),
+        event @ (ClientEvent::DisplayMessage(..)
+        | ClientEvent::Reload
+        | ClientEvent::InjectCss(..)) => send_to_browsers(app, event),
+        // interactions posted here didn't come from a browser, so they go to all of them
+        event @ (ClientEvent::Scroll(..)
+        | ClientEvent::Click(..)
+        | ClientEvent::Input(..)
+        | ClientEvent::Submit(..)
+        | ClientEvent::Location(..)) => {
+            let ghost = app.client_config.read().await.ghost;
+            if !event.is_ghost_enabled(&ghost) {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    "that kind of interaction is not enabled in `clients.ghost`".into(),
+                ));
+            }
+            send_to_browsers(app, event)
+        }
+        ClientEvent::Console(entry) => {
+            let levels = app.client_config.read().await.console.clone();
+            if entry.is_forwarded(&levels) {
+                forward_console(entry, user_agent, addr, &app.evt_receiver);
+            }
+            Ok(())
+        }
+        ClientEvent::Addressed(addressed) => {
+            if matches!(*addressed.event, ClientEvent::Addressed(..)) {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    "`Addressed` events cannot be nested".into(),
+                ));
+            }
+            let connected = app.clients.list().await;
+            let any_connected = connected
+                .iter()
+                .any(|client| addressed.clients.contains(&client.id.to_string()));
+            if !any_connected {
+                return Err((
+                    StatusCode::NOT_FOUND,
+                    "none of the listed clients are connected".into(),
+                ));
+            }
+            // each browser checks whether it's one of the listed clients
+            send_to_browsers(app, ClientEvent::Addressed(addressed))
         }
     }
-    Json(json!({"ok": true})).into_response()
+}
+
+fn flatten_change(change: ChangeDTO, paths: &mut Vec<PathBuf>) {
+    match change {
+        ChangeDTO::Fs { path, .. } => paths.push(PathBuf::from(path)),
+        ChangeDTO::FsMany(changes) => {
+            for change in changes {
+                flatten_change(change, paths);
+            }
+        }
+    }
+}
+
+fn send_to_browsers(app: &ServerState, event: ClientEvent) -> Result<(), EventRejection> {
+    // an error here only means that no browsers are connected, which is fine
+    if app.client_sender.send(event).is_err() {
+        tracing::debug!("no browsers connected to receive the posted event");
+    }
+    Ok(())
 }
@@ -1,10 +1,13 @@
+use crate::clients::Clients;
+use crate::record::Recorder;
 use crate::runtime_ctx::RuntimeCtx;
 use crate::servers_supervisor::get_servers_handler::{GetActiveServers, IncomingEvents};
+use crate::ws::GhostEvent;
 use actix::Recipient;
 use axum::Router;
 use bsnext_dto::ClientEvent;
 use bsnext_input::client_config::ClientConfig;
-use bsnext_input::route::Route;
+use bsnext_input::route::{AuthOpts, Route};
 use std::fmt::Formatter;
 use std::sync::Arc;
 use tokio::sync::{broadcast, RwLock};
@@ -19,6 +22,10 @@ pub struct ServerState {
     pub parent: Option<Recipient<GetActiveServers>>,
     pub evt_receiver: Option<Recipient<IncomingEvents>>,
     pub client_sender: Arc<broadcast::Sender<ClientEvent>>,
+    pub ghost_sender: Arc<broadcast::Sender<GhostEvent>>,
+    pub recorder: Arc<Recorder>,
+    pub auth: Arc<RwLock<Option<AuthOpts>>>,
+    pub clients: Arc<Clients>,
 }
 
 impl std::fmt::Debug for ServerState {
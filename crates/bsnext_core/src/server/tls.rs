@@ -0,0 +1,100 @@
+use axum_server::tls_rustls::RustlsConfig;
+use bsnext_dto::internal::ServerError;
+use bsnext_input::server_config::TlsConfig;
+use std::fs;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+
+///
+/// Where self-signed certificates are written, relative to the cwd.
+/// Re-using the same files across restarts means a browser only needs to trust it once
+///
+pub const SELF_SIGNED_DIR: &str = ".bslive/tls";
+pub const SELF_SIGNED_CERT: &str = "cert.pem";
+pub const SELF_SIGNED_KEY: &str = "key.pem";
+
+pub async fn rustls_config(
+    tls: &TlsConfig,
+    cwd: &Path,
+    socket_addr: &SocketAddr,
+) -> Result<Option<RustlsConfig>, ServerError> {
+    let (cert, key) = match tls {
+        TlsConfig::Enabled(false) => return Ok(None),
+        TlsConfig::Enabled(true) => self_signed_pair(&cwd.join(SELF_SIGNED_DIR), socket_addr)?,
+        TlsConfig::Files { cert, key } => (cwd.join(cert), cwd.join(key)),
+    };
+    tracing::debug!(?cert, ?key, "loading tls config");
+    RustlsConfig::from_pem_file(&cert, &key)
+        .await
+        .map(Some)
+        .map_err(|e| ServerError::Tls {
+            reason: format!(
+                "could not load cert: {} key: {}, {e}",
+                cert.display(),
+                key.display()
+            ),
+        })
+}
+
+fn self_signed_pair(
+    dir: &Path,
+    socket_addr: &SocketAddr,
+) -> Result<(PathBuf, PathBuf), ServerError> {
+    let cert_path = dir.join(SELF_SIGNED_CERT);
+    let key_path = dir.join(SELF_SIGNED_KEY);
+
+    if cert_path.exists() && key_path.exists() {
+        tracing::trace!(?cert_path, "re-using self-signed certificate");
+        return Ok((cert_path, key_path));
+    }
+
+    let mut names = vec![
+        "localhost".to_string(),
+        "127.0.0.1".to_string(),
+        "::1".to_string(),
+    ];
+
+    // allow LAN devices to connect when a specific address was given
+    let ip = socket_addr.ip();
+    if !ip.is_unspecified() && !ip.is_loopback() {
+        names.push(ip.to_string());
+    }
+
+    let rcgen::CertifiedKey { cert, key_pair } = rcgen::generate_simple_self_signed(names)
+        .map_err(|e| ServerError::Tls {
+            reason: e.to_string(),
+        })?;
+
+    let write = |path: &Path, contents: String| {
+        fs::write(path, contents).map_err(|e| ServerError::Io(format!("{}: {e}", path.display())))
+    };
+
+    fs::create_dir_all(dir).map_err(|e| ServerError::Io(format!("{}: {e}", dir.display())))?;
+    write(&cert_path, cert.pem())?;
+    write(&key_path, key_pair.serialize_pem())?;
+
+    tracing::debug!(?cert_path, "created self-signed certificate");
+
+    Ok((cert_path, key_path))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_self_signed_is_cached() -> anyhow::Result<()> {
+        let tmp_dir = tempfile::tempdir()?;
+        let dir = tmp_dir.path().join(SELF_SIGNED_DIR);
+        let addr: SocketAddr = "127.0.0.1:3000".parse()?;
+
+        let (cert, key) = self_signed_pair(&dir, &addr)?;
+        let first = fs::read_to_string(&cert)?;
+        assert!(first.contains("BEGIN CERTIFICATE"));
+        assert!(fs::read_to_string(&key)?.contains("PRIVATE KEY"));
+
+        let (cert_2, _) = self_signed_pair(&dir, &addr)?;
+        assert_eq!(fs::read_to_string(cert_2)?, first);
+        Ok(())
+    }
+}
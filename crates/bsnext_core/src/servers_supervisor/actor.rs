@@ -3,7 +3,7 @@ use actix::{Actor, Addr, ResponseFuture, Running};
 
 use crate::server::actor::ServerActor;
 
-use bsnext_input::server_config::ServerIdentity;
+use bsnext_input::server_config::{Scheme, ServerIdentity};
 use bsnext_input::Input;
 use std::collections::HashSet;
 use std::net::SocketAddr;
@@ -13,11 +13,12 @@ use crate::server::handler_listen::Listen;
 use crate::server::handler_patch::Patch;
 use crate::servers_supervisor::input_changed_handler::InputChangedResponse;
 use bsnext_dto::internal::{
-    ChildCreated, ChildHandlerMinimal, ChildNotCreated, ChildNotPatched, ChildPatched, ChildResult,
-    PatchError,
+    AnyEvent, ChildCreated, ChildHandlerMinimal, ChildNotCreated, ChildNotPatched, ChildPatched,
+    ChildResult, PatchError,
 };
 use futures_util::future::join_all;
 use futures_util::FutureExt;
+use tokio::sync::mpsc;
 use tokio::sync::oneshot::Sender;
 use tracing::{span, Instrument, Level};
 
@@ -25,6 +26,7 @@ use tracing::{span, Instrument, Level};
 pub struct ServersSupervisor {
     pub(crate) handlers: std::collections::HashMap<ServerIdentity, ChildHandler>,
     tx: Option<Sender<()>>,
+    pub(crate) any_event_sender: mpsc::Sender<AnyEvent>,
 }
 
 #[derive(Debug, Clone)]
@@ -32,6 +34,7 @@ pub struct ChildHandler {
     pub actor_address: Addr<ServerActor>,
     pub identity: ServerIdentity,
     pub socket_addr: SocketAddr,
+    pub scheme: Scheme,
 }
 
 impl ChildHandler {
@@ -39,15 +42,17 @@ impl ChildHandler {
         ChildHandlerMinimal {
             identity: self.identity.clone(),
             socket_addr: self.socket_addr,
+            scheme: self.scheme,
         }
     }
 }
 
 impl ServersSupervisor {
-    pub fn new(tx: Sender<()>) -> Self {
+    pub fn new(tx: Sender<()>, any_event_sender: mpsc::Sender<AnyEvent>) -> Self {
         Self {
             handlers: std::default::Default::default(),
             tx: Some(tx),
+            any_event_sender,
         }
     }
 
@@ -62,9 +67,32 @@ impl ServersSupervisor {
         let existing: HashSet<_> = self.handlers.values().map(|v| v.identity.clone()).collect();
         let incoming: HashSet<_> = input.servers.iter().map(|s| s.identity.clone()).collect();
 
-        let startup = incoming.difference(&existing).collect::<HashSet<_>>();
-        let shutdown = existing.difference(&incoming).collect::<HashSet<_>>();
-        let patch = existing.intersection(&incoming).collect::<HashSet<_>>();
+        // a listener can't switch between http and https, so those servers are restarted instead
+        let restart = existing
+            .intersection(&incoming)
+            .filter(|identity| {
+                let running = self.handlers.get(identity).map(|child| child.scheme);
+                let next = input
+                    .servers
+                    .iter()
+                    .find(|x| x.identity == **identity)
+                    .map(|config| config.scheme());
+                running != next
+            })
+            .collect::<HashSet<_>>();
+
+        let startup = incoming
+            .difference(&existing)
+            .chain(restart.iter().copied())
+            .collect::<HashSet<_>>();
+        let shutdown = existing
+            .difference(&incoming)
+            .chain(restart.iter().copied())
+            .collect::<HashSet<_>>();
+        let patch = existing
+            .intersection(&incoming)
+            .filter(|identity| !restart.contains(identity))
+            .collect::<HashSet<_>>();
 
         let shutdown_jobs = shutdown
             .into_iter()
@@ -131,6 +159,7 @@ impl ServersSupervisor {
                     Ok(Ok(socket_addr)) => {
                         let evt = ChildResult::Created(ChildCreated {
                             server_handler: ChildHandlerMinimal {
+                                scheme: c.scheme(),
                                 identity: c.identity,
                                 socket_addr,
                             },
@@ -1,6 +1,8 @@
 use crate::servers_supervisor::actor::ServersSupervisor;
 use crate::servers_supervisor::file_changed_handler::FilesChanged;
 use actix::AsyncContext;
+use bsnext_dto::external_events::ExternalEventsDTO;
+use bsnext_dto::internal::AnyEvent;
 use bsnext_dto::{ActiveServer, GetActiveServersResponse};
 
 #[derive(actix::Message)]
@@ -18,6 +20,7 @@ impl actix::Handler<GetActiveServers> for ServersSupervisor {
                 .map(|(identity, child_handler)| ActiveServer {
                     identity: identity.clone(),
                     socket_addr: child_handler.socket_addr,
+                    scheme: child_handler.scheme,
                 })
                 .collect(),
         }
@@ -28,6 +31,8 @@ impl actix::Handler<GetActiveServers> for ServersSupervisor {
 #[rtype(result = "()")]
 pub enum IncomingEvents {
     FilesChanged(FilesChanged),
+    /// Events raised inside a server, that are forwarded as-is to the event stream
+    External(ExternalEventsDTO),
 }
 
 impl actix::Handler<IncomingEvents> for ServersSupervisor {
@@ -36,6 +41,14 @@ impl actix::Handler<IncomingEvents> for ServersSupervisor {
     fn handle(&mut self, msg: IncomingEvents, ctx: &mut Self::Context) -> Self::Result {
         match msg {
             IncomingEvents::FilesChanged(files_changed) => ctx.notify(files_changed),
+            IncomingEvents::External(evt) => {
+                let sender = self.any_event_sender.clone();
+                actix::spawn(async move {
+                    if let Err(e) = sender.send(AnyEvent::External(evt)).await {
+                        tracing::error!(?e, "could not forward event");
+                    }
+                });
+            }
         }
     }
 }
@@ -0,0 +1,46 @@
+use axum::body::Body;
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use http::header::ACCEPT;
+use http::{HeaderMap, Method, StatusCode};
+use std::path::{Path, PathBuf};
+use tower::ServiceExt;
+use tower_http::services::ServeFile;
+
+///
+/// When a directory has no file for a navigation request, answer with the app's index
+/// file instead, so that client-side routers can handle paths like `/users/1`
+///
+pub async fn spa_fallback_mw(State(index): State<PathBuf>, req: Request, next: Next) -> Response {
+    if !is_navigation(req.method(), req.headers(), req.uri().path()) {
+        return next.run(req).await;
+    }
+    let (parts, body) = req.into_parts();
+    let res = next.run(Request::from_parts(parts.clone(), body)).await;
+    if res.status() != StatusCode::NOT_FOUND {
+        return res;
+    }
+    tracing::trace!(?index, path = parts.uri.path(), "serving spa index");
+    ServeFile::new(index)
+        .oneshot(Request::from_parts(parts, Body::empty()))
+        .await
+        .into_response()
+}
+
+/// Requests from the address bar or a link - not assets like `.js` or `.css` files
+fn is_navigation(method: &Method, headers: &HeaderMap, path: &str) -> bool {
+    if method != Method::GET && method != Method::HEAD {
+        return false;
+    }
+    let accepts_html = headers
+        .get_all(ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .any(|value| value.contains("text/html"));
+    let is_page = Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_none_or(|ext| ext == "html" || ext == "htm");
+    accepts_html && is_page
+}
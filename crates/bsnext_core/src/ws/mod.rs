@@ -1,12 +1,18 @@
 use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
-use axum::extract::{ConnectInfo, State};
+use axum::extract::{ConnectInfo, Query, State};
 use axum::response::IntoResponse;
 
 use futures_util::{SinkExt, StreamExt};
 
+use crate::clients::Clients;
 use crate::server::state::ServerState;
-use bsnext_dto::ClientEvent;
+use crate::servers_supervisor::get_servers_handler::IncomingEvents;
+use actix::Recipient;
+use bsnext_dto::external_events::ExternalEventsDTO;
+use bsnext_dto::{AddressedEventDTO, BrowserConsoleDTO, ClientEvent, ConsoleDTO, LocationDTO};
 use bsnext_input::client_config::ClientConfig;
+use http::header::USER_AGENT;
+use http::HeaderMap;
 use std::net::SocketAddr;
 use std::ops::ControlFlow;
 use std::sync::Arc;
@@ -23,26 +29,97 @@ pub async fn ws_handler(
     State(state): State<Arc<ServerState>>,
     ws: WebSocketUpgrade,
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    params: Option<Query<WsParams>>,
 ) -> impl IntoResponse {
+    let user_agent = headers
+        .get(USER_AGENT)
+        .and_then(|v| v.to_str().ok())
+        .map(ToOwned::to_owned);
+    let url = params
+        .and_then(|Query(params)| params.url)
+        .map(|path| LocationDTO { path })
+        .filter(LocationDTO::is_same_origin)
+        .map(|location| location.path);
     // finalize the upgrade process by returning upgrade callback.
     // we can customize the callback by sending additional info such as address.
-    ws.on_upgrade(move |socket| {
+    ws.on_upgrade(move |socket| async move {
+        let client = state.clients.add(addr, user_agent.clone(), url).await;
+        let events = state.evt_receiver.clone();
+        publish(
+            &events,
+            ExternalEventsDTO::ClientConnected((&client).into()),
+        );
+
+        let peer = Peer {
+            id: client.id,
+            who: addr,
+            user_agent,
+            ghost_sender: state.ghost_sender.clone(),
+            client_config: state.client_config.clone(),
+            clients: state.clients.clone(),
+            events: events.clone(),
+        };
         handle_socket(
             socket,
-            addr,
+            peer,
             state.client_sender.clone(),
             state.client_config.clone(),
         )
+        .await;
+
+        if let Some(client) = state.clients.remove(client.id).await {
+            publish(
+                &events,
+                ExternalEventsDTO::ClientDisconnected((&client).into()),
+            );
+        }
     })
 }
 
+/// Sent by the client script when it connects
+#[derive(Debug, serde::Deserialize)]
+pub struct WsParams {
+    /// Path, query and hash of the page the browser is on
+    #[serde(rename = "bslive.url")]
+    url: Option<String>,
+}
+
+///
+/// A connected browser, along with everything needed to act on the messages it sends
+///
+#[derive(Clone)]
+struct Peer {
+    id: u64,
+    who: SocketAddr,
+    user_agent: Option<String>,
+    ghost_sender: Arc<Sender<GhostEvent>>,
+    client_config: Arc<RwLock<ClientConfig>>,
+    clients: Arc<Clients>,
+    events: Option<Recipient<IncomingEvents>>,
+}
+
+/// Interactions such as scrolling come in quickly, so leave room for slower browsers to catch up
+pub const GHOST_CAPACITY: usize = 100;
+
+///
+/// An interaction from one browser (ghost mode), to be passed on to every other
+/// browser on the same server
+///
+#[derive(Debug, Clone)]
+pub struct GhostEvent {
+    pub from: SocketAddr,
+    pub event: ClientEvent,
+}
+
 /// Actual websocket statemachine (one will be spawned per connection)
 async fn handle_socket(
     mut socket: WebSocket,
-    who: SocketAddr,
+    peer: Peer,
     client_sender: Arc<Sender<ClientEvent>>,
     initial_config: Arc<RwLock<ClientConfig>>,
 ) {
+    let who = peer.who;
     //send a ping (unsupported by some browsers) just to kick things off and get a response
     if socket.send(Message::Ping(vec![1, 2, 3])).await.is_ok() {
         tracing::trace!("Pinged {who}...");
@@ -59,7 +136,7 @@ async fn handle_socket(
     // connections.
     if let Some(msg) = socket.recv().await {
         if let Ok(msg) = msg {
-            if process_message(msg, who).is_break() {
+            if process_message(msg, &peer).await.is_break() {
                 return;
             }
         } else {
@@ -72,6 +149,10 @@ async fn handle_socket(
     // unsolicited messages to client based on some sort of server's internal event (i.e .timer).
     let (mut sender, mut receiver) = socket.split();
 
+    // subscribe before splitting off the tasks, so that nothing sent in the meantime is missed
+    let mut ghost_receiver = peer.ghost_sender.subscribe();
+    let id = peer.id;
+
     // Spawn a task that will push several messages to the client (does not matter what client does)
     let mut send_task = tokio::spawn(async move {
         // send client config first
@@ -87,7 +168,25 @@ async fn handle_socket(
         let mut count = 0;
         let mut client_receiver = client_sender.subscribe();
         loop {
-            match client_receiver.recv().await {
+            let next = tokio::select! {
+                next = client_receiver.recv() => next,
+                next = ghost_receiver.recv() => match next {
+                    // browsers never get their own interactions back
+                    Ok(GhostEvent { from, .. }) if from == who => continue,
+                    Ok(GhostEvent { event, .. }) => Ok(event),
+                    Err(err) => Err(err),
+                },
+            };
+            let next = match next {
+                Ok(ClientEvent::Addressed(AddressedEventDTO { clients, event })) => {
+                    if !clients.contains(&id.to_string()) {
+                        continue;
+                    }
+                    Ok(*event)
+                }
+                other => other,
+            };
+            match next {
                 Ok(incoming) => {
                     tracing::trace!(?incoming, "ws incoming!");
                     let output = serde_json::to_string(&incoming);
@@ -118,7 +217,7 @@ async fn handle_socket(
         while let Some(Ok(msg)) = receiver.next().await {
             cnt += 1;
             // print message and break if instructed to do so
-            if process_message(msg, who).is_break() {
+            if process_message(msg, &peer).await.is_break() {
                 break;
             }
         }
@@ -148,10 +247,15 @@ async fn handle_socket(
 }
 
 /// helper to print contents of messages to stdout. Has special treatment for Close.
-fn process_message(msg: Message, who: SocketAddr) -> ControlFlow<(), ()> {
+/// Text messages are also checked for events that browsers send to the server
+async fn process_message(msg: Message, peer: &Peer) -> ControlFlow<(), ()> {
+    let who = peer.who;
     match msg {
         Message::Text(t) => {
             tracing::trace!(">>> {who} sent str: {t:?}");
+            if let Ok(event) = serde_json::from_str::<ClientEvent>(&t) {
+                handle_client_event(event, peer).await;
+            }
         }
         Message::Binary(d) => {
             tracing::trace!(">>> {} sent {} bytes: {:?}", who, d.len(), d);
@@ -182,3 +286,71 @@ fn process_message(msg: Message, who: SocketAddr) -> ControlFlow<(), ()> {
     }
     ControlFlow::Continue(())
 }
+
+async fn handle_client_event(event: ClientEvent, peer: &Peer) {
+    // the registry keeps track of where each browser is, even if it isn't passed on
+    if let ClientEvent::Location(location) = &event {
+        if !location.is_same_origin() {
+            tracing::debug!(?location, "{} sent a location on another origin", peer.who);
+            return;
+        }
+        peer.clients.set_url(peer.id, location.path.clone()).await;
+    }
+    let config = peer.client_config.read().await;
+    match event {
+        ClientEvent::Console(entry) => {
+            let forwarded = entry.is_forwarded(&config.console);
+            drop(config);
+            if forwarded {
+                forward_console(
+                    entry,
+                    peer.user_agent.clone(),
+                    peer.who.to_string(),
+                    &peer.events,
+                );
+            }
+        }
+        // interactions are passed on to every other browser, when that kind is enabled
+        event if event.is_ghost_enabled(&config.ghost) => {
+            drop(config);
+            // an error here only means no other browsers are connected
+            let _ = peer.ghost_sender.send(GhostEvent {
+                from: peer.who,
+                event,
+            });
+        }
+        event => {
+            tracing::trace!(?event, "{} sent an event that isn't passed on", peer.who);
+        }
+    }
+}
+
+/// Console entries from browsers end up in the terminal output, tagged with where they came from
+pub(crate) fn forward_console(
+    entry: ConsoleDTO,
+    user_agent: Option<String>,
+    addr: String,
+    events: &Option<Recipient<IncomingEvents>>,
+) {
+    let ConsoleDTO {
+        level,
+        message,
+        stack,
+    } = entry;
+    publish(
+        events,
+        ExternalEventsDTO::BrowserConsole(BrowserConsoleDTO {
+            level,
+            message,
+            stack,
+            user_agent,
+            addr,
+        }),
+    );
+}
+
+fn publish(events: &Option<Recipient<IncomingEvents>>, evt: ExternalEventsDTO) {
+    if let Some(events) = events {
+        events.do_send(IncomingEvents::External(evt));
+    }
+}
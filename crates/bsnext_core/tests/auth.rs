@@ -0,0 +1,93 @@
+use axum::body::Body;
+use axum::extract::Request;
+use bsnext_core::server::router::common::{from_yaml, to_resp_parts_and_body};
+use bsnext_core::server::router::make_router;
+use http::header::{AUTHORIZATION, COOKIE, SET_COOKIE, WWW_AUTHENTICATE};
+use std::sync::Arc;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn test_server_and_route_auth() -> Result<(), anyhow::Error> {
+    let input = include_str!("../../../examples/basic/auth.yml");
+    let state = Arc::new(from_yaml(input)?);
+
+    let parts_for = |req: Request| {
+        let state = state.clone();
+        async move {
+            let res = make_router(&state).oneshot(req).await.unwrap();
+            to_resp_parts_and_body(res).await
+        }
+    };
+    let get = |uri: &str| Request::get(uri);
+
+    // no credentials
+    let (parts, _) = parts_for(get("/").body(Body::empty())?).await;
+    assert_eq!(parts.status.as_u16(), 401);
+    assert!(parts.headers[WWW_AUTHENTICATE]
+        .to_str()?
+        .starts_with("Basic realm=\"bslive\""));
+
+    // built-ins are protected too
+    let (parts, _) = parts_for(get("/__bs_api/me").body(Body::empty())?).await;
+    assert_eq!(parts.status.as_u16(), 401);
+
+    // basic auth - 'admin:secret'
+    let basic = get("/")
+        .header(AUTHORIZATION, "Basic YWRtaW46c2VjcmV0")
+        .body(Body::empty())?;
+    let (parts, body) = parts_for(basic).await;
+    assert_eq!(parts.status.as_u16(), 200);
+    assert!(body.contains("hello world!"));
+
+    // a token in the query sets a cookie, which is then enough on its own
+    let (parts, _) = parts_for(get("/?bslive.token=letmein").body(Body::empty())?).await;
+    assert_eq!(parts.status.as_u16(), 200);
+    let set_cookie = parts.headers[SET_COOKIE].to_str()?.to_string();
+    assert!(set_cookie.starts_with("bslive_token_"));
+    assert!(set_cookie.ends_with("=letmein; Path=/; HttpOnly; SameSite=Lax"));
+    let server_cookie = set_cookie.split(';').next().unwrap_or_default().to_string();
+    let cookie = get("/__bs_api/me")
+        .header(COOKIE, &server_cookie)
+        .body(Body::empty())?;
+    let (parts, _) = parts_for(cookie).await;
+    assert_eq!(parts.status.as_u16(), 200);
+
+    // the connector script passes the token on to the websocket
+    let js = get("/__bs_js")
+        .header(AUTHORIZATION, "Bearer letmein")
+        .body(Body::empty())?;
+    let (parts, body) = parts_for(js).await;
+    assert_eq!(parts.status.as_u16(), 200);
+    assert!(body.contains(r#""token":"letmein""#));
+
+    // routes can require their own credentials, on top of the server's
+    let admin = |uri: &str| {
+        get(uri)
+            .header(AUTHORIZATION, "Bearer letmein")
+            .body(Body::empty())
+    };
+    let (parts, _) = parts_for(admin("/admin")?).await;
+    assert_eq!(parts.status.as_u16(), 401);
+    assert!(parts.headers[WWW_AUTHENTICATE]
+        .to_str()?
+        .starts_with("Bearer"));
+    let (parts, body) = parts_for(admin("/admin?bslive.token=top-secret")?).await;
+    assert_eq!(parts.status.as_u16(), 200);
+    assert!(body.contains("admins only"));
+
+    // the route's token gets a cookie of its own, so it doesn't replace the server's
+    let set_cookie = parts.headers[SET_COOKIE].to_str()?.to_string();
+    let admin_cookie = set_cookie.split(';').next().unwrap_or_default().to_string();
+    assert_ne!(
+        admin_cookie.split('=').next(),
+        server_cookie.split('=').next()
+    );
+    let both = format!("{server_cookie}; {admin_cookie}");
+    for uri in ["/", "/admin", "/__bs_api/me"] {
+        let req = get(uri).header(COOKIE, &both).body(Body::empty())?;
+        let (parts, _) = parts_for(req).await;
+        assert_eq!(parts.status.as_u16(), 200, "{uri}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use axum::body::Body;
+use axum::extract::Request;
+use bsnext_core::server::router::common::{from_yaml, to_resp_parts_and_body};
+use bsnext_core::server::router::make_router;
+use http_body_util::BodyExt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn test_chaos() -> Result<(), anyhow::Error> {
+    let input = include_str!("../../../examples/basic/chaos.yml");
+    let state = Arc::new(from_yaml(input)?);
+
+    let timed = |uri: &'static str| {
+        let state = state.clone();
+        async move {
+            let start = Instant::now();
+            let req = Request::get(uri).body(Body::empty()).unwrap();
+            let res = make_router(&state).oneshot(req).await.unwrap();
+            let (parts, body) = to_resp_parts_and_body(res).await;
+            (parts, body, start.elapsed())
+        }
+    };
+
+    let (parts, body, elapsed) = timed("/calm").await;
+    assert_eq!(parts.status.as_u16(), 200);
+    assert_eq!(body, "all good");
+    assert!(
+        elapsed >= Duration::from_millis(20),
+        "server-wide latency applies to every route"
+    );
+
+    let (parts, _, _) = timed("/flaky").await;
+    assert_eq!(parts.status.as_u16(), 503);
+
+    let (parts, _, _) =
+        timed("/calm?bslive.chaos.error_rate=1&bslive.chaos.error_status=429").await;
+    assert_eq!(parts.status.as_u16(), 429);
+
+    let (parts, body, elapsed) = timed("/slow.txt").await;
+    assert_eq!(parts.status.as_u16(), 200);
+    assert_eq!(body, "0123456789012345678901234567890123456789");
+    assert!(
+        elapsed >= Duration::from_millis(200),
+        "40 bytes at 200 bytes/sec, took {elapsed:?}"
+    );
+
+    let req = Request::get("/dropped.txt").body(Body::empty())?;
+    let res = make_router(&state).oneshot(req).await?;
+    assert_eq!(res.status().as_u16(), 200);
+    assert!(
+        res.into_body().collect().await.is_err(),
+        "the body is cut off"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use axum::body::Body;
+use axum::extract::Request;
+use bsnext_core::server::router::common::{from_yaml, to_resp_parts_and_body};
+use bsnext_core::server::router::make_router;
+use http::header::{
+    ACCESS_CONTROL_ALLOW_CREDENTIALS, ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS,
+    ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_EXPOSE_HEADERS, ACCESS_CONTROL_MAX_AGE,
+    ACCESS_CONTROL_REQUEST_METHOD, ORIGIN,
+};
+use http::Method;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn test_structured_cors() -> Result<(), anyhow::Error> {
+    let input = include_str!("../../../examples/basic/cors.yml");
+    let state = Arc::new(from_yaml(input)?);
+
+    let parts_for = |req: Request| {
+        let state = state.clone();
+        async move {
+            let res = make_router(&state).oneshot(req).await.unwrap();
+            to_resp_parts_and_body(res).await
+        }
+    };
+    let from_origin = |method: Method, uri: &str, origin: &str| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(ORIGIN, origin)
+    };
+
+    // `cors: true` is still permissive
+    let (parts, _) =
+        parts_for(from_origin(Method::GET, "/", "http://a.com").body(Body::empty())?).await;
+    assert_eq!(parts.headers[ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+
+    // exact origins, with credentials
+    let req = from_origin(Method::GET, "/api", "http://localhost:5173").body(Body::empty())?;
+    let (parts, body) = parts_for(req).await;
+    assert_eq!(body, r#"{"ok":true}"#);
+    assert_eq!(
+        parts.headers[ACCESS_CONTROL_ALLOW_ORIGIN],
+        "http://localhost:5173"
+    );
+    assert_eq!(parts.headers[ACCESS_CONTROL_ALLOW_CREDENTIALS], "true");
+    assert_eq!(parts.headers[ACCESS_CONTROL_EXPOSE_HEADERS], "x-request-id");
+
+    // origin patterns
+    let req = from_origin(Method::GET, "/api", "https://app.example.com").body(Body::empty())?;
+    let (parts, _) = parts_for(req).await;
+    assert_eq!(
+        parts.headers[ACCESS_CONTROL_ALLOW_ORIGIN],
+        "https://app.example.com"
+    );
+    let req = from_origin(Method::GET, "/api", "https://evil.com").body(Body::empty())?;
+    let (parts, _) = parts_for(req).await;
+    assert!(parts.headers.get(ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+
+    // preflight
+    let req = from_origin(Method::OPTIONS, "/api", "http://localhost:5173")
+        .header(ACCESS_CONTROL_REQUEST_METHOD, "POST")
+        .body(Body::empty())?;
+    let (parts, _) = parts_for(req).await;
+    assert_eq!(parts.headers[ACCESS_CONTROL_ALLOW_METHODS], "GET,POST");
+    assert_eq!(parts.headers[ACCESS_CONTROL_ALLOW_HEADERS], "content-type");
+    assert_eq!(parts.headers[ACCESS_CONTROL_MAX_AGE], "600");
+
+    // anything left out is permissive
+    let req = from_origin(Method::GET, "/api/open", "http://a.com").body(Body::empty())?;
+    let (parts, _) = parts_for(req).await;
+    assert_eq!(parts.headers[ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+    assert_eq!(parts.headers[ACCESS_CONTROL_EXPOSE_HEADERS], "*");
+
+    Ok(())
+}
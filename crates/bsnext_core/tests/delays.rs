@@ -68,12 +68,16 @@ async fn test_proxy_delay() -> Result<(), anyhow::Error> {
         matches!(proxy_route.kind, RouteKind::Proxy(..)),
         "must be a proxy route, check delays.yml"
     );
-    proxy_route.kind = RouteKind::Proxy(ProxyRoute {
+    proxy_route.kind = RouteKind::Proxy(Box::new(ProxyRoute {
         proxy: proxy.http_addr.clone(),
         rewrite_uri: None,
         proxy_headers: None,
-        unstable_mirror: None,
-    });
+        mirror: None,
+        replay: None,
+        rewrite_links: None,
+        timeouts: None,
+        retry: None,
+    }));
 
     let state = into_state(config);
     let (_, body1, dur1) = uri_to_res_parts(state.clone(), "/api").await;
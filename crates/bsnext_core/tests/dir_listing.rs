@@ -0,0 +1,69 @@
+use bsnext_core::server::router::common::example_req;
+use http::header::CONTENT_TYPE;
+
+const LISTING: &str = include_str!("../../../examples/basic/listing.yml");
+
+#[tokio::test]
+async fn test_dir_listing_html() -> anyhow::Result<()> {
+    let (parts, body) = example_req(LISTING, "listing", "/files/", Some("text/html")).await?;
+    assert_eq!(parts.status.as_u16(), 200);
+    assert!(body.contains("<title>Index of &#x2f;files&#x2f;</title>"));
+    assert!(body.contains(r#"<a href="markdown&#x2f;">markdown/</a>"#));
+    assert!(body.contains(r#"<a href="graceful-exit.js">graceful-exit.js</a>"#));
+    assert!(
+        body.find("markdown/").unwrap() < body.find("graceful-exit.js").unwrap(),
+        "folders come first"
+    );
+    assert!(body.contains(r#"<a href="&#x2f;files&#x2f;">files</a>"#));
+    assert!(
+        body.contains("/__bs_js"),
+        "the live-reload connector is injected"
+    );
+
+    let (_, body) = example_req(
+        LISTING,
+        "listing",
+        "/files/basic/public/",
+        Some("text/html"),
+    )
+    .await?;
+    assert!(
+        body.contains("<p>Edit me! - a full HTML</p>"),
+        "folders with an index.html are not listed"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dir_listing_json() -> anyhow::Result<()> {
+    let (parts, body) = example_req(
+        LISTING,
+        "listing",
+        "/files/markdown/?sort=size&order=desc",
+        Some("application/json"),
+    )
+    .await?;
+    assert_eq!(parts.status.as_u16(), 200);
+    assert_eq!(parts.headers.get(CONTENT_TYPE).unwrap(), "application/json");
+    let json: serde_json::Value = serde_json::from_str(&body)?;
+    assert_eq!(json["path"], "/files/markdown/");
+    let entries = json["entries"].as_array().unwrap();
+    let sizes = entries
+        .iter()
+        .map(|e| e["size"].as_u64().unwrap())
+        .collect::<Vec<_>>();
+    let mut sorted = sizes.clone();
+    sorted.sort_by(|a, b| b.cmp(a));
+    assert_eq!(sizes, sorted);
+    assert!(entries.iter().any(|e| e["name"] == "single.md"));
+
+    let (parts, _) = example_req(
+        LISTING,
+        "listing",
+        "/files/missing/",
+        Some("application/json"),
+    )
+    .await?;
+    assert_eq!(parts.status.as_u16(), 404);
+    Ok(())
+}
@@ -0,0 +1,172 @@
+use bsnext_core::export::{export_one_server, ExcludeReason, ExportCommand, ExportEvent};
+use bsnext_fs_helpers::WriteMode;
+use bsnext_input::Input;
+use clap::Parser;
+use std::fs;
+
+#[tokio::test]
+async fn export_follows_links_into_dir_routes() -> Result<(), anyhow::Error> {
+    let site = tempfile::tempdir()?;
+    let out = tempfile::tempdir()?;
+    fs::create_dir_all(site.path().join("public/img"))?;
+    fs::write(
+        site.path().join("public/about.html"),
+        r#"<html><body><img src="img/logo.png"><a href="/">home</a></body></html>"#,
+    )?;
+    fs::write(site.path().join("public/img/logo.png"), [0x89, 0x50, 0xff])?;
+
+    let input = format!(
+        r#"
+servers:
+- name: export
+  routes:
+  - path: /
+    html: <body><a href="/about.html">about</a><a href="/missing.html">missing</a></body>
+    when:
+      exact_uri: true
+  - path: /
+    dir: {}
+    "#,
+        site.path().join("public").display()
+    );
+    let input: Input = serde_yaml::from_str(&input)?;
+    let server = input.servers.first().expect("first").to_owned();
+    let cwd = site.path().to_path_buf();
+    let out_dir = out.path().to_string_lossy().to_string();
+    let cmd = ExportCommand::try_parse_from(["export", "--dir", &out_dir])?;
+
+    let events = export_one_server(&cwd, server, &cmd, WriteMode::Safe).await?;
+
+    let index = fs::read_to_string(out.path().join("index.html"))?;
+    assert!(index.contains(r#"<a href="/about.html">"#));
+    assert!(
+        !index.contains("__bs_js"),
+        "the client should not be injected"
+    );
+    assert!(out.path().join("about.html").exists());
+    assert_eq!(
+        fs::read(out.path().join("img/logo.png"))?,
+        vec![0x89, 0x50, 0xff]
+    );
+    let excluded = events
+        .iter()
+        .filter_map(|evt| match evt {
+            ExportEvent::Excluded { url_path, .. } => Some(url_path.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(excluded, vec!["/missing.html"]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn export_skips_url_paths_that_share_a_file() -> Result<(), anyhow::Error> {
+    let site = tempfile::tempdir()?;
+    let out = tempfile::tempdir()?;
+    fs::create_dir_all(site.path().join("public"))?;
+    fs::write(site.path().join("public/index.html"), "<p>from dir</p>")?;
+
+    let input = format!(
+        r#"
+servers:
+- name: export
+  routes:
+  - path: /
+    html: <body><a href="/">home</a><a href="/index.html">index</a></body>
+    when:
+      exact_uri: true
+  - path: /
+    dir: {}
+    "#,
+        site.path().join("public").display()
+    );
+    let input: Input = serde_yaml::from_str(&input)?;
+    let server = input.servers.first().expect("first").to_owned();
+    let cwd = site.path().to_path_buf();
+    let out_dir = out.path().to_string_lossy().to_string();
+    let cmd = ExportCommand::try_parse_from(["export", "--dir", &out_dir])?;
+
+    let events = export_one_server(&cwd, server, &cmd, WriteMode::Safe).await?;
+
+    let index = fs::read_to_string(out.path().join("index.html"))?;
+    assert!(index.contains(r#"<a href="/index.html">"#));
+    let excluded = events
+        .iter()
+        .filter_map(|evt| match evt {
+            ExportEvent::Excluded {
+                url_path,
+                reason: ExcludeReason::SameFile(filepath),
+            } => Some((url_path.as_str(), filepath.to_string_lossy().to_string())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(excluded, vec![("/index.html", "index.html".to_string())]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn export_stops_after_max_pages() -> Result<(), anyhow::Error> {
+    let out = tempfile::tempdir()?;
+    let input = r#"
+servers:
+- name: export
+  routes:
+  - path: /
+    html: <body><a href="docs/">docs</a></body>
+    "#;
+    let input: Input = serde_yaml::from_str(input)?;
+    let server = input.servers.first().expect("first").to_owned();
+    let cwd = out.path().to_path_buf();
+    let out_dir = out.path().to_string_lossy().to_string();
+    let cmd = ExportCommand::try_parse_from(["export", "--dir", &out_dir, "--max-pages", "3"])?;
+
+    let events = export_one_server(&cwd, server, &cmd, WriteMode::Safe).await?;
+
+    assert!(out.path().join("docs/docs/index.html").exists());
+    assert!(!out.path().join("docs/docs/docs/index.html").exists());
+    assert!(matches!(
+        events.last(),
+        Some(ExportEvent::MaxPagesReached(3))
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn export_skips_files_it_cannot_write() -> Result<(), anyhow::Error> {
+    let out = tempfile::tempdir()?;
+    fs::write(out.path().join("about.html"), "existing")?;
+    let input = r#"
+servers:
+- name: export
+  routes:
+  - path: /
+    html: <body><a href="/about.html">about</a></body>
+  - path: /about.html
+    html: <p>about</p>
+    "#;
+    let input: Input = serde_yaml::from_str(input)?;
+    let server = input.servers.first().expect("first").to_owned();
+    let cwd = out.path().to_path_buf();
+    let out_dir = out.path().to_string_lossy().to_string();
+    let cmd = ExportCommand::try_parse_from(["export", "--dir", &out_dir])?;
+
+    let events = export_one_server(&cwd, server, &cmd, WriteMode::Safe).await?;
+
+    assert!(out.path().join("index.html").exists());
+    assert_eq!(
+        fs::read_to_string(out.path().join("about.html"))?,
+        "existing"
+    );
+    let excluded = events
+        .iter()
+        .filter_map(|evt| match evt {
+            ExportEvent::Excluded {
+                url_path,
+                reason: ExcludeReason::Write(..),
+            } => Some(url_path.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(excluded, vec!["/about.html"]);
+    Ok(())
+}
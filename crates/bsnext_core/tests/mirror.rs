@@ -0,0 +1,77 @@
+use axum::response::{Html, IntoResponse};
+use axum::routing::get;
+use axum::Router;
+use bsnext_core::server::router::common::{from_yaml, test_proxy, uri_to_res_parts};
+use http::header::CONTENT_TYPE;
+use std::path::Path;
+use std::time::Duration;
+
+/// Files are moved into place once the body has been fully streamed, so allow a little time
+async fn wait_for(path: &Path) -> bool {
+    for _ in 0..50 {
+        if path.exists() {
+            return true;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    false
+}
+
+#[tokio::test]
+async fn test_mirror_then_offline() -> Result<(), anyhow::Error> {
+    let dir = tempfile::tempdir()?;
+    let target = Router::new()
+        .route("/", get(|| async { Html("<body>home</body>") }))
+        .route(
+            "/css/app.css",
+            get(|| async { ([(CONTENT_TYPE, "text/css")], "body{}").into_response() }),
+        )
+        .route(
+            "/api/data",
+            get(|| async { ([(CONTENT_TYPE, "application/json")], "[]").into_response() }),
+        );
+    let proxy = test_proxy(target).await?;
+
+    let input = format!(
+        r#"
+servers:
+- name: mirror
+  routes:
+  - path: /
+    proxy: {http}
+    mirror:
+      dir: {dir}
+      mime: [text/html, text/*]
+      offline: true
+    "#,
+        http = proxy.http_addr,
+        dir = dir.path().display(),
+    );
+    let state = from_yaml(&input)?;
+
+    let (_, body, _) = uri_to_res_parts(state.clone(), "/").await;
+    assert_eq!(body, "<body>home</body>");
+    let (_, body, _) = uri_to_res_parts(state.clone(), "/css/app.css").await;
+    assert_eq!(body, "body{}");
+    let (_, body, _) = uri_to_res_parts(state.clone(), "/api/data").await;
+    assert_eq!(body, "[]");
+
+    assert!(wait_for(&dir.path().join("index.html")).await);
+    assert!(wait_for(&dir.path().join("css/app.css")).await);
+    assert!(
+        !dir.path().join("api/data").exists(),
+        "json was not in the mime list"
+    );
+
+    proxy.destroy().await?;
+
+    let (parts, body, _) = uri_to_res_parts(state.clone(), "/css/app.css").await;
+    assert_eq!(parts.status, 200);
+    assert_eq!(body, "body{}");
+    let (parts, body, _) = uri_to_res_parts(state.clone(), "/").await;
+    assert_eq!(parts.status, 200);
+    assert!(body.contains("home"));
+    let (parts, _, _) = uri_to_res_parts(state.clone(), "/api/data").await;
+    assert_eq!(parts.status, 502);
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use axum::routing::get;
+use axum::Router;
+use bsnext_core::server::router::common::{
+    accept_html_req_to_body, from_yaml, test_proxy, uri_to_res_parts,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// An address that nothing is listening on
+fn unused_addr() -> String {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    format!("http://{}", listener.local_addr().unwrap())
+}
+
+#[tokio::test]
+async fn test_unreachable_target() -> Result<(), anyhow::Error> {
+    let target = unused_addr();
+    let input = format!(
+        r#"
+servers:
+- name: errors
+  routes:
+  - path: /
+    proxy: {target}
+    inject: false
+    retry:
+      count: 2
+      backoff_ms: 10
+    "#
+    );
+    let state = from_yaml(&input)?;
+
+    let (parts, body, _) = uri_to_res_parts(state.clone(), "/").await;
+    assert_eq!(parts.status, 502);
+    assert!(body.starts_with(&format!("could not reach {target}/ (connect)")));
+
+    let body = accept_html_req_to_body(state, "/").await;
+    assert!(body.contains("The proxy target could not be reached"));
+    assert!(body.contains(&format!("<code>{target}/</code>")));
+    assert!(body.contains("<code>connect</code>"));
+    assert!(body.contains("<dd>3</dd>"), "1 attempt + 2 retries");
+    assert!(body.contains("location.reload()"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_response_timeout_and_retry() -> Result<(), anyhow::Error> {
+    let count = Arc::new(AtomicUsize::new(0));
+    let counter = count.clone();
+    let target = Router::new()
+        .route(
+            "/slow",
+            get(|| async {
+                tokio::time::sleep(Duration::from_millis(500)).await;
+                "slow"
+            }),
+        )
+        .route(
+            "/flaky",
+            get(move || async move {
+                // only the first request is too slow
+                if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                    tokio::time::sleep(Duration::from_millis(500)).await;
+                }
+                "flaky"
+            }),
+        );
+    let proxy = test_proxy(target).await?;
+
+    let input = format!(
+        r#"
+servers:
+- name: timeouts
+  routes:
+  - path: /slow
+    proxy: {http}
+    rewrite_uri: false
+    timeouts:
+      response_ms: 50
+  - path: /flaky
+    proxy: {http}
+    rewrite_uri: false
+    timeouts:
+      connect_ms: 1000
+      response_ms: 50
+    retry:
+      count: 1
+      backoff_ms: 10
+    "#,
+        http = proxy.http_addr,
+    );
+    let state = from_yaml(&input)?;
+
+    let (parts, body, _) = uri_to_res_parts(state.clone(), "/slow").await;
+    assert_eq!(parts.status, 504);
+    assert!(body.contains("(response_timeout): no response after 50ms"));
+
+    let (parts, body, _) = uri_to_res_parts(state.clone(), "/flaky").await;
+    assert_eq!(parts.status, 200);
+    assert_eq!(body, "flaky");
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+
+    proxy.destroy().await?;
+    Ok(())
+}
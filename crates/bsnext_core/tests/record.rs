@@ -0,0 +1,52 @@
+use bsnext_core::server::router::common::{from_yaml, req_to_body, uri_to_res_parts};
+
+#[tokio::test]
+async fn test_record_to_har() -> Result<(), anyhow::Error> {
+    let input = r#"
+servers:
+- name: record
+  record: true
+  routes:
+  - path: /
+    html: hello world!
+  - path: /api
+    json: [1, 2, 3]
+    "#;
+    let state = from_yaml(input)?;
+
+    uri_to_res_parts(state.clone(), "/").await;
+    uri_to_res_parts(state.clone(), "/api?a=b").await;
+
+    let body = req_to_body(state.clone(), "/__bs_api/requests").await;
+    let har: serde_json::Value = serde_json::from_str(&body)?;
+    let entries = har["log"]["entries"].as_array().expect("entries");
+
+    assert_eq!(har["log"]["version"], "1.2");
+    assert_eq!(
+        entries.len(),
+        2,
+        "built-in /__bs_api routes are not recorded"
+    );
+    assert_eq!(entries[0]["request"]["url"], "http://localhost/");
+    assert_eq!(entries[0]["response"]["content"]["text"], "hello world!");
+    assert_eq!(entries[1]["request"]["queryString"][0]["name"], "a");
+    assert_eq!(entries[1]["response"]["content"]["text"], "[1,2,3]");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_record_disabled() -> Result<(), anyhow::Error> {
+    let input = r#"
+servers:
+- name: record
+  routes:
+  - path: /
+    html: hello world!
+    "#;
+    let state = from_yaml(input)?;
+
+    uri_to_res_parts(state.clone(), "/").await;
+
+    assert!(state.recorder.entries().await.is_empty());
+    Ok(())
+}
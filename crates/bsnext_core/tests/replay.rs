@@ -0,0 +1,91 @@
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::Router;
+use bsnext_core::server::router::common::{from_yaml, test_proxy, uri_to_res_parts};
+use std::fs;
+
+const RECORDING: &str = r#"{
+  "log": {
+    "version": "1.2",
+    "creator": { "name": "test", "version": "1" },
+    "entries": [
+      {
+        "request": { "method": "GET", "url": "http://localhost:3000/api/users?page=1" },
+        "response": {
+          "status": 200,
+          "headers": [{ "name": "content-type", "value": "application/json" }],
+          "content": { "mimeType": "application/json", "text": "[\"recorded\"]" }
+        }
+      }
+    ]
+  }
+}"#;
+
+#[tokio::test]
+async fn test_replay_modes() -> Result<(), anyhow::Error> {
+    let dir = tempfile::tempdir()?;
+    let file = dir.path().join("api.har");
+    fs::write(&file, RECORDING)?;
+
+    let target = Router::new()
+        .route("/users", get(|| async { "live users" }))
+        .route("/other", get(|| async { "live other" }))
+        .route(
+            "/flaky",
+            get(|| async { (StatusCode::SERVICE_UNAVAILABLE, "try again later") }),
+        );
+    let proxy = test_proxy(target).await?;
+
+    let input = |mode: &str| {
+        format!(
+            r#"
+servers:
+- name: replay
+  routes:
+  - path: /api
+    proxy: {http}
+    replay:
+      file: {file}
+      mode: {mode}
+    "#,
+            http = proxy.http_addr,
+            file = file.display(),
+        )
+    };
+
+    // strict: recorded entries are served, everything else is a 404
+    let strict = from_yaml(&input("strict"))?;
+    let (parts, body, _) = uri_to_res_parts(strict.clone(), "/api/users?page=1").await;
+    assert_eq!(parts.status, 200);
+    assert_eq!(parts.headers["content-type"], "application/json");
+    assert_eq!(body, r#"["recorded"]"#);
+    let (parts, _, _) = uri_to_res_parts(strict.clone(), "/api/users?page=2").await;
+    assert_eq!(parts.status, 404);
+
+    // passthrough: misses hit the target, but nothing is written
+    let passthrough = from_yaml(&input("passthrough"))?;
+    let (_, body, _) = uri_to_res_parts(passthrough.clone(), "/api/other").await;
+    assert_eq!(body, "live other");
+    assert!(!fs::read_to_string(&file)?.contains("live other"));
+
+    // record-missing: misses hit the target, and are appended for next time
+    let record_missing = from_yaml(&input("record-missing"))?;
+    let (_, body, _) = uri_to_res_parts(record_missing.clone(), "/api/other").await;
+    assert_eq!(body, "live other");
+    assert!(fs::read_to_string(&file)?.contains("live other"));
+
+    // server errors are passed on, but not recorded
+    let (parts, body, _) = uri_to_res_parts(record_missing.clone(), "/api/flaky").await;
+    assert_eq!(parts.status, 503);
+    assert_eq!(body, "try again later");
+    assert!(!fs::read_to_string(&file)?.contains("try again later"));
+
+    proxy.destroy().await?;
+
+    let (parts, body, _) = uri_to_res_parts(strict.clone(), "/api/other").await;
+    assert_eq!(parts.status, 200);
+    assert_eq!(body, "live other");
+    let (parts, _, _) = uri_to_res_parts(strict.clone(), "/api/flaky").await;
+    assert_eq!(parts.status, 404);
+    Ok(())
+}
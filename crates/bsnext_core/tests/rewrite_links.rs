@@ -0,0 +1,85 @@
+use axum::body::Body;
+use axum::extract::Request;
+use axum::response::{Html, IntoResponse, Redirect};
+use axum::routing::get;
+use axum::Router;
+use bsnext_core::server::router::common::{from_yaml, test_proxy, to_resp_parts_and_body};
+use bsnext_core::server::router::make_router;
+use http::header::{CONTENT_TYPE, HOST, LOCATION, SET_COOKIE};
+use std::sync::Arc;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn test_rewrite_links() -> Result<(), anyhow::Error> {
+    let target = Router::new()
+        .route(
+            "/",
+            get(|req: Request| async move {
+                let host = req.headers()[HOST].to_str().unwrap().to_owned();
+                Html(format!(
+                    r#"<body><a href="http://{host}/about">about</a><a href="https://other.com">other</a></body>"#
+                ))
+            }),
+        )
+        .route(
+            "/app.css",
+            get(|req: Request| async move {
+                let host = req.headers()[HOST].to_str().unwrap().to_owned();
+                ([(CONTENT_TYPE, "text/css")], format!("a{{background:url(//{host}/bg.png)}}"))
+            }),
+        )
+        .route(
+            "/login",
+            get(|req: Request| async move {
+                let host = req.headers()[HOST].to_str().unwrap().to_owned();
+                (
+                    [(SET_COOKIE, format!("sid=abc; Domain={host}; Path=/"))],
+                    Redirect::temporary(&format!("http://{host}/")),
+                )
+                    .into_response()
+            }),
+        );
+    let proxy = test_proxy(target).await?;
+    let target_host = proxy.socker_addr.to_string();
+
+    let input = format!(
+        r#"
+servers:
+- name: rewrite
+  routes:
+  - path: /
+    proxy: {http}
+    inject: false
+    rewrite_links: true
+    "#,
+        http = proxy.http_addr,
+    );
+    let state = Arc::new(from_yaml(&input)?);
+
+    let req = |uri: &str| {
+        Request::get(uri)
+            .header(HOST, "localhost:3000")
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let res = make_router(&state).oneshot(req("/")).await?;
+    let (_, body) = to_resp_parts_and_body(res).await;
+    assert_eq!(
+        body,
+        r#"<body><a href="//localhost:3000/about">about</a><a href="https://other.com">other</a></body>"#
+    );
+    assert!(!body.contains(&target_host));
+
+    let res = make_router(&state).oneshot(req("/app.css")).await?;
+    let (_, body) = to_resp_parts_and_body(res).await;
+    assert_eq!(body, "a{background:url(//localhost:3000/bg.png)}");
+
+    let res = make_router(&state).oneshot(req("/login")).await?;
+    let (parts, _) = to_resp_parts_and_body(res).await;
+    assert_eq!(parts.headers[LOCATION], "//localhost:3000/");
+    assert_eq!(parts.headers[SET_COOKIE], "sid=abc; Path=/");
+
+    proxy.destroy().await?;
+    Ok(())
+}
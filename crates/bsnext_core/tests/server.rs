@@ -5,12 +5,15 @@ use bsnext_core::server::handler_listen::Listen;
 use bsnext_core::servers_supervisor::file_changed_handler::FilesChanged;
 use bsnext_core::servers_supervisor::get_servers_handler::{GetActiveServers, IncomingEvents};
 use bsnext_dto::GetActiveServersResponse;
+use bsnext_input::client_config::{ConsoleLevel, GhostConfig};
 use bsnext_input::route::{JsonWrapper, Route, RouteKind};
 use bsnext_input::server_config::{ServerConfig, ServerIdentity};
+use futures_util::StreamExt;
 use http::header::ACCEPT;
 use mime_guess::mime::{APPLICATION_JSON, TEXT_HTML_UTF_8};
 use serde_json::Value;
 use std::path::PathBuf;
+use tokio_tungstenite::tungstenite;
 
 mod inject_tests;
 mod tests;
@@ -60,6 +63,98 @@ async fn system_test_01() {
     assert_eq!(body, "[]".to_string());
 }
 
+async fn system_test_03() {
+    use axum::extract::ws::{Message, WebSocketUpgrade};
+    use axum::routing::get;
+    use bsnext_core::server::router::common::test_proxy;
+    use bsnext_dto::external_events::ExternalEventsDTO;
+    use futures_util::SinkExt;
+    use http::HeaderMap;
+
+    let target = axum::Router::new().route(
+        "/ws",
+        get(|ws: WebSocketUpgrade, headers: HeaderMap| async move {
+            let from_proxy = headers["x-from-proxy"].to_str().unwrap().to_owned();
+            ws.on_upgrade(|mut socket| async move {
+                socket.send(Message::Text(from_proxy)).await.unwrap();
+                while let Some(Ok(Message::Text(text))) = socket.recv().await {
+                    socket.send(Message::Text(text)).await.unwrap();
+                }
+            })
+        }),
+    );
+    let proxy = test_proxy(target).await.unwrap();
+    let route: Route = serde_yaml::from_str(&format!(
+        r#"
+path: /api
+proxy: {}
+proxy_headers:
+  x-from-proxy: bslive
+"#,
+        proxy.http_addr
+    ))
+    .unwrap();
+
+    let server_parent = ServerParent::from_routes(vec![route]);
+    let server_actor = ServerActor::new_from_config(server_parent.server_config.clone()).start();
+    let parent = server_parent.start();
+
+    let addr = server_actor
+        .send(Listen {
+            runtime_ctx: RuntimeCtx::default(),
+            parent: parent.clone().recipient(),
+            evt_receiver: parent.clone().recipient(),
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+    // `/api` is dropped by `rewrite_uri`, so this reaches `/ws` on the target
+    let (mut socket, _) = tokio_tungstenite::connect_async(format!("ws://{addr}/api/ws"))
+        .await
+        .expect("upgrade through the proxy");
+    let first = socket.next().await.unwrap().unwrap();
+    assert_eq!(first.into_text().unwrap(), "bslive");
+
+    socket.send("hello".into()).await.unwrap();
+    let echo = socket.next().await.unwrap().unwrap();
+    assert_eq!(echo.into_text().unwrap(), "hello");
+    socket.close(None).await.unwrap();
+    while socket.next().await.is_some() {}
+    drop(socket);
+
+    let mut kinds = vec![];
+    for _ in 0..50 {
+        kinds = parent
+            .send(GetEvents)
+            .await
+            .unwrap()
+            .into_iter()
+            .filter_map(|evt| match evt {
+                IncomingEvents::External(ExternalEventsDTO::ProxyWsOpened(dto)) => {
+                    Some(("opened", dto.path))
+                }
+                IncomingEvents::External(ExternalEventsDTO::ProxyWsClosed(dto)) => {
+                    Some(("closed", dto.path))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if kinds.len() == 2 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    assert_eq!(
+        kinds,
+        vec![
+            ("opened", "/api/ws".to_string()),
+            ("closed", "/api/ws".to_string())
+        ]
+    );
+    proxy.destroy().await.unwrap();
+}
+
 async fn system_test_02() {
     let route1 = Route {
         path: "/".parse().unwrap(),
@@ -108,9 +203,371 @@ async fn system_test_02() {
             let first = paths.get(0).unwrap();
             assert_eq!(first, &PathBuf::from("styles.css"));
         }
+        other => panic!("unexpected event {other:?}"),
     }
 }
 
+async fn system_test_04() {
+    use futures_util::SinkExt;
+    use tokio_tungstenite::tungstenite::Message;
+
+    let route1 = Route {
+        path: "/".parse().unwrap(),
+        kind: RouteKind::new_html("hello world!"),
+        ..Default::default()
+    };
+    let mut server_parent = ServerParent::from_routes(vec![route1]);
+    server_parent.server_config.clients.ghost = GhostConfig {
+        scroll: true,
+        click: true,
+        ..Default::default()
+    };
+    let server_actor = ServerActor::new_from_config(server_parent.server_config.clone()).start();
+    let parent = server_parent.start();
+
+    let addr = server_actor
+        .send(Listen {
+            runtime_ctx: RuntimeCtx::default(),
+            parent: parent.clone().recipient(),
+            evt_receiver: parent.clone().recipient(),
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+    let ws_url = format!("ws://{addr}{}", bsnext_client::WS_PATH);
+    let (mut a, _) = tokio_tungstenite::connect_async(&ws_url).await.unwrap();
+    let (mut b, _) = tokio_tungstenite::connect_async(&ws_url).await.unwrap();
+    for socket in [&mut a, &mut b] {
+        let connection = next_text(socket).await.expect("connection message");
+        assert!(connection.contains(r#""kind":"WsConnection""#));
+        assert!(connection.contains(r#""scroll":true"#));
+    }
+
+    let scroll = r#"{"kind":"Scroll","payload":{"x":0.0,"y":0.5}}"#;
+    let input = r#"{"kind":"Input","payload":{"element":{"tag":"input","index":0},"value":"a","checked":null}}"#;
+    let click = r#"{"kind":"Click","payload":{"tag":"button","index":2}}"#;
+    for msg in [scroll, input, click] {
+        a.send(Message::Text(msg.to_string())).await.unwrap();
+    }
+
+    // `input` is not enabled, so it's skipped
+    assert_eq!(next_text(&mut b).await.as_deref(), Some(scroll));
+    assert_eq!(next_text(&mut b).await.as_deref(), Some(click));
+    // and nothing is sent back to where it came from
+    assert_eq!(next_text(&mut a).await, None);
+}
+
+async fn system_test_05() {
+    use bsnext_dto::external_events::ExternalEventsDTO;
+    use bsnext_dto::ConsoleLevelDTO;
+    use futures_util::SinkExt;
+    use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+    use tokio_tungstenite::tungstenite::Message;
+
+    let route1 = Route {
+        path: "/".parse().unwrap(),
+        kind: RouteKind::new_html("hello world!"),
+        ..Default::default()
+    };
+    let mut server_parent = ServerParent::from_routes(vec![route1]);
+    server_parent.server_config.clients.console = vec![ConsoleLevel::Warn, ConsoleLevel::Error];
+    let server_actor = ServerActor::new_from_config(server_parent.server_config.clone()).start();
+    let parent = server_parent.start();
+
+    let addr = server_actor
+        .send(Listen {
+            runtime_ctx: RuntimeCtx::default(),
+            parent: parent.clone().recipient(),
+            evt_receiver: parent.clone().recipient(),
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+    let mut req = format!("ws://{addr}{}", bsnext_client::WS_PATH)
+        .into_client_request()
+        .unwrap();
+    req.headers_mut()
+        .insert("user-agent", "Firefox/128.0".parse().unwrap());
+    let (mut socket, _) = tokio_tungstenite::connect_async(req).await.unwrap();
+
+    let log = r#"{"kind":"Console","payload":{"level":"log","message":"ignored","stack":null}}"#;
+    let warn = r#"{"kind":"Console","payload":{"level":"warn","message":"careful","stack":null}}"#;
+    let error = r#"{"kind":"Console","payload":{"level":"error","message":"Uncaught Error: oops","stack":"at app.js:1:1"}}"#;
+    for msg in [log, warn, error] {
+        socket.send(Message::Text(msg.to_string())).await.unwrap();
+    }
+
+    let mut forwarded = vec![];
+    for _ in 0..50 {
+        forwarded = parent
+            .send(GetEvents)
+            .await
+            .unwrap()
+            .into_iter()
+            .filter_map(|evt| match evt {
+                IncomingEvents::External(ExternalEventsDTO::BrowserConsole(dto)) => Some(dto),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if forwarded.len() == 2 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+
+    // `log` is not enabled, so it's skipped
+    assert_eq!(forwarded.len(), 2);
+    assert_eq!(forwarded[0].level, ConsoleLevelDTO::Warn);
+    assert_eq!(forwarded[0].message, "careful");
+    assert_eq!(forwarded[1].level, ConsoleLevelDTO::Error);
+    assert_eq!(forwarded[1].stack.as_deref(), Some("at app.js:1:1"));
+    for dto in &forwarded {
+        assert_eq!(dto.user_agent.as_deref(), Some("Firefox/128.0"));
+        assert!(dto.addr.starts_with("127.0.0.1:"));
+    }
+}
+
+// the next text message, or None if nothing arrives in time
+async fn next_text<S>(socket: &mut S) -> Option<String>
+where
+    S: StreamExt<Item = Result<tungstenite::Message, tungstenite::Error>> + Unpin,
+{
+    let wait = std::time::Duration::from_millis(500);
+    loop {
+        match tokio::time::timeout(wait, socket.next()).await {
+            Ok(Some(Ok(tungstenite::Message::Text(text)))) => return Some(text),
+            Ok(Some(Ok(_))) => continue,
+            _ => return None,
+        }
+    }
+}
+
+async fn system_test_06() {
+    use bsnext_dto::external_events::ExternalEventsDTO;
+    use futures_util::SinkExt;
+    use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+
+    let route1 = Route {
+        path: "/".parse().unwrap(),
+        kind: RouteKind::new_html("hello world!"),
+        ..Default::default()
+    };
+    let server_parent = ServerParent::from_routes(vec![route1]);
+    let server_actor = ServerActor::new_from_config(server_parent.server_config.clone()).start();
+    let parent = server_parent.start();
+
+    let addr = server_actor
+        .send(Listen {
+            runtime_ctx: RuntimeCtx::default(),
+            parent: parent.clone().recipient(),
+            evt_receiver: parent.clone().recipient(),
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+    let ws_url = format!("ws://{addr}{}", bsnext_client::WS_PATH);
+    let mut req = format!("{ws_url}?bslive.url=%2Fabout")
+        .into_client_request()
+        .unwrap();
+    req.headers_mut()
+        .insert("user-agent", "Firefox/128.0".parse().unwrap());
+    let (mut a, _) = tokio_tungstenite::connect_async(req).await.unwrap();
+    let (mut b, _) = tokio_tungstenite::connect_async(&ws_url).await.unwrap();
+    for socket in [&mut a, &mut b] {
+        let connection = next_text(socket).await.expect("connection message");
+        assert!(connection.contains(r#""kind":"WsConnection""#));
+    }
+
+    let (_parts, body) = bsnext_utils::req_to_str(addr, "/__bs_api/clients", |a| a)
+        .await
+        .expect("clients response");
+    let clients: Value = serde_json::from_str(&body).unwrap();
+    let clients = clients["clients"].as_array().unwrap();
+    assert_eq!(clients.len(), 2);
+    assert_eq!(clients[0]["id"], "1");
+    assert_eq!(clients[0]["url"], "/about");
+    assert_eq!(clients[0]["user_agent"], "Firefox/128.0");
+    assert_eq!(clients[1]["id"], "2");
+    assert_eq!(clients[1]["url"], Value::Null);
+
+    // navigation is reported without ghost mode too, but only kept in the registry
+    let location = r#"{"kind":"Location","payload":{"path":"/contact"}}"#;
+    b.send(tungstenite::Message::Text(location.into()))
+        .await
+        .unwrap();
+    let mut url = Value::Null;
+    for _ in 0..50 {
+        let (_parts, body) = bsnext_utils::req_to_str(addr, "/__bs_api/clients", |a| a)
+            .await
+            .expect("clients response");
+        let clients: Value = serde_json::from_str(&body).unwrap();
+        url = clients["clients"][1]["url"].clone();
+        if url == "/contact" {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    assert_eq!(url, "/contact");
+    assert_eq!(next_text(&mut a).await, None);
+
+    // only the listed browsers get an addressed event, and without the wrapper
+    let message = r#"{"kind":"DisplayMessage","payload":{"message":"hi","reason":null}}"#;
+    let addressed =
+        format!(r#"{{"kind":"Addressed","payload":{{"clients":["2"],"event":{message}}}}}"#);
+    let (_parts, body) = bsnext_utils::post_to_events(addr, &addressed)
+        .await
+        .expect("events response");
+    assert_eq!(body, r#"{"ok":true}"#);
+    assert_eq!(next_text(&mut b).await.as_deref(), Some(message));
+    assert_eq!(next_text(&mut a).await, None);
+
+    a.close(None).await.unwrap();
+    while next_text(&mut a).await.is_some() {}
+    drop(a);
+
+    let mut seen = vec![];
+    for _ in 0..50 {
+        seen = parent
+            .send(GetEvents)
+            .await
+            .unwrap()
+            .into_iter()
+            .filter_map(|evt| match evt {
+                IncomingEvents::External(ExternalEventsDTO::ClientConnected(dto)) => {
+                    Some(("connected", dto.id))
+                }
+                IncomingEvents::External(ExternalEventsDTO::ClientDisconnected(dto)) => {
+                    Some(("disconnected", dto.id))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if seen.len() == 3 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    assert_eq!(
+        seen,
+        vec![
+            ("connected", "1".to_string()),
+            ("connected", "2".to_string()),
+            ("disconnected", "1".to_string()),
+        ]
+    );
+
+    let (_parts, body) = bsnext_utils::req_to_str(addr, "/__bs_api/clients", |a| a)
+        .await
+        .expect("clients response");
+    let clients: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(clients["clients"].as_array().unwrap().len(), 1);
+}
+
+async fn system_test_07() {
+    let route1 = Route {
+        path: "/".parse().unwrap(),
+        kind: RouteKind::new_html("hello world!"),
+        ..Default::default()
+    };
+    let server_parent = ServerParent::from_routes(vec![route1]);
+    let server_actor = ServerActor::new_from_config(server_parent.server_config.clone()).start();
+    let parent = server_parent.start();
+
+    let addr = server_actor
+        .send(Listen {
+            runtime_ctx: RuntimeCtx::default(),
+            parent: parent.clone().recipient(),
+            evt_receiver: parent.clone().recipient(),
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+    let ws_url = format!("ws://{addr}{}", bsnext_client::WS_PATH);
+    let (mut socket, _) = tokio_tungstenite::connect_async(&ws_url).await.unwrap();
+    let connection = next_text(&mut socket).await.expect("connection message");
+    assert!(connection.contains(r#""kind":"WsConnection""#));
+
+    async fn post(addr: std::net::SocketAddr, body: &str) -> (u16, String) {
+        let (parts, body) = bsnext_utils::post_to_events(addr, body)
+            .await
+            .expect("events response");
+        (parts.status.as_u16(), body)
+    }
+
+    // nested change lists are flattened
+    let nested = r#"{"kind":"Change","payload":{"kind":"FsMany","payload":[
+        {"kind":"Fs","payload":{"path":"a.css","change_kind":"Changed"}},
+        {"kind":"FsMany","payload":[
+            {"kind":"Fs","payload":{"path":"b.css","change_kind":"Changed"}},
+            {"kind":"Fs","payload":{"path":"c.css","change_kind":"Added"}}
+        ]}
+    ]}}"#;
+    assert_eq!(
+        post(addr, nested).await,
+        (200, r#"{"ok":true}"#.to_string())
+    );
+    let events = parent.send(GetEvents).await.unwrap();
+    let paths = events
+        .iter()
+        .find_map(|evt| match evt {
+            IncomingEvents::FilesChanged(FilesChanged { paths, .. }) => Some(paths.clone()),
+            _ => None,
+        })
+        .expect("files changed");
+    assert_eq!(
+        paths,
+        vec![
+            PathBuf::from("a.css"),
+            PathBuf::from("b.css"),
+            PathBuf::from("c.css")
+        ]
+    );
+
+    // commands and messages go straight to browsers
+    for msg in [
+        r#"{"kind":"Reload"}"#,
+        r#"{"kind":"InjectCss","payload":{"path":"styles.css"}}"#,
+        r#"{"kind":"DisplayMessage","payload":{"message":"hi","reason":null}}"#,
+    ] {
+        assert_eq!(post(addr, msg).await.0, 200);
+        assert_eq!(next_text(&mut socket).await.as_deref(), Some(msg));
+    }
+
+    // config is only sent on when something changed
+    let config = r#"{"kind":"Config","payload":{"log_level":"debug","ghost":{"scroll":true,"click":false,"input":false,"submit":false,"location":false},"console":["error"]}}"#;
+    assert_eq!(post(addr, config).await.0, 200);
+    let sent = next_text(&mut socket).await.expect("config message");
+    assert!(sent.contains(r#""kind":"Config""#));
+    assert!(sent.contains(r#""log_level":"debug""#));
+    assert_eq!(post(addr, config).await.0, 200);
+    assert_eq!(next_text(&mut socket).await, None);
+
+    let (status, body) = post(addr, &connection).await;
+    assert_eq!(status, 400);
+    assert!(body.contains(r#""ok":false"#));
+
+    let empty = r#"{"kind":"Change","payload":{"kind":"FsMany","payload":[]}}"#;
+    assert_eq!(post(addr, empty).await.0, 400);
+
+    let nested_addressed = r#"{"kind":"Addressed","payload":{"clients":["1"],"event":{"kind":"Addressed","payload":{"clients":["1"],"event":{"kind":"Reload"}}}}}"#;
+    assert_eq!(post(addr, nested_addressed).await.0, 400);
+
+    let unknown_client =
+        r#"{"kind":"Addressed","payload":{"clients":["99"],"event":{"kind":"Reload"}}}"#;
+    assert_eq!(post(addr, unknown_client).await.0, 404);
+
+    // not enabled in `clients.ghost`
+    let click = r#"{"kind":"Click","payload":{"tag":"a","index":0}}"#;
+    assert_eq!(post(addr, click).await.0, 400);
+
+    let (status, _) = post(addr, r#"{"kind":"Nope"}"#).await;
+    assert!((400..500).contains(&status));
+}
+
 struct ServerParent {
     server_config: ServerConfig,
     events: Vec<IncomingEvents>,
@@ -171,3 +628,28 @@ async fn test_init() {
 async fn test_init_02() {
     system_test_02().await
 }
+
+#[actix_rt::test]
+async fn test_ws_proxy() {
+    system_test_03().await
+}
+
+#[actix_rt::test]
+async fn test_ghost_mode() {
+    system_test_04().await
+}
+
+#[actix_rt::test]
+async fn test_console_forwarding() {
+    system_test_05().await
+}
+
+#[actix_rt::test]
+async fn test_clients_registry() {
+    system_test_06().await
+}
+
+#[actix_rt::test]
+async fn test_events_api() {
+    system_test_07().await
+}
@@ -0,0 +1,32 @@
+use bsnext_core::server::router::common::example_req;
+
+const SPA: &str = include_str!("../../../examples/basic/spa.yml");
+
+#[tokio::test]
+async fn test_spa_fallback() -> anyhow::Result<()> {
+    let index = "<p>Edit me! - a full HTML</p>";
+    let browser = Some("text/html,application/xhtml+xml,*/*;q=0.8");
+
+    let (parts, body) = example_req(SPA, "spa", "/users/1", browser).await?;
+    assert_eq!(parts.status.as_u16(), 200);
+    assert!(body.contains(index));
+
+    let (parts, body) = example_req(SPA, "spa", "/script.js", browser).await?;
+    assert_eq!(parts.status.as_u16(), 200);
+    assert_eq!(
+        body,
+        include_str!("../../../examples/basic/public/script.js")
+    );
+
+    let (parts, _) = example_req(SPA, "spa", "/missing.js", browser).await?;
+    assert_eq!(parts.status.as_u16(), 404, "missing assets still 404");
+
+    let (parts, _) = example_req(SPA, "spa", "/users/1", Some("application/json")).await?;
+    assert_eq!(parts.status.as_u16(), 404, "only navigation requests");
+
+    let (parts, body) = example_req(SPA, "spa-index", "/app/settings/profile", browser).await?;
+    assert_eq!(parts.status.as_u16(), 200);
+    assert!(body.contains(index));
+
+    Ok(())
+}
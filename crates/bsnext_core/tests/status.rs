@@ -0,0 +1,65 @@
+use axum::body::Body;
+use axum::extract::Request;
+use bsnext_core::server::router::common::{from_yaml, to_resp_parts_and_body};
+use bsnext_core::server::router::make_router;
+use http::header::LOCATION;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn test_status_and_redirects() -> Result<(), anyhow::Error> {
+    let input = include_str!("../../../examples/basic/status.yml");
+    let state = Arc::new(from_yaml(input)?);
+
+    let parts_for = |uri: &'static str| {
+        let state = state.clone();
+        async move {
+            let req = Request::get(uri).body(Body::empty()).unwrap();
+            let res = make_router(&state).oneshot(req).await.unwrap();
+            to_resp_parts_and_body(res).await
+        }
+    };
+
+    let (parts, body) = parts_for("/teapot").await;
+    assert_eq!(parts.status.as_u16(), 418);
+    assert_eq!(body, "<h1>I'm a teapot</h1>");
+
+    let (parts, body) = parts_for("/api/broken").await;
+    assert_eq!(parts.status.as_u16(), 500);
+    assert_eq!(body, r#"{"error":"something went wrong"}"#);
+
+    let (parts, body) = parts_for("/missing").await;
+    assert_eq!(parts.status.as_u16(), 404);
+    assert_eq!(body, "<p>nothing to see here</p>");
+
+    let (parts, _) = parts_for("/dashboard").await;
+    assert_eq!(parts.status.as_u16(), 302);
+    assert_eq!(parts.headers.get(LOCATION).unwrap(), "/login");
+
+    let (parts, _) = parts_for("/old").await;
+    assert_eq!(parts.status.as_u16(), 301);
+    assert_eq!(parts.headers.get(LOCATION).unwrap(), "/new");
+
+    let (parts, body) = parts_for("/api/ping").await;
+    assert_eq!(parts.status.as_u16(), 204);
+    assert_eq!(body, "");
+
+    let (parts, body) = parts_for("/api/empty").await;
+    assert_eq!(parts.status.as_u16(), 200);
+    assert_eq!(body, "");
+
+    let (_, me) = parts_for("/__bs_api/me").await;
+    let me: serde_json::Value = serde_json::from_str(&me)?;
+    let routes = me["routes"].as_array().unwrap();
+    assert_eq!(routes[0]["status"], 418);
+    assert_eq!(
+        routes[4]["kind"],
+        serde_json::json!({ "kind": "Redirect", "payload": { "to": "/new", "status": 301 } })
+    );
+    assert_eq!(
+        routes[5]["kind"],
+        serde_json::json!({ "kind": "NoContent", "payload": { "no_content": true } })
+    );
+
+    Ok(())
+}
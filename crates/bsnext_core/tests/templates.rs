@@ -0,0 +1,40 @@
+use axum::body::Body;
+use axum::extract::Request;
+use bsnext_core::server::router::common::{from_yaml, to_resp_parts_and_body};
+use bsnext_core::server::router::make_router;
+use http::header::{CONTENT_TYPE, USER_AGENT};
+use std::sync::Arc;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn test_templates() -> Result<(), anyhow::Error> {
+    let input = include_str!("../../../examples/basic/templates.yml");
+    let state = Arc::new(from_yaml(input)?);
+
+    let body_for = |req: Request| {
+        let state = state.clone();
+        async move {
+            let res = make_router(&state).oneshot(req).await.unwrap();
+            to_resp_parts_and_body(res).await.1
+        }
+    };
+
+    let json = body_for(Request::get("/api/users/42?fields=name").body(Body::empty())?).await;
+    let json: serde_json::Value = serde_json::from_str(&json)?;
+    assert_eq!(json["id"], "42");
+    assert_eq!(json["fields"], "name");
+    assert!(json["email"].as_str().unwrap().ends_with("@example.com"));
+    assert!(chrono::DateTime::parse_from_rfc3339(json["created"].as_str().unwrap()).is_ok());
+
+    let echo = Request::post("/echo")
+        .header(CONTENT_TYPE, "application/json")
+        .header(USER_AGENT, "tests")
+        .body(Body::from(r#"{"message":"hello"}"#))?;
+    assert_eq!(body_for(echo).await, "POST hello from tests");
+
+    let html = Request::get("/greet/kittie?note=%3Cb%3Ehi%3C%2Fb%3E").body(Body::empty())?;
+    let html = body_for(html).await;
+    assert!(html.starts_with("<h1>Hello kittie</h1><p>&lt;b&gt;hi&lt;&#x2f;b&gt;</p>"));
+
+    Ok(())
+}
@@ -0,0 +1,180 @@
+use axum::body::Body;
+use axum::extract::Request;
+use bsnext_core::server::router::common::{from_yaml, to_resp_parts_and_body};
+use bsnext_core::server::router::make_router;
+use http::header::{AUTHORIZATION, CONTENT_TYPE, COOKIE};
+use http::Method;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn test_when_guards() -> Result<(), anyhow::Error> {
+    let input = include_str!("../../../examples/basic/when_guards.yml");
+    let state = Arc::new(from_yaml(input)?);
+
+    let body_for = |req: Request| {
+        let state = state.clone();
+        async move {
+            let res = make_router(&state).oneshot(req).await.unwrap();
+            to_resp_parts_and_body(res).await.1
+        }
+    };
+
+    let delete = Request::builder()
+        .method(Method::DELETE)
+        .uri("/api/users/1")
+        .body(Body::empty())?;
+    assert_eq!(body_for(delete).await, r#"{"deleted":true}"#);
+
+    let admin = Request::get("/api/users/1")
+        .header(AUTHORIZATION, "Bearer abc")
+        .body(Body::empty())?;
+    assert_eq!(body_for(admin).await, r#"{"name":"admin"}"#);
+
+    let other_id = Request::get("/api/users/2")
+        .header(AUTHORIZATION, "Bearer abc")
+        .body(Body::empty())?;
+    assert_eq!(body_for(other_id).await, "unauthorized");
+
+    let with_cookie = Request::get("/api/users/2")
+        .header(COOKIE, "theme=dark; session=abc")
+        .body(Body::empty())?;
+    assert_eq!(body_for(with_cookie).await, r#"{"name":"kittie"}"#);
+
+    let wrong_cookie = Request::get("/api/users/2")
+        .header(COOKIE, "session=abcd")
+        .body(Body::empty())?;
+    assert_eq!(body_for(wrong_cookie).await, "unauthorized");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_when_guard_combinators() -> Result<(), anyhow::Error> {
+    let input = r#"
+servers:
+- name: combinators
+  routes:
+  - path: /api
+    raw: "read"
+    when:
+      any:
+        - method: GET
+        - method: HEAD
+  - path: /api
+    raw: "kittie or friend"
+    when_body:
+      any:
+        - json:
+            path: /name
+            is: kittie
+        - not:
+            json:
+              path: /role
+              has: stranger
+  - path: /api
+    raw: "fallback"
+    "#;
+    let state = Arc::new(from_yaml(input)?);
+
+    let body_for = |req: Request| {
+        let state = state.clone();
+        async move {
+            let res = make_router(&state).oneshot(req).await.unwrap();
+            to_resp_parts_and_body(res).await.1
+        }
+    };
+    let post = |json: &'static str| {
+        Request::post("/api")
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(json))
+            .unwrap()
+    };
+
+    assert_eq!(
+        body_for(Request::get("/api").body(Body::empty())?).await,
+        "read"
+    );
+    assert_eq!(
+        body_for(post(r#"{"name":"kittie","role":"stranger"}"#)).await,
+        "kittie or friend"
+    );
+    assert_eq!(
+        body_for(post(r#"{"name":"shane","role":"friend"}"#)).await,
+        "kittie or friend"
+    );
+    assert_eq!(
+        body_for(post(r#"{"name":"shane","role":"stranger"}"#)).await,
+        "fallback"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_body_guards() -> Result<(), anyhow::Error> {
+    let input = include_str!("../../../examples/basic/body_guards.yml");
+    let state = Arc::new(from_yaml(input)?);
+
+    let body_for = |req: Request| {
+        let state = state.clone();
+        async move {
+            let res = make_router(&state).oneshot(req).await.unwrap();
+            to_resp_parts_and_body(res).await.1
+        }
+    };
+    let graphql = |method: Method, json: &'static str| {
+        Request::builder()
+            .method(method)
+            .uri("/graphql")
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(json))
+            .unwrap()
+    };
+
+    assert_eq!(
+        body_for(graphql(Method::POST, r#"{"operationName":"GetUser"}"#)).await,
+        r#"{"data":{"user":{"name":"kittie"}}}"#
+    );
+    assert_eq!(
+        body_for(graphql(
+            Method::PUT,
+            r#"{"operationName":"ListUsersPage2","variables":{"limit":20}}"#
+        ))
+        .await,
+        r#"{"data":{"users":[]}}"#
+    );
+    assert_eq!(
+        body_for(graphql(
+            Method::PATCH,
+            r#"{"operationName":"ListUsers","variables":{"limit":500}}"#
+        ))
+        .await,
+        r#"{"errors":[{"message":"unknown operation"}]}"#
+    );
+
+    let login = |form: &'static str| {
+        Request::post("/login")
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from(form))
+            .unwrap()
+    };
+    assert_eq!(
+        body_for(login("user=kittie&remember=on")).await,
+        "welcome back"
+    );
+    assert_ne!(body_for(login("user=kittie")).await, "welcome back");
+
+    let upload = Request::post("/upload")
+        .header(CONTENT_TYPE, "multipart/form-data; boundary=\"b0undary\"")
+        .body(Body::from(
+            "--b0undary\r\n\
+            Content-Disposition: form-data; name=\"avatar\"; filename=\"me.png\"\r\n\
+            \r\n\
+            not really a png\r\n\
+            --b0undary--\r\n",
+        ))?;
+    assert_eq!(body_for(upload).await, "thanks for the picture");
+
+    Ok(())
+}
@@ -1,11 +1,13 @@
 use crate::archy::{archy, overlay_results, ArchyNode, Prefix};
 use crate::{
-    FileChangedDTO, FilesChangedDTO, InputAcceptedDTO, OutputLineDTO, ServerIdentityDTO,
+    BrowserConsoleDTO, ClientDTO, ConsoleLevelDTO, FileChangedDTO, FilesChangedDTO,
+    InputAcceptedDTO, OutputLineDTO, ProxyErrorDTO, ProxyWsDTO, ServerIdentityDTO,
     ServersChangedDTO, StderrLineDTO, StdoutLineDTO, StoppedWatchingDTO, WatchingDTO,
 };
 use bsnext_output::OutputWriterTrait;
 use bsnext_task::task_report::TaskReport;
 use bsnext_task::NodePath;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::io::Write;
 use std::path::PathBuf;
@@ -27,6 +29,12 @@ pub enum ExternalEventsDTO {
     TaskAction(TaskActionDTO),
     TaskTreePreview(TaskTreePreview),
     TaskTreeSummary(TaskTreeSummary),
+    ProxyWsOpened(ProxyWsDTO),
+    ProxyWsClosed(ProxyWsDTO),
+    ProxyError(ProxyErrorDTO),
+    BrowserConsole(BrowserConsoleDTO),
+    ClientConnected(ClientDTO),
+    ClientDisconnected(ClientDTO),
 }
 
 #[typeshare]
@@ -109,11 +117,19 @@ pub enum TaskConclusionDTO {
 pub struct InvocationIdDTO(pub String);
 
 impl ExternalEventsDTO {
-    pub fn stdout_line(line: String, prefix: Option<String>) -> Self {
-        Self::OutputLine(crate::OutputLineDTO::Stdout(StdoutLineDTO { line, prefix }))
+    pub fn stdout_line(line: String, prefix: Option<String>, node_path: &NodePath) -> Self {
+        Self::OutputLine(crate::OutputLineDTO::stdout(
+            line,
+            prefix,
+            Some(node_path.to_string()),
+        ))
     }
-    pub fn stderr_line(line: String, prefix: Option<String>) -> Self {
-        Self::OutputLine(crate::OutputLineDTO::Stderr(StderrLineDTO { line, prefix }))
+    pub fn stderr_line(line: String, prefix: Option<String>, node_path: &NodePath) -> Self {
+        Self::OutputLine(crate::OutputLineDTO::stderr(
+            line,
+            prefix,
+            Some(node_path.to_string()),
+        ))
     }
 }
 
@@ -158,6 +174,16 @@ impl OutputWriterTrait for ExternalEventsDTO {
             ExternalEventsDTO::TaskTreeSummary(TaskTreeSummary { tree, report_map }) => {
                 print_task_tree_summary(sink, tree, report_map)
             }
+            ExternalEventsDTO::ProxyWsOpened(ws) => print_proxy_ws_opened(sink, ws),
+            ExternalEventsDTO::ProxyWsClosed(ws) => print_proxy_ws_closed(sink, ws),
+            ExternalEventsDTO::ProxyError(err) => print_proxy_error(sink, err),
+            ExternalEventsDTO::BrowserConsole(evt) => print_browser_console(sink, evt),
+            ExternalEventsDTO::ClientConnected(client) => {
+                print_client(sink, "[client:connected]", client)
+            }
+            ExternalEventsDTO::ClientDisconnected(client) => {
+                print_client(sink, "[client:disconnected]", client)
+            }
         }
     }
 }
@@ -201,19 +227,17 @@ where
     for server_dto in &servers_resp.servers {
         match &server_dto.identity {
             ServerIdentityDTO::Both { name, .. } => {
-                writeln!(w, "[server] [{}] http://{}", name, server_dto.socket_addr)?;
+                writeln!(w, "[server] [{}] {}", name, server_dto.url)?;
             }
             ServerIdentityDTO::Address { .. } => {
-                writeln!(w, "[server] http://{}", server_dto.socket_addr)?;
+                writeln!(w, "[server] {}", server_dto.url)?;
             }
             ServerIdentityDTO::Named { name } => {
-                writeln!(w, "[server] [{}] http://{}", name, &server_dto.socket_addr)?
-            }
-            ServerIdentityDTO::Port { .. } => {
-                writeln!(w, "[server] http://{}", &server_dto.socket_addr)?
+                writeln!(w, "[server] [{}] {}", name, &server_dto.url)?
             }
+            ServerIdentityDTO::Port { .. } => writeln!(w, "[server] {}", &server_dto.url)?,
             ServerIdentityDTO::PortNamed { name, .. } => {
-                writeln!(w, "[server] [{}] http://{}", name, &server_dto.socket_addr)?
+                writeln!(w, "[server] [{}] {}", name, &server_dto.url)?
             }
         }
     }
@@ -227,6 +251,89 @@ pub fn print_stopped_watching<W: Write>(w: &mut W, evt: &StoppedWatchingDTO) ->
     Ok(())
 }
 
+pub fn print_proxy_ws_opened<W: Write>(w: &mut W, evt: &ProxyWsDTO) -> anyhow::Result<()> {
+    writeln!(w, "[proxy:ws] {} -> {}", evt.path, evt.target)?;
+    Ok(())
+}
+
+pub fn print_proxy_ws_closed<W: Write>(w: &mut W, evt: &ProxyWsDTO) -> anyhow::Result<()> {
+    writeln!(w, "[proxy:ws:closed] {} -> {}", evt.path, evt.target)?;
+    Ok(())
+}
+
+pub fn print_proxy_error<W: Write>(w: &mut W, evt: &ProxyErrorDTO) -> anyhow::Result<()> {
+    writeln!(
+        w,
+        "[proxy:error] {} -> {} ({}, {} attempt(s)) {}",
+        evt.path,
+        evt.target,
+        evt.kind.as_str(),
+        evt.attempts,
+        evt.message
+    )?;
+    Ok(())
+}
+
+pub fn print_browser_console<W: Write>(w: &mut W, evt: &BrowserConsoleDTO) -> anyhow::Result<()> {
+    let client = match evt.user_agent.as_deref().and_then(short_user_agent) {
+        Some(browser) => format!("{} {}", escape_control(browser), evt.addr),
+        None => evt.addr.clone(),
+    };
+    let label = format!("[browser:{}]", evt.level.as_str());
+    match evt.level {
+        ConsoleLevelDTO::Error => write!(w, "\x1b[1;31m{label}\x1b[0m")?,
+        ConsoleLevelDTO::Warn => write!(w, "\x1b[1;33m{label}\x1b[0m")?,
+        _ => write!(w, "{label}")?,
+    }
+    writeln!(w, " [{client}] {}", escape_control(&evt.message))?;
+    if let Some(stack) = &evt.stack {
+        for line in stack.lines().filter(|l| !l.trim().is_empty()) {
+            writeln!(w, "    {}", escape_control(line.trim()))?;
+        }
+    }
+    Ok(())
+}
+
+pub fn print_client<W: Write>(w: &mut W, label: &str, client: &ClientDTO) -> anyhow::Result<()> {
+    write!(w, "{label} #{}", client.id)?;
+    if let Some(browser) = client.user_agent.as_deref().and_then(short_user_agent) {
+        write!(w, " {}", escape_control(browser))?;
+    }
+    write!(w, " {}", client.addr)?;
+    if let Some(url) = &client.url {
+        write!(w, " {}", escape_control(url))?;
+    }
+    writeln!(w)?;
+    Ok(())
+}
+
+///
+/// Text sent by a page is printed as-is otherwise, so escape sequences in it could
+/// restyle the terminal or rewrite lines that came before it
+///
+fn escape_control(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(char::is_control) {
+        return Cow::Borrowed(text);
+    }
+    text.chars()
+        .map(|c| match c.is_control() {
+            true => c.escape_default().to_string(),
+            false => c.to_string(),
+        })
+        .collect()
+}
+
+/// Just the browser name + version, since full user agents are too noisy for the terminal
+fn short_user_agent(ua: &str) -> Option<&str> {
+    // order matters, since most browsers also claim to be Chrome and/or Safari
+    ["Edg/", "OPR/", "Firefox/", "Chrome/", "Safari/"]
+        .iter()
+        .find_map(|name| {
+            let start = ua.find(name)?;
+            ua[start..].split_whitespace().next()
+        })
+}
+
 pub fn print_input_accepted<W: Write>(w: &mut W, evt: &InputAcceptedDTO) -> anyhow::Result<()> {
     writeln!(w, "[input] {}", evt.path)?;
     Ok(())
@@ -329,3 +436,28 @@ pub fn print_watching<W: Write>(w: &mut W, evt: &WatchingDTO) -> anyhow::Result<
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_print_browser_console_escapes_control_chars() -> anyhow::Result<()> {
+        let evt = BrowserConsoleDTO {
+            level: ConsoleLevelDTO::Log,
+            message: "\x1b[2J\x1b]0;title\x07cleared\nfake line".into(),
+            stack: Some("Error\n    at \x1b[31mred\x1b[0m".into()),
+            user_agent: None,
+            addr: "127.0.0.1:5000".into(),
+        };
+        let mut out = vec![];
+        print_browser_console(&mut out, &evt)?;
+        assert_eq!(
+            String::from_utf8(out)?,
+            "[browser:log] [127.0.0.1:5000] \\u{1b}[2J\\u{1b}]0;title\\u{7}cleared\\nfake line\n\
+             \x20   Error\n\
+             \x20   at \\u{1b}[31mred\\u{1b}[0m\n"
+        );
+        Ok(())
+    }
+}
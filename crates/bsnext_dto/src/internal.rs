@@ -4,7 +4,7 @@ use crate::external_events::{
     TaskReportDTO, TaskResultDTO,
 };
 use crate::{GetActiveServersResponse, GetActiveServersResponseDTO, StartupError};
-use bsnext_input::server_config::ServerIdentity;
+use bsnext_input::server_config::{Scheme, ServerIdentity};
 use bsnext_input::InputError;
 use bsnext_task::invocation_result::{InvocationConclusion, InvocationResult};
 use bsnext_task::task_report::TaskReport;
@@ -130,6 +130,13 @@ pub enum InternalEventsDTO {
 pub struct ChildHandlerMinimal {
     pub identity: ServerIdentity,
     pub socket_addr: SocketAddr,
+    pub scheme: Scheme,
+}
+
+impl ChildHandlerMinimal {
+    pub fn url(&self) -> String {
+        self.scheme.url(&self.socket_addr)
+    }
 }
 
 #[derive(Debug, Clone, actix::Message)]
@@ -197,6 +204,8 @@ pub enum ServerError {
     Unknown(String),
     #[error("io error {0}")]
     Io(String),
+    #[error("tls error: {reason}")]
+    Tls { reason: String },
     #[error("server was closed")]
     Closed,
 }
@@ -99,7 +99,7 @@ pub fn print_server_updates(evts: &[ChildResult]) -> Vec<String> {
                     "[created] {}",
                     server_display(
                         &ServerIdentityDTO::from(&created.server_handler.identity),
-                        &created.server_handler.socket_addr.to_string()
+                        &created.server_handler.url()
                     ),
                 )]
             }
@@ -142,22 +142,22 @@ pub fn print_server_updates(evts: &[ChildResult]) -> Vec<String> {
         .collect()
 }
 
-pub fn server_display(identity_dto: &ServerIdentityDTO, socket_addr: &str) -> String {
+pub fn server_display(identity_dto: &ServerIdentityDTO, url: &str) -> String {
     match &identity_dto {
         ServerIdentityDTO::Both { name, .. } => {
-            format!("[server] [{name}] http://{socket_addr}")
+            format!("[server] [{name}] {url}")
         }
         ServerIdentityDTO::Address { .. } => {
-            format!("[server] http://{socket_addr}")
+            format!("[server] {url}")
         }
         ServerIdentityDTO::Named { name } => {
-            format!("[server] [{name}] http://{socket_addr}")
+            format!("[server] [{name}] {url}")
         }
         ServerIdentityDTO::Port { port } => {
-            format!("[server] [{port}] http://{socket_addr}")
+            format!("[server] [{port}] {url}")
         }
         ServerIdentityDTO::PortNamed { name, .. } => {
-            format!("[server] [{name}] http://{socket_addr}")
+            format!("[server] [{name}] {url}")
         }
     }
 }
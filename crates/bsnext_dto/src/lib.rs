@@ -1,5 +1,5 @@
 use actix::MessageResponse;
-use bsnext_input::server_config::ServerIdentity;
+use bsnext_input::server_config::{Scheme, ServerIdentity};
 use bsnext_input::InputError;
 use std::fmt::{Display, Formatter};
 use std::net::SocketAddr;
@@ -7,8 +7,8 @@ use std::path::Path;
 
 use crate::internal::{ServerError, StartupEvent};
 use bsnext_fs::Debounce;
-use bsnext_input::client_config::ClientConfig;
-use bsnext_input::route::{DirRoute, ProxyRoute, RawRoute, Route, RouteKind};
+use bsnext_input::client_config::{ClientConfig, ConsoleLevel, GhostConfig};
+use bsnext_input::route::{DirRoute, RawRoute, Route, RouteKind};
 use bsnext_tracing::LogLevel;
 use typeshare::typeshare;
 
@@ -30,6 +30,8 @@ pub struct ServerDesc {
 pub struct RouteDTO {
     pub path: String,
     pub kind: RouteKindDTO,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
 }
 
 impl From<Route> for RouteDTO {
@@ -37,6 +39,7 @@ impl From<Route> for RouteDTO {
         Self {
             path: value.path.as_str().to_owned(),
             kind: RouteKindDTO::from(value.kind),
+            status: value.opts.status,
         }
     }
 }
@@ -45,6 +48,7 @@ impl From<&Route> for RouteDTO {
         Self {
             path: value.path.as_str().to_owned(),
             kind: RouteKindDTO::from(value.kind.clone()),
+            status: value.opts.status,
         }
     }
 }
@@ -58,6 +62,8 @@ pub enum RouteKindDTO {
     Json { json_str: String },
     Raw { raw: String },
     Sse { sse: SseDTOOpts },
+    Redirect { to: String, status: u16 },
+    NoContent { no_content: bool },
     Proxy { proxy: String },
     Dir { dir: String, base: Option<String> },
 }
@@ -80,14 +86,14 @@ impl From<RouteKind> for RouteKindDTO {
                 RawRoute::Sse { sse: opts } => RouteKindDTO::Sse {
                     sse: SseDTOOpts { body: opts.body },
                 },
+                RawRoute::Redirect { redirect } => RouteKindDTO::Redirect {
+                    status: redirect.status(),
+                    to: redirect.to,
+                },
+                RawRoute::NoContent { no_content } => RouteKindDTO::NoContent { no_content },
             },
-            RouteKind::Proxy(ProxyRoute {
-                proxy,
-                proxy_headers: _outgoing_headers,
-                rewrite_uri: _rewrite,
-                ..
-            }) => RouteKindDTO::Proxy { proxy },
-            RouteKind::Dir(DirRoute { dir, base }) => RouteKindDTO::Dir {
+            RouteKind::Proxy(proxy) => RouteKindDTO::Proxy { proxy: proxy.proxy },
+            RouteKind::Dir(DirRoute { dir, base, .. }) => RouteKindDTO::Dir {
                 dir,
                 base: base.map(|b| b.to_string_lossy().to_string()),
             },
@@ -155,11 +161,19 @@ pub enum OutputLineDTO {
 }
 
 impl OutputLineDTO {
-    pub fn stdout(line: String, prefix: Option<String>) -> Self {
-        Self::Stdout(StdoutLineDTO { line, prefix })
+    pub fn stdout(line: String, prefix: Option<String>, node_path: Option<String>) -> Self {
+        Self::Stdout(StdoutLineDTO {
+            line,
+            prefix,
+            node_path,
+        })
     }
-    pub fn stderr(line: String, prefix: Option<String>) -> Self {
-        Self::Stderr(StderrLineDTO { line, prefix })
+    pub fn stderr(line: String, prefix: Option<String>, node_path: Option<String>) -> Self {
+        Self::Stderr(StderrLineDTO {
+            line,
+            prefix,
+            node_path,
+        })
     }
 }
 
@@ -175,6 +189,8 @@ pub struct StdoutLineDTO {
     pub line: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_path: Option<String>,
 }
 
 #[typeshare]
@@ -183,6 +199,8 @@ pub struct StderrLineDTO {
     pub line: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_path: Option<String>,
 }
 
 #[typeshare]
@@ -204,6 +222,63 @@ pub struct WatchingDTO {
     pub debounce: DebounceDTO,
 }
 
+/// A browser connected to a server over the websocket
+#[typeshare]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ClientDTO {
+    pub id: String,
+    pub addr: String,
+    pub user_agent: Option<String>,
+    /// Path, query and hash of the page the browser is on
+    pub url: Option<String>,
+    /// RFC 3339, eg: `2024-01-01T00:00:00.000Z`
+    pub connected_at: String,
+}
+
+#[typeshare]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ConnectedClientsDTO {
+    pub clients: Vec<ClientDTO>,
+}
+
+#[typeshare]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ProxyWsDTO {
+    pub path: String,
+    pub target: String,
+}
+
+#[typeshare]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ProxyErrorDTO {
+    pub path: String,
+    pub target: String,
+    pub kind: ProxyErrorKindDTO,
+    pub message: String,
+    pub attempts: u32,
+}
+
+#[typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProxyErrorKindDTO {
+    ConnectTimeout,
+    ResponseTimeout,
+    Connect,
+    Other,
+}
+
+impl ProxyErrorKindDTO {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProxyErrorKindDTO::ConnectTimeout => "connect_timeout",
+            ProxyErrorKindDTO::ResponseTimeout => "response_timeout",
+            ProxyErrorKindDTO::Connect => "connect",
+            ProxyErrorKindDTO::Other => "other",
+        }
+    }
+}
+
 #[typeshare]
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct StoppedWatchingDTO {
@@ -312,15 +387,7 @@ pub struct GetActiveServersResponseDTO {
 impl From<&GetActiveServersResponse> for GetActiveServersResponseDTO {
     fn from(value: &GetActiveServersResponse) -> Self {
         Self {
-            servers: value
-                .servers
-                .iter()
-                .map(|s| ServerDTO {
-                    id: s.identity.as_id().to_string(),
-                    identity: (&s.identity).into(),
-                    socket_addr: s.socket_addr.to_string(),
-                })
-                .collect(),
+            servers: value.servers.iter().map(ServerDTO::from).collect(),
         }
     }
 }
@@ -331,6 +398,7 @@ pub struct ServerDTO {
     pub id: String,
     pub identity: ServerIdentityDTO,
     pub socket_addr: String,
+    pub url: String,
 }
 
 impl From<&ActiveServer> for ServerDTO {
@@ -339,6 +407,7 @@ impl From<&ActiveServer> for ServerDTO {
             id: value.identity.as_id().to_string(),
             identity: ServerIdentityDTO::from(&value.identity),
             socket_addr: value.socket_addr.to_string(),
+            url: value.url(),
         }
     }
 }
@@ -347,6 +416,13 @@ impl From<&ActiveServer> for ServerDTO {
 pub struct ActiveServer {
     pub identity: ServerIdentity,
     pub socket_addr: SocketAddr,
+    pub scheme: Scheme,
+}
+
+impl ActiveServer {
+    pub fn url(&self) -> String {
+        self.scheme.url(&self.socket_addr)
+    }
 }
 
 #[derive(Debug)]
@@ -414,6 +490,7 @@ pub enum InputErrorDTO {
     PortError(String),
     DirError(String),
     YamlError(String),
+    TomlError(String),
     MarkdownError(String),
     HtmlError(String),
     Io(String),
@@ -436,6 +513,7 @@ impl From<&InputError> for InputErrorDTO {
             e @ InputError::MarkdownError(_) => InputErrorDTO::MarkdownError(e.to_string()),
             e @ InputError::HtmlError(_) => InputErrorDTO::HtmlError(e.to_string()),
             e @ InputError::YamlError(_) => InputErrorDTO::YamlError(e.to_string()),
+            e @ InputError::TomlError(_) => InputErrorDTO::TomlError(e.to_string()),
             e @ InputError::Io(_) => InputErrorDTO::Io(e.to_string()),
             e @ InputError::UnsupportedExtension(_) => {
                 InputErrorDTO::UnsupportedExtension(e.to_string())
@@ -462,6 +540,129 @@ pub enum ClientEvent {
     WsConnection(ClientConfigDTO),
     Config(ClientConfigDTO),
     DisplayMessage(DisplayMessageDTO),
+    Scroll(ScrollDTO),
+    Click(ElementDTO),
+    Input(InputDTO),
+    Submit(ElementDTO),
+    Location(LocationDTO),
+    Console(ConsoleDTO),
+    Addressed(AddressedEventDTO),
+    /// Reload the whole page
+    Reload,
+    InjectCss(InjectCssDTO),
+}
+
+impl ClientEvent {
+    /// Events that come from a browser, and are only passed on when enabled in `ghost`
+    pub fn is_ghost_enabled(&self, ghost: &GhostConfig) -> bool {
+        match self {
+            ClientEvent::Scroll(..) => ghost.scroll,
+            ClientEvent::Click(..) => ghost.click,
+            ClientEvent::Input(..) => ghost.input,
+            ClientEvent::Submit(..) => ghost.submit,
+            ClientEvent::Location(..) => ghost.location,
+            ClientEvent::Change(..)
+            | ClientEvent::Console(..)
+            | ClientEvent::Addressed(..)
+            | ClientEvent::Reload
+            | ClientEvent::InjectCss(..)
+            | ClientEvent::WsConnection(..)
+            | ClientEvent::Config(..)
+            | ClientEvent::DisplayMessage(..) => false,
+        }
+    }
+}
+
+/// Scroll position as a share (0 to 1) of the scrollable distance, so that pages line up
+/// across different screen sizes
+#[typeshare::typeshare]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ScrollDTO {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// An element, found by its tag name and position in the document - eg: the 3rd `input`
+#[typeshare::typeshare]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ElementDTO {
+    pub tag: String,
+    pub index: u32,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InputDTO {
+    pub element: ElementDTO,
+    pub value: String,
+    /// For checkboxes and radios
+    pub checked: Option<bool>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LocationDTO {
+    /// Path, query and hash, eg: `/about?tab=1#team`
+    pub path: String,
+}
+
+impl LocationDTO {
+    ///
+    /// Browsers navigate to this path, so it must stay on the same origin - `//host` and
+    /// `/\host` both point somewhere else, as does anything with a scheme
+    ///
+    pub fn is_same_origin(&self) -> bool {
+        let mut chars = self.path.chars();
+        chars.next() == Some('/') && !matches!(chars.next(), Some('/' | '\\'))
+    }
+}
+
+///
+/// An event for some browsers only, by the ids listed at `/__bs_api/clients`.
+/// Browsers receive just the inner `event`
+///
+#[typeshare::typeshare]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AddressedEventDTO {
+    pub clients: Vec<String>,
+    pub event: Box<ClientEvent>,
+}
+
+/// Swap a stylesheet in place, without reloading the page
+#[typeshare::typeshare]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InjectCssDTO {
+    /// Matched against the `href` of each stylesheet, eg: `styles.css` or `/css/app.css`
+    pub path: String,
+}
+
+/// A `console.*` call, uncaught error or unhandled rejection in a browser
+#[typeshare::typeshare]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ConsoleDTO {
+    pub level: ConsoleLevelDTO,
+    pub message: String,
+    pub stack: Option<String>,
+}
+
+impl ConsoleDTO {
+    /// Only levels listed in `clients.console` make it to the terminal
+    pub fn is_forwarded(&self, levels: &[ConsoleLevel]) -> bool {
+        levels
+            .iter()
+            .any(|level| ConsoleLevelDTO::from(*level) == self.level)
+    }
+}
+
+/// A [`ConsoleDTO`], along with the browser it came from
+#[typeshare::typeshare]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BrowserConsoleDTO {
+    pub level: ConsoleLevelDTO,
+    pub message: String,
+    pub stack: Option<String>,
+    pub user_agent: Option<String>,
+    pub addr: String,
 }
 
 #[typeshare::typeshare]
@@ -475,13 +676,13 @@ pub struct DisplayMessageDTO {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ClientConfigDTO {
     pub log_level: LogLevelDTO,
+    pub ghost: GhostConfigDTO,
+    pub console: Vec<ConsoleLevelDTO>,
 }
 
 impl From<ClientConfig> for ClientConfigDTO {
     fn from(value: ClientConfig) -> Self {
-        Self {
-            log_level: value.log.into(),
-        }
+        Self::from(&value)
     }
 }
 
@@ -489,12 +690,59 @@ impl From<&ClientConfig> for ClientConfigDTO {
     fn from(value: &ClientConfig) -> Self {
         Self {
             log_level: value.log.into(),
+            ghost: value.ghost.into(),
+            console: value.console.iter().copied().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Config sent to `/__bs_api/events` is applied the same way as config from a file
+impl From<&ClientConfigDTO> for ClientConfig {
+    fn from(value: &ClientConfigDTO) -> Self {
+        Self {
+            log: value.log_level.into(),
+            ghost: value.ghost.into(),
+            console: value.console.iter().copied().map(Into::into).collect(),
         }
     }
 }
 
 #[typeshare::typeshare]
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct GhostConfigDTO {
+    pub scroll: bool,
+    pub click: bool,
+    pub input: bool,
+    pub submit: bool,
+    pub location: bool,
+}
+
+impl From<GhostConfigDTO> for GhostConfig {
+    fn from(value: GhostConfigDTO) -> Self {
+        Self {
+            scroll: value.scroll,
+            click: value.click,
+            input: value.input,
+            submit: value.submit,
+            location: value.location,
+        }
+    }
+}
+
+impl From<GhostConfig> for GhostConfigDTO {
+    fn from(value: GhostConfig) -> Self {
+        Self {
+            scroll: value.scroll,
+            click: value.click,
+            input: value.input,
+            submit: value.submit,
+            location: value.location,
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum LogLevelDTO {
     Info,
@@ -503,6 +751,17 @@ pub enum LogLevelDTO {
     Error,
 }
 
+impl From<LogLevelDTO> for LogLevel {
+    fn from(value: LogLevelDTO) -> Self {
+        match value {
+            LogLevelDTO::Info => LogLevel::Info,
+            LogLevelDTO::Debug => LogLevel::Debug,
+            LogLevelDTO::Trace => LogLevel::Trace,
+            LogLevelDTO::Error => LogLevel::Error,
+        }
+    }
+}
+
 impl From<LogLevel> for LogLevelDTO {
     fn from(value: LogLevel) -> Self {
         match value {
@@ -514,6 +773,53 @@ impl From<LogLevel> for LogLevelDTO {
     }
 }
 
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConsoleLevelDTO {
+    Debug,
+    Log,
+    Info,
+    Warn,
+    Error,
+}
+
+impl ConsoleLevelDTO {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConsoleLevelDTO::Debug => "debug",
+            ConsoleLevelDTO::Log => "log",
+            ConsoleLevelDTO::Info => "info",
+            ConsoleLevelDTO::Warn => "warn",
+            ConsoleLevelDTO::Error => "error",
+        }
+    }
+}
+
+impl From<ConsoleLevelDTO> for ConsoleLevel {
+    fn from(value: ConsoleLevelDTO) -> Self {
+        match value {
+            ConsoleLevelDTO::Debug => ConsoleLevel::Debug,
+            ConsoleLevelDTO::Log => ConsoleLevel::Log,
+            ConsoleLevelDTO::Info => ConsoleLevel::Info,
+            ConsoleLevelDTO::Warn => ConsoleLevel::Warn,
+            ConsoleLevelDTO::Error => ConsoleLevel::Error,
+        }
+    }
+}
+
+impl From<ConsoleLevel> for ConsoleLevelDTO {
+    fn from(value: ConsoleLevel) -> Self {
+        match value {
+            ConsoleLevel::Debug => ConsoleLevelDTO::Debug,
+            ConsoleLevel::Log => ConsoleLevelDTO::Log,
+            ConsoleLevel::Info => ConsoleLevelDTO::Info,
+            ConsoleLevel::Warn => ConsoleLevelDTO::Warn,
+            ConsoleLevel::Error => ConsoleLevelDTO::Error,
+        }
+    }
+}
+
 /// An event describing a change
 /// @discriminator kind
 #[typeshare::typeshare]
@@ -523,10 +829,24 @@ pub enum ChangeDTO {
     Fs {
         path: String,
         change_kind: ChangeKind,
+        /// What browsers should do about the change, decided by the server from the file type
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        effect: Option<ChangeEffect>,
+        /// The url paths this file is served at, taken from the `dir` routes
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        urls: Option<Vec<String>>,
     },
     FsMany(Vec<ChangeDTO>),
 }
 
+#[typeshare::typeshare]
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum ChangeEffect {
+    InjectCss,
+    SwapImage,
+    ReloadPage,
+}
+
 #[typeshare::typeshare]
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub enum ChangeKind {
@@ -547,4 +867,23 @@ pub struct InjectConfig {
 pub struct ConnectInfo {
     pub ws_path: String,
     pub host: Option<String>,
+    /// Set when the server is protected by a token, so the websocket can pass it along
+    pub token: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location_is_same_origin() {
+        let location = |path: &str| LocationDTO { path: path.into() };
+        assert!(location("/").is_same_origin());
+        assert!(location("/about?tab=1#team").is_same_origin());
+        assert!(!location("//example.com").is_same_origin());
+        assert!(!location("/\\example.com").is_same_origin());
+        assert!(!location("https://example.com").is_same_origin());
+        assert!(!location("javascript:alert(1)").is_same_origin());
+        assert!(!location("").is_same_origin());
+    }
 }
@@ -27,7 +27,7 @@ pub enum DirError {
     Exists { path: PathBuf },
 }
 
-#[derive(Default, Debug, PartialEq)]
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub enum WriteMode {
     #[default]
     Safe,
@@ -39,11 +39,20 @@ pub fn fs_write_str(
     path: &Path,
     string: &str,
     write_mode: &WriteMode,
+) -> Result<PathBuf, FsWriteError> {
+    fs_write_bytes(cwd, path, string.as_bytes(), write_mode)
+}
+
+pub fn fs_write_bytes(
+    cwd: &Path,
+    path: &Path,
+    bytes: &[u8],
+    write_mode: &WriteMode,
 ) -> Result<PathBuf, FsWriteError> {
     let next_path = cwd.join(path);
     tracing::info!(
         "✏️ writing {} bytes to {}",
-        string.len(),
+        bytes.len(),
         next_path.display()
     );
 
@@ -55,7 +64,7 @@ pub fn fs_write_str(
         return Err(FsWriteError::Exists { path: next_path });
     }
 
-    fs::write(&next_path, string)
+    fs::write(&next_path, bytes)
         .map(|()| next_path.clone())
         .map_err(|_e| FsWriteError::FailedWrite { path: next_path })
 }
@@ -1,9 +1,9 @@
-use bsnext_input::{Input, InputWriter};
+use bsnext_input::{Input, InputError, InputWriter};
 
 pub struct HtmlWriter;
 
 impl InputWriter for HtmlWriter {
-    fn input_to_str(&self, input: &Input) -> String {
+    fn input_to_str(&self, input: &Input) -> Result<String, Box<InputError>> {
         if input.servers.is_empty() {
             todo!("html requires at least 1 server definition")
         }
@@ -28,7 +28,7 @@ impl InputWriter for HtmlWriter {
             blocks.push(indented);
             blocks.push("</script>".into());
         }
-        blocks.join("\n")
+        Ok(blocks.join("\n"))
     }
 }
 
@@ -49,6 +49,6 @@ fn test_html_writer_for_playground() {
     let mut server = ServerConfig::default();
     server.playground = Some(playground);
     input.servers.push(server);
-    let output = HtmlWriter.input_to_str(&input);
+    let output = HtmlWriter.input_to_str(&input).expect("to html");
     insta::assert_snapshot!(output);
 }
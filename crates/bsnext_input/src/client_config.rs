@@ -3,7 +3,58 @@ use std::cmp::PartialEq;
 
 #[derive(Debug, Default, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
 pub struct ClientConfig {
+    #[serde(default)]
     pub log: LogLevel,
+    #[serde(default)]
+    pub ghost: GhostConfig,
+    /// Which browser `console.*` levels are sent back to the terminal
+    #[serde(default)]
+    pub console: Vec<ConsoleLevel>,
+}
+
+///
+/// Ghost mode - mirror interactions from one browser to every other browser connected to
+/// the same server. Each kind is off unless enabled:
+///
+/// ```yaml
+/// clients:
+///   ghost:
+///     scroll: true
+///     click: true
+///     input: true
+///     submit: true
+///     location: true
+/// ```
+///
+#[derive(Debug, Default, PartialEq, Clone, Copy, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct GhostConfig {
+    pub scroll: bool,
+    pub click: bool,
+    /// Typing into inputs, textareas and selects, and toggling checkboxes
+    pub input: bool,
+    pub submit: bool,
+    /// Navigating to another page
+    pub location: bool,
+}
+
+///
+/// Browser console levels that can be forwarded. Uncaught errors and unhandled
+/// rejections are forwarded as `error`:
+///
+/// ```yaml
+/// clients:
+///   console: [warn, error]
+/// ```
+///
+#[derive(Debug, PartialEq, Clone, Copy, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConsoleLevel {
+    Debug,
+    Log,
+    Info,
+    Warn,
+    Error,
 }
 
 impl ClientConfig {
@@ -1,6 +1,15 @@
 use crate::InputError;
 use std::path::{Path, PathBuf};
 
+/// Files that are looked for (in order) in the cwd, when no inputs were given
+pub const AUTO_LOOKUPS: [&str; 5] = [
+    "bslive.yml",
+    "bslive.yaml",
+    "bslive.toml",
+    "bslive.md",
+    "bslive.html",
+];
+
 pub enum ResolvedInputOutcome {
     /// You provided a path that couldn't be resolved
     Missing {
@@ -59,9 +68,7 @@ fn resolve_inputs<T: AsRef<str>>(cwd: PathBuf, inputs: &[T]) -> ResolvedInputOut
         };
     }
 
-    let auto_lookups = ["bslive.yml", "bslive.yaml", "bslive.md", "bslive.html"];
-
-    for named in auto_lookups {
+    for named in AUTO_LOOKUPS {
         let abs = cwd.join(named);
         let exists = Path::exists(&abs);
         if !exists {
@@ -1,10 +1,11 @@
 use crate::route::{
-    CompressionOpts, CorsOpts, DebounceDuration, DelayKind, DelayOpts, MultiWatch, PathPattern,
-    Route, WatchSpec, WatcherDirs,
+    CompressionOpts, CorsConfig, CorsOpts, DebounceDuration, DelayKind, DelayOpts, MultiWatch,
+    PathPattern, Route, WatchSpec, WatcherDirs,
 };
 use crate::watch_opts::WatchOpts;
 use crate::Input;
 use insta::assert_debug_snapshot;
+use std::str::FromStr;
 
 #[test]
 fn test_deserialize() {
@@ -64,6 +65,55 @@ fn test_deserialize_cors_false() {
     assert_eq!(opts, CorsOpts::Cors(false));
 }
 
+#[test]
+fn test_deserialize_cors_config() {
+    #[derive(serde::Deserialize, serde::Serialize, Debug)]
+    struct Config {
+        pub items: Vec<Route>,
+    }
+
+    let input = r#"
+    items:
+      - path: /api
+        json: []
+        cors:
+          origins: [http://localhost:5173]
+          credentials: true
+          max_age: 600
+        "#;
+    let c: Config = serde_yaml::from_str(input).unwrap();
+    let first = c.items.first().unwrap().to_owned();
+    let opts = first.opts.cors.unwrap();
+    let expected = CorsOpts::Config(CorsConfig {
+        origins: Some(vec!["http://localhost:5173".to_string()]),
+        credentials: Some(true),
+        max_age: Some(600),
+        ..Default::default()
+    });
+    assert_eq!(opts, expected);
+}
+
+#[test]
+fn test_cors_from_str() {
+    assert_eq!(CorsOpts::from_str("true").unwrap(), CorsOpts::Cors(true));
+    assert_eq!(
+        CorsOpts::from_str("http://a.com, http://b.com").unwrap(),
+        CorsOpts::Config(CorsConfig {
+            origins: Some(vec!["http://a.com".to_string(), "http://b.com".to_string()]),
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        CorsOpts::from_str("{methods: [GET], credentials: true}").unwrap(),
+        CorsOpts::Config(CorsConfig {
+            methods: Some(vec!["GET".to_string()]),
+            credentials: Some(true),
+            ..Default::default()
+        })
+    );
+    assert!(CorsOpts::from_str("{nope: 1}").is_err());
+}
+
 #[test]
 fn test_deserialize_compressions_absent() {
     #[derive(serde::Deserialize, serde::Serialize, Debug)]
@@ -0,0 +1,188 @@
+use serde_json::Value;
+
+///
+/// A small subset of JSONPath, enough to reach into request bodies:
+///
+/// - `$.data.user.name` or `$['data']['user']` - object keys
+/// - `$.items[0]` - array indexes
+/// - `$.items[*].id` or `$.user.*` - every array item, or every object value
+/// - `$..id` - the key at any depth
+///
+/// Selectors that start with `/` are treated as JSON pointers instead, eg: `/data/user/name`
+///
+#[derive(Debug, PartialEq, Clone)]
+pub enum JsonPathSegment {
+    Key(String),
+    Index(usize),
+    Wildcard,
+    Descendant(String),
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum JsonPathError {
+    #[error("selectors must start with `$`, or `/` for a JSON pointer")]
+    MissingRoot,
+    #[error("unexpected `{0}` at position {1}")]
+    Unexpected(char, usize),
+    #[error("unexpected end of selector")]
+    UnexpectedEnd,
+    #[error("index `{0}` at position {1} is too large")]
+    IndexTooLarge(String, usize),
+}
+
+pub fn parse(selector: &str) -> Result<Vec<JsonPathSegment>, JsonPathError> {
+    let mut chars = selector.char_indices().peekable();
+    match chars.next() {
+        Some((_, '$')) => {}
+        _ => return Err(JsonPathError::MissingRoot),
+    }
+    let mut segments = vec![];
+    while let Some((pos, c)) = chars.next() {
+        match c {
+            '.' => {
+                let descendant = chars.next_if(|(_, c)| *c == '.').is_some();
+                if !descendant && chars.next_if(|(_, c)| *c == '*').is_some() {
+                    segments.push(JsonPathSegment::Wildcard);
+                    continue;
+                }
+                let mut name = String::new();
+                while let Some((_, c)) = chars.next_if(|(_, c)| *c != '.' && *c != '[') {
+                    name.push(c);
+                }
+                if name.is_empty() {
+                    return Err(match chars.peek() {
+                        Some((pos, c)) => JsonPathError::Unexpected(*c, *pos),
+                        None => JsonPathError::UnexpectedEnd,
+                    });
+                }
+                segments.push(match descendant {
+                    true => JsonPathSegment::Descendant(name),
+                    false => JsonPathSegment::Key(name),
+                });
+            }
+            '[' => {
+                let segment = match chars.next() {
+                    Some((_, '*')) => JsonPathSegment::Wildcard,
+                    Some((_, quote @ ('\'' | '"'))) => {
+                        let mut name = String::new();
+                        loop {
+                            match chars.next() {
+                                Some((_, c)) if c == quote => break,
+                                Some((_, c)) => name.push(c),
+                                None => return Err(JsonPathError::UnexpectedEnd),
+                            }
+                        }
+                        JsonPathSegment::Key(name)
+                    }
+                    Some((pos, c)) if c.is_ascii_digit() => {
+                        let mut digits = c.to_string();
+                        while let Some((_, c)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                            digits.push(c);
+                        }
+                        match digits.parse() {
+                            Ok(index) => JsonPathSegment::Index(index),
+                            Err(_) => return Err(JsonPathError::IndexTooLarge(digits, pos)),
+                        }
+                    }
+                    Some((pos, c)) => return Err(JsonPathError::Unexpected(c, pos)),
+                    None => return Err(JsonPathError::UnexpectedEnd),
+                };
+                match chars.next() {
+                    Some((_, ']')) => segments.push(segment),
+                    Some((pos, c)) => return Err(JsonPathError::Unexpected(c, pos)),
+                    None => return Err(JsonPathError::UnexpectedEnd),
+                }
+            }
+            c => return Err(JsonPathError::Unexpected(c, pos)),
+        }
+    }
+    Ok(segments)
+}
+
+/// Every value reached by `selector`. Invalid selectors select nothing
+pub fn select<'a>(value: &'a Value, selector: &str) -> Vec<&'a Value> {
+    if selector.is_empty() || selector.starts_with('/') {
+        return value.pointer(selector).into_iter().collect();
+    }
+    let Ok(segments) = parse(selector) else {
+        return vec![];
+    };
+    segments.iter().fold(vec![value], |current, segment| {
+        current
+            .into_iter()
+            .flat_map(|value| match segment {
+                JsonPathSegment::Key(key) => value.get(key).into_iter().collect(),
+                JsonPathSegment::Index(index) => value.get(*index).into_iter().collect(),
+                JsonPathSegment::Wildcard => match value {
+                    Value::Array(items) => items.iter().collect(),
+                    Value::Object(map) => map.values().collect(),
+                    _ => vec![],
+                },
+                JsonPathSegment::Descendant(key) => {
+                    let mut found = vec![];
+                    descendants(value, key, &mut found);
+                    found
+                }
+            })
+            .collect()
+    })
+}
+
+fn descendants<'a>(value: &'a Value, key: &str, found: &mut Vec<&'a Value>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(v) = map.get(key) {
+                found.push(v);
+            }
+            map.values().for_each(|v| descendants(v, key, found));
+        }
+        Value::Array(items) => items.iter().for_each(|v| descendants(v, key, found)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("$.data['user'].posts[2][*]..id"),
+            Ok(vec![
+                JsonPathSegment::Key("data".to_string()),
+                JsonPathSegment::Key("user".to_string()),
+                JsonPathSegment::Key("posts".to_string()),
+                JsonPathSegment::Index(2),
+                JsonPathSegment::Wildcard,
+                JsonPathSegment::Descendant("id".to_string()),
+            ])
+        );
+        assert_eq!(parse("data.user"), Err(JsonPathError::MissingRoot));
+        assert_eq!(parse("$.a[x]"), Err(JsonPathError::Unexpected('x', 4)));
+        assert_eq!(parse("$.a["), Err(JsonPathError::UnexpectedEnd));
+        assert_eq!(
+            parse("$.a[99999999999999999999999]"),
+            Err(JsonPathError::IndexTooLarge(
+                "99999999999999999999999".to_string(),
+                4
+            ))
+        );
+    }
+
+    #[test]
+    fn test_select() {
+        let value = json!({
+            "operationName": "GetUser",
+            "variables": { "id": 1, "filter": { "id": 2 } },
+            "items": [{ "id": 3 }, { "id": 4 }]
+        });
+        assert_eq!(select(&value, "$.operationName"), vec![&json!("GetUser")]);
+        assert_eq!(select(&value, "/variables/id"), vec![&json!(1)]);
+        assert_eq!(select(&value, "$.items[1].id"), vec![&json!(4)]);
+        assert_eq!(select(&value, "$.items[*].id"), vec![&json!(3), &json!(4)]);
+        assert_eq!(select(&value, "$..id").len(), 4);
+        assert!(select(&value, "$.missing").is_empty());
+    }
+}
@@ -1,6 +1,8 @@
 use crate::route::{BeforeRunOptItem, DebounceDuration, MultiWatch, PathPattern, RunOptItem};
 use crate::server_config::{ServerConfig, ServerIdentity};
 use crate::startup::StartupContext;
+use crate::tml::TomlError;
+use crate::when_guard::GuardValidationError;
 use crate::yml::YamlError;
 use bsnext_fs_helpers::{DirError, FsWriteError};
 use miette::JSONReportHandler;
@@ -13,6 +15,7 @@ use std::str::FromStr;
 pub mod client_config;
 #[cfg(test)]
 pub mod input_test;
+pub mod json_path;
 pub mod path_def;
 pub mod playground;
 pub mod route;
@@ -24,6 +27,7 @@ pub mod route_manifest;
 pub mod server_config;
 pub mod startup;
 pub mod target;
+pub mod tml;
 #[cfg(test)]
 pub mod watch_opt_test;
 pub mod watch_opts;
@@ -49,6 +53,29 @@ impl Input {
             ..Default::default()
         }
     }
+    pub fn validate_guards(&self) -> Result<(), GuardValidationError> {
+        for (server_index, server) in self.servers.iter().enumerate() {
+            for (route_index, route) in server.routes.iter().enumerate() {
+                route.validate_guards().map_err(|e| {
+                    e.within_index(route_index)
+                        .within_key("routes")
+                        .within_index(server_index)
+                        .within_key("servers")
+                })?;
+            }
+        }
+        Ok(())
+    }
+    /// For loaders that can't point back into their source - the yaml loader reports a span instead
+    pub fn validated(self) -> Result<Self, Box<InputError>> {
+        match self.validate_guards() {
+            Ok(()) => Ok(self),
+            Err(e) => Err(Box::new(InputError::InvalidInput(format!(
+                "{e}, at `{}`",
+                e.location()
+            )))),
+        }
+    }
     pub fn before_run_opts(&self) -> Vec<RunOptItem> {
         let root_tasks = self
             .watchers
@@ -262,7 +289,7 @@ pub trait InputCreation {
 }
 
 pub trait InputWriter {
-    fn input_to_str(&self, input: &Input) -> String;
+    fn input_to_str(&self, input: &Input) -> Result<String, Box<InputError>>;
 }
 
 #[derive(Debug, miette::Diagnostic, thiserror::Error)]
@@ -295,6 +322,8 @@ pub enum InputError {
     HtmlError(String),
     #[error("{0}")]
     YamlError(#[from] YamlError),
+    #[error("{0}")]
+    TomlError(#[from] TomlError),
     #[error(transparent)]
     #[diagnostic(transparent)]
     BsLiveRules(#[from] BsLiveRulesError),
@@ -71,6 +71,50 @@ impl PathDef {
     }
 }
 
+///
+/// Reads named params, like `:id` in `/users/:id`, from request paths. It's built once
+/// for a route's path and then used for every request.
+///
+/// Routes are nested, so a pattern also matches any path beneath it
+///
+pub struct PathParams {
+    router: Option<matchit::Router<()>>,
+}
+
+impl PathParams {
+    pub fn new(pattern: &str) -> Self {
+        let mut r = matchit::Router::new();
+        if r.insert(pattern, ()).is_err() {
+            return Self { router: None };
+        }
+        let nested = format!("{}/*__bslive_rest", pattern.trim_end_matches('/'));
+        // a pattern ending in a catch-all already matches everything beneath it
+        let _ = r.insert(nested, ());
+        Self { router: Some(r) }
+    }
+
+    /// Every named param in `path`
+    pub fn all(&self, path: &str) -> Vec<(String, String)> {
+        match self.router.as_ref().map(|r| r.at(path)) {
+            Some(Ok(matched)) => matched
+                .params
+                .iter()
+                .filter(|(name, _)| *name != "__bslive_rest")
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Look up a single named param in `path`
+    pub fn get(&self, path: &str, name: &str) -> Option<String> {
+        self.all(path)
+            .into_iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -100,4 +144,20 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_path_param() {
+        let users = PathParams::new("/users/:id");
+        assert_eq!(users.get("/users/12", "id"), Some("12".to_string()));
+        assert_eq!(users.get("/users/12/posts", "id"), Some("12".to_string()));
+        assert_eq!(users.get("/users/12", "other"), None);
+        assert_eq!(users.get("/other/12", "id"), None);
+        assert_eq!(
+            PathParams::new("/users/:id/posts/:post").all("/users/12/posts/3/comments"),
+            vec![
+                ("id".to_string(), "12".to_string()),
+                ("post".to_string(), "3".to_string()),
+            ]
+        );
+    }
 }
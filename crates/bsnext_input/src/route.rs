@@ -2,17 +2,18 @@ use crate::bs_live_built_in_task::BsLiveBuiltInTask;
 use crate::path_def::PathDef;
 use crate::route_cli::RouteCli;
 use crate::watch_opts::WatchOpts;
-use crate::when_guard::{WhenBodyGuard, WhenGuard};
+use crate::when_guard::{GuardValidationError, GuardValidationKind, WhenBodyGuard, WhenGuard};
 use crate::InputConfig;
 use bsnext_fs::Debounce;
 use bsnext_resp::cache_opts::CacheOpts;
 use bsnext_resp::inject_opts::InjectOpts;
+use globset::{Glob, GlobSet, GlobSetBuilder};
 use matchit::InsertError;
 use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::ops::Deref;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::time::Duration;
 
@@ -36,6 +37,22 @@ pub enum ListOrSingle<T> {
     WhenMany(Vec<T>),
 }
 
+impl<T> ListOrSingle<T> {
+    /// Errors from a list are given the index of the item that caused them
+    pub fn try_each(
+        &self,
+        f: impl Fn(&T) -> Result<(), GuardValidationError>,
+    ) -> Result<(), GuardValidationError> {
+        match self {
+            ListOrSingle::WhenOne(one) => f(one),
+            ListOrSingle::WhenMany(many) => many
+                .iter()
+                .enumerate()
+                .try_for_each(|(index, item)| f(item).map_err(|e| e.within_index(index))),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum PathDefError {
     #[error("Paths must start with a slash")]
@@ -56,7 +73,7 @@ pub struct FallbackRoute {
 
 #[derive(Debug, Default, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
 pub struct Opts {
-    #[serde(flatten)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cors: Option<CorsOpts>,
     #[serde(flatten)]
     pub delay: Option<DelayOpts>,
@@ -68,6 +85,16 @@ pub struct Opts {
     #[serde(default)]
     pub cache: CacheOpts,
     pub compression: Option<CompressionOpts>,
+    /// Render `html`, `json` and `raw` bodies as templates, see `bsnext_core::route_template`
+    pub template: Option<bool>,
+    /// Answer with this status code instead, eg: `status: 418`. Only successful (2xx) responses
+    /// are changed, so errors such as a missing file are left alone
+    pub status: Option<u16>,
+    #[serde(default, alias = "network", skip_serializing_if = "Option::is_none")]
+    pub chaos: Option<ChaosOpts>,
+    /// Checked after any server-wide `auth`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<AuthOpts>,
 }
 
 impl Default for Route {
@@ -114,6 +141,25 @@ impl Route {
             next
         }
     }
+    /// Checks the parts of `when`, `when_body` and `mirror` that cannot be expressed in the types alone
+    pub fn validate_guards(&self) -> Result<(), GuardValidationError> {
+        if let Some(when) = &self.when {
+            when.try_each(WhenGuard::validate)
+                .map_err(|e| e.within_key("when"))?;
+        }
+        if let Some(when_body) = &self.when_body {
+            when_body
+                .try_each(WhenBodyGuard::validate)
+                .map_err(|e| e.within_key("when_body"))?;
+        }
+        if let Some(mirror) = match &self.kind {
+            RouteKind::Proxy(proxy) => proxy.mirror(),
+            RouteKind::Raw(_) | RouteKind::Dir(_) => None,
+        } {
+            mirror.glob_set().map_err(|e| e.within_key("mirror"))?;
+        }
+        Ok(())
+    }
     pub fn from_cli_str<A: AsRef<str>>(a: A) -> Result<Self, anyhow::Error> {
         let cli = RouteCli::try_from_cli_str(a)?;
         cli.try_into()
@@ -126,12 +172,16 @@ impl Route {
                 cors: Some(CorsOpts::Cors(true)),
                 ..Default::default()
             },
-            kind: RouteKind::Proxy(ProxyRoute {
+            kind: RouteKind::Proxy(Box::new(ProxyRoute {
                 proxy: a.as_ref().to_string(),
                 proxy_headers: None,
                 rewrite_uri: None,
-                unstable_mirror: None,
-            }),
+                mirror: None,
+                replay: None,
+                rewrite_links: None,
+                timeouts: None,
+                retry: None,
+            })),
             ..Default::default()
         }
     }
@@ -141,7 +191,8 @@ impl Route {
 #[serde(untagged)]
 pub enum RouteKind {
     Raw(RawRoute),
-    Proxy(ProxyRoute),
+    /// Boxed, since proxies have far more options than the other kinds
+    Proxy(Box<ProxyRoute>),
     Dir(DirRoute),
 }
 
@@ -153,6 +204,8 @@ impl Display for RouteKind {
                 RawRoute::Json { json: _ } => write!(f, "Raw(JSON)"),
                 RawRoute::Raw { raw: _ } => write!(f, "Raw(Text)"),
                 RawRoute::Sse { sse: _ } => write!(f, "Raw(SSE)"),
+                RawRoute::Redirect { redirect } => write!(f, "Redirect({})", redirect.to),
+                RawRoute::NoContent { no_content: _ } => write!(f, "Raw(NoContent)"),
             },
             RouteKind::Proxy(proxy) => write!(f, "Proxy({})", proxy.proxy),
             RouteKind::Dir(dir) => write!(f, "Dir({})", dir.dir),
@@ -212,6 +265,48 @@ impl Hash for JsonWrapper {
 pub struct DirRoute {
     pub dir: String,
     pub base: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spa: Option<SpaOpts>,
+    /// Show the contents of folders that don't have an `index.html`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub listing: Option<bool>,
+}
+
+impl DirRoute {
+    /// Where `dir` lives on disk - joined onto `base` when given, otherwise onto `cwd` if relative
+    pub fn root(&self, cwd: &Path) -> PathBuf {
+        let dir = PathBuf::from(&self.dir);
+        match &self.base {
+            Some(base) => base.join(dir),
+            None if dir.is_absolute() => dir,
+            None => cwd.join(dir),
+        }
+    }
+    pub fn lists(&self) -> bool {
+        self.listing.unwrap_or(false)
+    }
+    /// The file to serve for navigation requests that don't match a file, relative to `dir`
+    pub fn spa_index(&self) -> Option<&str> {
+        match self.spa.as_ref()? {
+            SpaOpts::Enabled(true) => Some("index.html"),
+            SpaOpts::Enabled(false) => None,
+            SpaOpts::Index(index) => Some(index),
+        }
+    }
+}
+
+///
+/// For apps with client-side routing. Navigation requests (those that accept `text/html`)
+/// that don't match a file are given the app's index file instead, whilst missing assets,
+/// like `.js` or `.css` files, still 404.
+///
+/// `spa: true` serves `index.html`, or give a path within `dir`, eg: `spa: app/shell.html`
+///
+#[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum SpaOpts {
+    Enabled(bool),
+    Index(String),
 }
 
 #[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
@@ -219,12 +314,335 @@ pub struct ProxyRoute {
     pub proxy: String,
     pub proxy_headers: Option<BTreeMap<String, String>>,
     pub rewrite_uri: Option<bool>,
-    pub unstable_mirror: Option<String>,
+    #[serde(
+        default,
+        alias = "unstable_mirror",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub mirror: Option<MirrorOpts>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replay: Option<ReplayOpts>,
+    /// Map absolute links to the target (in html/css/js bodies, `Location` and `Set-Cookie` headers)
+    /// back onto the local server
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rewrite_links: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeouts: Option<ProxyTimeouts>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryOpts>,
 }
 
 impl ProxyRoute {
-    pub fn mirror(&self) -> Option<PathBuf> {
-        self.unstable_mirror.as_ref().map(PathBuf::from)
+    pub fn mirror(&self) -> Option<MirrorConfig> {
+        self.mirror.as_ref().map(MirrorOpts::config)
+    }
+    pub fn rewrites_links(&self) -> bool {
+        self.rewrite_links.unwrap_or(false)
+    }
+    pub fn retry(&self) -> RetryConfig {
+        self.retry
+            .as_ref()
+            .map(RetryOpts::config)
+            .unwrap_or_default()
+    }
+}
+
+///
+/// Give up on the proxy target when it's too slow. Without these, requests wait for as
+/// long as the target takes
+///
+#[derive(Debug, Default, PartialEq, Hash, Clone, Copy, serde::Deserialize, serde::Serialize)]
+pub struct ProxyTimeouts {
+    /// How long to wait for a connection to the target
+    pub connect_ms: Option<u64>,
+    /// How long to wait for the target to start responding, including the time to connect
+    pub response_ms: Option<u64>,
+}
+
+///
+/// Try failed proxy requests again, waiting `backoff_ms` before the first retry and
+/// doubling the wait each time after that.
+///
+/// `retry: 3` uses the default backoff, or provide `{ count, backoff_ms }`
+///
+#[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum RetryOpts {
+    Count(u32),
+    Config(RetryConfig),
+}
+
+#[derive(Debug, PartialEq, Hash, Clone, Copy, serde::Deserialize, serde::Serialize)]
+pub struct RetryConfig {
+    pub count: u32,
+    #[serde(default = "RetryConfig::default_backoff_ms")]
+    pub backoff_ms: u64,
+}
+
+impl RetryConfig {
+    fn default_backoff_ms() -> u64 {
+        200
+    }
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            backoff_ms: Self::default_backoff_ms(),
+        }
+    }
+}
+
+impl RetryOpts {
+    pub fn config(&self) -> RetryConfig {
+        match self {
+            RetryOpts::Count(count) => RetryConfig {
+                count: *count,
+                ..Default::default()
+            },
+            RetryOpts::Config(config) => *config,
+        }
+    }
+}
+
+///
+/// Write proxied responses to a directory, in a layout that a `dir` route can serve directly.
+///
+/// `mirror: mirror-dir` writes every successful `GET` response, or provide
+/// `{ dir, mime, glob, offline }` to narrow it down
+///
+#[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum MirrorOpts {
+    Dir(String),
+    Config(MirrorConfig),
+}
+
+#[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+pub struct MirrorConfig {
+    pub dir: String,
+    /// Only mirror responses with these content types, like `text/css` or `image/*`
+    #[serde(default)]
+    pub mime: Vec<String>,
+    /// Only mirror url paths that match these globs, like `assets/**` or `**/*.js`
+    #[serde(default)]
+    pub glob: Vec<String>,
+    /// When the proxy target cannot be reached, serve from the mirror instead
+    #[serde(default)]
+    pub offline: bool,
+}
+
+impl MirrorConfig {
+    /// Globs are matched against url paths without their leading `/`
+    pub fn glob_set(&self) -> Result<GlobSet, GuardValidationError> {
+        let mut builder = GlobSetBuilder::new();
+        for (index, glob) in self.glob.iter().enumerate() {
+            let compiled = Glob::new(glob.trim_start_matches('/')).map_err(|e| {
+                GuardValidationError::new(GuardValidationKind::InvalidGlob(
+                    glob.to_string(),
+                    e.to_string(),
+                ))
+                .within_index(index)
+                .within_key("glob")
+            })?;
+            builder.add(compiled);
+        }
+        builder.build().map_err(|e| {
+            GuardValidationError::new(GuardValidationKind::InvalidGlob(
+                self.glob.join(", "),
+                e.to_string(),
+            ))
+            .within_key("glob")
+        })
+    }
+}
+
+impl MirrorOpts {
+    pub fn config(&self) -> MirrorConfig {
+        match self {
+            MirrorOpts::Dir(dir) => MirrorConfig {
+                dir: dir.clone(),
+                mime: vec![],
+                glob: vec![],
+                offline: false,
+            },
+            MirrorOpts::Config(config) => config.clone(),
+        }
+    }
+}
+
+///
+/// Answer requests from a previously recorded HAR file (for example, one written by the
+/// `record` server option) instead of hitting the proxy target.
+///
+/// `replay: recordings/api.har` uses the defaults, or provide `{ file, mode, match_headers, match_body }`
+///
+#[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum ReplayOpts {
+    File(PathBuf),
+    Config(ReplayConfig),
+}
+
+#[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ReplayConfig {
+    pub file: PathBuf,
+    #[serde(default)]
+    pub mode: ReplayMode,
+    /// Request headers that must also be equal for a recorded entry to match
+    #[serde(default)]
+    pub match_headers: Vec<String>,
+    /// When true, the request body must also be equal for a recorded entry to match
+    #[serde(default)]
+    pub match_body: bool,
+}
+
+#[derive(
+    Debug, Default, Copy, PartialEq, Eq, Hash, Clone, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReplayMode {
+    /// Requests without a recorded response get a 404
+    #[default]
+    Strict,
+    /// Requests without a recorded response are sent to the proxy target
+    Passthrough,
+    /// Like `passthrough`, but the new responses are also appended to the file
+    RecordMissing,
+}
+
+impl ReplayOpts {
+    pub fn config(&self) -> ReplayConfig {
+        match self {
+            ReplayOpts::File(file) => ReplayConfig {
+                file: file.clone(),
+                mode: ReplayMode::default(),
+                match_headers: vec![],
+                match_body: false,
+            },
+            ReplayOpts::Config(config) => config.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod proxy_route_tests {
+    use super::*;
+
+    #[test]
+    fn test_timeouts_and_retry() {
+        let input = r#"
+- path: /
+  proxy: https://example.com
+  retry: 3
+- path: /
+  proxy: https://example.com
+  timeouts:
+    connect_ms: 500
+  retry:
+    count: 2
+    backoff_ms: 50
+"#;
+        let routes: Vec<Route> = serde_yaml::from_str(input).unwrap();
+        let proxies = routes
+            .iter()
+            .filter_map(|route| match &route.kind {
+                RouteKind::Proxy(proxy) => Some(proxy),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(proxies[0].timeouts, None);
+        assert_eq!(
+            proxies[0].retry(),
+            RetryConfig {
+                count: 3,
+                backoff_ms: 200
+            }
+        );
+        assert_eq!(
+            proxies[1].timeouts,
+            Some(ProxyTimeouts {
+                connect_ms: Some(500),
+                response_ms: None
+            })
+        );
+        assert_eq!(
+            proxies[1].retry(),
+            RetryConfig {
+                count: 2,
+                backoff_ms: 50
+            }
+        );
+    }
+
+    #[test]
+    fn test_replay_opts() {
+        let input = r#"
+- path: /api
+  proxy: https://example.com
+  replay: recordings/api.har
+- path: /api2
+  proxy: https://example.com
+  replay:
+    file: recordings/api2.har
+    mode: record-missing
+    match_headers: [authorization]
+"#;
+        let routes: Vec<Route> = serde_yaml::from_str(input).unwrap();
+        let configs = routes
+            .iter()
+            .filter_map(|route| match &route.kind {
+                RouteKind::Proxy(proxy) => proxy.replay.as_ref().map(ReplayOpts::config),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(configs[0].file, PathBuf::from("recordings/api.har"));
+        assert_eq!(configs[0].mode, ReplayMode::Strict);
+        assert_eq!(configs[1].mode, ReplayMode::RecordMissing);
+        assert_eq!(configs[1].match_headers, vec!["authorization".to_string()]);
+    }
+
+    #[test]
+    fn test_mirror_opts() {
+        let input = r#"
+- path: /
+  proxy: https://example.com
+  unstable_mirror: mirror
+- path: /
+  proxy: https://example.com
+  mirror:
+    dir: mirror
+    mime: [text/css, image/*]
+    offline: true
+"#;
+        let routes: Vec<Route> = serde_yaml::from_str(input).unwrap();
+        let configs = routes
+            .iter()
+            .filter_map(|route| match &route.kind {
+                RouteKind::Proxy(proxy) => proxy.mirror(),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(configs[0].dir, "mirror");
+        assert!(!configs[0].offline);
+        assert_eq!(configs[1].mime, vec!["text/css", "image/*"]);
+        assert!(configs[1].offline);
+    }
+
+    #[test]
+    fn test_mirror_globs_are_validated() {
+        let input = r#"
+path: /
+proxy: https://example.com
+mirror:
+  dir: mirror
+  glob: ["assets/**", "a[b"]
+"#;
+        let route: Route = serde_yaml::from_str(input).unwrap();
+        let err = route.validate_guards().unwrap_err();
+        assert!(matches!(err.kind, GuardValidationKind::InvalidGlob(..)));
+        assert_eq!(err.location(), "mirror.glob[1]");
     }
 }
 
@@ -237,10 +655,43 @@ struct Mirror {
 #[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
 #[serde(untagged)]
 pub enum RawRoute {
-    Html { html: String },
-    Json { json: JsonWrapper },
-    Raw { raw: String },
-    Sse { sse: SseOpts },
+    Html {
+        html: String,
+    },
+    Json {
+        json: JsonWrapper,
+    },
+    Raw {
+        raw: String,
+    },
+    Sse {
+        sse: SseOpts,
+    },
+    Redirect {
+        redirect: RedirectOpts,
+    },
+    NoContent {
+        #[serde(alias = "empty")]
+        no_content: bool,
+    },
+}
+
+///
+/// Send the browser somewhere else, eg: `redirect: { to: /login }`.
+///
+/// `status` defaults to `302`
+///
+#[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+pub struct RedirectOpts {
+    pub to: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+}
+
+impl RedirectOpts {
+    pub fn status(&self) -> u16 {
+        self.status.unwrap_or(302)
+    }
 }
 
 #[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
@@ -249,10 +700,71 @@ pub struct SseOpts {
     pub throttle_ms: Option<u64>,
 }
 
+///
+/// `cors: true` allows everything, or give the details:
+///
+/// ```yaml
+/// cors:
+///   origins: [http://localhost:5173, "https://*.example.com"]
+///   methods: [GET, POST]
+///   headers: [content-type, authorization]
+///   expose_headers: [x-request-id]
+///   credentials: true
+///   max_age: 600
+/// ```
+///
 #[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
 pub enum CorsOpts {
-    #[serde(rename = "cors")]
     Cors(bool),
+    Config(CorsConfig),
+}
+
+///
+/// Anything left out is permissive - although with `credentials: true`, origins, methods and
+/// headers are mirrored from the request instead of using `*`, since browsers reject that
+///
+#[derive(Debug, Default, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CorsConfig {
+    /// Exact origins, or patterns with `*` such as `https://*.example.com`
+    pub origins: Option<Vec<String>>,
+    pub methods: Option<Vec<String>>,
+    /// Request headers that are allowed
+    pub headers: Option<Vec<String>>,
+    /// Response headers that scripts are allowed to read
+    pub expose_headers: Option<Vec<String>>,
+    pub credentials: Option<bool>,
+    /// How long (in seconds) browsers can cache a preflight response for
+    pub max_age: Option<u64>,
+}
+
+///
+/// For the `--cors` flag: `true`/`false`, a comma-separated list of origins, or the full
+/// config as inline YAML, eg: `--cors='{origins: [http://localhost:5173], credentials: true}'`
+///
+impl FromStr for CorsOpts {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let next = s.trim();
+        if next.is_empty() {
+            return Err(anyhow::anyhow!("cors cannot be empty"));
+        }
+        if next.starts_with('{') || next == "true" || next == "false" {
+            return Ok(serde_yaml::from_str(next)?);
+        }
+        let origins = next
+            .split(',')
+            .map(str::trim)
+            .filter(|origin| !origin.is_empty())
+            .map(ToOwned::to_owned)
+            .collect();
+        Ok(CorsOpts::Config(CorsConfig {
+            origins: Some(origins),
+            ..Default::default()
+        }))
+    }
 }
 
 #[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
@@ -280,6 +792,99 @@ impl Default for CompressionOpts {
     }
 }
 
+///
+/// Simulate an unreliable network or server, to see how an app copes.
+///
+/// ```yaml
+/// chaos:
+///   error_rate: 0.1      # 10% of requests fail...
+///   error_status: 503    # ...with a 503 (defaults to 500)
+///   latency:             # wait 100-300ms (+/- 20ms) before responding
+///     min_ms: 100
+///     max_ms: 300
+///     jitter_ms: 20
+///   reset_rate: 0.05     # 5% of responses are cut off part-way through the body
+///   bytes_per_sec: 2048  # stream bodies at 2kb/s
+/// ```
+///
+#[derive(Debug, Default, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ChaosOpts {
+    pub error_rate: Option<f64>,
+    pub error_status: Option<u16>,
+    pub latency: Option<LatencyOpts>,
+    pub reset_rate: Option<f64>,
+    pub bytes_per_sec: Option<u64>,
+}
+
+impl Hash for ChaosOpts {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.error_rate.map(f64::to_bits).hash(state);
+        self.error_status.hash(state);
+        self.latency.hash(state);
+        self.reset_rate.map(f64::to_bits).hash(state);
+        self.bytes_per_sec.hash(state);
+    }
+}
+
+impl ChaosOpts {
+    /// Values that are set here take precedence over those in `other`
+    pub fn or(&self, other: &ChaosOpts) -> ChaosOpts {
+        ChaosOpts {
+            error_rate: self.error_rate.or(other.error_rate),
+            error_status: self.error_status.or(other.error_status),
+            latency: self.latency.or(other.latency),
+            reset_rate: self.reset_rate.or(other.reset_rate),
+            bytes_per_sec: self.bytes_per_sec.or(other.bytes_per_sec),
+        }
+    }
+    pub fn error_status(&self) -> u16 {
+        self.error_status.unwrap_or(500)
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Hash, Clone, Copy, serde::Deserialize, serde::Serialize)]
+pub struct LatencyOpts {
+    #[serde(default)]
+    pub min_ms: u64,
+    /// Defaults to `min_ms`
+    pub max_ms: Option<u64>,
+    /// Added or taken away from the chosen latency
+    pub jitter_ms: Option<u64>,
+}
+
+///
+/// Protect a server or route with HTTP basic auth, a token, or both (either one is enough).
+///
+/// ```yaml
+/// auth:
+///   basic:
+///     username: admin
+///     password: secret
+///   token: abc123
+/// ```
+///
+/// Tokens can be given with an `Authorization: Bearer <token>` header, or in the
+/// `bslive.token` query param - after which a cookie is set, so that links, assets and the
+/// websocket keep working
+///
+#[derive(Debug, Default, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+pub struct AuthOpts {
+    pub basic: Option<BasicAuth>,
+    pub token: Option<String>,
+}
+
+impl AuthOpts {
+    pub fn is_empty(&self) -> bool {
+        self.basic.is_none() && self.token.is_none()
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+pub struct BasicAuth {
+    pub username: String,
+    pub password: String,
+}
+
 #[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
 pub enum DelayOpts {
     #[serde(rename = "delay")]
@@ -26,18 +26,27 @@ impl TryInto<Route> for RouteCli {
                 dir, path, opts, ..
             } => Route {
                 path: PathDef::try_new(path)?,
-                kind: RouteKind::Dir(DirRoute { dir, base: None }),
+                kind: RouteKind::Dir(DirRoute {
+                    dir,
+                    base: None,
+                    spa: None,
+                    listing: None,
+                }),
                 opts: opts_to_route_opts(&opts),
                 ..std::default::Default::default()
             },
             SubCommands::Proxy { path, target, opts } => Route {
                 path: PathDef::try_new(path)?,
-                kind: RouteKind::Proxy(ProxyRoute {
+                kind: RouteKind::Proxy(Box::new(ProxyRoute {
                     proxy: target,
                     proxy_headers: None,
                     rewrite_uri: None,
-                    unstable_mirror: None,
-                }),
+                    mirror: None,
+                    replay: None,
+                    rewrite_links: None,
+                    timeouts: None,
+                    retry: None,
+                })),
                 opts: opts_to_route_opts(&opts),
                 ..std::default::Default::default()
             },
@@ -61,6 +61,8 @@ impl From<&Route> for RouteIdentity {
                     RawRoute::Json { .. } => "RouteKind::Raw::Json",
                     RawRoute::Raw { .. } => "RouteKind::Raw::Raw",
                     RawRoute::Sse { .. } => "RouteKind::Raw::Sse",
+                    RawRoute::Redirect { .. } => "RouteKind::Raw::Redirect",
+                    RawRoute::NoContent { .. } => "RouteKind::Raw::NoContent",
                 },
                 RouteKind::Proxy(_) => "RouteKind::Proxy",
                 RouteKind::Dir(_) => "RouteKind::Dir",
@@ -1,10 +1,12 @@
 use crate::client_config::ClientConfig;
 use crate::playground::Playground;
-use crate::route::{MultiWatch, Route};
+use crate::route::{AuthOpts, ChaosOpts, MultiWatch, Route};
 use crate::{rand_word, PortError};
 use serde::{de, Deserializer};
+use std::fmt::{Display, Formatter};
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::str::FromStr;
 
 #[derive(Debug, Default, Clone, serde::Deserialize, serde::Serialize)]
@@ -19,6 +21,16 @@ pub struct ServerConfig {
     pub playground: Option<Playground>,
     #[serde(default)]
     pub clients: ClientConfig,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record: Option<RecordConfig>,
+    /// Applied to every route, routes can override any part of it with their own `chaos`
+    #[serde(default, alias = "network", skip_serializing_if = "Option::is_none")]
+    pub chaos: Option<ChaosOpts>,
+    /// Protects every route, plus the built-in ones such as `/__bs_api` and the websocket
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<AuthOpts>,
 }
 
 impl ServerConfig {
@@ -27,7 +39,7 @@ impl ServerConfig {
     ///
     pub fn combined_routes(&self) -> Vec<Route> {
         let routes = self.routes.clone();
-        match &self.playground {
+        let mut combined = match &self.playground {
             None => self.routes.clone(),
             Some(playground) => match playground.as_routes() {
                 Ok(mut pg_routes) => {
@@ -36,7 +48,14 @@ impl ServerConfig {
                 }
                 Err(_) => routes,
             },
+        };
+        if let Some(chaos) = &self.chaos {
+            for route in combined.iter_mut() {
+                let from_route = route.opts.chaos.take().unwrap_or_default();
+                route.opts.chaos = Some(from_route.or(chaos));
+            }
         }
+        combined
     }
     pub fn raw_routes(&self) -> &[Route] {
         &self.routes
@@ -49,6 +68,72 @@ impl ServerConfig {
             ..std::default::Default::default()
         }
     }
+
+    pub fn scheme(&self) -> Scheme {
+        match &self.tls {
+            None | Some(TlsConfig::Enabled(false)) => Scheme::Http,
+            Some(_) => Scheme::Https,
+        }
+    }
+}
+
+///
+/// `tls: true` will generate a self-signed certificate (cached on disk, so that browsers
+/// only need to trust it once), or provide your own with `tls: { cert: .., key: .. }`
+///
+#[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum TlsConfig {
+    Enabled(bool),
+    Files { cert: PathBuf, key: PathBuf },
+}
+
+///
+/// `record: true` keeps every request/response pair in memory, viewable at `/__bs_api/requests`.
+/// Use `record: { har: traffic.har }` to also write them as a HAR file when the server stops
+///
+#[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum RecordConfig {
+    Enabled(bool),
+    Har { har: PathBuf },
+}
+
+impl RecordConfig {
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self, RecordConfig::Enabled(false))
+    }
+    pub fn har_path(&self) -> Option<&PathBuf> {
+        match self {
+            RecordConfig::Enabled(..) => None,
+            RecordConfig::Har { har } => Some(har),
+        }
+    }
+}
+
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Scheme {
+    #[default]
+    Http,
+    Https,
+}
+
+impl Scheme {
+    pub fn url(&self, socket_addr: &SocketAddr) -> String {
+        format!("{self}://{socket_addr}")
+    }
+}
+
+impl Display for Scheme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Scheme::Http => write!(f, "http"),
+            Scheme::Https => write!(f, "https"),
+        }
+    }
 }
 
 #[derive(
@@ -101,6 +186,14 @@ where
             }
         }
 
+        fn visit_i64<E>(self, value: i64) -> Result<u16, E>
+        where
+            E: de::Error,
+        {
+            u16::try_from(value)
+                .map_err(|_| E::custom(format!("port number out of range: {value}")))
+        }
+
         fn visit_str<E>(self, value: &str) -> Result<u16, E>
         where
             E: de::Error,
@@ -207,3 +300,63 @@ servers:
     let second_expected = ServerIdentity::Port { port: 3001 };
     assert_eq!(second.identity, second_expected);
 }
+
+#[test]
+fn with_tls() {
+    #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+    struct C {
+        servers: Vec<ServerConfig>,
+    }
+    let input = r#"
+servers:
+    - port: 3000
+    - port: 3001
+      tls: true
+    - port: 3002
+      tls:
+        cert: certs/cert.pem
+        key: certs/key.pem
+    "#;
+    let c: C = serde_yaml::from_str(input).unwrap();
+    let schemes = c.servers.iter().map(|s| s.scheme()).collect::<Vec<_>>();
+    assert_eq!(schemes, vec![Scheme::Http, Scheme::Https, Scheme::Https]);
+
+    let third = c.servers.get(2).unwrap();
+    assert_eq!(
+        third.tls,
+        Some(TlsConfig::Files {
+            cert: PathBuf::from("certs/cert.pem"),
+            key: PathBuf::from("certs/key.pem"),
+        })
+    );
+}
+
+#[test]
+fn with_record() {
+    #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+    struct C {
+        servers: Vec<ServerConfig>,
+    }
+    let input = r#"
+servers:
+    - port: 3000
+    - port: 3001
+      record: true
+    - port: 3002
+      record:
+        har: traffic.har
+    "#;
+    let c: C = serde_yaml::from_str(input).unwrap();
+    let enabled = c
+        .servers
+        .iter()
+        .map(|s| s.record.as_ref().is_some_and(RecordConfig::is_enabled))
+        .collect::<Vec<_>>();
+    assert_eq!(enabled, vec![false, true, true]);
+
+    let third = c.servers.get(2).unwrap();
+    assert_eq!(
+        third.record.as_ref().and_then(RecordConfig::har_path),
+        Some(&PathBuf::from("traffic.har"))
+    );
+}
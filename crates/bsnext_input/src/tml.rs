@@ -0,0 +1,79 @@
+#[derive(Debug, thiserror::Error)]
+pub enum TomlError {
+    #[error(
+        r#"
+could not parse toml file:
+  {path}
+
+input content was:
+{input}
+
+original error:
+
+  {serde_error}
+       "#
+    )]
+    ParseError {
+        path: String,
+        input: String,
+        serde_error: toml::de::Error,
+    },
+    #[error(
+        r#"
+could not parse raw toml, input content was:
+{input}
+
+(error at line: {line}, column: {column})
+
+original error:
+
+  {serde_error}
+       "#
+    )]
+    ParseRawInputErrorWithLocation {
+        input: String,
+        serde_error: toml::de::Error,
+        line: usize,
+        column: usize,
+    },
+    #[error(
+        r#"
+could not parse raw toml, input content was:
+{input}
+
+original error:
+
+  {serde_error}
+       "#
+    )]
+    ParseRawInputError {
+        input: String,
+        serde_error: toml::de::Error,
+    },
+    #[error("Input file was empty: {path}")]
+    EmptyError { path: String },
+    #[error("could not write toml: {serde_error}")]
+    SerializeError { serde_error: toml::ser::Error },
+}
+
+///
+/// `toml` reports errors as a byte range, this converts the start of it
+/// into a 1-based line + column, to match what `serde_yaml` gives us
+///
+pub fn line_and_column(input: &str, index: usize) -> (usize, usize) {
+    let before = &input[..index.min(input.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rfind('\n')
+        .map(|nl| before.len() - nl)
+        .unwrap_or(before.len() + 1);
+    (line, column)
+}
+
+#[test]
+fn test_line_and_column() {
+    let input = "a = 1\nb = 2\nc = oops";
+    assert_eq!(line_and_column(input, 0), (1, 1));
+    assert_eq!(line_and_column(input, 6), (2, 1));
+    assert_eq!(line_and_column(input, 16), (3, 5));
+}
@@ -1,3 +1,6 @@
+use crate::json_path;
+use crate::json_path::JsonPathError;
+
 #[derive(Debug, Default, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
 #[serde(untagged)]
 pub enum WhenGuard {
@@ -13,6 +16,41 @@ pub enum WhenGuard {
     Accept {
         accept: HasGuard,
     },
+    Method {
+        method: HasGuard,
+    },
+    Header {
+        header: NamedHasGuard,
+    },
+    Cookie {
+        cookie: NamedHasGuard,
+    },
+    Param {
+        param: NamedHasGuard,
+    },
+    /// Passes when at least one of the nested guards passes
+    Any {
+        any: Vec<WhenGuard>,
+    },
+    /// Passes when every nested guard passes
+    All {
+        all: Vec<WhenGuard>,
+    },
+    /// Passes when the nested guard doesn't
+    Not {
+        not: Box<WhenGuard>,
+    },
+}
+
+impl WhenGuard {
+    pub fn validate(&self) -> Result<(), GuardValidationError> {
+        match self {
+            WhenGuard::Any { any } => validate_list("any", any, WhenGuard::validate),
+            WhenGuard::All { all } => validate_list("all", all, WhenGuard::validate),
+            WhenGuard::Not { not } => not.validate().map_err(|e| e.within_key("not")),
+            _ => Ok(()),
+        }
+    }
 }
 
 #[derive(Debug, Default, PartialEq, Hash, Clone, serde::Serialize, serde::Deserialize)]
@@ -23,6 +61,125 @@ pub enum WhenBodyGuard {
     Json {
         json: JsonGuard,
     },
+    /// A field from an `application/x-www-form-urlencoded` body
+    Form {
+        form: NamedHasGuard,
+    },
+    /// A field from a `multipart/form-data` body. For file uploads, the filename is checked
+    Multipart {
+        multipart: NamedHasGuard,
+    },
+    Any {
+        any: Vec<WhenBodyGuard>,
+    },
+    All {
+        all: Vec<WhenBodyGuard>,
+    },
+    Not {
+        not: Box<WhenBodyGuard>,
+    },
+}
+
+impl WhenBodyGuard {
+    /// Whether the request body has to be read to check this guard
+    pub fn needs_body(&self) -> bool {
+        match self {
+            WhenBodyGuard::Never => false,
+            WhenBodyGuard::Json { .. } => true,
+            WhenBodyGuard::Form { .. } => true,
+            WhenBodyGuard::Multipart { .. } => true,
+            WhenBodyGuard::Any { any: items } | WhenBodyGuard::All { all: items } => {
+                items.iter().any(WhenBodyGuard::needs_body)
+            }
+            WhenBodyGuard::Not { not } => not.needs_body(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), GuardValidationError> {
+        match self {
+            WhenBodyGuard::Json { json } => json.validate().map_err(|e| e.within_key("json")),
+            WhenBodyGuard::Any { any } => validate_list("any", any, WhenBodyGuard::validate),
+            WhenBodyGuard::All { all } => validate_list("all", all, WhenBodyGuard::validate),
+            WhenBodyGuard::Not { not } => not.validate().map_err(|e| e.within_key("not")),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A single step into the input, used to point back at where a guard was defined
+#[derive(Debug, PartialEq, Clone)]
+pub enum InputPathSegment {
+    Key(&'static str),
+    Index(usize),
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum GuardValidationKind {
+    #[error("`{0}` must contain at least one guard")]
+    EmptyList(&'static str),
+    #[error("invalid selector `{0}`: {1}")]
+    InvalidSelector(String, JsonPathError),
+    #[error("invalid regex `{0}`: {1}")]
+    InvalidRegex(String, String),
+    #[error("invalid glob `{0}`: {1}")]
+    InvalidGlob(String, String),
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[error("{kind}")]
+pub struct GuardValidationError {
+    /// From the root of the input, down to the offending value
+    pub path: Vec<InputPathSegment>,
+    pub kind: GuardValidationKind,
+}
+
+impl GuardValidationError {
+    pub(crate) fn new(kind: GuardValidationKind) -> Self {
+        Self { path: vec![], kind }
+    }
+
+    pub fn within_key(self, key: &'static str) -> Self {
+        self.within(InputPathSegment::Key(key))
+    }
+
+    pub fn within_index(self, index: usize) -> Self {
+        self.within(InputPathSegment::Index(index))
+    }
+
+    /// The path as written in a config file, eg: `servers[0].routes[1].when.any`
+    pub fn location(&self) -> String {
+        let mut location = String::new();
+        for segment in &self.path {
+            match segment {
+                InputPathSegment::Key(key) if location.is_empty() => location.push_str(key),
+                InputPathSegment::Key(key) => {
+                    location.push('.');
+                    location.push_str(key);
+                }
+                InputPathSegment::Index(index) => location.push_str(&format!("[{index}]")),
+            }
+        }
+        location
+    }
+
+    fn within(mut self, segment: InputPathSegment) -> Self {
+        self.path.insert(0, segment);
+        self
+    }
+}
+
+fn validate_list<T>(
+    key: &'static str,
+    items: &[T],
+    validate: impl Fn(&T) -> Result<(), GuardValidationError>,
+) -> Result<(), GuardValidationError> {
+    if items.is_empty() {
+        return Err(GuardValidationError::new(GuardValidationKind::EmptyList(key)).within_key(key));
+    }
+    for (index, item) in items.iter().enumerate() {
+        validate(item).map_err(|e| e.within_index(index).within_key(key))?;
+    }
+    Ok(())
 }
 
 #[derive(Debug, PartialEq, Hash, Clone, serde::Serialize, serde::Deserialize)]
@@ -43,11 +200,52 @@ pub enum JsonGuard {
     Path(JsonPropGuard),
 }
 
+impl JsonGuard {
+    pub fn validate(&self) -> Result<(), GuardValidationError> {
+        let (items, key, props) = match self {
+            JsonGuard::ArrayLast { items, last } => (items, "last", last),
+            JsonGuard::ArrayAny { items, any } => (items, "any", any),
+            JsonGuard::ArrayAll { items, all } => (items, "all", all),
+            JsonGuard::Path(prop) => return prop.validate(),
+        };
+        validate_selector(items).map_err(|e| e.within_key("items"))?;
+        for (index, prop) in props.iter().enumerate() {
+            prop.validate()
+                .map_err(|e| e.within_index(index).within_key(key))?;
+        }
+        Ok(())
+    }
+}
+
+///
+/// `path` is either a JSON pointer, like `/user/name`, or a JSONPath-style selector,
+/// like `$.user.name` - see [`crate::json_path`]. When a selector reaches more than
+/// one value, the guard passes if any of them match
+///
 #[derive(Debug, PartialEq, Hash, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(untagged)]
 pub enum JsonPropGuard {
-    PathIs { path: String, is: String },
-    PathHas { path: String, has: String },
+    /// Exact match on strings, numbers and booleans
+    PathIs {
+        path: String,
+        is: String,
+    },
+    PathHas {
+        path: String,
+        has: String,
+    },
+    PathExists {
+        path: String,
+        exists: bool,
+    },
+    PathMatches {
+        path: String,
+        matches: Pattern,
+    },
+    PathNumber {
+        path: String,
+        number: NumberGuard,
+    },
 }
 
 impl JsonPropGuard {
@@ -55,8 +253,116 @@ impl JsonPropGuard {
         match self {
             JsonPropGuard::PathIs { path, .. } => path,
             JsonPropGuard::PathHas { path, .. } => path,
+            JsonPropGuard::PathExists { path, .. } => path,
+            JsonPropGuard::PathMatches { path, .. } => path,
+            JsonPropGuard::PathNumber { path, .. } => path,
         }
     }
+
+    pub fn validate(&self) -> Result<(), GuardValidationError> {
+        validate_selector(self.path()).map_err(|e| e.within_key("path"))?;
+        if let JsonPropGuard::PathMatches { matches, .. } = self {
+            matches.regex().map_err(|e| {
+                GuardValidationError::new(GuardValidationKind::InvalidRegex(
+                    matches.as_str().to_string(),
+                    e.to_string(),
+                ))
+                .within_key("matches")
+            })?;
+        }
+        Ok(())
+    }
+}
+
+///
+/// A regex from the input, compiled once when it's read. It's compared, hashed and
+/// written back out as the original source
+///
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(from = "String", into = "String")]
+pub struct Pattern {
+    source: String,
+    compiled: Result<regex::Regex, regex::Error>,
+}
+
+impl Pattern {
+    pub fn new(source: impl Into<String>) -> Self {
+        let source = source.into();
+        let compiled = regex::Regex::new(&source);
+        Self { source, compiled }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Invalid patterns are reported when the input is validated
+    pub fn regex(&self) -> Result<&regex::Regex, &regex::Error> {
+        self.compiled.as_ref()
+    }
+}
+
+impl From<String> for Pattern {
+    fn from(source: String) -> Self {
+        Self::new(source)
+    }
+}
+
+impl From<Pattern> for String {
+    fn from(pattern: Pattern) -> Self {
+        pattern.source
+    }
+}
+
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl std::hash::Hash for Pattern {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.source.hash(state)
+    }
+}
+
+/// Every bound that's given must hold
+#[derive(Debug, Default, PartialEq, Hash, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NumberGuard {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<serde_json::Number>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gt: Option<serde_json::Number>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gte: Option<serde_json::Number>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lt: Option<serde_json::Number>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lte: Option<serde_json::Number>,
+}
+
+impl NumberGuard {
+    pub fn test(&self, value: f64) -> bool {
+        let bound = |b: &Option<serde_json::Number>| b.as_ref().and_then(|n| n.as_f64());
+        bound(&self.eq).is_none_or(|b| value == b)
+            && bound(&self.gt).is_none_or(|b| value > b)
+            && bound(&self.gte).is_none_or(|b| value >= b)
+            && bound(&self.lt).is_none_or(|b| value < b)
+            && bound(&self.lte).is_none_or(|b| value <= b)
+    }
+}
+
+fn validate_selector(selector: &str) -> Result<(), GuardValidationError> {
+    if selector.is_empty() || selector.starts_with('/') {
+        return Ok(());
+    }
+    json_path::parse(selector).map(|_| ()).map_err(|e| {
+        GuardValidationError::new(GuardValidationKind::InvalidSelector(
+            selector.to_string(),
+            e,
+        ))
+    })
 }
 
 #[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
@@ -75,6 +381,33 @@ pub enum HasGuard {
     },
 }
 
+impl HasGuard {
+    pub fn test(&self, value: &str) -> bool {
+        match self {
+            HasGuard::Literal(is) | HasGuard::Is { is } => is == value,
+            HasGuard::Has { has } => value.contains(has),
+            HasGuard::NotHas { not_has } => !value.contains(not_has),
+        }
+    }
+}
+
+///
+/// A [`HasGuard`] applied to a single named value, like a header, cookie or path param.
+///
+/// ```yaml
+/// when:
+///   header:
+///     name: authorization
+///     has: Bearer
+/// ```
+///
+#[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+pub struct NamedHasGuard {
+    pub name: String,
+    #[serde(flatten)]
+    pub guard: HasGuard,
+}
+
 #[test]
 fn test_when_guard() {
     use insta::assert_debug_snapshot;
@@ -106,3 +439,120 @@ fn test_when_guard_has() {
     let when: A = serde_yaml::from_str(input).expect("test");
     assert_debug_snapshot!(&when);
 }
+
+#[test]
+fn test_when_guard_method() {
+    use insta::assert_debug_snapshot;
+    #[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+    struct A {
+        when: Vec<WhenGuard>,
+    }
+    let input = r#"
+    when:
+      - method: DELETE
+      - method:
+          is: GET
+    "#;
+    let when: A = serde_yaml::from_str(input).expect("test");
+    assert_debug_snapshot!(&when);
+}
+
+#[test]
+fn test_when_guard_named() {
+    use insta::assert_debug_snapshot;
+    #[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+    struct A {
+        when: Vec<WhenGuard>,
+    }
+    let input = r#"
+    when:
+      - header:
+          name: authorization
+          has: Bearer
+      - cookie:
+          name: session
+          is: abc
+      - param:
+          name: id
+          not.has: admin
+    "#;
+    let when: A = serde_yaml::from_str(input).expect("test");
+    assert_debug_snapshot!(&when);
+}
+
+#[test]
+fn test_when_guard_combinators() {
+    use insta::assert_debug_snapshot;
+    #[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+    struct A {
+        when: WhenGuard,
+    }
+    let input = r#"
+    when:
+      any:
+        - method: GET
+        - all:
+            - method: POST
+            - not:
+                query:
+                  has: "dry-run"
+    "#;
+    let when: A = serde_yaml::from_str(input).expect("test");
+    assert_debug_snapshot!(&when);
+}
+
+#[test]
+fn test_when_guard_validate() {
+    let input = r#"
+    any:
+      - method: GET
+      - not:
+          all: []
+    "#;
+    let when: WhenGuard = serde_yaml::from_str(input).expect("test");
+    let err = when.validate().unwrap_err();
+    assert_eq!(err.kind, GuardValidationKind::EmptyList("all"));
+    assert_eq!(
+        err.path,
+        vec![
+            InputPathSegment::Key("any"),
+            InputPathSegment::Index(1),
+            InputPathSegment::Key("not"),
+            InputPathSegment::Key("all"),
+        ]
+    );
+}
+
+#[test]
+fn test_when_body_guard_validate() {
+    let input = r#"
+    json:
+      items: $.users
+      any:
+        - path: $.name
+          is: kittie
+        - path: $.name
+          matches: "(unclosed"
+    "#;
+    let when: WhenBodyGuard = serde_yaml::from_str(input).expect("test");
+    let err = when.validate().unwrap_err();
+    assert!(matches!(err.kind, GuardValidationKind::InvalidRegex(..)));
+    assert_eq!(
+        err.path,
+        vec![
+            InputPathSegment::Key("json"),
+            InputPathSegment::Key("any"),
+            InputPathSegment::Index(1),
+            InputPathSegment::Key("matches"),
+        ]
+    );
+
+    let input = r#"
+    json:
+      path: $.user[name]
+      exists: true
+    "#;
+    let when: WhenBodyGuard = serde_yaml::from_str(input).expect("test");
+    let err = when.validate().unwrap_err();
+    assert!(matches!(err.kind, GuardValidationKind::InvalidSelector(..)));
+}
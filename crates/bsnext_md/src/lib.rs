@@ -78,14 +78,16 @@ fn pair_to_route((first, second): (&Node, &Node)) -> Result<Route, anyhow::Error
                 #[derive(serde::Deserialize)]
                 struct PathOnly {
                     path: String,
+                    status: Option<u16>,
                 }
                 let r: PathOnly = serde_yaml::from_str(&code.value)?;
                 let route_kind = route_kind_from_body_node(second)?;
-                let route = Route {
+                let mut route = Route {
                     path: PathDef::from_str(&r.path)?,
                     kind: route_kind,
                     ..Default::default()
                 };
+                route.opts.status = r.status;
                 Ok(route)
             }
             _ => Err(anyhow::anyhow!("unreachlable")),
@@ -94,6 +96,14 @@ fn pair_to_route((first, second): (&Node, &Node)) -> Result<Route, anyhow::Error
     }
 }
 
+/// Routes without a body, like redirects, are given in full by the yaml block
+fn node_to_route(node: &Node) -> Result<Route, anyhow::Error> {
+    match node {
+        Node::Code(code) => Ok(serde_yaml::from_str(&code.value)?),
+        _ => Err(anyhow::anyhow!("unreachable")),
+    }
+}
+
 fn route_kind_from_body_node(node: &Node) -> anyhow::Result<RouteKind> {
     match node {
         Node::Code(code) => {
@@ -253,6 +263,13 @@ pub fn nodes_to_input(nodes: &[Node], ctx: &InputCtx) -> Result<Input, MarkdownE
                 }
             },
         ),
+        map(
+            parser_for(BsLiveKinds::Route),
+            |v: &Node| match node_to_route(v) {
+                Ok(route) => Convert::Route(Box::new(route)),
+                Err(_e) => Convert::None,
+            },
+        ),
         map(
             pair(
                 parser_for(BsLiveKinds::PlaygroundHtml),
@@ -10,12 +10,12 @@ impl InputCreation for MdFs {
         let str = read_to_string(path).map_err(|e| Box::new(e.into()))?;
         let input = md_to_input(&str, ctx)
             .map_err(|e| Box::new(InputError::MarkdownError(e.to_string())))?;
-        Ok(input)
+        input.validated()
     }
     fn from_input_str<P: AsRef<str>>(content: P, ctx: &InputCtx) -> Result<Input, Box<InputError>> {
         let input = md_to_input(content.as_ref(), ctx)
             .map_err(|e| Box::new(InputError::MarkdownError(e.to_string())))?;
-        Ok(input)
+        input.validated()
     }
 }
 
@@ -1,14 +1,14 @@
 use bsnext_input::route::{RawRoute, RouteKind};
 use bsnext_input::server_config::ServerIdentity;
-use bsnext_input::{Input, InputWriter};
+use bsnext_input::{Input, InputError, InputWriter};
 use mime_guess::get_mime_extensions_str;
 use serde_json::json;
 
 pub struct MdWriter;
 
 impl InputWriter for MdWriter {
-    fn input_to_str(&self, input: &Input) -> String {
-        _input_to_str(input)
+    fn input_to_str(&self, input: &Input) -> Result<String, Box<InputError>> {
+        Ok(_input_to_str(input))
     }
 }
 
@@ -44,10 +44,25 @@ fn _input_to_str(input: &Input) -> String {
         }
 
         for route in server_config.raw_routes() {
-            let path_only = json!({"path": route.path.as_str()});
-            let route_yaml = serde_yaml::to_string(&path_only).expect("never fail here on route?");
+            let body = route_to_markdown(&route.kind, route.path.as_str());
+            let mut route_def = json!({"path": route.path.as_str()});
+            if let Some(status) = route.opts.status {
+                route_def["status"] = json!(status);
+            }
+            // routes without a body, like redirects, are described entirely by the yaml block
+            if body.is_none() {
+                if let Ok(serde_json::Value::Object(kind)) = serde_json::to_value(&route.kind) {
+                    route_def
+                        .as_object_mut()
+                        .expect("always an object")
+                        .extend(kind);
+                }
+            }
+            let route_yaml = serde_yaml::to_string(&route_def).expect("never fail here on route?");
             chunks.push(fenced_route(&route_yaml));
-            chunks.push(route_to_markdown(&route.kind, route.path.as_str()));
+            if let Some(body) = body {
+                chunks.push(body);
+            }
         }
     }
     for _x in input.servers.iter().skip(1) {
@@ -56,8 +71,8 @@ fn _input_to_str(input: &Input) -> String {
     chunks.join("\n")
 }
 
-fn route_to_markdown(kind: &RouteKind, path: &str) -> String {
-    match kind {
+fn route_to_markdown(kind: &RouteKind, path: &str) -> Option<String> {
+    let body = match kind {
         RouteKind::Raw(raw) => match raw {
             RawRoute::Html { html } => fenced_body("html", html),
             RawRoute::Json { .. } => todo!("unsupported json"),
@@ -72,10 +87,12 @@ fn route_to_markdown(kind: &RouteKind, path: &str) -> String {
                 }
             }
             RawRoute::Sse { .. } => todo!("unsupported"),
+            RawRoute::Redirect { .. } | RawRoute::NoContent { .. } => return None,
         },
         RouteKind::Proxy(_) => todo!("unsupported"),
         RouteKind::Dir(_) => todo!("unsupported"),
-    }
+    };
+    Some(body)
 }
 
 fn fenced_input(code: &str) -> String {
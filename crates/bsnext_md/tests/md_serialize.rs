@@ -6,7 +6,7 @@ use bsnext_md::md_writer::MdWriter;
 fn test_input_to_str() -> anyhow::Result<()> {
     let input_str = include_str!("../../../examples/markdown/single.md");
     let input = MdFs::from_input_str(&input_str, &Default::default()).expect("unwrap");
-    let output = MdWriter.input_to_str(&input);
+    let output = MdWriter.input_to_str(&input).expect("to md");
     println!("{}", output);
     let input = MdFs::from_input_str(&output, &Default::default()).expect("unwrapped 2");
     println!("{:?}", input);
@@ -14,3 +14,37 @@ fn test_input_to_str() -> anyhow::Result<()> {
     assert_eq!(input.servers.first().unwrap().routes.len(), 2);
     Ok(())
 }
+
+#[test]
+fn test_bodyless_routes_to_str() -> anyhow::Result<()> {
+    let input_str = r#"
+```yaml bslive_route
+path: /old
+redirect:
+  to: /new
+  status: 301
+```
+
+```yaml bslive_route
+path: /ping
+no_content: true
+```
+
+```yaml bslive_route
+path: /teapot
+status: 418
+```
+
+```html
+<p>short and stout</p>
+```
+"#;
+    let input = MdFs::from_input_str(input_str, &Default::default()).expect("unwrap");
+    let output = MdWriter.input_to_str(&input).expect("to md");
+    let round_trip = MdFs::from_input_str(&output, &Default::default()).expect("unwrapped 2");
+    let routes = &round_trip.servers.first().unwrap().routes;
+    assert_eq!(routes, &input.servers.first().unwrap().routes);
+    assert_eq!(routes.len(), 3);
+    assert_eq!(routes[2].opts.status, Some(418));
+    Ok(())
+}
@@ -1,9 +1,8 @@
-// pub mod ratatui;
-
 use std::fmt::{Display, Formatter};
 use std::io::Write;
 use stdout::StdoutTarget;
 
+pub mod ratatui;
 pub mod stdout;
 
 #[derive(Debug, Default)]
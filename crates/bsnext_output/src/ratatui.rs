@@ -1,37 +1,80 @@
 use self::common::{init_terminal, install_hooks, restore_terminal, Tui};
-// use bsnext_dto::GetServersMessageResponseDTO;
+use ansi_to_tui::IntoText;
+use std::collections::VecDeque;
 use std::sync::mpsc;
 use std::sync::mpsc::Sender;
 use std::thread::JoinHandle;
 use std::{
     io::{self},
     thread,
-    time::{Duration, Instant},
+    time::Duration,
 };
 
-// use bsnext_dto::internal::{AnyEvent, InternalEvents};
 use ratatui::{
     buffer::Buffer,
-    crossterm::event::{self, Event},
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
     layout::{Constraint, Layout, Rect},
-    style::Stylize,
-    text::Line,
+    style::{Style, Stylize},
+    text::{Line, Span},
     widgets::{Block, Paragraph, Widget, Wrap},
 };
+use tokio::sync::mpsc::UnboundedSender;
+
+/// How many lines of history are kept for the event log and for each task output pane
+const HISTORY: usize = 500;
 
 pub struct Ratatui(App);
 
+///
+/// Handle used to push events into the dashboard from any thread
+///
+#[derive(Debug, Clone)]
 pub struct RatatuiSender(Sender<RatatuiEvent>);
 
+impl RatatuiSender {
+    pub fn send(&self, evt: DashboardEvent) -> anyhow::Result<()> {
+        self.0
+            .send(RatatuiEvent::Evt(evt))
+            .map_err(|_| anyhow::anyhow!("dashboard is closed"))
+    }
+}
+
+///
+/// Everything the dashboard can display. Producers are expected to convert their
+/// own event types into these, which keeps this crate free of any domain types.
+///
+#[derive(Debug, Clone)]
+pub enum DashboardEvent {
+    /// Replaces the contents of the servers pane, one line per server
+    ServersChanged(Vec<String>),
+    /// A single (possibly multi-line) entry for the event log
+    Log(String),
+    /// A line of output from a running task, grouped by its `node_path`
+    TaskOutput { node_path: String, line: String },
+    /// Replaces the contents of the task tree pane
+    TaskTree(String),
+}
+
+///
+/// Actions requested by the user via keybindings
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum DashboardCommand {
+    /// Trigger a `run` entry by name
+    Run(String),
+    RestartServers,
+    Quit,
+}
+
 impl Ratatui {
-    pub fn try_new() -> anyhow::Result<Self> {
-        let app = App::new();
+    pub fn try_new(commands: UnboundedSender<DashboardCommand>) -> anyhow::Result<Self> {
+        let app = App::new(commands);
         Ok(Ratatui(app))
     }
 
     pub fn install(self) -> anyhow::Result<(RatatuiSender, JoinHandle<()>, JoinHandle<()>)> {
         tracing::debug!("TUI: installing ratatui hooks");
-        install_hooks()?;
+        install_hooks();
         let mut terminal = init_terminal()?;
         tracing::debug!("TUI: init... terminal");
         let mut app = self.0;
@@ -41,245 +84,317 @@ impl Ratatui {
             sender,
             thread::spawn(move || {
                 tracing::debug!("TUI: on new thread... terminal");
-                app.run(&mut terminal, rx).expect("running");
+                if let Err(e) = app.run(&mut terminal, rx) {
+                    tracing::error!(?e, "TUI: exited with an error");
+                }
+                let _ = app.commands.send(DashboardCommand::Quit);
                 tracing::debug!("TUI: tui all done");
                 restore_terminal().expect("restore");
                 tracing::debug!("TUI: terminal restored");
             }),
-            input_handling(tx.clone()),
+            input_handling(tx),
         ))
     }
 }
 
-fn input_handling(tx: mpsc::Sender<RatatuiEvent>) -> JoinHandle<()> {
-    let tick_rate = Duration::from_millis(500);
-    thread::spawn(move || {
-        let last_tick = Instant::now();
-        loop {
-            // poll for tick rate duration, if no events, sent tick event.
-            let timeout = tick_rate.saturating_sub(last_tick.elapsed());
-            if event::poll(timeout).is_ok_and(|r| r) {
-                let evt = match crossterm::event::read() {
-                    Ok(evt) => match evt {
-                        Event::FocusGained => None,
-                        Event::FocusLost => None,
-                        Event::Key(key) => Some(RatatuiEvent::Input(key)),
-                        Event::Mouse(_) => None,
-                        Event::Paste(_) => None,
-                        Event::Resize(_, _) => Some(RatatuiEvent::Resize),
-                    },
-                    Err(_) => None,
-                };
-                if let Some(evt) = evt {
-                    match tx.send(evt) {
-                        Ok(_) => {}
-                        Err(_) => tracing::error!("couldn't send"),
-                    };
-                }
+fn input_handling(tx: Sender<RatatuiEvent>) -> JoinHandle<()> {
+    let poll_rate = Duration::from_millis(250);
+    thread::spawn(move || loop {
+        if !event::poll(poll_rate).is_ok_and(|r| r) {
+            continue;
+        }
+        let evt = match event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => {
+                Some(RatatuiEvent::Input(key))
+            }
+            Ok(Event::Resize(_, _)) => Some(RatatuiEvent::Resize),
+            Ok(_) => None,
+            Err(_) => None,
+        };
+        if let Some(evt) = evt {
+            if tx.send(evt).is_err() {
+                tracing::debug!("TUI: dashboard closed, stopping input handling");
+                break;
             }
-            // if last_tick.elapsed() >= tick_rate {
-            //     match tx.send(RatatuiEvent::Tick) {
-            //         Ok(_) => {}
-            //         Err(_) => tracing::error!("couldn't send"),
-            //     };
-            //     last_tick = Instant::now();
-            // }
         }
     })
 }
 
 #[derive(Debug)]
-struct App {
-    should_exit: bool,
-    scroll: u16,
-    last_tick: Instant,
-    events: FixedSizeQueue,
-    server_status: Option<GetServersMessageResponseDTO>,
-}
-
 enum RatatuiEvent {
-    Input(crossterm::event::KeyEvent),
-    #[allow(dead_code)]
-    Tick,
+    Input(KeyEvent),
     Resize,
-    Evt(AnyEvent),
+    Evt(DashboardEvent),
 }
 
-impl App {
-    /// The duration between each tick.
-    const TICK_RATE: Duration = Duration::from_millis(250);
+#[derive(Debug, Default, PartialEq)]
+enum Mode {
+    #[default]
+    Normal,
+    /// Collecting the name of a `run` entry
+    RunPrompt(String),
+}
+
+#[derive(Debug)]
+struct TaskOutput {
+    node_path: String,
+    lines: FixedSizeQueue<String>,
+}
+
+#[derive(Debug)]
+struct App {
+    should_exit: bool,
+    mode: Mode,
+    commands: UnboundedSender<DashboardCommand>,
+    servers: Vec<String>,
+    events: FixedSizeQueue<RecordedEvent>,
+    events_scroll: u16,
+    task_outputs: Vec<TaskOutput>,
+    selected_output: usize,
+    task_tree: Option<String>,
+}
 
+impl App {
     /// Create a new instance of the app.
-    fn new() -> Self {
+    fn new(commands: UnboundedSender<DashboardCommand>) -> Self {
         Self {
             should_exit: false,
-            scroll: 0,
-            last_tick: Instant::now(),
-            events: FixedSizeQueue::new(10),
-            server_status: None,
+            mode: Mode::default(),
+            commands,
+            servers: vec![],
+            events: FixedSizeQueue::new(HISTORY),
+            events_scroll: 0,
+            task_outputs: vec![],
+            selected_output: 0,
+            task_tree: None,
         }
     }
 
     /// Run the app until the user exits.
     fn run(&mut self, terminal: &mut Tui, rx: mpsc::Receiver<RatatuiEvent>) -> anyhow::Result<()> {
-        let mut redraw = true;
-        loop {
-            if self.should_exit {
-                break;
-            }
-            if redraw {
-                tracing::info!("drawing...");
-                self.draw(terminal)?;
-            }
-            redraw = true;
-
+        while !self.should_exit {
+            self.draw(terminal)?;
             match rx.recv()? {
-                RatatuiEvent::Input(event) => {
-                    if event.code == crossterm::event::KeyCode::Char('q') {
-                        break;
-                    }
-                }
+                RatatuiEvent::Input(key) => self.on_key(key),
                 RatatuiEvent::Resize => {
                     terminal.autoresize()?;
                 }
-                RatatuiEvent::Tick => {
-                    tracing::info!("did tick...");
-                    if self.last_tick.elapsed() >= Self::TICK_RATE {
-                        self.on_tick();
-                        self.last_tick = Instant::now();
+                RatatuiEvent::Evt(evt) => self.on_event(evt),
+            }
+        }
+        Ok(())
+    }
+
+    /// Draw the app to the terminal.
+    fn draw(&mut self, terminal: &mut Tui) -> io::Result<()> {
+        terminal.draw(|frame| frame.render_widget(self, frame.area()))?;
+        Ok(())
+    }
+
+    fn on_event(&mut self, evt: DashboardEvent) {
+        match evt {
+            DashboardEvent::ServersChanged(servers) => self.servers = servers,
+            DashboardEvent::Log(log) => {
+                self.events.add(RecordedEvent::new(log));
+            }
+            DashboardEvent::TaskOutput { node_path, line } => {
+                match self
+                    .task_outputs
+                    .iter_mut()
+                    .find(|output| output.node_path == node_path)
+                {
+                    Some(output) => output.lines.add(line),
+                    None => {
+                        let mut lines = FixedSizeQueue::new(HISTORY);
+                        lines.add(line);
+                        self.task_outputs.push(TaskOutput { node_path, lines });
                     }
                 }
-                RatatuiEvent::Evt(AnyEvent::Internal(evt)) => {
-                    match &evt {
-                        InternalEvents::ServersChanged { server_resp, .. } => {
-                            self.server_status = Some(server_resp.clone());
-                        }
-                        InternalEvents::InputError(_) => {
-                            todo!("InternalEvents::InputError")
-                        }
-                        InternalEvents::StartupError(_) => {
-                            todo!("InternalEvents::StartupError")
-                        }
+            }
+            DashboardEvent::TaskTree(tree) => self.task_tree = Some(tree),
+        }
+    }
+
+    fn on_key(&mut self, key: KeyEvent) {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.should_exit = true;
+            return;
+        }
+        match &mut self.mode {
+            Mode::RunPrompt(name) => match key.code {
+                KeyCode::Enter => {
+                    let name = name.trim().to_string();
+                    if !name.is_empty() {
+                        self.command(DashboardCommand::Run(name));
                     }
-                    self.events.add(RecordedEvent::new(AnyEvent::Internal(evt)));
+                    self.mode = Mode::Normal;
                 }
-                RatatuiEvent::Evt(ext_event) => {
-                    self.events.add(RecordedEvent::new(ext_event));
+                KeyCode::Esc => self.mode = Mode::Normal,
+                KeyCode::Backspace => {
+                    name.pop();
                 }
-            }
+                KeyCode::Char(c) => name.push(c),
+                _ => {}
+            },
+            Mode::Normal => match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => self.should_exit = true,
+                KeyCode::Char('r') => self.mode = Mode::RunPrompt(String::new()),
+                KeyCode::Char('R') => self.command(DashboardCommand::RestartServers),
+                KeyCode::Char('c') => self.events = FixedSizeQueue::new(HISTORY),
+                KeyCode::Up => self.events_scroll = self.events_scroll.saturating_sub(1),
+                KeyCode::Down => self.events_scroll = self.events_scroll.saturating_add(1),
+                KeyCode::Left => {
+                    self.selected_output = self.selected_output.saturating_sub(1);
+                }
+                KeyCode::Right | KeyCode::Tab if !self.task_outputs.is_empty() => {
+                    self.selected_output = (self.selected_output + 1) % self.task_outputs.len();
+                }
+                _ => {}
+            },
         }
+    }
 
-        // let mut redraw = true;
-        // loop {
-        //     if redraw {
-        //         terminal.draw(|f| ui(f, &downloads))?;
-        //     }
-        //     redraw = true;
-        // }
-        // while !self.should_exit {
-        //     self.draw(terminal)?;
-        //     // self.handle_events()?;
-        //     if self.last_tick.elapsed() >= Self::TICK_RATE {
-        //         self.on_tick();
-        //         self.last_tick = Instant::now();
-        //     }
-        // }
-        if self.last_tick.elapsed() >= Self::TICK_RATE {
-            self.last_tick = Instant::now();
+    fn command(&mut self, cmd: DashboardCommand) {
+        self.events
+            .add(RecordedEvent::new(format!("[dashboard] {cmd:?}")));
+        if self.commands.send(cmd).is_err() {
+            tracing::error!("TUI: could not send dashboard command");
         }
-        Ok(())
     }
 
-    /// Draw the app to the terminal.
-    fn draw(&mut self, terminal: &mut Tui) -> io::Result<()> {
-        terminal.draw(|frame| frame.render_widget(self, frame.size()))?;
-        Ok(())
+    fn create_servers(&self) -> Vec<Line<'static>> {
+        if self.servers.is_empty() {
+            return vec![Line::raw("no servers running").dark_gray()];
+        }
+        self.servers.iter().flat_map(|s| ansi_lines(s)).collect()
     }
 
-    fn on_tick(&mut self) {
-        self.scroll = (self.scroll + 1) % 10;
+    fn create_task_tree(&self) -> Vec<Line<'static>> {
+        match &self.task_tree {
+            None => vec![Line::raw("no tasks have run yet").dark_gray()],
+            Some(tree) => ansi_lines(tree),
+        }
     }
 
-    /// Create some lines to display in the paragraph.
-    fn create_servers(&self) -> Vec<Line<'static>> {
-        // self.server_status
-        //     .as_ref()
-        //     .map(|server_resp| {
-        //         server_resp
-        //             .servers
-        //             .iter()
-        //             .map(|s| Line::raw(server_display(&s.identity, &s.socket_addr)))
-        //             .collect()
-        //     })
-        //     .unwrap_or_default()
-        todo!("re-implement")
+    fn create_events(&self) -> Vec<Line<'static>> {
+        self.events
+            .get()
+            .iter()
+            .flat_map(|evt| {
+                let time = format!("{} ", evt.now.format("%H:%M:%S"));
+                ansi_lines(&evt.evt).into_iter().map(move |line| {
+                    let mut spans = vec![Span::raw(time.clone()).dark_gray()];
+                    spans.extend(line.spans);
+                    Line::from(spans)
+                })
+            })
+            .collect()
     }
 
-    /// Create some lines to display in the paragraph.
-    fn create_events(&mut self) -> Vec<Line<'static>> {
-        // self.events
-        //     .get()
-        //     .iter()
-        //     .map(|evt| match &evt.evt {
-        //         AnyEvent::Internal(int) => match int {
-        //             InternalEvents::ServersChanged { child_results, .. } => {
-        //                 (evt.now, print_server_updates(child_results))
-        //             }
-        //             InternalEvents::InputError(_) => {
-        //                 todo!("InternalEvents::InputError")
-        //             }
-        //             InternalEvents::StartupError(_) => {
-        //                 todo!("InternalEvents::StartupError")
-        //             }
-        //         },
-        //         AnyEvent::External(_ext) => {
-        //             todo!("re-implement")
-        //             // let mut writer = BufWriter::new(Vec::new());
-        //             // PrettyPrint
-        //             //     .handle_external_event(&mut writer, ext)
-        //             //     .expect("can write");
-        //             // (
-        //             //     evt.now,
-        //             //     vec![String::from_utf8(writer.into_inner().expect("into_inner"))
-        //             //         .expect("as_utf8")],
-        //             // )
-        //         }
-        //     })
-        //     .flat_map(|(dt, strs)| {
-        //         strs.iter()
-        //             .map(|str| Line::raw(format!("{} {str}", dt.format("%-I.%M%P"))))
-        //             .collect::<Vec<_>>()
-        //     })
-        //     .collect()
-        // .flatten()
-        // .collect()
-        todo!("re-implement")
+    /// The most recent lines of the selected output, oldest first, that fit in `height`
+    fn create_output(&self, height: u16) -> Vec<Line<'static>> {
+        let Some(output) = self.task_outputs.get(self.selected_output) else {
+            return vec![Line::raw("no task output yet").dark_gray()];
+        };
+        // entries are newest first, but the lines within each entry are already in order
+        output
+            .lines
+            .get()
+            .iter()
+            .take(height as usize)
+            .rev()
+            .flat_map(|line| ansi_lines(line))
+            .collect()
+    }
+
+    fn output_title(&self) -> String {
+        match self.task_outputs.get(self.selected_output) {
+            None => "Output".to_string(),
+            Some(output) => format!(
+                "Output [{}/{}] {}",
+                self.selected_output + 1,
+                self.task_outputs.len(),
+                output.node_path
+            ),
+        }
+    }
+
+    fn create_footer(&self) -> Line<'static> {
+        match &self.mode {
+            Mode::RunPrompt(name) => Line::from(vec![
+                Span::raw(" run: ").bold(),
+                Span::raw(format!("{name}█")),
+                Span::raw("  (enter to run, esc to cancel)").dark_gray(),
+            ]),
+            Mode::Normal => Line::from(
+                [
+                    ("q", "quit"),
+                    ("r", "run task"),
+                    ("R", "restart servers"),
+                    ("←/→", "task output"),
+                    ("↑/↓", "scroll events"),
+                    ("c", "clear events"),
+                ]
+                .iter()
+                .flat_map(|(key, desc)| {
+                    [
+                        Span::raw(format!(" {key} ")).bold(),
+                        Span::raw(format!("{desc} ")).dark_gray(),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+            ),
+        }
     }
 }
 
 impl Widget for &mut App {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let areas = Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)])
-            .split(area);
+        let [main, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(area);
+        let [left, right] =
+            Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)])
+                .areas(main);
+        let [servers, tree] =
+            Layout::vertical([Constraint::Percentage(40), Constraint::Percentage(60)]).areas(left);
+        let [events, output] =
+            Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(right);
+
         Paragraph::new(self.create_servers())
             .block(title_block("Servers"))
-            .gray()
-            .render(areas[0], buf);
+            .render(servers, buf);
+        Paragraph::new(self.create_task_tree())
+            .block(title_block("Tasks"))
+            .render(tree, buf);
         Paragraph::new(self.create_events())
             .block(title_block(
                 format!("Events ({})", self.events.get().len()).as_str(),
             ))
-            .gray()
-            .wrap(Wrap { trim: true })
-            .render(areas[1], buf);
+            .wrap(Wrap { trim: false })
+            .scroll((self.events_scroll, 0))
+            .render(events, buf);
+        Paragraph::new(self.create_output(output.height.saturating_sub(2)))
+            .block(title_block(&self.output_title()))
+            .render(output, buf);
+        Paragraph::new(self.create_footer())
+            .style(Style::default().reversed())
+            .render(footer, buf);
     }
 }
 
 /// Create a bordered block with a title.
-fn title_block(title: &str) -> Block {
+fn title_block(title: &str) -> Block<'static> {
     Block::bordered()
         .gray()
-        .title(title.bold().into_centered_line())
+        .title(title.to_string().bold().into_centered_line())
+}
+
+/// Task output and pretty-printed events may contain ANSI colors, convert them where possible
+fn ansi_lines(input: &str) -> Vec<Line<'static>> {
+    match input.trim_end().into_text() {
+        Ok(text) => text.lines,
+        Err(_) => input.lines().map(|l| Line::raw(l.to_string())).collect(),
+    }
 }
 
 /// A module for common functionality used in the examples.
@@ -289,17 +404,13 @@ mod common {
         panic,
     };
 
-    use color_eyre::{
-        config::{EyreHook, HookBuilder, PanicHook},
-        eyre,
-    };
-    use crossterm::ExecutableCommand;
+    use ratatui::crossterm::ExecutableCommand;
     use ratatui::{
         backend::CrosstermBackend,
         crossterm::terminal::{
             disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
         },
-        terminal::Terminal,
+        Terminal,
     };
 
     // A simple alias for the terminal type used in this example.
@@ -320,48 +431,26 @@ mod common {
         Ok(())
     }
 
-    /// Installs hooks for panic and error handling.
-    ///
-    /// Makes the app resilient to panics and errors by restoring the terminal before printing the
-    /// panic or error message. This prevents error messages from being messed up by the terminal
-    /// state.
-    pub fn install_hooks() -> anyhow::Result<()> {
-        let (panic_hook, eyre_hook) = HookBuilder::default().into_hooks();
-        install_panic_hook(panic_hook);
-        install_error_hook(eyre_hook)?;
-        Ok(())
-    }
-
     /// Install a panic hook that restores the terminal before printing the panic.
-    fn install_panic_hook(panic_hook: PanicHook) {
-        let panic_hook = panic_hook.into_panic_hook();
+    ///
+    /// This prevents panic messages from being messed up by the terminal state.
+    pub fn install_hooks() {
+        let panic_hook = panic::take_hook();
         panic::set_hook(Box::new(move |panic_info| {
             let _ = restore_terminal();
             panic_hook(panic_info);
         }));
     }
-
-    /// Install an error hook that restores the terminal before printing the error.
-    fn install_error_hook(eyre_hook: EyreHook) -> anyhow::Result<()> {
-        let eyre_hook = eyre_hook.into_eyre_hook();
-        eyre::set_hook(Box::new(move |error| {
-            let _ = restore_terminal();
-            eyre_hook(error)
-        }))?;
-        Ok(())
-    }
 }
 
-use std::collections::VecDeque;
-
 #[derive(Debug)]
 struct RecordedEvent {
-    evt: AnyEvent,
+    evt: String,
     now: chrono::DateTime<chrono::Local>,
 }
 
 impl RecordedEvent {
-    pub fn new(evt: AnyEvent) -> Self {
+    pub fn new(evt: String) -> Self {
         Self {
             evt,
             now: chrono::Local::now(),
@@ -369,13 +458,14 @@ impl RecordedEvent {
     }
 }
 
+/// Newest items are at the front
 #[derive(Debug, Default)]
-struct FixedSizeQueue {
-    deque: VecDeque<RecordedEvent>,
+struct FixedSizeQueue<T> {
+    deque: VecDeque<T>,
     capacity: usize,
 }
 
-impl FixedSizeQueue {
+impl<T> FixedSizeQueue<T> {
     fn new(capacity: usize) -> Self {
         FixedSizeQueue {
             deque: VecDeque::with_capacity(capacity),
@@ -383,26 +473,69 @@ impl FixedSizeQueue {
         }
     }
 
-    fn add(&mut self, value: RecordedEvent) {
+    fn add(&mut self, value: T) {
         if self.deque.len() == self.capacity {
             self.deque.pop_back();
         }
         self.deque.push_front(value);
     }
 
-    fn get(&self) -> &VecDeque<RecordedEvent> {
+    fn get(&self) -> &VecDeque<T> {
         &self.deque
     }
 }
 
-// fn main() {
-// let mut queue = FixedSizeQueue::new(200);
-//
-// for i in 1..=201 {
-//     queue.add(format!("Element {}", i));
-// }
-//
-// for elem in queue.get() {
-//     println!("{}", elem);
-// }
-// }
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn app() -> (App, tokio::sync::mpsc::UnboundedReceiver<DashboardCommand>) {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        (App::new(tx), rx)
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_run_prompt() {
+        let (mut app, mut rx) = app();
+        app.on_key(key(KeyCode::Char('r')));
+        for c in "build".chars() {
+            app.on_key(key(KeyCode::Char(c)));
+        }
+        assert_eq!(app.mode, Mode::RunPrompt("build".to_string()));
+        app.on_key(key(KeyCode::Enter));
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(
+            rx.try_recv().ok(),
+            Some(DashboardCommand::Run("build".into()))
+        );
+    }
+
+    #[test]
+    fn test_task_output_per_node_path() {
+        let (mut app, _rx) = app();
+        for (node_path, line) in [("0", "a"), ("1", "b"), ("0", "c\nd")] {
+            app.on_event(DashboardEvent::TaskOutput {
+                node_path: node_path.into(),
+                line: line.into(),
+            });
+        }
+        assert_eq!(app.task_outputs.len(), 2);
+        let lines = app.create_output(10);
+        assert_eq!(lines, vec![Line::raw("a"), Line::raw("c"), Line::raw("d")]);
+        app.on_key(key(KeyCode::Right));
+        assert_eq!(app.output_title(), "Output [2/2] 1");
+    }
+
+    #[test]
+    fn test_fixed_size_queue() {
+        let mut queue = FixedSizeQueue::new(2);
+        for i in 1..=3 {
+            queue.add(i);
+        }
+        assert_eq!(queue.get(), &VecDeque::from([3, 2]));
+    }
+}
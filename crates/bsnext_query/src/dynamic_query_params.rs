@@ -13,6 +13,27 @@ pub struct DynamicQueryParams {
     /// Control if Browsersync will add cache-busting headers, or not.
     #[serde(rename = "bslive.inject")]
     pub inject: Option<InjectParam>,
+    /// Fail this share of requests, eg: ?bslive.chaos.error_rate=0.5
+    #[serde(rename = "bslive.chaos.error_rate")]
+    pub chaos_error_rate: Option<f64>,
+    /// The status code used for failed requests, eg: ?bslive.chaos.error_status=503
+    #[serde(rename = "bslive.chaos.error_status")]
+    pub chaos_error_status: Option<u16>,
+    /// The shortest random latency, eg: ?bslive.chaos.latency.min_ms=100
+    #[serde(rename = "bslive.chaos.latency.min_ms")]
+    pub chaos_latency_min_ms: Option<u64>,
+    /// The longest random latency, eg: ?bslive.chaos.latency.max_ms=500
+    #[serde(rename = "bslive.chaos.latency.max_ms")]
+    pub chaos_latency_max_ms: Option<u64>,
+    /// Added to, or taken from, the latency, eg: ?bslive.chaos.latency.jitter_ms=50
+    #[serde(rename = "bslive.chaos.latency.jitter_ms")]
+    pub chaos_latency_jitter_ms: Option<u64>,
+    /// Cut off this share of responses part-way through the body, eg: ?bslive.chaos.reset_rate=0.2
+    #[serde(rename = "bslive.chaos.reset_rate")]
+    pub chaos_reset_rate: Option<f64>,
+    /// Stream the body at this speed, eg: ?bslive.chaos.bytes_per_sec=1024
+    #[serde(rename = "bslive.chaos.bytes_per_sec")]
+    pub chaos_bytes_per_sec: Option<u64>,
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -2,6 +2,7 @@ use crate::builtin_strings::BuiltinStringDef;
 use crate::inject_addition::InjectAddition;
 use crate::inject_replacement::InjectReplacement;
 use crate::injector_guard::ByteReplacer;
+use crate::rewrite_links::RewriteLinks;
 use axum::extract::Request;
 use bsnext_guards::route_guard::RouteGuard;
 use bsnext_guards::MatcherList;
@@ -35,6 +36,9 @@ pub enum Injection {
     UnknownNamed(UnknownStringDef),
     Replacement(InjectReplacement),
     Addition(InjectAddition),
+    /// Added by proxy routes with `rewrite_links`, rather than being configured directly
+    #[serde(skip_deserializing)]
+    RewriteLinks(RewriteLinks),
 }
 
 #[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
@@ -53,6 +57,7 @@ impl RouteGuard for InjectionItem {
                 Injection::UnknownNamed(_) => todo!("accept_req Injection::UnknownNamed"),
                 Injection::Replacement(def) => def.accept_req(req, outer_uri),
                 Injection::Addition(add) => add.accept_req(req, outer_uri),
+                Injection::RewriteLinks(rewrite) => rewrite.accept_req(req, outer_uri),
             }
         } else {
             false
@@ -65,6 +70,7 @@ impl RouteGuard for InjectionItem {
             Injection::UnknownNamed(_) => todo!("accept_res Injection::UnknownNamed"),
             Injection::Replacement(def) => def.accept_res(res, outer_uri),
             Injection::Addition(add) => add.accept_res(res, outer_uri),
+            Injection::RewriteLinks(rewrite) => rewrite.accept_res(res, outer_uri),
         }
     }
 }
@@ -75,6 +81,7 @@ impl ByteReplacer for InjectionItem {
             Injection::UnknownNamed(_) => todo!("Injection::UnknownNamed"),
             Injection::Replacement(def) => def.apply(body),
             Injection::Addition(add) => add.apply(body),
+            Injection::RewriteLinks(rewrite) => rewrite.apply(body),
         }
     }
 }
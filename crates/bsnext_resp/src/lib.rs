@@ -6,6 +6,7 @@ pub mod inject_addition;
 pub mod inject_opts;
 pub mod inject_replacement;
 pub mod injector_guard;
+pub mod rewrite_links;
 
 use crate::inject_opts::InjectionItem;
 #[cfg(test)]
@@ -112,7 +113,7 @@ pub async fn response_modifications_layer(
 
     let mut next = bytes;
     for (index, injection) in res_accepted.iter().enumerate() {
-        match injection.replace_bytes(&next, &res_headers, &req_headers) {
+        match injection.replace_bytes(&next, &req_headers, &res_headers) {
             Some(bytes) => next = bytes,
             None => tracing::debug!(?index, "could not write bytes"),
         }
@@ -0,0 +1,209 @@
+use crate::injector_guard::ByteReplacer;
+use axum::extract::Request;
+use bsnext_guards::route_guard::RouteGuard;
+use http::header::CONTENT_TYPE;
+use http::{Response, Uri};
+
+/// Response types that can contain links back to the target
+const CONTENT_TYPES: [&str; 4] = [
+    "text/html",
+    "text/css",
+    "text/javascript",
+    "application/javascript",
+];
+
+///
+/// Maps absolute links to a proxy target back onto the local server, so that
+/// navigation stays on the dev server.
+///
+/// Links are rewritten to be protocol-relative (eg: `https://example.com/a` -> `//localhost:3000/a`)
+/// which keeps them working whether the local server is running with TLS or not
+///
+#[derive(Debug, PartialEq, Hash, Clone, serde::Deserialize, serde::Serialize)]
+pub struct RewriteLinks {
+    /// The authority of the proxy target, like `example.com` or `127.0.0.1:8080`
+    pub from: String,
+    /// The authority the browser used to reach the local server, like `localhost:3000`
+    pub to: String,
+}
+
+impl RewriteLinks {
+    pub fn new(from: impl Into<String>, to: impl Into<String>) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+        }
+    }
+
+    /// Only absolute urls that point at the target are changed, relative ones are left as-is
+    pub fn rewrite_location(&self, location: &str) -> Option<String> {
+        let next = rewrite_origin(location, &self.from, &self.to);
+        (next != location).then_some(next)
+    }
+
+    /// Drops the `Domain` attribute, so that the cookie belongs to whichever host the browser used
+    pub fn rewrite_set_cookie(&self, cookie: &str) -> Option<String> {
+        let mut parts = cookie.split(';');
+        let first = parts.next()?;
+        let mut removed = false;
+        let mut next = first.to_string();
+        for attr in parts {
+            let is_domain = attr
+                .trim()
+                .split('=')
+                .next()
+                .is_some_and(|name| name.trim().eq_ignore_ascii_case("domain"));
+            if is_domain {
+                removed = true;
+                continue;
+            }
+            next.push(';');
+            next.push_str(attr);
+        }
+        removed.then_some(next)
+    }
+}
+
+impl RouteGuard for RewriteLinks {
+    fn accept_req(&self, _req: &Request, _outer_uri: &Uri) -> bool {
+        true
+    }
+
+    fn accept_res<T>(&self, res: &Response<T>, _outer_uri: &Uri) -> bool {
+        res.headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|ct| CONTENT_TYPES.iter().any(|t| ct.contains(t)))
+    }
+}
+
+impl ByteReplacer for RewriteLinks {
+    fn apply(&self, body: &'_ str) -> Option<String> {
+        Some(rewrite_origin(body, &self.from, &self.to))
+    }
+}
+
+/// Slashes can appear escaped when urls are embedded in JSON, eg: `"https:\/\/example.com"`
+const SLASHES: [&str; 2] = ["//", "\\/\\/"];
+const SCHEMES: [&str; 2] = ["https:", "http:"];
+
+///
+/// Replace every `http://{from}`, `https://{from}` and `//{from}` with `//{to}`.
+///
+/// The character after `from` must end the authority, so that `example.com` does not
+/// match `example.com.evil.org` or `example.community`
+///
+fn rewrite_origin(body: &str, from: &str, to: &str) -> String {
+    if from.is_empty() {
+        return body.to_string();
+    }
+    let mut out = String::with_capacity(body.len());
+    let mut consumed = 0;
+    let mut search_from = 0;
+
+    while let Some(found) = body[search_from..].find(from) {
+        let start = search_from + found;
+        let end = start + from.len();
+        search_from = end;
+
+        let before = &body[consumed..start];
+        let Some(slashes) = SLASHES.iter().find(|s| before.ends_with(*s)) else {
+            continue;
+        };
+        if !ends_authority(&body[end..], from) {
+            continue;
+        }
+
+        let before = &before[..before.len() - slashes.len()];
+        let before = SCHEMES
+            .iter()
+            .find_map(|scheme| {
+                before
+                    .len()
+                    .checked_sub(scheme.len())
+                    .filter(|idx| before.is_char_boundary(*idx))
+                    .filter(|idx| before[*idx..].eq_ignore_ascii_case(scheme))
+                    .map(|idx| &before[..idx])
+            })
+            .unwrap_or(before);
+
+        out.push_str(before);
+        out.push_str(slashes);
+        out.push_str(to);
+        consumed = end;
+    }
+
+    out.push_str(&body[consumed..]);
+    out
+}
+
+fn ends_authority(rest: &str, from: &str) -> bool {
+    let mut chars = rest.chars();
+    match chars.next() {
+        None => true,
+        Some(c) if c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '_' => false,
+        // `example.com:8080` is a different origin to `example.com`
+        Some(':') if !from.contains(':') => !chars.next().is_some_and(|c| c.is_ascii_digit()),
+        Some(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rewrite_origin() {
+        let cases = [
+            (
+                r#"<a href="https://example.com/about">about</a>"#,
+                r#"<a href="//localhost:3000/about">about</a>"#,
+            ),
+            (
+                r#"<link href="http://example.com">"#,
+                r#"<link href="//localhost:3000">"#,
+            ),
+            (
+                "body { background: url(//example.com/bg.png) }",
+                "body { background: url(//localhost:3000/bg.png) }",
+            ),
+            (
+                r#"fetch("HTTPS://example.com/api?a=b")"#,
+                r#"fetch("//localhost:3000/api?a=b")"#,
+            ),
+            (
+                r#"{"url":"https:\/\/example.com\/api"}"#,
+                r#"{"url":"\/\/localhost:3000\/api"}"#,
+            ),
+            (
+                "https://example.com.evil.org https://example.community https://example.com:8080",
+                "https://example.com.evil.org https://example.community https://example.com:8080",
+            ),
+            (
+                "example.com/a mailto:me@example.com",
+                "example.com/a mailto:me@example.com",
+            ),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                rewrite_origin(input, "example.com", "localhost:3000"),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_rewrite_headers() {
+        let rewrite = RewriteLinks::new("example.com", "localhost:3000");
+        assert_eq!(
+            rewrite.rewrite_location("https://example.com/login?next=/"),
+            Some("//localhost:3000/login?next=/".to_string())
+        );
+        assert_eq!(rewrite.rewrite_location("/login"), None);
+        assert_eq!(
+            rewrite.rewrite_set_cookie("sid=abc; Path=/; Domain=.example.com; HttpOnly"),
+            Some("sid=abc; Path=/; HttpOnly".to_string())
+        );
+        assert_eq!(rewrite.rewrite_set_cookie("sid=abc; Path=/"), None);
+    }
+}
@@ -1,12 +1,14 @@
-use crate::servers::ReadActiveServers;
+use crate::run::run_named::RunNamed;
+use crate::servers::{ReadActiveServers, RestartServers};
 use crate::start::start_system::StopSystem;
 use crate::system::BsSystem;
 use actix::Addr;
-use bsnext_dto::internal::ServerError;
+use bsnext_dto::internal::{ServerError, TaskReportAndTree};
 use bsnext_dto::ActiveServer;
 use bsnext_fs::{Debounce, FsEvent};
 use bsnext_input::route::WatchSpec;
 use bsnext_path_monitor::PathMonitorEvent;
+use std::future::Future;
 use tokio::sync::oneshot;
 
 #[derive(Debug)]
@@ -44,6 +46,23 @@ impl BsSystemApi {
             .map_err(|e| anyhow::anyhow!("could not wait: {:?}", e))
     }
 
+    ///
+    /// Like [`Self::handle`], but a ctrl-c will stop the system (and its servers) first
+    ///
+    pub async fn handle_or_interrupt(self) -> anyhow::Result<()> {
+        let sys_address = self.sys_address.clone();
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {
+                tracing::debug!("ctrl-c received, stopping the system");
+                sys_address
+                    .send(StopSystem)
+                    .await
+                    .map_err(|e| anyhow::anyhow!("could not stop: {:?}", e))
+            }
+            r = self.handle() => r,
+        }
+    }
+
     pub fn fs_event(&self, evt: FsEvent) {
         self.sys_address.do_send(PathMonitorEvent::singular(
             evt,
@@ -63,4 +82,36 @@ impl BsSystemApi {
             }
         }
     }
+
+    ///
+    /// Execute `run` entries by name, from the current input. Resolves once they complete
+    ///
+    pub fn run_named(
+        &self,
+        named: Vec<String>,
+    ) -> impl Future<Output = anyhow::Result<TaskReportAndTree>> + 'static {
+        let sys_address = self.sys_address.clone();
+        async move {
+            sys_address
+                .send(RunNamed { named })
+                .await
+                .map_err(|e| anyhow::anyhow!("could not send RunNamed: {:?}", e))?
+        }
+    }
+
+    ///
+    /// Stop and re-start all servers from the current input
+    ///
+    pub fn restart_servers(
+        &self,
+    ) -> impl Future<Output = Result<Vec<ActiveServer>, ServerError>> + 'static {
+        let sys_address = self.sys_address.clone();
+        async move {
+            match sys_address.send(RestartServers).await {
+                Ok(Ok((resp, _))) => Ok(resp.servers),
+                Ok(Err(e)) => Err(e),
+                Err(e) => Err(ServerError::Unknown(e.to_string())),
+            }
+        }
+    }
 }
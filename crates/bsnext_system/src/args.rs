@@ -2,6 +2,7 @@ use crate::run::RunCommand;
 use crate::start::start_command::StartCommand;
 use crate::watch::watch_sub_opts::WatchSubOpts;
 use crate::watch::WatchCommand;
+use bsnext_core::export::ExportCommand;
 use bsnext_core::shared_args::{FsOpts, InputOpts, LoggingOpts};
 use bsnext_tracing::OutputFormat;
 // bslive route --path=/ --dir=
@@ -45,6 +46,7 @@ impl Args {
             Some(SubCommands::Watch(WatchCommand { logging, .. })) => logging,
             Some(SubCommands::Start(StartCommand { logging, .. })) => logging,
             Some(SubCommands::Run(RunCommand { logging, .. })) => logging,
+            Some(SubCommands::Export(ExportCommand { logging, .. })) => logging,
             _ => &self.logging,
         }
     }
@@ -53,6 +55,7 @@ impl Args {
             Some(SubCommands::Watch(WatchCommand { format, .. })) => format.to_owned(),
             Some(SubCommands::Start(StartCommand { format, .. })) => format.to_owned(),
             Some(SubCommands::Run(RunCommand { format, .. })) => format.to_owned(),
+            Some(SubCommands::Export(ExportCommand { format, .. })) => format.to_owned(),
             _ => self.format,
         }
     }
@@ -66,4 +69,6 @@ pub enum SubCommands {
     Watch(WatchCommand),
     /// Just run tasks
     Run(RunCommand),
+    /// Render every route to disk, following links found in HTML
+    Export(ExportCommand),
 }
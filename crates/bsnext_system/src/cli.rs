@@ -1,11 +1,14 @@
 use crate::args::{Args, SubCommands};
+use crate::export::export_cmd;
 use crate::start;
+use crate::start::dashboard;
 use crate::start::start_command::StartCommand;
 use crate::start::start_kind::start_from_inputs::StartFromInput;
 use crate::start::start_kind::StartKind;
 use crate::start::stdout_channel;
 use bsnext_input::route::MultiWatch;
 use bsnext_input::Input;
+use bsnext_output::stdout::completion_writer;
 use bsnext_output::OutputWriters;
 use bsnext_tracing::{
     init_tracing, init_tracing_with_otel, LineNumberOption, OutputFormat, WriteOption,
@@ -71,7 +74,7 @@ where
 
     let sub_command = args.command.unwrap_or_else(move || {
         SubCommands::Start(StartCommand {
-            cors: false,
+            cors: None,
             port: args.port,
             trailing: args.trailing.clone(),
             proxies: vec![],
@@ -84,7 +87,7 @@ where
 
     tracing::debug!("subcommand = {:?}", sub_command);
     let _guard = debug_span!("parent").entered();
-    let r = async_init(sub_command, writer, args_c, cwd).await;
+    let r = async_init(sub_command, writer, format, args_c, cwd).await;
     drop(_guard);
     drop(tracing_guard);
     r
@@ -93,27 +96,47 @@ where
 async fn async_init(
     command: SubCommands,
     writer: OutputWriters,
+    format: OutputFormat,
     args: Args,
     cwd: PathBuf,
 ) -> Result<(), anyhow::Error> {
     match command {
         SubCommands::Start(start) => {
             let start_kind = start.as_start_kind(&args.fs_opts, &args.input_opts);
-            start_stdout_wrapper(start_kind, cwd, writer).await
+            start_output_wrapper(start_kind, cwd, writer, format).await
         }
         SubCommands::Watch(watch) => {
             let mut input = Input::default();
             let multi = MultiWatch::from(watch);
             input.watchers.push(multi);
             let start_kind = StartKind::FromInput(StartFromInput { input });
-            start_stdout_wrapper(start_kind, cwd, writer).await
+            start_output_wrapper(start_kind, cwd, writer, format).await
         }
+        // `run` exits once tasks complete, so it always writes directly to stdout
         SubCommands::Run(run) => {
             let start_kind = run.as_start_kind(&args.input_opts);
             start_stdout_wrapper(start_kind, cwd, writer)
                 .instrument(debug_span!("SubCommands::Run").or_current())
                 .await
         }
+        SubCommands::Export(cmd) => {
+            let result = export_cmd(&cwd, &cmd, &args).await;
+            completion_writer(writer, result)
+        }
+    }
+}
+
+async fn start_output_wrapper(
+    start_kind: StartKind,
+    cwd: PathBuf,
+    writer: OutputWriters,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
+    match format {
+        OutputFormat::Tui => dashboard::with_dashboard(cwd, start_kind).await,
+        OutputFormat::Normal | OutputFormat::Json => {
+            start_stdout_wrapper(start_kind, cwd, writer).await
+        }
     }
 }
 
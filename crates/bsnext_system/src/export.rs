@@ -0,0 +1,90 @@
+use crate::args::Args;
+use crate::start::start_kind::start_from_inputs::StartFromInputPaths;
+use crate::start::start_kind::start_from_paths::StartFromPaths;
+use crate::start::start_kind::StartKind;
+use bsnext_core::export::{export_one_server, ExportCommand, ExportError, ExportEvent};
+use bsnext_fs_helpers::WriteMode;
+use bsnext_input::server_config::{ServerConfig, ServerIdentity};
+use bsnext_input::startup::{StartupContext, SystemStart, SystemStartArgs};
+use bsnext_input::Input;
+use std::path::PathBuf;
+
+///
+/// Resolve the input in the same way as `start` does, then export every server.
+/// When there's more than one server, each one is written into a sub-folder of `--dir`
+///
+pub async fn export_cmd(
+    cwd: &PathBuf,
+    cmd: &ExportCommand,
+    args: &Args,
+) -> Result<Vec<ExportEvent>, ExportError> {
+    let write_mode = if cmd.force {
+        WriteMode::Override
+    } else {
+        WriteMode::Safe
+    };
+    let input = resolve_input(cwd, cmd, args)?;
+
+    if input.servers.is_empty() {
+        return Err(ExportError::NoServers);
+    }
+
+    let multiple = input.servers.len() > 1;
+    let mut events = vec![];
+    for server in input.servers {
+        let cmd = if multiple {
+            ExportCommand {
+                out_dir: cmd.out_dir.join(sub_dir(&server)),
+                ..cmd.clone()
+            }
+        } else {
+            cmd.clone()
+        };
+        events.extend(export_one_server(cwd, server, &cmd, write_mode).await?);
+    }
+    Ok(events)
+}
+
+fn resolve_input(cwd: &PathBuf, cmd: &ExportCommand, args: &Args) -> Result<Input, ExportError> {
+    let ctx = StartupContext::from_cwd(Some(cwd));
+    let start_kind = if cmd.trailing.is_empty() {
+        StartKind::FromInputPaths(StartFromInputPaths {
+            input_paths: args.input_opts.input.clone(),
+            port: None,
+            no_watch: true,
+        })
+    } else {
+        StartKind::FromPaths(StartFromPaths {
+            paths: cmd.trailing.clone(),
+            write_input: false,
+            port: None,
+            force: false,
+            route_opts: Default::default(),
+            watch_sub_opts: Default::default(),
+            no_watch: true,
+        })
+    };
+
+    match start_kind.resolve_input(&ctx).map_err(ExportError::Input)? {
+        SystemStartArgs::PathWithInput { input, .. } | SystemStartArgs::InputOnly { input } => {
+            Ok(input)
+        }
+        SystemStartArgs::InputOnlyDeferred { input, create } => {
+            create.exec(input).map_err(ExportError::Input)
+        }
+        SystemStartArgs::PathWithInvalidInput { input_error, .. } => {
+            Err(ExportError::Input(Box::new(input_error)))
+        }
+        SystemStartArgs::RunOnly { .. } => unreachable!("export never resolves a run-only input"),
+    }
+}
+
+fn sub_dir(server: &ServerConfig) -> String {
+    match &server.identity {
+        ServerIdentity::Both { name, .. }
+        | ServerIdentity::Named { name }
+        | ServerIdentity::PortNamed { name, .. } => name.to_owned(),
+        ServerIdentity::Port { port } => port.to_string(),
+        ServerIdentity::Address { bind_address } => bind_address.replace([':', '.'], "_"),
+    }
+}
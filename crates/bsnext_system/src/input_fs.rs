@@ -7,6 +7,7 @@ pub fn from_input_path<P: AsRef<Path>>(path: P, ctx: &InputCtx) -> Result<Input,
             path.as_ref().to_owned(),
         ))),
         Some("yml") | Some("yaml") => bsnext_yaml::yaml_fs::YamlFs::from_input_path(path, ctx),
+        Some("toml") => bsnext_toml::toml_fs::TomlFs::from_input_path(path, ctx),
         Some("md") | Some("markdown") => bsnext_md::md_fs::MdFs::from_input_path(path, ctx),
         Some("html") => bsnext_html::HtmlFs::from_input_path(path, ctx),
         Some("js") => bsnext_js::JsFs::from_input_path(path, ctx),
@@ -2,6 +2,7 @@ mod api;
 pub mod args;
 pub mod capabilities;
 pub mod cli;
+pub mod export;
 mod external_event_sender;
 mod fs_task_tracker;
 mod handle_fs_event_grouping;
@@ -36,6 +36,7 @@ impl actix::Handler<MonitorAny> for BsSystem {
         let cwd = self.cwd.clone();
         let recip = ctx.address().recipient();
         let monitor = self.path_monitors.clone();
+        self.input = Some(msg.input.clone());
 
         let future = async move {
             let input = msg.input;
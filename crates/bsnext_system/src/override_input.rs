@@ -40,6 +40,7 @@ impl actix::Handler<OverrideInput> for BsSystem {
                     MonitorPathWatchables::new(actor.cwd.clone(), &input_clone, addr.recipient());
                 actor.path_monitors.do_send(msg);
                 actor.update_ctx(&input_clone, &start_ctx_clone);
+                actor.input = Some(input_clone);
                 output
             });
         Box::pin(f)
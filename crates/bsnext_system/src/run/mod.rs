@@ -1,4 +1,5 @@
 pub mod resolve_spec;
+pub mod run_named;
 
 use crate::start::start_kind::run_from_input::RunFromInputPaths;
 use crate::start::start_kind::StartKind;
@@ -0,0 +1,35 @@
+use crate::run::resolve_spec::{InvokeRunTasks, ResolveSpec};
+use crate::system::BsSystem;
+use actix::{AsyncContext, ResponseFuture};
+use bsnext_dto::internal::TaskReportAndTree;
+use bsnext_input::startup::TopLevelRunMode;
+
+///
+/// Execute one or more `run` entries, by name, against the current input.
+/// Output is reported in the same way as tasks triggered by file changes.
+///
+#[derive(Debug, actix::Message)]
+#[rtype(result = "Result<TaskReportAndTree, anyhow::Error>")]
+pub struct RunNamed {
+    pub named: Vec<String>,
+}
+
+impl actix::Handler<RunNamed> for BsSystem {
+    type Result = ResponseFuture<Result<TaskReportAndTree, anyhow::Error>>;
+
+    #[tracing::instrument(skip_all, name = "Handler->RunNamed->BsSystem")]
+    fn handle(&mut self, msg: RunNamed, ctx: &mut Self::Context) -> Self::Result {
+        let Some(input) = self.input.clone() else {
+            return Box::pin(async { Err(anyhow::anyhow!("no input has been accepted yet")) });
+        };
+        let addr = ctx.address();
+        Box::pin(async move {
+            let spec = addr
+                .send(ResolveSpec::new(input, msg.named, TopLevelRunMode::Seq))
+                .await??
+                .as_spec();
+            let report_and_tree = addr.send(InvokeRunTasks::new(spec)).await??;
+            Ok(report_and_tree)
+        })
+    }
+}
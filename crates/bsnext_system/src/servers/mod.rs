@@ -1,5 +1,5 @@
 use crate::system::BsSystem;
-use actix::ResponseFuture;
+use actix::{AsyncContext, ResponseFuture};
 use actix_rt::Arbiter;
 use bsnext_core::server::handler_client_config::ClientConfigChange;
 use bsnext_core::server::handler_routes_updated::RoutesUpdated;
@@ -56,6 +56,7 @@ impl actix::Handler<ResolveServers> for BsSystem {
                             actor_address: maybe_addr.clone().expect("guarded above"),
                             identity: c.server_handler.identity.clone(),
                             socket_addr: c.server_handler.socket_addr,
+                            scheme: c.server_handler.scheme,
                         };
                         addr.do_send(ChildCreatedInsert { child_handler })
                     }
@@ -135,3 +136,41 @@ impl actix::Handler<ReadActiveServers> for BsSystem {
         })
     }
 }
+
+///
+/// Stop every running server, then start them again from the current input.
+///
+#[derive(actix::Message)]
+#[rtype(result = "Result<(GetActiveServersResponse, Vec<ChildResult>), ServerError>")]
+pub struct RestartServers;
+
+impl actix::Handler<RestartServers> for BsSystem {
+    type Result = ResponseFuture<Result<(GetActiveServersResponse, Vec<ChildResult>), ServerError>>;
+
+    #[tracing::instrument(skip_all, name = "Handler->RestartServers->BsSystem")]
+    fn handle(&mut self, _msg: RestartServers, ctx: &mut Self::Context) -> Self::Result {
+        let Some(input) = self.input.clone() else {
+            return Box::pin(async {
+                Err(ServerError::Unknown(
+                    "no input has been accepted yet".to_string(),
+                ))
+            });
+        };
+        let addr = ctx.address();
+        let without_servers = Input {
+            servers: vec![],
+            ..input.clone()
+        };
+
+        Box::pin(async move {
+            debug!("stopping all servers before restart");
+            addr.send(ResolveServers::new(without_servers))
+                .await
+                .map_err(|e| ServerError::Unknown(e.to_string()))??;
+            debug!("starting servers again");
+            addr.send(ResolveServers::new(input))
+                .await
+                .map_err(|e| ServerError::Unknown(e.to_string()))?
+        })
+    }
+}
@@ -0,0 +1,172 @@
+use crate::start::start_kind::StartKind;
+use crate::start::start_system::start_system;
+use bsnext_dto::archy::{archy, overlay_results, Prefix};
+use bsnext_dto::external_events::{
+    ExternalEventsDTO, TaskActionDTO, TaskActionStageDTO, TaskTreePreview, TaskTreeSummary,
+};
+use bsnext_dto::internal::{AnyEvent, InternalEvents};
+use bsnext_dto::internal_events::server_display;
+use bsnext_dto::{OutputLineDTO, ServerIdentityDTO, ServersChangedDTO};
+use bsnext_output::ratatui::{DashboardCommand, DashboardEvent, Ratatui, RatatuiSender};
+use bsnext_output::{OutputWriterTrait, OutputWriters};
+use std::future::Future;
+use std::path::PathBuf;
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::Sender;
+
+///
+/// The equivalent of [`crate::start::stdout_channel`], but events are forwarded
+/// into the dashboard instead of being written to stdout
+///
+pub fn dashboard_channel(dashboard: RatatuiSender) -> (Sender<AnyEvent>, impl Future<Output = ()>) {
+    let (events_sender, mut events_receiver) = mpsc::channel::<AnyEvent>(1);
+    let channel_future = async move {
+        while let Some(evt) = events_receiver.recv().await {
+            tracing::trace!(parent: None, ?evt, "dashboard_channel recv()");
+            for dashboard_event in dashboard_events(&evt) {
+                if dashboard.send(dashboard_event).is_err() {
+                    tracing::debug!("dashboard closed, dropping events");
+                    return;
+                }
+            }
+        }
+    };
+    (events_sender, channel_future)
+}
+
+///
+/// Start the system with the dashboard attached. This keeps running until
+/// the dashboard is closed, forwarding any keybinding commands to the system
+///
+pub async fn with_dashboard(cwd: PathBuf, start_kind: StartKind) -> anyhow::Result<()> {
+    let (commands_sender, mut commands) = mpsc::unbounded_channel::<DashboardCommand>();
+    let (dashboard, ui_handle, _input_handle) = Ratatui::try_new(commands_sender)?.install()?;
+    let (events_sender, channel_future) = dashboard_channel(dashboard.clone());
+    actix_rt::spawn(channel_future);
+
+    let api = match start_system(cwd, start_kind, events_sender.clone()).await {
+        Ok(Some(api)) => Some(api),
+        Ok(None) => None,
+        Err(err) => {
+            let _ = events_sender
+                .send(AnyEvent::Internal(InternalEvents::StartupError(err)))
+                .await;
+            None
+        }
+    };
+
+    while let Some(cmd) = commands.recv().await {
+        tracing::debug!(?cmd, "dashboard command");
+        let Some(api) = api.as_ref() else {
+            if cmd == DashboardCommand::Quit {
+                break;
+            }
+            let _ = dashboard.send(DashboardEvent::Log(
+                "[dashboard] the system is not running".to_string(),
+            ));
+            continue;
+        };
+        match cmd {
+            DashboardCommand::Run(name) => {
+                let run = api.run_named(vec![name]);
+                let dashboard = dashboard.clone();
+                actix_rt::spawn(async move {
+                    if let Err(e) = run.await {
+                        let _ = dashboard.send(DashboardEvent::Log(format!("[run] {e}")));
+                    }
+                });
+            }
+            DashboardCommand::RestartServers => {
+                let restart = api.restart_servers();
+                let dashboard = dashboard.clone();
+                actix_rt::spawn(async move {
+                    if let Err(e) = restart.await {
+                        let _ = dashboard.send(DashboardEvent::Log(format!("[restart] {e}")));
+                    }
+                });
+            }
+            DashboardCommand::Quit => break,
+        }
+    }
+
+    if let Some(api) = api {
+        api.stop().await?;
+    }
+
+    ui_handle
+        .join()
+        .map_err(|_| anyhow::anyhow!("dashboard thread panicked"))
+}
+
+fn dashboard_events(evt: &AnyEvent) -> Vec<DashboardEvent> {
+    match evt {
+        AnyEvent::Internal(int @ InternalEvents::ServersChanged { server_resp, .. }) => {
+            let servers = server_resp
+                .servers
+                .iter()
+                .map(|s| server_display(&ServerIdentityDTO::from(&s.identity), &s.url()))
+                .collect();
+            let mut events = vec![DashboardEvent::ServersChanged(servers)];
+            events.extend(pretty(int).map(DashboardEvent::Log));
+            events
+        }
+        AnyEvent::Internal(int) => pretty(int).map(DashboardEvent::Log).into_iter().collect(),
+        AnyEvent::External(ExternalEventsDTO::ServersChanged(ServersChangedDTO {
+            servers_resp,
+        })) => {
+            let servers = servers_resp
+                .servers
+                .iter()
+                .map(|s| server_display(&s.identity, &s.url))
+                .collect();
+            vec![DashboardEvent::ServersChanged(servers)]
+        }
+        AnyEvent::External(ExternalEventsDTO::OutputLine(output)) => {
+            let (line, prefix, node_path) = match output {
+                OutputLineDTO::Stdout(out) => (&out.line, &out.prefix, &out.node_path),
+                OutputLineDTO::Stderr(err) => (&err.line, &err.prefix, &err.node_path),
+            };
+            let node_path = node_path
+                .as_ref()
+                .or(prefix.as_ref())
+                .cloned()
+                .unwrap_or_else(|| "output".to_string());
+            vec![DashboardEvent::TaskOutput {
+                node_path,
+                line: line.to_owned(),
+            }]
+        }
+        AnyEvent::External(ExternalEventsDTO::TaskAction(TaskActionDTO { stage })) => {
+            let tree = match stage {
+                TaskActionStageDTO::Started { tree } => archy(tree, Prefix::None),
+                TaskActionStageDTO::Ended {
+                    tree, report_map, ..
+                } => archy(&overlay_results(tree, report_map), Prefix::None),
+                TaskActionStageDTO::Error => return vec![],
+            };
+            vec![DashboardEvent::TaskTree(tree)]
+        }
+        AnyEvent::External(ExternalEventsDTO::TaskTreePreview(TaskTreePreview {
+            tree, ..
+        })) => vec![DashboardEvent::TaskTree(archy(tree, Prefix::None))],
+        AnyEvent::External(ExternalEventsDTO::TaskTreeSummary(TaskTreeSummary {
+            tree,
+            report_map,
+        })) => vec![DashboardEvent::TaskTree(archy(
+            &overlay_results(tree, report_map),
+            Prefix::None,
+        ))],
+        AnyEvent::External(ext) => pretty(ext).map(DashboardEvent::Log).into_iter().collect(),
+    }
+}
+
+/// Re-use the pretty printers so the event log reads the same as the normal output
+fn pretty(evt: &impl OutputWriterTrait) -> Option<String> {
+    let mut buf = Vec::new();
+    if let Err(e) = OutputWriters::Pretty.write_evt(evt, &mut buf) {
+        tracing::error!(?e, "could not write event for the dashboard");
+        return None;
+    }
+    let output = String::from_utf8_lossy(&buf).trim_end().to_string();
+    (!output.is_empty()).then_some(output)
+}
@@ -8,6 +8,7 @@ use std::path::PathBuf;
 use tokio::sync::mpsc;
 use tokio::sync::mpsc::Sender;
 
+pub mod dashboard;
 pub mod start_command;
 pub mod start_kind;
 pub mod start_system;
@@ -45,10 +46,7 @@ pub async fn with_sender(
     let startup = start_system(cwd, start_kind, events_sender).await;
     match startup {
         // If the startup was successful, keep hold of the handle to keep the system running
-        Ok(Some(api)) => match api.handle().await {
-            Ok(..) => Ok(()),
-            Err(er) => Err(anyhow::anyhow!("{}", er)),
-        },
+        Ok(Some(api)) => api.handle_or_interrupt().await,
         Ok(None) => Ok(()),
         Err(err) => {
             let as_str = err.to_string();
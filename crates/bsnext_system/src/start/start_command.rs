@@ -10,9 +10,11 @@ use bsnext_tracing::OutputFormat;
 
 #[derive(Debug, Default, Clone, clap::Parser)]
 pub struct StartCommand {
-    /// Should permissive cors headers be added to all responses?
-    #[arg(long)]
-    pub cors: bool,
+    /// Add cors headers to all responses. On its own this allows everything, or give a
+    /// comma-separated list of origins, or the full config as inline YAML,
+    /// eg: --cors='{origins: [http://localhost:5173], credentials: true}'
+    #[arg(long, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+    pub cors: Option<CorsOpts>,
 
     /// Specify a port instead of a random one
     #[arg(short, long)]
@@ -57,7 +59,7 @@ impl StartCommand {
                 force: fs_opts.force,
                 watch_sub_opts: self.watch_sub_opts.clone(),
                 route_opts: Opts {
-                    cors: self.cors.then_some(CorsOpts::Cors(true)),
+                    cors: self.cors.clone(),
                     ..Default::default()
                 },
                 no_watch: self.no_watch,
@@ -1,7 +1,7 @@
 use crate::start::start_kind::run_from_input::RunFromInputPaths;
 use crate::start::start_kind::start_from_inputs::{StartFromInput, StartFromInputPaths};
 use crate::start::start_kind::start_from_paths::StartFromPaths;
-use bsnext_fs_helpers::{fs_write_str, FsWriteError, WriteMode};
+use bsnext_fs_helpers::{fs_write_str, WriteMode};
 use bsnext_input::startup::{StartupContext, SystemStart, SystemStartArgs};
 use bsnext_input::target::TargetKind;
 use bsnext_input::InputWriter;
@@ -37,19 +37,20 @@ pub fn fs_write_input(
     input: &Input,
     target_kind: TargetKind,
     write_mode: &WriteMode,
-) -> Result<PathBuf, FsWriteError> {
+) -> Result<PathBuf, Box<InputError>> {
     let string = match target_kind {
         TargetKind::Yaml => bsnext_yaml::yaml_writer::YamlWriter.input_to_str(input),
-        TargetKind::Toml => todo!("toml missing"),
+        TargetKind::Toml => bsnext_toml::toml_writer::TomlWriter.input_to_str(input),
         TargetKind::Md => bsnext_md::md_writer::MdWriter.input_to_str(input),
         TargetKind::Html => bsnext_html::html_writer::HtmlWriter.input_to_str(input),
-    };
+    }?;
     let name = match target_kind {
         TargetKind::Yaml => "bslive.yml",
-        TargetKind::Toml => todo!("toml missing"),
+        TargetKind::Toml => "bslive.toml",
         TargetKind::Md => "bslive.md",
         TargetKind::Html => "bslive.html",
     };
 
     fs_write_str(cwd, &PathBuf::from(name), &string, write_mode)
+        .map_err(|e| Box::new(InputError::InputWriteError(e)))
 }
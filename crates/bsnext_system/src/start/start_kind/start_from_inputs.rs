@@ -1,6 +1,7 @@
 use bsnext_input::startup::{StartupContext, SystemStart, SystemStartArgs};
 
 use crate::input_fs::from_input_path;
+use bsnext_input::input_fs::AUTO_LOOKUPS;
 use bsnext_input::{Input, InputArgs, InputCtx, InputError, WatchGlobalConfig};
 use std::path::{Path, PathBuf};
 
@@ -42,7 +43,7 @@ fn from_input_paths<T: AsRef<str>>(
         .map(|path| cwd.join(path.as_ref()))
         .collect::<Vec<PathBuf>>();
 
-    let lookups = ["bslive.yml", "bslive.yaml", "bslive.md", "bslive.html"]
+    let lookups = AUTO_LOOKUPS
         .iter()
         .map(|path| cwd.join(path))
         .collect::<Vec<PathBuf>>();
@@ -101,6 +102,10 @@ fn from_input_paths<T: AsRef<str>>(
                 path: input_path.to_path_buf(),
                 input_error: InputError::YamlError(yaml_error),
             }),
+            InputError::TomlError(toml_error) => Ok(SystemStartArgs::PathWithInvalidInput {
+                path: input_path.to_path_buf(),
+                input_error: InputError::TomlError(toml_error),
+            }),
             InputError::BsLiveRules(bs_live_rules) => Ok(SystemStartArgs::PathWithInvalidInput {
                 path: input_path.to_path_buf(),
                 input_error: InputError::BsLiveRules(bs_live_rules),
@@ -156,6 +156,8 @@ fn from_dir_paths<T: AsRef<str>>(
                     kind: RouteKind::Dir(DirRoute {
                         dir: str.into(),
                         base: None,
+                        spa: None,
+                        listing: None,
                     }),
                     opts: route_opts.clone(),
                     ..Default::default()
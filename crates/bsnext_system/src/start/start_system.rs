@@ -10,6 +10,7 @@ use crate::system::{
 use actix::{
     Actor, ActorContext, ActorFutureExt, AsyncContext, Handler, ResponseActFuture, WrapFuture,
 };
+use bsnext_core::servers_supervisor::stop_handler::StopServers;
 use bsnext_dto::internal::{AnyEvent, ChildResult, InternalEvents};
 use bsnext_dto::{DidStart, StartupError};
 use bsnext_input::startup::{RunMode, SystemStart, SystemStartArgs};
@@ -206,10 +207,21 @@ impl Handler<Start> for BsSystem {
 pub struct StopSystem;
 
 impl Handler<StopSystem> for BsSystem {
-    type Result = ();
-
-    fn handle(&mut self, _msg: StopSystem, ctx: &mut Self::Context) -> Self::Result {
-        tracing::trace!("handling StopSystem. Note: not graceful.");
-        ctx.stop();
+    type Result = ResponseActFuture<Self, ()>;
+
+    fn handle(&mut self, _msg: StopSystem, _ctx: &mut Self::Context) -> Self::Result {
+        tracing::trace!("handling StopSystem");
+        // servers are stopped first, giving them a chance to flush anything like recorded requests
+        Box::pin(
+            self.servers()
+                .send(StopServers)
+                .into_actor(self)
+                .map(|res, _actor, ctx| {
+                    if let Err(e) = res {
+                        tracing::debug!(?e, "could not stop servers");
+                    }
+                    ctx.stop();
+                }),
+        )
     }
 }
@@ -33,6 +33,7 @@ pub struct BsSystem {
     pub(crate) invoker_addr: Addr<Invoker>,
     pub(crate) cwd: PathBuf,
     pub(crate) start_context: StartupContext,
+    pub(crate) input: Option<Input>,
 }
 
 impl Actor for BsSystem {
@@ -70,7 +71,7 @@ impl BsSystem {
         cwd: PathBuf,
         tx: tokio::sync::oneshot::Sender<()>,
     ) -> Self {
-        let servers = ServersSupervisor::new(tx);
+        let servers = ServersSupervisor::new(tx, any_event_sender.clone());
         let servers_addr = servers.start();
         let capabilities = Capabilities::new(any_event_sender.clone(), servers_addr.clone());
         let capabilities_addr = capabilities.start();
@@ -95,6 +96,7 @@ impl BsSystem {
             fs_task_tracker,
             cwd,
             start_context,
+            input: None,
         }
     }
 
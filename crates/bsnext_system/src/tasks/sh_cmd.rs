@@ -279,12 +279,15 @@ async fn sh_cmd(
     let mut stdout_reader = BufReader::new(stdout).lines();
     let mut stderr_reader = BufReader::new(stderr).lines();
 
+    let stdout_node_path = node_path.clone();
+    let stderr_node_path = node_path.clone();
+
     let h = tokio::spawn(async move {
         tracing::debug!(?pid, "reading stdout");
         while let Ok(Some(line)) = stdout_reader.next_line().await {
             match sender
                 .send(TaggedEvent::new(AnyEvent::External(
-                    ExternalEventsDTO::stdout_line(line, (*sh_prefix).clone()),
+                    ExternalEventsDTO::stdout_line(line, (*sh_prefix).clone(), &stdout_node_path),
                 )))
                 .await
             {
@@ -299,7 +302,7 @@ async fn sh_cmd(
         while let Ok(Some(line)) = stderr_reader.next_line().await {
             match sender2
                 .send(TaggedEvent::new(AnyEvent::External(
-                    ExternalEventsDTO::stderr_line(line, (*sh_prefix_2).clone()),
+                    ExternalEventsDTO::stderr_line(line, (*sh_prefix_2).clone(), &stderr_node_path),
                 )))
                 .await
             {
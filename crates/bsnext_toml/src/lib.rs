@@ -0,0 +1,2 @@
+pub mod toml_fs;
+pub mod toml_writer;
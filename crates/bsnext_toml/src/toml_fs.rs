@@ -0,0 +1,62 @@
+use bsnext_input::tml::{line_and_column, TomlError};
+use bsnext_input::{BsLiveRulesError, Input, InputCreation, InputCtx, InputError};
+use miette::NamedSource;
+use std::fs::read_to_string;
+use std::path::Path;
+
+pub struct TomlFs;
+
+impl InputCreation for TomlFs {
+    fn from_input_path<P: AsRef<Path>>(path: P, _ctx: &InputCtx) -> Result<Input, Box<InputError>> {
+        let str = read_to_string(&path).map_err(|e| Box::new(e.into()))?;
+        if str.trim().is_empty() {
+            return Err(Box::new(InputError::TomlError(TomlError::EmptyError {
+                path: path.as_ref().to_string_lossy().to_string(),
+            })));
+        }
+        let output = toml::from_str::<Input>(str.as_str()).map_err(move |e| {
+            let err = match e.span() {
+                Some(span) => InputError::BsLiveRules(BsLiveRulesError {
+                    err_span: (span.start..span.end.max(span.start + 1)).into(),
+                    src: NamedSource::new(path.as_ref().to_string_lossy(), str),
+                    message: e.message().to_string(),
+                    summary: None,
+                }),
+                None => InputError::TomlError(TomlError::ParseError {
+                    path: path.as_ref().to_string_lossy().to_string(),
+                    input: str,
+                    serde_error: e,
+                }),
+            };
+            Box::new(err)
+        })?;
+        output.validated()
+    }
+
+    fn from_input_str<P: AsRef<str>>(
+        content: P,
+        _ctx: &InputCtx,
+    ) -> Result<Input, Box<InputError>> {
+        let input = content.as_ref();
+        toml::from_str::<Input>(input)
+            .map_err(|e| {
+                let err = match e.span() {
+                    Some(span) => {
+                        let (line, column) = line_and_column(input, span.start);
+                        TomlError::ParseRawInputErrorWithLocation {
+                            input: input.to_string(),
+                            serde_error: e,
+                            line,
+                            column,
+                        }
+                    }
+                    None => TomlError::ParseRawInputError {
+                        input: input.to_string(),
+                        serde_error: e,
+                    },
+                };
+                Box::new(InputError::TomlError(err))
+            })?
+            .validated()
+    }
+}
@@ -0,0 +1,16 @@
+use bsnext_input::tml::TomlError;
+use bsnext_input::{Input, InputError, InputWriter};
+
+pub struct TomlWriter;
+
+impl InputWriter for TomlWriter {
+    fn input_to_str(&self, input: &Input) -> Result<String, Box<InputError>> {
+        input_to_str(input)
+    }
+}
+
+/// Not everything can be written as toml, eg: a `null` inside a json body
+fn input_to_str(input: &Input) -> Result<String, Box<InputError>> {
+    toml::to_string_pretty(&input)
+        .map_err(|serde_error| Box::new(TomlError::SerializeError { serde_error }.into()))
+}
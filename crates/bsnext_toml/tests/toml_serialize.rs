@@ -0,0 +1,92 @@
+use bsnext_input::route::{JsonWrapper, RouteKind};
+use bsnext_input::server_config::ServerIdentity;
+use bsnext_input::{InputCreation, InputError, InputWriter};
+use bsnext_toml::toml_fs::TomlFs;
+use bsnext_toml::toml_writer::TomlWriter;
+
+#[test]
+fn test_input_from_str() -> anyhow::Result<()> {
+    let input_str = include_str!("../../../examples/toml/bslive.toml");
+    let input = TomlFs::from_input_str(input_str, &Default::default()).expect("unwrap");
+    let first = input.servers.first().expect("one server");
+    assert_eq!(
+        first.identity,
+        ServerIdentity::PortNamed {
+            port: 3000,
+            name: "toml".to_string()
+        }
+    );
+    assert_eq!(first.routes.len(), 4);
+    assert!(matches!(first.routes[2].kind, RouteKind::Dir(..)));
+    Ok(())
+}
+
+#[test]
+fn test_input_to_str() -> anyhow::Result<()> {
+    let input_str = include_str!("../../../examples/toml/bslive.toml");
+    let input = TomlFs::from_input_str(input_str, &Default::default()).expect("unwrap");
+    let output = TomlWriter.input_to_str(&input).expect("to toml");
+    let input = TomlFs::from_input_str(&output, &Default::default()).expect("unwrapped 2");
+    assert_eq!(input.servers.len(), 1);
+    assert_eq!(input.servers.first().unwrap().routes.len(), 4);
+    Ok(())
+}
+
+#[test]
+fn test_unrepresentable_input_to_str() -> anyhow::Result<()> {
+    let input_str = include_str!("../../../examples/toml/bslive.toml");
+    let mut input = TomlFs::from_input_str(input_str, &Default::default()).expect("unwrap");
+    let route = input.servers[0].routes.first_mut().expect("a route");
+    route.kind = RouteKind::new_json(JsonWrapper(serde_json::json!({ "user": null })));
+    let err = TomlWriter
+        .input_to_str(&input)
+        .expect_err("null can't be written as toml");
+    assert!(matches!(
+        *err,
+        InputError::TomlError(bsnext_input::tml::TomlError::SerializeError { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_located_error() -> anyhow::Result<()> {
+    let input_str = r#"
+[[servers]]
+name = "toml"
+
+[[servers.routes]]
+path = "/"
+html = 
+"#;
+    let err = TomlFs::from_input_str(input_str, &Default::default()).expect_err("must fail");
+    match *err {
+        InputError::TomlError(bsnext_input::tml::TomlError::ParseRawInputErrorWithLocation {
+            line,
+            ..
+        }) => assert_eq!(line, 7),
+        other => panic!("unexpected error {other:?}"),
+    }
+    Ok(())
+}
+
+#[test]
+fn test_empty_guard_list() -> anyhow::Result<()> {
+    let input_str = r#"
+[[servers]]
+name = "toml"
+
+[[servers.routes]]
+path = "/"
+html = "hello"
+when = { any = [] }
+"#;
+    let err = TomlFs::from_input_str(input_str, &Default::default()).expect_err("must fail");
+    match *err {
+        InputError::InvalidInput(message) => assert_eq!(
+            message,
+            "`any` must contain at least one guard, at `servers[0].routes[0].when.any`"
+        ),
+        other => panic!("unexpected error {other:?}"),
+    }
+    Ok(())
+}
@@ -30,15 +30,17 @@ pub fn create_filter_and_fmt(
                 .with_writer(file)
                 .boxed()
         }
-        (OutputFormat::Normal, WriteOption::None) | (OutputFormat::Tui, WriteOption::None) => {
-            tracing_subscriber::fmt::layer()
-                .without_time()
-                .with_ansi(true)
-                .with_target(true)
-                .with_file(include_lines)
-                .with_line_number(include_lines)
-                .boxed()
-        }
+        // the dashboard owns the terminal, so logs are only kept when written to a file
+        (OutputFormat::Tui, WriteOption::None) => tracing_subscriber::fmt::layer()
+            .with_writer(std::io::sink)
+            .boxed(),
+        (OutputFormat::Normal, WriteOption::None) => tracing_subscriber::fmt::layer()
+            .without_time()
+            .with_ansi(true)
+            .with_target(true)
+            .with_file(include_lines)
+            .with_line_number(include_lines)
+            .boxed(),
         (OutputFormat::Normal, WriteOption::File) | (OutputFormat::Tui, WriteOption::File) => {
             let file = File::create("bslive.log").expect("create bslive.log");
             tracing_subscriber::fmt::layer()
@@ -1,2 +1,3 @@
 pub mod yaml_fs;
+mod yaml_location;
 pub mod yaml_writer;
@@ -1,3 +1,4 @@
+use crate::yaml_location::locate;
 use bsnext_input::yml::YamlError;
 use bsnext_input::{BsLiveRulesError, Input, InputCreation, InputCtx, InputError};
 use miette::NamedSource;
@@ -15,11 +16,11 @@ impl InputCreation for YamlFs {
             })));
         }
         let output = serde_yaml::from_str::<Input>(str.as_str())
-            .map_err(move |e| {
+            .map_err(|e| {
                 if let Some(loc) = e.location() {
                     BsLiveRulesError {
                         err_span: (loc.index()..loc.index() + 1).into(),
-                        src: NamedSource::new(path.as_ref().to_string_lossy(), str),
+                        src: NamedSource::new(path.as_ref().to_string_lossy(), str.clone()),
                         message: e.to_string(),
                         summary: None,
                     }
@@ -28,6 +29,15 @@ impl InputCreation for YamlFs {
                 }
             })
             .map_err(|e| Box::new(e.into()))?;
+        if let Err(e) = output.validate_guards() {
+            let index = locate(&str, &e.path).map_or(0, |loc| loc.index());
+            return Err(Box::new(InputError::BsLiveRules(BsLiveRulesError {
+                err_span: (index..index + 1).into(),
+                src: NamedSource::new(path.as_ref().to_string_lossy(), str),
+                message: e.to_string(),
+                summary: None,
+            })));
+        }
         // todo: don't allow duplicates?.
         Ok(output)
     }
@@ -0,0 +1,112 @@
+use bsnext_input::when_guard::InputPathSegment;
+use serde::de::{DeserializeSeed, Error, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::Deserializer;
+use std::fmt::Formatter;
+
+///
+/// Find where a value lives in the original YAML, for errors that are only
+/// discovered after the input has been deserialized.
+///
+/// Flattened and untagged types lose their location, so instead the document is walked
+/// a second time, following `path`, and an error is raised once the value is reached -
+/// `serde_yaml` then attaches the location for us.
+///
+pub fn locate(src: &str, path: &[InputPathSegment]) -> Option<serde_yaml::Location> {
+    let de = serde_yaml::Deserializer::from_str(src);
+    match Locate(path).deserialize(de) {
+        Ok(()) => None,
+        Err(e) => e.location(),
+    }
+}
+
+struct Locate<'a>(&'a [InputPathSegment]);
+
+impl<'de> DeserializeSeed<'de> for Locate<'_> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match self.0.first() {
+            None => Err(D::Error::custom("found")),
+            Some(InputPathSegment::Key(..)) => deserializer.deserialize_map(self),
+            Some(InputPathSegment::Index(..)) => deserializer.deserialize_seq(self),
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for Locate<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "a map or sequence")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let Some((InputPathSegment::Index(wanted), rest)) = self.0.split_first() else {
+            return Ok(());
+        };
+        let mut index = 0;
+        while index < *wanted {
+            if seq.next_element::<IgnoredAny>()?.is_none() {
+                return Ok(());
+            }
+            index += 1;
+        }
+        seq.next_element_seed(Locate(rest))?;
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(())
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let Some((InputPathSegment::Key(wanted), rest)) = self.0.split_first() else {
+            return Ok(());
+        };
+        while let Some(key) = map.next_key::<serde_yaml::Value>()? {
+            if key.as_str() == Some(wanted) {
+                map.next_value_seed(Locate(rest))?;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        let src = r#"
+servers:
+  - name: a
+    routes:
+      - path: /
+        html: hi
+        when:
+          any: []
+"#;
+        let path = [
+            InputPathSegment::Key("servers"),
+            InputPathSegment::Index(0),
+            InputPathSegment::Key("routes"),
+            InputPathSegment::Index(0),
+            InputPathSegment::Key("when"),
+            InputPathSegment::Key("any"),
+        ];
+        let location = locate(src, &path).expect("located");
+        assert_eq!((location.line(), location.column()), (8, 11));
+
+        let missing = [InputPathSegment::Key("servers"), InputPathSegment::Index(3)];
+        assert!(locate(src, &missing).is_none());
+    }
+}
@@ -1,10 +1,10 @@
-use bsnext_input::{Input, InputWriter};
+use bsnext_input::{Input, InputError, InputWriter};
 
 pub struct YamlWriter;
 
 impl InputWriter for YamlWriter {
-    fn input_to_str(&self, input: &Input) -> String {
-        input_to_str(input)
+    fn input_to_str(&self, input: &Input) -> Result<String, Box<InputError>> {
+        Ok(input_to_str(input))
     }
 }
 
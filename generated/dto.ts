@@ -10,6 +10,15 @@ export interface ArchyNode {
 	nodes: ArchyNode[];
 }
 
+/**
+ * An event for some browsers only, by the ids listed at `/__bs_api/clients`.
+ * Browsers receive just the inner `event`
+ */
+export interface AddressedEventDTO {
+	clients: string[];
+	event: ClientEvent;
+}
+
 export enum LogLevelDTO {
 	Info = "info",
 	Debug = "debug",
@@ -17,13 +26,49 @@ export enum LogLevelDTO {
 	Error = "error",
 }
 
+export enum ConsoleLevelDTO {
+	Debug = "debug",
+	Log = "log",
+	Info = "info",
+	Warn = "warn",
+	Error = "error",
+}
+
+/** A [`ConsoleDTO`], along with the browser it came from */
+export interface BrowserConsoleDTO {
+	level: ConsoleLevelDTO;
+	message: string;
+	stack?: string;
+	user_agent?: string;
+	addr: string;
+}
+
+export interface GhostConfigDTO {
+	scroll: boolean;
+	click: boolean;
+	input: boolean;
+	submit: boolean;
+	location: boolean;
+}
+
 export interface ClientConfigDTO {
 	log_level: LogLevelDTO;
+	ghost: GhostConfigDTO;
+	console: ConsoleLevelDTO[];
 }
 
 export interface ConnectInfo {
 	ws_path: string;
 	host?: string;
+	/** Set when the server is protected by a token, so the websocket can pass it along */
+	token?: string;
+}
+
+/** A `console.*` call, uncaught error or unhandled rejection in a browser */
+export interface ConsoleDTO {
+	level: ConsoleLevelDTO;
+	message: string;
+	stack?: string;
 }
 
 export interface DebounceDTO {
@@ -36,6 +81,12 @@ export interface DisplayMessageDTO {
 	reason?: string;
 }
 
+/** An element, found by its tag name and position in the document - eg: the 3rd `input` */
+export interface ElementDTO {
+	tag: string;
+	index: number;
+}
+
 export interface FileChangedDTO {
 	path: string;
 }
@@ -68,6 +119,7 @@ export interface ServerDTO {
 	id: string;
 	identity: ServerIdentityDTO;
 	socket_addr: string;
+	url: string;
 }
 
 export interface GetActiveServersResponseDTO {
@@ -79,10 +131,28 @@ export interface InjectConfig {
 	ctx_message: string;
 }
 
+/** Swap a stylesheet in place, without reloading the page */
+export interface InjectCssDTO {
+	/** Matched against the `href` of each stylesheet, eg: `styles.css` or `/css/app.css` */
+	path: string;
+}
+
+export interface InputDTO {
+	element: ElementDTO;
+	value: string;
+	/** For checkboxes and radios */
+	checked?: boolean;
+}
+
 export interface InputAcceptedDTO {
 	path: string;
 }
 
+export interface LocationDTO {
+	/** Path, query and hash, eg: `/about?tab=1#team` */
+	path: string;
+}
+
 /** @discriminator kind */
 export type RouteKindDTO = 
 	| { kind: "Html", payload: {
@@ -96,6 +166,13 @@ export type RouteKindDTO =
 }}
 	| { kind: "Sse", payload: {
 	sse: SseDTOOpts;
+}}
+	| { kind: "Redirect", payload: {
+	to: string;
+	status: number;
+}}
+	| { kind: "NoContent", payload: {
+	no_content: boolean;
 }}
 	| { kind: "Proxy", payload: {
 	proxy: string;
@@ -108,6 +185,7 @@ export type RouteKindDTO =
 export interface RouteDTO {
 	path: string;
 	kind: RouteKindDTO;
+	status?: number;
 }
 
 /** @discriminator kind */
@@ -121,6 +199,15 @@ export type ServerChange =
 	error: string;
 }};
 
+/**
+ * Scroll position as a share (0 to 1) of the scrollable distance, so that pages line up
+ * across different screen sizes
+ */
+export interface ScrollDTO {
+	x: number;
+	y: number;
+}
+
 export interface ServerChangeSetItem {
 	identity: ServerIdentityDTO;
 	change: ServerChange;
@@ -146,11 +233,48 @@ export interface SseDTOOpts {
 export interface StderrLineDTO {
 	line: string;
 	prefix?: string;
+	node_path?: string;
 }
 
 export interface StdoutLineDTO {
 	line: string;
 	prefix?: string;
+	node_path?: string;
+}
+
+/** A browser connected to a server over the websocket */
+export interface ClientDTO {
+	id: string;
+	addr: string;
+	user_agent?: string;
+	/** Path, query and hash of the page the browser is on */
+	url?: string;
+	/** RFC 3339, eg: `2024-01-01T00:00:00.000Z` */
+	connected_at: string;
+}
+
+export interface ConnectedClientsDTO {
+	clients: ClientDTO[];
+}
+
+export enum ProxyErrorKindDTO {
+	ConnectTimeout = "connect_timeout",
+	ResponseTimeout = "response_timeout",
+	Connect = "connect",
+	Other = "other",
+}
+
+export interface ProxyErrorDTO {
+	path: string;
+	target: string;
+	kind: ProxyErrorKindDTO;
+	message: string;
+	attempts: number;
+}
+
+export interface ProxyWsDTO {
+	path: string;
+	target: string;
 }
 
 export interface StoppedWatchingDTO {
@@ -211,9 +335,19 @@ export type ChangeDTO =
 	| { kind: "Fs", payload: {
 	path: string;
 	change_kind: ChangeKind;
+	/** What browsers should do about the change, decided by the server from the file type */
+	effect?: ChangeEffect;
+	/** The url paths this file is served at, taken from the `dir` routes */
+	urls?: string[];
 }}
 	| { kind: "FsMany", payload: ChangeDTO[] };
 
+export enum ChangeEffect {
+	InjectCss = "InjectCss",
+	SwapImage = "SwapImage",
+	ReloadPage = "ReloadPage",
+}
+
 export enum ChangeKind {
 	Changed = "Changed",
 	Added = "Added",
@@ -225,7 +359,16 @@ export type ClientEvent =
 	| { kind: "Change", payload: ChangeDTO }
 	| { kind: "WsConnection", payload: ClientConfigDTO }
 	| { kind: "Config", payload: ClientConfigDTO }
-	| { kind: "DisplayMessage", payload: DisplayMessageDTO };
+	| { kind: "DisplayMessage", payload: DisplayMessageDTO }
+	| { kind: "Scroll", payload: ScrollDTO }
+	| { kind: "Click", payload: ElementDTO }
+	| { kind: "Input", payload: InputDTO }
+	| { kind: "Submit", payload: ElementDTO }
+	| { kind: "Location", payload: LocationDTO }
+	| { kind: "Console", payload: ConsoleDTO }
+	| { kind: "Addressed", payload: AddressedEventDTO }
+	| { kind: "Reload", payload?: undefined }
+	| { kind: "InjectCss", payload: InjectCssDTO };
 
 export enum EventLevel {
 	External = "BSLIVE_EXTERNAL",
@@ -243,7 +386,13 @@ export type ExternalEventsDTO =
 	| { kind: "OutputLine", payload: OutputLineDTO }
 	| { kind: "TaskAction", payload: TaskActionDTO }
 	| { kind: "TaskTreePreview", payload: TaskTreePreview }
-	| { kind: "TaskTreeSummary", payload: TaskTreeSummary };
+	| { kind: "TaskTreeSummary", payload: TaskTreeSummary }
+	| { kind: "ProxyWsOpened", payload: ProxyWsDTO }
+	| { kind: "ProxyWsClosed", payload: ProxyWsDTO }
+	| { kind: "ProxyError", payload: ProxyErrorDTO }
+	| { kind: "BrowserConsole", payload: BrowserConsoleDTO }
+	| { kind: "ClientConnected", payload: ClientDTO }
+	| { kind: "ClientDisconnected", payload: ClientDTO };
 
 /** @discriminator kind */
 export type InputErrorDTO = 
@@ -255,6 +404,7 @@ export type InputErrorDTO =
 	| { kind: "PortError", payload: string }
 	| { kind: "DirError", payload: string }
 	| { kind: "YamlError", payload: string }
+	| { kind: "TomlError", payload: string }
 	| { kind: "MarkdownError", payload: string }
 	| { kind: "HtmlError", payload: string }
 	| { kind: "Io", payload: string }
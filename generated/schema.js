@@ -9,6 +9,27 @@ var LogLevelDTO = /* @__PURE__ */ ((LogLevelDTO2) => {
   LogLevelDTO2["Error"] = "error";
   return LogLevelDTO2;
 })(LogLevelDTO || {});
+var ConsoleLevelDTO = /* @__PURE__ */ ((ConsoleLevelDTO2) => {
+  ConsoleLevelDTO2["Debug"] = "debug";
+  ConsoleLevelDTO2["Log"] = "log";
+  ConsoleLevelDTO2["Info"] = "info";
+  ConsoleLevelDTO2["Warn"] = "warn";
+  ConsoleLevelDTO2["Error"] = "error";
+  return ConsoleLevelDTO2;
+})(ConsoleLevelDTO || {});
+var ProxyErrorKindDTO = /* @__PURE__ */ ((ProxyErrorKindDTO2) => {
+  ProxyErrorKindDTO2["ConnectTimeout"] = "connect_timeout";
+  ProxyErrorKindDTO2["ResponseTimeout"] = "response_timeout";
+  ProxyErrorKindDTO2["Connect"] = "connect";
+  ProxyErrorKindDTO2["Other"] = "other";
+  return ProxyErrorKindDTO2;
+})(ProxyErrorKindDTO || {});
+var ChangeEffect = /* @__PURE__ */ ((ChangeEffect2) => {
+  ChangeEffect2["InjectCss"] = "InjectCss";
+  ChangeEffect2["SwapImage"] = "SwapImage";
+  ChangeEffect2["ReloadPage"] = "ReloadPage";
+  return ChangeEffect2;
+})(ChangeEffect || {});
 var ChangeKind = /* @__PURE__ */ ((ChangeKind2) => {
   ChangeKind2["Changed"] = "Changed";
   ChangeKind2["Added"] = "Added";
@@ -30,12 +51,35 @@ var archyNodeSchema = z.lazy(
   })
 );
 var logLevelDTOSchema = z.nativeEnum(LogLevelDTO);
+var consoleLevelDTOSchema = z.nativeEnum(ConsoleLevelDTO);
+var browserConsoleDTOSchema = z.object({
+  level: consoleLevelDTOSchema,
+  message: z.string(),
+  stack: z.string().optional(),
+  user_agent: z.string().optional(),
+  addr: z.string()
+});
+var ghostConfigDTOSchema = z.object({
+  scroll: z.boolean(),
+  click: z.boolean(),
+  input: z.boolean(),
+  submit: z.boolean(),
+  location: z.boolean()
+});
 var clientConfigDTOSchema = z.object({
-  log_level: logLevelDTOSchema
+  log_level: logLevelDTOSchema,
+  ghost: ghostConfigDTOSchema,
+  console: z.array(consoleLevelDTOSchema)
 });
 var connectInfoSchema = z.object({
   ws_path: z.string(),
-  host: z.string().optional()
+  host: z.string().optional(),
+  token: z.string().optional()
+});
+var consoleDTOSchema = z.object({
+  level: consoleLevelDTOSchema,
+  message: z.string(),
+  stack: z.string().optional()
 });
 var debounceDTOSchema = z.object({
   kind: z.string(),
@@ -88,7 +132,8 @@ var serverIdentityDTOSchema = z.discriminatedUnion("kind", [
 var serverDTOSchema = z.object({
   id: z.string(),
   identity: serverIdentityDTOSchema,
-  socket_addr: z.string()
+  socket_addr: z.string(),
+  url: z.string()
 });
 var getActiveServersResponseDTOSchema = z.object({
   servers: z.array(serverDTOSchema)
@@ -128,6 +173,19 @@ var routeKindDTOSchema = z.discriminatedUnion("kind", [
       sse: sseDTOOptsSchema
     })
   }),
+  z.object({
+    kind: z.literal("Redirect"),
+    payload: z.object({
+      to: z.string(),
+      status: z.number()
+    })
+  }),
+  z.object({
+    kind: z.literal("NoContent"),
+    payload: z.object({
+      no_content: z.boolean()
+    })
+  }),
   z.object({
     kind: z.literal("Proxy"),
     payload: z.object({
@@ -173,18 +231,43 @@ var serverChangeSetSchema = z.object({
 });
 var routeDTOSchema = z.object({
   path: z.string(),
-  kind: routeKindDTOSchema
+  kind: routeKindDTOSchema,
+  status: z.number().optional()
 });
 var serversChangedDTOSchema = z.object({
   servers_resp: getActiveServersResponseDTOSchema
 });
 var stderrLineDTOSchema = z.object({
   line: z.string(),
-  prefix: z.string().optional()
+  prefix: z.string().optional(),
+  node_path: z.string().optional()
 });
 var stdoutLineDTOSchema = z.object({
   line: z.string(),
-  prefix: z.string().optional()
+  prefix: z.string().optional(),
+  node_path: z.string().optional()
+});
+var clientDTOSchema = z.object({
+  id: z.string(),
+  addr: z.string(),
+  user_agent: z.string().optional(),
+  url: z.string().optional(),
+  connected_at: z.string()
+});
+var connectedClientsDTOSchema = z.object({
+  clients: z.array(clientDTOSchema)
+});
+var proxyErrorKindDTOSchema = z.nativeEnum(ProxyErrorKindDTO);
+var proxyErrorDTOSchema = z.object({
+  path: z.string(),
+  target: z.string(),
+  kind: proxyErrorKindDTOSchema,
+  message: z.string(),
+  attempts: z.number()
+});
+var proxyWsDTOSchema = z.object({
+  path: z.string(),
+  target: z.string()
 });
 var stoppedWatchingDTOSchema = z.object({
   paths: z.array(z.string())
@@ -212,13 +295,16 @@ var watchingDTOSchema = z.object({
   debounce: debounceDTOSchema
 });
 var changeKindSchema = z.nativeEnum(ChangeKind);
+var changeEffectSchema = z.nativeEnum(ChangeEffect);
 var changeDTOSchema = z.lazy(
   () => z.discriminatedUnion("kind", [
     z.object({
       kind: z.literal("Fs"),
       payload: z.object({
         path: z.string(),
-        change_kind: changeKindSchema
+        change_kind: changeKindSchema,
+        effect: changeEffectSchema.optional(),
+        urls: z.array(z.string()).optional()
       })
     }),
     z.object({
@@ -227,24 +313,87 @@ var changeDTOSchema = z.lazy(
     })
   ])
 );
-var clientEventSchema = z.discriminatedUnion("kind", [
-  z.object({
-    kind: z.literal("Change"),
-    payload: changeDTOSchema
-  }),
-  z.object({
-    kind: z.literal("WsConnection"),
-    payload: clientConfigDTOSchema
-  }),
-  z.object({
-    kind: z.literal("Config"),
-    payload: clientConfigDTOSchema
-  }),
-  z.object({
-    kind: z.literal("DisplayMessage"),
-    payload: displayMessageDTOSchema
+var elementDTOSchema = z.object({
+  tag: z.string(),
+  index: z.number()
+});
+var injectCssDTOSchema = z.object({
+  path: z.string()
+});
+var inputDTOSchema = z.object({
+  element: elementDTOSchema,
+  value: z.string(),
+  checked: z.boolean().optional()
+});
+var locationDTOSchema = z.object({
+  path: z.string()
+});
+var scrollDTOSchema = z.object({
+  x: z.number(),
+  y: z.number()
+});
+var addressedEventDTOSchema = z.lazy(
+  () => z.object({
+    clients: z.array(z.string()),
+    event: clientEventSchema
   })
-]);
+);
+var clientEventSchema = z.lazy(
+  () => z.discriminatedUnion("kind", [
+    z.object({
+      kind: z.literal("Change"),
+      payload: changeDTOSchema
+    }),
+    z.object({
+      kind: z.literal("WsConnection"),
+      payload: clientConfigDTOSchema
+    }),
+    z.object({
+      kind: z.literal("Config"),
+      payload: clientConfigDTOSchema
+    }),
+    z.object({
+      kind: z.literal("DisplayMessage"),
+      payload: displayMessageDTOSchema
+    }),
+    z.object({
+      kind: z.literal("Scroll"),
+      payload: scrollDTOSchema
+    }),
+    z.object({
+      kind: z.literal("Click"),
+      payload: elementDTOSchema
+    }),
+    z.object({
+      kind: z.literal("Input"),
+      payload: inputDTOSchema
+    }),
+    z.object({
+      kind: z.literal("Submit"),
+      payload: elementDTOSchema
+    }),
+    z.object({
+      kind: z.literal("Location"),
+      payload: locationDTOSchema
+    }),
+    z.object({
+      kind: z.literal("Console"),
+      payload: consoleDTOSchema
+    }),
+    z.object({
+      kind: z.literal("Addressed"),
+      payload: addressedEventDTOSchema
+    }),
+    z.object({
+      kind: z.literal("Reload"),
+      payload: z.undefined().optional()
+    }),
+    z.object({
+      kind: z.literal("InjectCss"),
+      payload: injectCssDTOSchema
+    })
+  ])
+);
 var eventLevelSchema = z.nativeEnum(EventLevel);
 var outputLineDTOSchema = z.discriminatedUnion("kind", [
   z.object({
@@ -289,6 +438,10 @@ var inputErrorDTOSchema = z.discriminatedUnion("kind", [
     kind: z.literal("YamlError"),
     payload: z.string()
   }),
+  z.object({
+    kind: z.literal("TomlError"),
+    payload: z.string()
+  }),
   z.object({
     kind: z.literal("MarkdownError"),
     payload: z.string()
@@ -440,32 +593,72 @@ var externalEventsDTOSchema = z.lazy(
     z.object({
       kind: z.literal("TaskTreeSummary"),
       payload: taskTreeSummarySchema
+    }),
+    z.object({
+      kind: z.literal("ProxyWsOpened"),
+      payload: proxyWsDTOSchema
+    }),
+    z.object({
+      kind: z.literal("ProxyWsClosed"),
+      payload: proxyWsDTOSchema
+    }),
+    z.object({
+      kind: z.literal("ProxyError"),
+      payload: proxyErrorDTOSchema
+    }),
+    z.object({
+      kind: z.literal("BrowserConsole"),
+      payload: browserConsoleDTOSchema
+    }),
+    z.object({
+      kind: z.literal("ClientConnected"),
+      payload: clientDTOSchema
+    }),
+    z.object({
+      kind: z.literal("ClientDisconnected"),
+      payload: clientDTOSchema
     })
   ])
 );
 export {
+  addressedEventDTOSchema,
   archyNodeSchema,
+  browserConsoleDTOSchema,
   changeDTOSchema,
+  changeEffectSchema,
   changeKindSchema,
   clientConfigDTOSchema,
+  clientDTOSchema,
   clientEventSchema,
   connectInfoSchema,
+  connectedClientsDTOSchema,
+  consoleDTOSchema,
+  consoleLevelDTOSchema,
   debounceDTOSchema,
   displayMessageDTOSchema,
+  elementDTOSchema,
   eventLevelSchema,
   externalEventsDTOSchema,
   fileChangedDTOSchema,
   filesChangedDTOSchema,
   getActiveServersResponseDTOSchema,
+  ghostConfigDTOSchema,
   injectConfigSchema,
+  injectCssDTOSchema,
   inputAcceptedDTOSchema,
+  inputDTOSchema,
   inputErrorDTOSchema,
   internalEventsDTOSchema,
   invocationIdDTOSchema,
+  locationDTOSchema,
   logLevelDTOSchema,
   outputLineDTOSchema,
+  proxyErrorDTOSchema,
+  proxyErrorKindDTOSchema,
+  proxyWsDTOSchema,
   routeDTOSchema,
   routeKindDTOSchema,
+  scrollDTOSchema,
   serverChangeSchema,
   serverChangeSetItemSchema,
   serverChangeSetSchema,
@@ -1,7 +1,9 @@
 // Generated by ts-to-zod
 import { z } from "zod";
 import {
+    type AddressedEventDTO,
     type ArchyNode,
+    type ClientEvent,
     type ChangeDTO,
     type TaskActionStageDTO,
     type TaskReportDTO,
@@ -10,6 +12,9 @@ import {
     type TaskTreeSummary,
     type ExternalEventsDTO,
     LogLevelDTO,
+    ConsoleLevelDTO,
+    ProxyErrorKindDTO,
+    ChangeEffect,
     ChangeKind,
     EventLevel,
 } from "./dto";
@@ -26,13 +31,40 @@ export const archyNodeSchema: z.ZodSchema<ArchyNode> = z.lazy(() =>
 
 export const logLevelDTOSchema = z.nativeEnum(LogLevelDTO);
 
+export const consoleLevelDTOSchema = z.nativeEnum(ConsoleLevelDTO);
+
+export const browserConsoleDTOSchema = z.object({
+    level: consoleLevelDTOSchema,
+    message: z.string(),
+    stack: z.string().optional(),
+    user_agent: z.string().optional(),
+    addr: z.string(),
+});
+
+export const ghostConfigDTOSchema = z.object({
+    scroll: z.boolean(),
+    click: z.boolean(),
+    input: z.boolean(),
+    submit: z.boolean(),
+    location: z.boolean(),
+});
+
 export const clientConfigDTOSchema = z.object({
     log_level: logLevelDTOSchema,
+    ghost: ghostConfigDTOSchema,
+    console: z.array(consoleLevelDTOSchema),
 });
 
 export const connectInfoSchema = z.object({
     ws_path: z.string(),
     host: z.string().optional(),
+    token: z.string().optional(),
+});
+
+export const consoleDTOSchema = z.object({
+    level: consoleLevelDTOSchema,
+    message: z.string(),
+    stack: z.string().optional(),
 });
 
 export const debounceDTOSchema = z.object({
@@ -92,6 +124,7 @@ export const serverDTOSchema = z.object({
     id: z.string(),
     identity: serverIdentityDTOSchema,
     socket_addr: z.string(),
+    url: z.string(),
 });
 
 export const getActiveServersResponseDTOSchema = z.object({
@@ -136,6 +169,19 @@ export const routeKindDTOSchema = z.discriminatedUnion("kind", [
             sse: sseDTOOptsSchema,
         }),
     }),
+    z.object({
+        kind: z.literal("Redirect"),
+        payload: z.object({
+            to: z.string(),
+            status: z.number(),
+        }),
+    }),
+    z.object({
+        kind: z.literal("NoContent"),
+        payload: z.object({
+            no_content: z.boolean(),
+        }),
+    }),
     z.object({
         kind: z.literal("Proxy"),
         payload: z.object({
@@ -186,6 +232,7 @@ export const serverChangeSetSchema = z.object({
 export const routeDTOSchema = z.object({
     path: z.string(),
     kind: routeKindDTOSchema,
+    status: z.number().optional(),
 });
 
 export const serversChangedDTOSchema = z.object({
@@ -195,11 +242,40 @@ export const serversChangedDTOSchema = z.object({
 export const stderrLineDTOSchema = z.object({
     line: z.string(),
     prefix: z.string().optional(),
+    node_path: z.string().optional(),
 });
 
 export const stdoutLineDTOSchema = z.object({
     line: z.string(),
     prefix: z.string().optional(),
+    node_path: z.string().optional(),
+});
+
+export const clientDTOSchema = z.object({
+    id: z.string(),
+    addr: z.string(),
+    user_agent: z.string().optional(),
+    url: z.string().optional(),
+    connected_at: z.string(),
+});
+
+export const connectedClientsDTOSchema = z.object({
+    clients: z.array(clientDTOSchema),
+});
+
+export const proxyErrorKindDTOSchema = z.nativeEnum(ProxyErrorKindDTO);
+
+export const proxyErrorDTOSchema = z.object({
+    path: z.string(),
+    target: z.string(),
+    kind: proxyErrorKindDTOSchema,
+    message: z.string(),
+    attempts: z.number(),
+});
+
+export const proxyWsDTOSchema = z.object({
+    path: z.string(),
+    target: z.string(),
 });
 
 export const stoppedWatchingDTOSchema = z.object({
@@ -233,6 +309,8 @@ export const watchingDTOSchema = z.object({
 
 export const changeKindSchema = z.nativeEnum(ChangeKind);
 
+export const changeEffectSchema = z.nativeEnum(ChangeEffect);
+
 export const changeDTOSchema: z.ZodSchema<ChangeDTO> = z.lazy(() =>
     z.discriminatedUnion("kind", [
         z.object({
@@ -240,6 +318,8 @@ export const changeDTOSchema: z.ZodSchema<ChangeDTO> = z.lazy(() =>
             payload: z.object({
                 path: z.string(),
                 change_kind: changeKindSchema,
+                effect: changeEffectSchema.optional(),
+                urls: z.array(z.string()).optional(),
             }),
         }),
         z.object({
@@ -249,24 +329,94 @@ export const changeDTOSchema: z.ZodSchema<ChangeDTO> = z.lazy(() =>
     ]),
 );
 
-export const clientEventSchema = z.discriminatedUnion("kind", [
-    z.object({
-        kind: z.literal("Change"),
-        payload: changeDTOSchema,
-    }),
-    z.object({
-        kind: z.literal("WsConnection"),
-        payload: clientConfigDTOSchema,
-    }),
-    z.object({
-        kind: z.literal("Config"),
-        payload: clientConfigDTOSchema,
-    }),
-    z.object({
-        kind: z.literal("DisplayMessage"),
-        payload: displayMessageDTOSchema,
-    }),
-]);
+export const elementDTOSchema = z.object({
+    tag: z.string(),
+    index: z.number(),
+});
+
+export const injectCssDTOSchema = z.object({
+    path: z.string(),
+});
+
+export const inputDTOSchema = z.object({
+    element: elementDTOSchema,
+    value: z.string(),
+    checked: z.boolean().optional(),
+});
+
+export const locationDTOSchema = z.object({
+    path: z.string(),
+});
+
+export const scrollDTOSchema = z.object({
+    x: z.number(),
+    y: z.number(),
+});
+
+export const addressedEventDTOSchema: z.ZodSchema<AddressedEventDTO> = z.lazy(
+    () =>
+        z.object({
+            clients: z.array(z.string()),
+            event: clientEventSchema,
+        }),
+);
+
+export const clientEventSchema: z.ZodSchema<ClientEvent> = z.lazy(() =>
+    z.discriminatedUnion("kind", [
+        z.object({
+            kind: z.literal("Change"),
+            payload: changeDTOSchema,
+        }),
+        z.object({
+            kind: z.literal("WsConnection"),
+            payload: clientConfigDTOSchema,
+        }),
+        z.object({
+            kind: z.literal("Config"),
+            payload: clientConfigDTOSchema,
+        }),
+        z.object({
+            kind: z.literal("DisplayMessage"),
+            payload: displayMessageDTOSchema,
+        }),
+        z.object({
+            kind: z.literal("Scroll"),
+            payload: scrollDTOSchema,
+        }),
+        z.object({
+            kind: z.literal("Click"),
+            payload: elementDTOSchema,
+        }),
+        z.object({
+            kind: z.literal("Input"),
+            payload: inputDTOSchema,
+        }),
+        z.object({
+            kind: z.literal("Submit"),
+            payload: elementDTOSchema,
+        }),
+        z.object({
+            kind: z.literal("Location"),
+            payload: locationDTOSchema,
+        }),
+        z.object({
+            kind: z.literal("Console"),
+            payload: consoleDTOSchema,
+        }),
+        z.object({
+            kind: z.literal("Addressed"),
+            payload: addressedEventDTOSchema,
+        }),
+        z.object({
+            kind: z.literal("Reload"),
+            payload: z.undefined().optional(),
+        }),
+        z.object({
+            kind: z.literal("InjectCss"),
+            payload: injectCssDTOSchema,
+        }),
+    ]),
+);
 
 export const eventLevelSchema = z.nativeEnum(EventLevel);
 
@@ -314,6 +464,10 @@ export const inputErrorDTOSchema = z.discriminatedUnion("kind", [
         kind: z.literal("YamlError"),
         payload: z.string(),
     }),
+    z.object({
+        kind: z.literal("TomlError"),
+        payload: z.string(),
+    }),
     z.object({
         kind: z.literal("MarkdownError"),
         payload: z.string(),
@@ -477,5 +631,29 @@ export const externalEventsDTOSchema: z.ZodSchema<ExternalEventsDTO> = z.lazy(
                 kind: z.literal("TaskTreeSummary"),
                 payload: taskTreeSummarySchema,
             }),
+            z.object({
+                kind: z.literal("ProxyWsOpened"),
+                payload: proxyWsDTOSchema,
+            }),
+            z.object({
+                kind: z.literal("ProxyWsClosed"),
+                payload: proxyWsDTOSchema,
+            }),
+            z.object({
+                kind: z.literal("ProxyError"),
+                payload: proxyErrorDTOSchema,
+            }),
+            z.object({
+                kind: z.literal("BrowserConsole"),
+                payload: browserConsoleDTOSchema,
+            }),
+            z.object({
+                kind: z.literal("ClientConnected"),
+                payload: clientDTOSchema,
+            }),
+            z.object({
+                kind: z.literal("ClientDisconnected"),
+                payload: clientDTOSchema,
+            }),
         ]),
 );
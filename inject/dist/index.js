@@ -1,6 +1,6 @@
 var Vi=Object.create;var Er=Object.defineProperty;var cn=Object.getOwnPropertyDescriptor;var Bi=Object.getOwnPropertyNames;var Wi=Object.getPrototypeOf,Hi=Object.prototype.hasOwnProperty;var qi=(r,e)=>()=>(e||r((e={exports:{}}).exports,e),e.exports);var Gi=(r,e,t,n)=>{if(e&&typeof e=="object"||typeof e=="function")for(let i of Bi(e))!Hi.call(r,i)&&i!==t&&Er(r,i,{get:()=>e[i],enumerable:!(n=cn(e,i))||n.enumerable});return r};var Yi=(r,e,t)=>(t=r!=null?Vi(Wi(r)):{},Gi(e||!r||!r.__esModule?Er(t,"default",{value:r,enumerable:!0}):t,r));var W=(r,e,t,n)=>{for(var i=n>1?void 0:n?cn(e,t):e,o=r.length-1,s;o>=0;o--)(s=r[o])&&(i=(n?s(e,t,i):s(i))||i);return n&&i&&Er(e,t,i),i};var hi=qi(pi=>{"use strict";var dr=class{constructor(e){this.func=e,this.running=!1,this.id=null,this._handler=()=>(this.running=!1,this.id=null,this.func())}start(e){this.running&&clearTimeout(this.id),this.id=setTimeout(this._handler,e),this.running=!0}stop(){this.running&&(clearTimeout(this.id),this.running=!1,this.id=null)}};dr.start=(r,e)=>setTimeout(e,r);pi.Timer=dr});var Or=function(r,e){return Or=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(t,n){t.__proto__=n}||function(t,n){for(var i in n)Object.prototype.hasOwnProperty.call(n,i)&&(t[i]=n[i])},Or(r,e)};function R(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");Or(r,e);function t(){this.constructor=r}r.prototype=e===null?Object.create(e):(t.prototype=e.prototype,new t)}var jt=function(){return jt=Object.assign||function(e){for(var t,n=1,i=arguments.length;n<i;n++){t=arguments[n];for(var o in t)Object.prototype.hasOwnProperty.call(t,o)&&(e[o]=t[o])}return e},jt.apply(this,arguments)};function ln(r,e,t,n){function i(o){return o instanceof t?o:new t(function(s){s(o)})}return new(t||(t=Promise))(function(o,s){function c(u){try{d(n.next(u))}catch(p){s(p)}}function l(u){try{d(n.throw(u))}catch(p){s(p)}}function d(u){u.done?o(u.value):i(u.value).then(c,l)}d((n=n.apply(r,e||[])).next())})}function Rt(r,e){var t={label:0,sent:function(){if(o[0]&1)throw o[1];return o[1]},trys:[],ops:[]},n,i,o,s;return s={next:c(0),throw:c(1),return:c(2)},typeof Symbol=="function"&&(s[Symbol.iterator]=function(){return this}),s;function c(d){return function(u){return l([d,u])}}function l(d){if(n)throw new TypeError("Generator is already executing.");for(;s&&(s=0,d[0]&&(t=0)),t;)try{if(n=1,i&&(o=d[0]&2?i.return:d[0]?i.throw||((o=i.return)&&o.call(i),0):i.next)&&!(o=o.call(i,d[1])).done)return o;switch(i=0,o&&(d=[d[0]&2,o.value]),d[0]){case 0:case 1:o=d;break;case 4:return t.label++,{value:d[1],done:!1};case 5:t.label++,i=d[1],d=[0];continue;case 7:d=t.ops.pop(),t.trys.pop();continue;default:if(o=t.trys,!(o=o.length>0&&o[o.length-1])&&(d[0]===6||d[0]===2)){t=0;continue}if(d[0]===3&&(!o||d[1]>o[0]&&d[1]<o[3])){t.label=d[1];break}if(d[0]===6&&t.label<o[1]){t.label=o[1],o=d;break}if(o&&t.label<o[2]){t.label=o[2],t.ops.push(d);break}o[2]&&t.ops.pop(),t.trys.pop();continue}d=e.call(r,t)}catch(u){d=[6,u],i=0}finally{n=o=0}if(d[0]&5)throw d[1];return{value:d[0]?d[1]:void 0,done:!0}}}function re(r){var e=typeof Symbol=="function"&&Symbol.iterator,t=e&&r[e],n=0;if(t)return t.call(r);if(r&&typeof r.length=="number")return{next:function(){return r&&n>=r.length&&(r=void 0),{value:r&&r[n++],done:!r}}};throw new TypeError(e?"Object is not iterable.":"Symbol.iterator is not defined.")}function H(r,e){var t=typeof Symbol=="function"&&r[Symbol.iterator];if(!t)return r;var n=t.call(r),i,o=[],s;try{for(;(e===void 0||e-- >0)&&!(i=n.next()).done;)o.push(i.value)}catch(c){s={error:c}}finally{try{i&&!i.done&&(t=n.return)&&t.call(n)}finally{if(s)throw s.error}}return o}function q(r,e,t){if(t||arguments.length===2)for(var n=0,i=e.length,o;n<i;n++)(o||!(n in e))&&(o||(o=Array.prototype.slice.call(e,0,n)),o[n]=e[n]);return r.concat(o||Array.prototype.slice.call(e))}function ve(r){return this instanceof ve?(this.v=r,this):new ve(r)}function dn(r,e,t){if(!Symbol.asyncIterator)throw new TypeError("Symbol.asyncIterator is not defined.");var n=t.apply(r,e||[]),i,o=[];return i={},c("next"),c("throw"),c("return",s),i[Symbol.asyncIterator]=function(){return this},i;function s(y){return function(A){return Promise.resolve(A).then(y,p)}}function c(y,A){n[y]&&(i[y]=function(S){return new Promise(function(I,U){o.push([y,S,I,U])>1||l(y,S)})},A&&(i[y]=A(i[y])))}function l(y,A){try{d(n[y](A))}catch(S){w(o[0][3],S)}}function d(y){y.value instanceof ve?Promise.resolve(y.value.v).then(u,p):w(o[0][2],y)}function u(y){l("next",y)}function p(y){l("throw",y)}function w(y,A){y(A),o.shift(),o.length&&l(o[0][0],o[0][1])}}function un(r){if(!Symbol.asyncIterator)throw new TypeError("Symbol.asyncIterator is not defined.");var e=r[Symbol.asyncIterator],t;return e?e.call(r):(r=typeof re=="function"?re(r):r[Symbol.iterator](),t={},n("next"),n("throw"),n("return"),t[Symbol.asyncIterator]=function(){return this},t);function n(o){t[o]=r[o]&&function(s){return new Promise(function(c,l){s=r[o](s),i(c,l,s.done,s.value)})}}function i(o,s,c,l){Promise.resolve(l).then(function(d){o({value:d,done:c})},s)}}function k(r){return typeof r=="function"}function It(r){var e=function(n){Error.call(n),n.stack=new Error().stack},t=r(e);return t.prototype=Object.create(Error.prototype),t.prototype.constructor=t,t}var Pt=It(function(r){return function(t){r(this),this.message=t?t.length+` errors occurred during unsubscription:
 `+t.map(function(n,i){return i+1+") "+n.toString()}).join(`
-  `):"",this.name="UnsubscriptionError",this.errors=t}});function ye(r,e){if(r){var t=r.indexOf(e);0<=t&&r.splice(t,1)}}var X=(function(){function r(e){this.initialTeardown=e,this.closed=!1,this._parentage=null,this._finalizers=null}return r.prototype.unsubscribe=function(){var e,t,n,i,o;if(!this.closed){this.closed=!0;var s=this._parentage;if(s)if(this._parentage=null,Array.isArray(s))try{for(var c=re(s),l=c.next();!l.done;l=c.next()){var d=l.value;d.remove(this)}}catch(S){e={error:S}}finally{try{l&&!l.done&&(t=c.return)&&t.call(c)}finally{if(e)throw e.error}}else s.remove(this);var u=this.initialTeardown;if(k(u))try{u()}catch(S){o=S instanceof Pt?S.errors:[S]}var p=this._finalizers;if(p){this._finalizers=null;try{for(var w=re(p),y=w.next();!y.done;y=w.next()){var A=y.value;try{fn(A)}catch(S){o=o??[],S instanceof Pt?o=q(q([],H(o)),H(S.errors)):o.push(S)}}}catch(S){n={error:S}}finally{try{y&&!y.done&&(i=w.return)&&i.call(w)}finally{if(n)throw n.error}}}if(o)throw new Pt(o)}},r.prototype.add=function(e){var t;if(e&&e!==this)if(this.closed)fn(e);else{if(e instanceof r){if(e.closed||e._hasParent(this))return;e._addParent(this)}(this._finalizers=(t=this._finalizers)!==null&&t!==void 0?t:[]).push(e)}},r.prototype._hasParent=function(e){var t=this._parentage;return t===e||Array.isArray(t)&&t.includes(e)},r.prototype._addParent=function(e){var t=this._parentage;this._parentage=Array.isArray(t)?(t.push(e),t):t?[t,e]:e},r.prototype._removeParent=function(e){var t=this._parentage;t===e?this._parentage=null:Array.isArray(t)&&ye(t,e)},r.prototype.remove=function(e){var t=this._finalizers;t&&ye(t,e),e instanceof r&&e._removeParent(this)},r.EMPTY=(function(){var e=new r;return e.closed=!0,e})(),r})();var Cr=X.EMPTY;function Mt(r){return r instanceof X||r&&"closed"in r&&k(r.remove)&&k(r.add)&&k(r.unsubscribe)}function fn(r){k(r)?r():r.unsubscribe()}var G={onUnhandledError:null,onStoppedNotification:null,Promise:void 0,useDeprecatedSynchronousErrorHandling:!1,useDeprecatedNextContext:!1};var qe={setTimeout:function(r,e){for(var t=[],n=2;n<arguments.length;n++)t[n-2]=arguments[n];var i=qe.delegate;return i?.setTimeout?i.setTimeout.apply(i,q([r,e],H(t))):setTimeout.apply(void 0,q([r,e],H(t)))},clearTimeout:function(r){var e=qe.delegate;return(e?.clearTimeout||clearTimeout)(r)},delegate:void 0};function Dt(r){qe.setTimeout(function(){var e=G.onUnhandledError;if(e)e(r);else throw r})}function dt(){}var pn=(function(){return $r("C",void 0,void 0)})();function hn(r){return $r("E",void 0,r)}function mn(r){return $r("N",r,void 0)}function $r(r,e,t){return{kind:r,value:e,error:t}}var ge=null;function Ge(r){if(G.useDeprecatedSynchronousErrorHandling){var e=!ge;if(e&&(ge={errorThrown:!1,error:null}),r(),e){var t=ge,n=t.errorThrown,i=t.error;if(ge=null,n)throw i}}else r()}function vn(r){G.useDeprecatedSynchronousErrorHandling&&ge&&(ge.errorThrown=!0,ge.error=r)}var _e=(function(r){R(e,r);function e(t){var n=r.call(this)||this;return n.isStopped=!1,t?(n.destination=t,Mt(t)&&t.add(n)):n.destination=Qi,n}return e.create=function(t,n,i){return new be(t,n,i)},e.prototype.next=function(t){this.isStopped?Rr(mn(t),this):this._next(t)},e.prototype.error=function(t){this.isStopped?Rr(hn(t),this):(this.isStopped=!0,this._error(t))},e.prototype.complete=function(){this.isStopped?Rr(pn,this):(this.isStopped=!0,this._complete())},e.prototype.unsubscribe=function(){this.closed||(this.isStopped=!0,r.prototype.unsubscribe.call(this),this.destination=null)},e.prototype._next=function(t){this.destination.next(t)},e.prototype._error=function(t){try{this.destination.error(t)}finally{this.unsubscribe()}},e.prototype._complete=function(){try{this.destination.complete()}finally{this.unsubscribe()}},e})(X);var Ji=Function.prototype.bind;function jr(r,e){return Ji.call(r,e)}var Ki=(function(){function r(e){this.partialObserver=e}return r.prototype.next=function(e){var t=this.partialObserver;if(t.next)try{t.next(e)}catch(n){Nt(n)}},r.prototype.error=function(e){var t=this.partialObserver;if(t.error)try{t.error(e)}catch(n){Nt(n)}else Nt(e)},r.prototype.complete=function(){var e=this.partialObserver;if(e.complete)try{e.complete()}catch(t){Nt(t)}},r})(),be=(function(r){R(e,r);function e(t,n,i){var o=r.call(this)||this,s;if(k(t)||!t)s={next:t??void 0,error:n??void 0,complete:i??void 0};else{var c;o&&G.useDeprecatedNextContext?(c=Object.create(t),c.unsubscribe=function(){return o.unsubscribe()},s={next:t.next&&jr(t.next,c),error:t.error&&jr(t.error,c),complete:t.complete&&jr(t.complete,c)}):s=t}return o.destination=new Ki(s),o}return e})(_e);function Nt(r){G.useDeprecatedSynchronousErrorHandling?vn(r):Dt(r)}function Xi(r){throw r}function Rr(r,e){var t=G.onStoppedNotification;t&&qe.setTimeout(function(){return t(r,e)})}var Qi={closed:!0,next:dt,error:Xi,complete:dt};var Ye=(function(){return typeof Symbol=="function"&&Symbol.observable||"@@observable"})();function le(r){return r}function yn(r){return r.length===0?le:r.length===1?r[0]:function(t){return r.reduce(function(n,i){return i(n)},t)}}var E=(function(){function r(e){e&&(this._subscribe=e)}return r.prototype.lift=function(e){var t=new r;return t.source=this,t.operator=e,t},r.prototype.subscribe=function(e,t,n){var i=this,o=to(e)?e:new be(e,t,n);return Ge(function(){var s=i,c=s.operator,l=s.source;o.add(c?c.call(o,l):l?i._subscribe(o):i._trySubscribe(o))}),o},r.prototype._trySubscribe=function(e){try{return this._subscribe(e)}catch(t){e.error(t)}},r.prototype.forEach=function(e,t){var n=this;return t=gn(t),new t(function(i,o){var s=new be({next:function(c){try{e(c)}catch(l){o(l),s.unsubscribe()}},error:o,complete:i});n.subscribe(s)})},r.prototype._subscribe=function(e){var t;return(t=this.source)===null||t===void 0?void 0:t.subscribe(e)},r.prototype[Ye]=function(){return this},r.prototype.pipe=function(){for(var e=[],t=0;t<arguments.length;t++)e[t]=arguments[t];return yn(e)(this)},r.prototype.toPromise=function(e){var t=this;return e=gn(e),new e(function(n,i){var o;t.subscribe(function(s){return o=s},function(s){return i(s)},function(){return n(o)})})},r.create=function(e){return new r(e)},r})();function gn(r){var e;return(e=r??G.Promise)!==null&&e!==void 0?e:Promise}function eo(r){return r&&k(r.next)&&k(r.error)&&k(r.complete)}function to(r){return r&&r instanceof _e||eo(r)&&Mt(r)}function ro(r){return k(r?.lift)}function C(r){return function(e){if(ro(e))return e.lift(function(t){try{return r(t,this)}catch(n){this.error(n)}});throw new TypeError("Unable to lift unknown Observable type")}}function $(r,e,t,n,i){return new no(r,e,t,n,i)}var no=(function(r){R(e,r);function e(t,n,i,o,s,c){var l=r.call(this,t)||this;return l.onFinalize=s,l.shouldUnsubscribe=c,l._next=n?function(d){try{n(d)}catch(u){t.error(u)}}:r.prototype._next,l._error=o?function(d){try{o(d)}catch(u){t.error(u)}finally{this.unsubscribe()}}:r.prototype._error,l._complete=i?function(){try{i()}catch(d){t.error(d)}finally{this.unsubscribe()}}:r.prototype._complete,l}return e.prototype.unsubscribe=function(){var t;if(!this.shouldUnsubscribe||this.shouldUnsubscribe()){var n=this.closed;r.prototype.unsubscribe.call(this),!n&&((t=this.onFinalize)===null||t===void 0||t.call(this))}},e})(_e);var _n=It(function(r){return function(){r(this),this.name="ObjectUnsubscribedError",this.message="object unsubscribed"}});var Y=(function(r){R(e,r);function e(){var t=r.call(this)||this;return t.closed=!1,t.currentObservers=null,t.observers=[],t.isStopped=!1,t.hasError=!1,t.thrownError=null,t}return e.prototype.lift=function(t){var n=new Lt(this,this);return n.operator=t,n},e.prototype._throwIfClosed=function(){if(this.closed)throw new _n},e.prototype.next=function(t){var n=this;Ge(function(){var i,o;if(n._throwIfClosed(),!n.isStopped){n.currentObservers||(n.currentObservers=Array.from(n.observers));try{for(var s=re(n.currentObservers),c=s.next();!c.done;c=s.next()){var l=c.value;l.next(t)}}catch(d){i={error:d}}finally{try{c&&!c.done&&(o=s.return)&&o.call(s)}finally{if(i)throw i.error}}}})},e.prototype.error=function(t){var n=this;Ge(function(){if(n._throwIfClosed(),!n.isStopped){n.hasError=n.isStopped=!0,n.thrownError=t;for(var i=n.observers;i.length;)i.shift().error(t)}})},e.prototype.complete=function(){var t=this;Ge(function(){if(t._throwIfClosed(),!t.isStopped){t.isStopped=!0;for(var n=t.observers;n.length;)n.shift().complete()}})},e.prototype.unsubscribe=function(){this.isStopped=this.closed=!0,this.observers=this.currentObservers=null},Object.defineProperty(e.prototype,"observed",{get:function(){var t;return((t=this.observers)===null||t===void 0?void 0:t.length)>0},enumerable:!1,configurable:!0}),e.prototype._trySubscribe=function(t){return this._throwIfClosed(),r.prototype._trySubscribe.call(this,t)},e.prototype._subscribe=function(t){return this._throwIfClosed(),this._checkFinalizedStatuses(t),this._innerSubscribe(t)},e.prototype._innerSubscribe=function(t){var n=this,i=this,o=i.hasError,s=i.isStopped,c=i.observers;return o||s?Cr:(this.currentObservers=null,c.push(t),new X(function(){n.currentObservers=null,ye(c,t)}))},e.prototype._checkFinalizedStatuses=function(t){var n=this,i=n.hasError,o=n.thrownError,s=n.isStopped;i?t.error(o):s&&t.complete()},e.prototype.asObservable=function(){var t=new E;return t.source=this,t},e.create=function(t,n){return new Lt(t,n)},e})(E);var Lt=(function(r){R(e,r);function e(t,n){var i=r.call(this)||this;return i.destination=t,i.source=n,i}return e.prototype.next=function(t){var n,i;(i=(n=this.destination)===null||n===void 0?void 0:n.next)===null||i===void 0||i.call(n,t)},e.prototype.error=function(t){var n,i;(i=(n=this.destination)===null||n===void 0?void 0:n.error)===null||i===void 0||i.call(n,t)},e.prototype.complete=function(){var t,n;(n=(t=this.destination)===null||t===void 0?void 0:t.complete)===null||n===void 0||n.call(t)},e.prototype._subscribe=function(t){var n,i;return(i=(n=this.source)===null||n===void 0?void 0:n.subscribe(t))!==null&&i!==void 0?i:Cr},e})(Y);var ut={now:function(){return(ut.delegate||Date).now()},delegate:void 0};var Ut=(function(r){R(e,r);function e(t,n,i){t===void 0&&(t=1/0),n===void 0&&(n=1/0),i===void 0&&(i=ut);var o=r.call(this)||this;return o._bufferSize=t,o._windowTime=n,o._timestampProvider=i,o._buffer=[],o._infiniteTimeWindow=!0,o._infiniteTimeWindow=n===1/0,o._bufferSize=Math.max(1,t),o._windowTime=Math.max(1,n),o}return e.prototype.next=function(t){var n=this,i=n.isStopped,o=n._buffer,s=n._infiniteTimeWindow,c=n._timestampProvider,l=n._windowTime;i||(o.push(t),!s&&o.push(c.now()+l)),this._trimBuffer(),r.prototype.next.call(this,t)},e.prototype._subscribe=function(t){this._throwIfClosed(),this._trimBuffer();for(var n=this._innerSubscribe(t),i=this,o=i._infiniteTimeWindow,s=i._buffer,c=s.slice(),l=0;l<c.length&&!t.closed;l+=o?1:2)t.next(c[l]);return this._checkFinalizedStatuses(t),n},e.prototype._trimBuffer=function(){var t=this,n=t._bufferSize,i=t._timestampProvider,o=t._buffer,s=t._infiniteTimeWindow,c=(s?1:2)*n;if(n<1/0&&c<o.length&&o.splice(0,o.length-c),!s){for(var l=i.now(),d=0,u=1;u<o.length&&o[u]<=l;u+=2)d=u;d&&o.splice(0,d+1)}},e})(Y);var bn=(function(r){R(e,r);function e(t,n){return r.call(this)||this}return e.prototype.schedule=function(t,n){return n===void 0&&(n=0),this},e})(X);var ft={setInterval:function(r,e){for(var t=[],n=2;n<arguments.length;n++)t[n-2]=arguments[n];var i=ft.delegate;return i?.setInterval?i.setInterval.apply(i,q([r,e],H(t))):setInterval.apply(void 0,q([r,e],H(t)))},clearInterval:function(r){var e=ft.delegate;return(e?.clearInterval||clearInterval)(r)},delegate:void 0};var xn=(function(r){R(e,r);function e(t,n){var i=r.call(this,t,n)||this;return i.scheduler=t,i.work=n,i.pending=!1,i}return e.prototype.schedule=function(t,n){var i;if(n===void 0&&(n=0),this.closed)return this;this.state=t;var o=this.id,s=this.scheduler;return o!=null&&(this.id=this.recycleAsyncId(s,o,n)),this.pending=!0,this.delay=n,this.id=(i=this.id)!==null&&i!==void 0?i:this.requestAsyncId(s,this.id,n),this},e.prototype.requestAsyncId=function(t,n,i){return i===void 0&&(i=0),ft.setInterval(t.flush.bind(t,this),i)},e.prototype.recycleAsyncId=function(t,n,i){if(i===void 0&&(i=0),i!=null&&this.delay===i&&this.pending===!1)return n;n!=null&&ft.clearInterval(n)},e.prototype.execute=function(t,n){if(this.closed)return new Error("executing a cancelled action");this.pending=!1;var i=this._execute(t,n);if(i)return i;this.pending===!1&&this.id!=null&&(this.id=this.recycleAsyncId(this.scheduler,this.id,null))},e.prototype._execute=function(t,n){var i=!1,o;try{this.work(t)}catch(s){i=!0,o=s||new Error("Scheduled action threw falsy error")}if(i)return this.unsubscribe(),o},e.prototype.unsubscribe=function(){if(!this.closed){var t=this,n=t.id,i=t.scheduler,o=i.actions;this.work=this.state=this.scheduler=null,this.pending=!1,ye(o,this),n!=null&&(this.id=this.recycleAsyncId(i,n,null)),this.delay=null,r.prototype.unsubscribe.call(this)}},e})(bn);var Ir=(function(){function r(e,t){t===void 0&&(t=r.now),this.schedulerActionCtor=e,this.now=t}return r.prototype.schedule=function(e,t,n){return t===void 0&&(t=0),new this.schedulerActionCtor(this,e).schedule(n,t)},r.now=ut.now,r})();var wn=(function(r){R(e,r);function e(t,n){n===void 0&&(n=Ir.now);var i=r.call(this,t,n)||this;return i.actions=[],i._active=!1,i}return e.prototype.flush=function(t){var n=this.actions;if(this._active){n.push(t);return}var i;this._active=!0;do if(i=t.execute(t.state,t.delay))break;while(t=n.shift());if(this._active=!1,i){for(;t=n.shift();)t.unsubscribe();throw i}},e})(Ir);var io=new wn(xn),Sn=io;var kn=new E(function(r){return r.complete()});function zt(r){return r&&k(r.schedule)}function Tn(r){return r[r.length-1]}function An(r){return zt(Tn(r))?r.pop():void 0}function En(r,e){return typeof Tn(r)=="number"?r.pop():e}var Zt=(function(r){return r&&typeof r.length=="number"&&typeof r!="function"});function Ft(r){return k(r?.then)}function Vt(r){return k(r[Ye])}function Bt(r){return Symbol.asyncIterator&&k(r?.[Symbol.asyncIterator])}function Wt(r){return new TypeError("You provided "+(r!==null&&typeof r=="object"?"an invalid object":"'"+r+"'")+" where a stream was expected. You can provide an Observable, Promise, ReadableStream, Array, AsyncIterable, or Iterable.")}function oo(){return typeof Symbol!="function"||!Symbol.iterator?"@@iterator":Symbol.iterator}var Ht=oo();function qt(r){return k(r?.[Ht])}function Gt(r){return dn(this,arguments,function(){var t,n,i,o;return Rt(this,function(s){switch(s.label){case 0:t=r.getReader(),s.label=1;case 1:s.trys.push([1,,9,10]),s.label=2;case 2:return[4,ve(t.read())];case 3:return n=s.sent(),i=n.value,o=n.done,o?[4,ve(void 0)]:[3,5];case 4:return[2,s.sent()];case 5:return[4,ve(i)];case 6:return[4,s.sent()];case 7:return s.sent(),[3,2];case 8:return[3,10];case 9:return t.releaseLock(),[7];case 10:return[2]}})})}function Yt(r){return k(r?.getReader)}function j(r){if(r instanceof E)return r;if(r!=null){if(Vt(r))return so(r);if(Zt(r))return ao(r);if(Ft(r))return co(r);if(Bt(r))return On(r);if(qt(r))return lo(r);if(Yt(r))return uo(r)}throw Wt(r)}function so(r){return new E(function(e){var t=r[Ye]();if(k(t.subscribe))return t.subscribe(e);throw new TypeError("Provided object does not correctly implement Symbol.observable")})}function ao(r){return new E(function(e){for(var t=0;t<r.length&&!e.closed;t++)e.next(r[t]);e.complete()})}function co(r){return new E(function(e){r.then(function(t){e.closed||(e.next(t),e.complete())},function(t){return e.error(t)}).then(null,Dt)})}function lo(r){return new E(function(e){var t,n;try{for(var i=re(r),o=i.next();!o.done;o=i.next()){var s=o.value;if(e.next(s),e.closed)return}}catch(c){t={error:c}}finally{try{o&&!o.done&&(n=i.return)&&n.call(i)}finally{if(t)throw t.error}}e.complete()})}function On(r){return new E(function(e){fo(r,e).catch(function(t){return e.error(t)})})}function uo(r){return On(Gt(r))}function fo(r,e){var t,n,i,o;return ln(this,void 0,void 0,function(){var s,c;return Rt(this,function(l){switch(l.label){case 0:l.trys.push([0,5,6,11]),t=un(r),l.label=1;case 1:return[4,t.next()];case 2:if(n=l.sent(),!!n.done)return[3,4];if(s=n.value,e.next(s),e.closed)return[2];l.label=3;case 3:return[3,1];case 4:return[3,11];case 5:return c=l.sent(),i={error:c},[3,11];case 6:return l.trys.push([6,,9,10]),n&&!n.done&&(o=t.return)?[4,o.call(t)]:[3,8];case 7:l.sent(),l.label=8;case 8:return[3,10];case 9:if(i)throw i.error;return[7];case 10:return[7];case 11:return e.complete(),[2]}})})}function F(r,e,t,n,i){n===void 0&&(n=0),i===void 0&&(i=!1);var o=e.schedule(function(){t(),i?r.add(this.schedule(null,n)):this.unsubscribe()},n);if(r.add(o),!i)return o}function Jt(r,e){return e===void 0&&(e=0),C(function(t,n){t.subscribe($(n,function(i){return F(n,r,function(){return n.next(i)},e)},function(){return F(n,r,function(){return n.complete()},e)},function(i){return F(n,r,function(){return n.error(i)},e)}))})}function Kt(r,e){return e===void 0&&(e=0),C(function(t,n){n.add(r.schedule(function(){return t.subscribe(n)},e))})}function Cn(r,e){return j(r).pipe(Kt(e),Jt(e))}function $n(r,e){return j(r).pipe(Kt(e),Jt(e))}function jn(r,e){return new E(function(t){var n=0;return e.schedule(function(){n===r.length?t.complete():(t.next(r[n++]),t.closed||this.schedule())})})}function Rn(r,e){return new E(function(t){var n;return F(t,e,function(){n=r[Ht](),F(t,e,function(){var i,o,s;try{i=n.next(),o=i.value,s=i.done}catch(c){t.error(c);return}s?t.complete():t.next(o)},0,!0)}),function(){return k(n?.return)&&n.return()}})}function Xt(r,e){if(!r)throw new Error("Iterable cannot be null");return new E(function(t){F(t,e,function(){var n=r[Symbol.asyncIterator]();F(t,e,function(){n.next().then(function(i){i.done?t.complete():t.next(i.value)})},0,!0)})})}function In(r,e){return Xt(Gt(r),e)}function Pn(r,e){if(r!=null){if(Vt(r))return Cn(r,e);if(Zt(r))return jn(r,e);if(Ft(r))return $n(r,e);if(Bt(r))return Xt(r,e);if(qt(r))return Rn(r,e);if(Yt(r))return In(r,e)}throw Wt(r)}function Mn(r,e){return e?Pn(r,e):j(r)}function Dn(r){return r instanceof Date&&!isNaN(r)}function Q(r,e){return C(function(t,n){var i=0;t.subscribe($(n,function(o){n.next(r.call(e,o,i++))}))})}function Nn(r,e,t,n,i,o,s,c){var l=[],d=0,u=0,p=!1,w=function(){p&&!l.length&&!d&&e.complete()},y=function(S){return d<n?A(S):l.push(S)},A=function(S){o&&e.next(S),d++;var I=!1;j(t(S,u++)).subscribe($(e,function(U){i?.(U),o?y(U):e.next(U)},function(){I=!0},void 0,function(){if(I)try{d--;for(var U=function(){var He=l.shift();s?F(e,s,function(){return A(He)}):A(He)};l.length&&d<n;)U();w()}catch(He){e.error(He)}}))};return r.subscribe($(e,y,function(){p=!0,w()})),function(){c?.()}}function Pr(r,e,t){return t===void 0&&(t=1/0),k(e)?Pr(function(n,i){return Q(function(o,s){return e(n,o,i,s)})(j(r(n,i)))},t):(typeof e=="number"&&(t=e),C(function(n,i){return Nn(n,i,r,t)}))}function Ln(r){return r===void 0&&(r=1/0),Pr(le,r)}function pt(r,e,t){r===void 0&&(r=0),t===void 0&&(t=Sn);var n=-1;return e!=null&&(zt(e)?t=e:n=e),new E(function(i){var o=Dn(r)?+r-t.now():r;o<0&&(o=0);var s=0;return t.schedule(function(){i.closed||(i.next(s++),0<=n?this.schedule(void 0,n):i.complete())},o)})}function Qt(){for(var r=[],e=0;e<arguments.length;e++)r[e]=arguments[e];var t=An(r),n=En(r,1/0),i=r;return i.length?i.length===1?j(i[0]):Ln(n)(Mn(i,t)):kn}function de(r,e){return C(function(t,n){var i=0;t.subscribe($(n,function(o){return r.call(e,o,i++)&&n.next(o)}))})}function xe(){return C(function(r,e){r.subscribe($(e,dt))})}function Mr(r){r===void 0&&(r=1/0);var e;r&&typeof r=="object"?e=r:e={count:r};var t=e.count,n=t===void 0?1/0:t,i=e.delay,o=e.resetOnSuccess,s=o===void 0?!1:o;return n<=0?le:C(function(c,l){var d=0,u,p=function(){var w=!1;u=c.subscribe($(l,function(y){s&&(d=0),l.next(y)},void 0,function(y){if(d++<n){var A=function(){u?(u.unsubscribe(),u=null,p()):w=!0};if(i!=null){var S=typeof i=="number"?pt(i):j(i(y,d)),I=$(l,function(){I.unsubscribe(),A()},function(){l.complete()});S.subscribe(I)}else A()}else l.error(y)})),w&&(u.unsubscribe(),u=null,p())};p()})}function Nr(r){r===void 0&&(r={});var e=r.connector,t=e===void 0?function(){return new Y}:e,n=r.resetOnError,i=n===void 0?!0:n,o=r.resetOnComplete,s=o===void 0?!0:o,c=r.resetOnRefCountZero,l=c===void 0?!0:c;return function(d){var u,p,w,y=0,A=!1,S=!1,I=function(){p?.unsubscribe(),p=void 0},U=function(){I(),u=w=void 0,A=S=!1},He=function(){var lt=u;U(),lt?.unsubscribe()};return C(function(lt,an){y++,!S&&!A&&I();var Ct=w=w??t();an.add(function(){y--,y===0&&!S&&!A&&(p=Dr(He,l))}),Ct.subscribe(an),!u&&y>0&&(u=new be({next:function($t){return Ct.next($t)},error:function($t){S=!0,I(),p=Dr(U,i,$t),Ct.error($t)},complete:function(){A=!0,I(),p=Dr(U,s),Ct.complete()}}),j(lt).subscribe(u))})(d)}}function Dr(r,e){for(var t=[],n=2;n<arguments.length;n++)t[n-2]=arguments[n];if(e===!0){r();return}if(e!==!1){var i=new be({next:function(){i.unsubscribe(),r()}});return j(e.apply(void 0,q([],H(t)))).subscribe(i)}}function ht(r,e){return C(function(t,n){var i=null,o=0,s=!1,c=function(){return s&&!i&&n.complete()};t.subscribe($(n,function(l){i?.unsubscribe();var d=0,u=o++;j(r(l,u)).subscribe(i=$(n,function(p){return n.next(e?e(l,p,u,d++):p)},function(){i=null,c()}))},function(){s=!0,c()}))})}function we(r,e,t){var n=k(r)||e||t?{next:r,error:e,complete:t}:r;return n?C(function(i,o){var s;(s=n.subscribe)===null||s===void 0||s.call(n);var c=!0;i.subscribe($(o,function(l){var d;(d=n.next)===null||d===void 0||d.call(n,l),o.next(l)},function(){var l;c=!1,(l=n.complete)===null||l===void 0||l.call(n),o.complete()},function(l){var d;c=!1,(d=n.error)===null||d===void 0||d.call(n,l),o.error(l)},function(){var l,d;c&&((l=n.unsubscribe)===null||l===void 0||l.call(n)),(d=n.finalize)===null||d===void 0||d.call(n)}))}):le}var po={url:"",deserializer:function(r){return JSON.parse(r.data)},serializer:function(r){return JSON.stringify(r)}},ho="WebSocketSubject.error must be called with an object with an error code, and an optional reason: { code: number, reason: string }",Un=(function(r){R(e,r);function e(t,n){var i=r.call(this)||this;if(i._socket=null,t instanceof E)i.destination=n,i.source=t;else{var o=i._config=jt({},po);if(i._output=new Y,typeof t=="string")o.url=t;else for(var s in t)t.hasOwnProperty(s)&&(o[s]=t[s]);if(!o.WebSocketCtor&&WebSocket)o.WebSocketCtor=WebSocket;else if(!o.WebSocketCtor)throw new Error("no WebSocket constructor can be found");i.destination=new Ut}return i}return e.prototype.lift=function(t){var n=new e(this._config,this.destination);return n.operator=t,n.source=this,n},e.prototype._resetState=function(){this._socket=null,this.source||(this.destination=new Ut),this._output=new Y},e.prototype.multiplex=function(t,n,i){var o=this;return new E(function(s){try{o.next(t())}catch(l){s.error(l)}var c=o.subscribe({next:function(l){try{i(l)&&s.next(l)}catch(d){s.error(d)}},error:function(l){return s.error(l)},complete:function(){return s.complete()}});return function(){try{o.next(n())}catch(l){s.error(l)}c.unsubscribe()}})},e.prototype._connectSocket=function(){var t=this,n=this._config,i=n.WebSocketCtor,o=n.protocol,s=n.url,c=n.binaryType,l=this._output,d=null;try{d=o?new i(s,o):new i(s),this._socket=d,c&&(this._socket.binaryType=c)}catch(p){l.error(p);return}var u=new X(function(){t._socket=null,d&&d.readyState===1&&d.close()});d.onopen=function(p){var w=t._socket;if(!w){d.close(),t._resetState();return}var y=t._config.openObserver;y&&y.next(p);var A=t.destination;t.destination=_e.create(function(S){if(d.readyState===1)try{var I=t._config.serializer;d.send(I(S))}catch(U){t.destination.error(U)}},function(S){var I=t._config.closingObserver;I&&I.next(void 0),S&&S.code?d.close(S.code,S.reason):l.error(new TypeError(ho)),t._resetState()},function(){var S=t._config.closingObserver;S&&S.next(void 0),d.close(),t._resetState()}),A&&A instanceof Ut&&u.add(A.subscribe(t.destination))},d.onerror=function(p){t._resetState(),l.error(p)},d.onclose=function(p){d===t._socket&&t._resetState();var w=t._config.closeObserver;w&&w.next(p),p.wasClean?l.complete():l.error(p)},d.onmessage=function(p){try{var w=t._config.deserializer;l.next(w(p))}catch(y){l.error(y)}}},e.prototype._subscribe=function(t){var n=this,i=this.source;return i?i.subscribe(t):(this._socket||this._connectSocket(),this._output.subscribe(t),t.add(function(){var o=n._socket;n._output.observers.length===0&&(o&&(o.readyState===1||o.readyState===0)&&o.close(),n._resetState())}),t)},e.prototype.unsubscribe=function(){var t=this._socket;t&&(t.readyState===1||t.readyState===0)&&t.close(),this._resetState(),r.prototype.unsubscribe.call(this)},e})(Lt);function Lr(r){return new Un(r)}function zn(){return{create:r=>{let e=new URL(window.location.href),t=e.protocol==="https:"?"wss":"ws",n;if(r.host)n=new URL(r.ws_path,t+"://"+r.host);else{let o=new URL(e);o.protocol=t,o.pathname=r.ws_path,n=o}return Lr(n.toString()).pipe(Mr({delay:5e3}))}}}var Zn={debug(...r){},error(...r){},info(...r){},trace(...r){}},Ur={name:"console",globalSetup:r=>{let e=new Y;return[e,{debug:function(...n){e.next({level:"debug",args:n})},info:function(...n){e.next({level:"info",args:n})},trace:function(...n){e.next({level:"trace",args:n})},error:function(...n){e.next({level:"error",args:n})}}]},resetSink:(r,e,t)=>r.pipe(we(n=>{let i=["trace","debug","info","error"],o=i.indexOf(n.level),s=i.indexOf(t.log_level);o>=s&&console.log(`[${n.level}]`,...n.args)}),xe())};var T;(function(r){r.assertEqual=i=>i;function e(i){}r.assertIs=e;function t(i){throw new Error}r.assertNever=t,r.arrayToEnum=i=>{let o={};for(let s of i)o[s]=s;return o},r.getValidEnumValues=i=>{let o=r.objectKeys(i).filter(c=>typeof i[i[c]]!="number"),s={};for(let c of o)s[c]=i[c];return r.objectValues(s)},r.objectValues=i=>r.objectKeys(i).map(function(o){return i[o]}),r.objectKeys=typeof Object.keys=="function"?i=>Object.keys(i):i=>{let o=[];for(let s in i)Object.prototype.hasOwnProperty.call(i,s)&&o.push(s);return o},r.find=(i,o)=>{for(let s of i)if(o(s))return s},r.isInteger=typeof Number.isInteger=="function"?i=>Number.isInteger(i):i=>typeof i=="number"&&isFinite(i)&&Math.floor(i)===i;function n(i,o=" | "){return i.map(s=>typeof s=="string"?`'${s}'`:s).join(o)}r.joinValues=n,r.jsonStringifyReplacer=(i,o)=>typeof o=="bigint"?o.toString():o})(T||(T={}));var Zr;(function(r){r.mergeShapes=(e,t)=>({...e,...t})})(Zr||(Zr={}));var m=T.arrayToEnum(["string","nan","number","integer","float","boolean","date","bigint","symbol","function","undefined","null","array","object","unknown","promise","void","never","map","set"]),ie=r=>{switch(typeof r){case"undefined":return m.undefined;case"string":return m.string;case"number":return isNaN(r)?m.nan:m.number;case"boolean":return m.boolean;case"function":return m.function;case"bigint":return m.bigint;case"symbol":return m.symbol;case"object":return Array.isArray(r)?m.array:r===null?m.null:r.then&&typeof r.then=="function"&&r.catch&&typeof r.catch=="function"?m.promise:typeof Map<"u"&&r instanceof Map?m.map:typeof Set<"u"&&r instanceof Set?m.set:typeof Date<"u"&&r instanceof Date?m.date:m.object;default:return m.unknown}},f=T.arrayToEnum(["invalid_type","invalid_literal","custom","invalid_union","invalid_union_discriminator","invalid_enum_value","unrecognized_keys","invalid_arguments","invalid_return_type","invalid_date","invalid_string","too_small","too_big","invalid_intersection_types","not_multiple_of","not_finite"]),mo=r=>JSON.stringify(r,null,2).replace(/"([^"]+)":/g,"$1:"),z=class r extends Error{get errors(){return this.issues}constructor(e){super(),this.issues=[],this.addIssue=n=>{this.issues=[...this.issues,n]},this.addIssues=(n=[])=>{this.issues=[...this.issues,...n]};let t=new.target.prototype;Object.setPrototypeOf?Object.setPrototypeOf(this,t):this.__proto__=t,this.name="ZodError",this.issues=e}format(e){let t=e||function(o){return o.message},n={_errors:[]},i=o=>{for(let s of o.issues)if(s.code==="invalid_union")s.unionErrors.map(i);else if(s.code==="invalid_return_type")i(s.returnTypeError);else if(s.code==="invalid_arguments")i(s.argumentsError);else if(s.path.length===0)n._errors.push(t(s));else{let c=n,l=0;for(;l<s.path.length;){let d=s.path[l];l===s.path.length-1?(c[d]=c[d]||{_errors:[]},c[d]._errors.push(t(s))):c[d]=c[d]||{_errors:[]},c=c[d],l++}}};return i(this),n}static assert(e){if(!(e instanceof r))throw new Error(`Not a ZodError: ${e}`)}toString(){return this.message}get message(){return JSON.stringify(this.issues,T.jsonStringifyReplacer,2)}get isEmpty(){return this.issues.length===0}flatten(e=t=>t.message){let t={},n=[];for(let i of this.issues)i.path.length>0?(t[i.path[0]]=t[i.path[0]]||[],t[i.path[0]].push(e(i))):n.push(e(i));return{formErrors:n,fieldErrors:t}}get formErrors(){return this.flatten()}};z.create=r=>new z(r);var Xe=(r,e)=>{let t;switch(r.code){case f.invalid_type:r.received===m.undefined?t="Required":t=`Expected ${r.expected}, received ${r.received}`;break;case f.invalid_literal:t=`Invalid literal value, expected ${JSON.stringify(r.expected,T.jsonStringifyReplacer)}`;break;case f.unrecognized_keys:t=`Unrecognized key(s) in object: ${T.joinValues(r.keys,", ")}`;break;case f.invalid_union:t="Invalid input";break;case f.invalid_union_discriminator:t=`Invalid discriminator value. Expected ${T.joinValues(r.options)}`;break;case f.invalid_enum_value:t=`Invalid enum value. Expected ${T.joinValues(r.options)}, received '${r.received}'`;break;case f.invalid_arguments:t="Invalid function arguments";break;case f.invalid_return_type:t="Invalid function return type";break;case f.invalid_date:t="Invalid date";break;case f.invalid_string:typeof r.validation=="object"?"includes"in r.validation?(t=`Invalid input: must include "${r.validation.includes}"`,typeof r.validation.position=="number"&&(t=`${t} at one or more positions greater than or equal to ${r.validation.position}`)):"startsWith"in r.validation?t=`Invalid input: must start with "${r.validation.startsWith}"`:"endsWith"in r.validation?t=`Invalid input: must end with "${r.validation.endsWith}"`:T.assertNever(r.validation):r.validation!=="regex"?t=`Invalid ${r.validation}`:t="Invalid";break;case f.too_small:r.type==="array"?t=`Array must contain ${r.exact?"exactly":r.inclusive?"at least":"more than"} ${r.minimum} element(s)`:r.type==="string"?t=`String must contain ${r.exact?"exactly":r.inclusive?"at least":"over"} ${r.minimum} character(s)`:r.type==="number"?t=`Number must be ${r.exact?"exactly equal to ":r.inclusive?"greater than or equal to ":"greater than "}${r.minimum}`:r.type==="date"?t=`Date must be ${r.exact?"exactly equal to ":r.inclusive?"greater than or equal to ":"greater than "}${new Date(Number(r.minimum))}`:t="Invalid input";break;case f.too_big:r.type==="array"?t=`Array must contain ${r.exact?"exactly":r.inclusive?"at most":"less than"} ${r.maximum} element(s)`:r.type==="string"?t=`String must contain ${r.exact?"exactly":r.inclusive?"at most":"under"} ${r.maximum} character(s)`:r.type==="number"?t=`Number must be ${r.exact?"exactly":r.inclusive?"less than or equal to":"less than"} ${r.maximum}`:r.type==="bigint"?t=`BigInt must be ${r.exact?"exactly":r.inclusive?"less than or equal to":"less than"} ${r.maximum}`:r.type==="date"?t=`Date must be ${r.exact?"exactly":r.inclusive?"smaller than or equal to":"smaller than"} ${new Date(Number(r.maximum))}`:t="Invalid input";break;case f.custom:t="Invalid input";break;case f.invalid_intersection_types:t="Intersection results could not be merged";break;case f.not_multiple_of:t=`Number must be a multiple of ${r.multipleOf}`;break;case f.not_finite:t="Number must be finite";break;default:t=e.defaultError,T.assertNever(r)}return{message:t}},Wn=Xe;function vo(r){Wn=r}function er(){return Wn}var tr=r=>{let{data:e,path:t,errorMaps:n,issueData:i}=r,o=[...t,...i.path||[]],s={...i,path:o};if(i.message!==void 0)return{...i,path:o,message:i.message};let c="",l=n.filter(d=>!!d).slice().reverse();for(let d of l)c=d(s,{data:e,defaultError:c}).message;return{...i,path:o,message:c}},yo=[];function h(r,e){let t=er(),n=tr({issueData:e,data:r.data,path:r.path,errorMaps:[r.common.contextualErrorMap,r.schemaErrorMap,t,t===Xe?void 0:Xe].filter(i=>!!i)});r.common.issues.push(n)}var P=class r{constructor(){this.value="valid"}dirty(){this.value==="valid"&&(this.value="dirty")}abort(){this.value!=="aborted"&&(this.value="aborted")}static mergeArray(e,t){let n=[];for(let i of t){if(i.status==="aborted")return _;i.status==="dirty"&&e.dirty(),n.push(i.value)}return{status:e.value,value:n}}static async mergeObjectAsync(e,t){let n=[];for(let i of t){let o=await i.key,s=await i.value;n.push({key:o,value:s})}return r.mergeObjectSync(e,n)}static mergeObjectSync(e,t){let n={};for(let i of t){let{key:o,value:s}=i;if(o.status==="aborted"||s.status==="aborted")return _;o.status==="dirty"&&e.dirty(),s.status==="dirty"&&e.dirty(),o.value!=="__proto__"&&(typeof s.value<"u"||i.alwaysSet)&&(n[o.value]=s.value)}return{status:e.value,value:n}}},_=Object.freeze({status:"aborted"}),Ke=r=>({status:"dirty",value:r}),M=r=>({status:"valid",value:r}),Fr=r=>r.status==="aborted",Vr=r=>r.status==="dirty",Se=r=>r.status==="valid",yt=r=>typeof Promise<"u"&&r instanceof Promise;function rr(r,e,t,n){if(t==="a"&&!n)throw new TypeError("Private accessor was defined without a getter");if(typeof e=="function"?r!==e||!n:!e.has(r))throw new TypeError("Cannot read private member from an object whose class did not declare it");return t==="m"?n:t==="a"?n.call(r):n?n.value:e.get(r)}function Hn(r,e,t,n,i){if(n==="m")throw new TypeError("Private method is not writable");if(n==="a"&&!i)throw new TypeError("Private accessor was defined without a setter");if(typeof e=="function"?r!==e||!i:!e.has(r))throw new TypeError("Cannot write private member to an object whose class did not declare it");return n==="a"?i.call(r,t):i?i.value=t:e.set(r,t),t}var v;(function(r){r.errToObj=e=>typeof e=="string"?{message:e}:e||{},r.toString=e=>typeof e=="string"?e:e?.message})(v||(v={}));var mt,vt,B=class{constructor(e,t,n,i){this._cachedPath=[],this.parent=e,this.data=t,this._path=n,this._key=i}get path(){return this._cachedPath.length||(this._key instanceof Array?this._cachedPath.push(...this._path,...this._key):this._cachedPath.push(...this._path,this._key)),this._cachedPath}},Fn=(r,e)=>{if(Se(e))return{success:!0,data:e.value};if(!r.common.issues.length)throw new Error("Validation failed but no issues detected.");return{success:!1,get error(){if(this._error)return this._error;let t=new z(r.common.issues);return this._error=t,this._error}}};function b(r){if(!r)return{};let{errorMap:e,invalid_type_error:t,required_error:n,description:i}=r;if(e&&(t||n))throw new Error(`Can't use "invalid_type_error" or "required_error" in conjunction with custom error map.`);return e?{errorMap:e,description:i}:{errorMap:(s,c)=>{var l,d;let{message:u}=r;return s.code==="invalid_enum_value"?{message:u??c.defaultError}:typeof c.data>"u"?{message:(l=u??n)!==null&&l!==void 0?l:c.defaultError}:s.code!=="invalid_type"?{message:c.defaultError}:{message:(d=u??t)!==null&&d!==void 0?d:c.defaultError}},description:i}}var x=class{get description(){return this._def.description}_getType(e){return ie(e.data)}_getOrReturnCtx(e,t){return t||{common:e.parent.common,data:e.data,parsedType:ie(e.data),schemaErrorMap:this._def.errorMap,path:e.path,parent:e.parent}}_processInputParams(e){return{status:new P,ctx:{common:e.parent.common,data:e.data,parsedType:ie(e.data),schemaErrorMap:this._def.errorMap,path:e.path,parent:e.parent}}}_parseSync(e){let t=this._parse(e);if(yt(t))throw new Error("Synchronous parse encountered promise.");return t}_parseAsync(e){let t=this._parse(e);return Promise.resolve(t)}parse(e,t){let n=this.safeParse(e,t);if(n.success)return n.data;throw n.error}safeParse(e,t){var n;let i={common:{issues:[],async:(n=t?.async)!==null&&n!==void 0?n:!1,contextualErrorMap:t?.errorMap},path:t?.path||[],schemaErrorMap:this._def.errorMap,parent:null,data:e,parsedType:ie(e)},o=this._parseSync({data:e,path:i.path,parent:i});return Fn(i,o)}"~validate"(e){var t,n;let i={common:{issues:[],async:!!this["~standard"].async},path:[],schemaErrorMap:this._def.errorMap,parent:null,data:e,parsedType:ie(e)};if(!this["~standard"].async)try{let o=this._parseSync({data:e,path:[],parent:i});return Se(o)?{value:o.value}:{issues:i.common.issues}}catch(o){!((n=(t=o?.message)===null||t===void 0?void 0:t.toLowerCase())===null||n===void 0)&&n.includes("encountered")&&(this["~standard"].async=!0),i.common={issues:[],async:!0}}return this._parseAsync({data:e,path:[],parent:i}).then(o=>Se(o)?{value:o.value}:{issues:i.common.issues})}async parseAsync(e,t){let n=await this.safeParseAsync(e,t);if(n.success)return n.data;throw n.error}async safeParseAsync(e,t){let n={common:{issues:[],contextualErrorMap:t?.errorMap,async:!0},path:t?.path||[],schemaErrorMap:this._def.errorMap,parent:null,data:e,parsedType:ie(e)},i=this._parse({data:e,path:n.path,parent:n}),o=await(yt(i)?i:Promise.resolve(i));return Fn(n,o)}refine(e,t){let n=i=>typeof t=="string"||typeof t>"u"?{message:t}:typeof t=="function"?t(i):t;return this._refinement((i,o)=>{let s=e(i),c=()=>o.addIssue({code:f.custom,...n(i)});return typeof Promise<"u"&&s instanceof Promise?s.then(l=>l?!0:(c(),!1)):s?!0:(c(),!1)})}refinement(e,t){return this._refinement((n,i)=>e(n)?!0:(i.addIssue(typeof t=="function"?t(n,i):t),!1))}_refinement(e){return new Z({schema:this,typeName:g.ZodEffects,effect:{type:"refinement",refinement:e}})}superRefine(e){return this._refinement(e)}constructor(e){this.spa=this.safeParseAsync,this._def=e,this.parse=this.parse.bind(this),this.safeParse=this.safeParse.bind(this),this.parseAsync=this.parseAsync.bind(this),this.safeParseAsync=this.safeParseAsync.bind(this),this.spa=this.spa.bind(this),this.refine=this.refine.bind(this),this.refinement=this.refinement.bind(this),this.superRefine=this.superRefine.bind(this),this.optional=this.optional.bind(this),this.nullable=this.nullable.bind(this),this.nullish=this.nullish.bind(this),this.array=this.array.bind(this),this.promise=this.promise.bind(this),this.or=this.or.bind(this),this.and=this.and.bind(this),this.transform=this.transform.bind(this),this.brand=this.brand.bind(this),this.default=this.default.bind(this),this.catch=this.catch.bind(this),this.describe=this.describe.bind(this),this.pipe=this.pipe.bind(this),this.readonly=this.readonly.bind(this),this.isNullable=this.isNullable.bind(this),this.isOptional=this.isOptional.bind(this),this["~standard"]={version:1,vendor:"zod",validate:t=>this["~validate"](t)}}optional(){return V.create(this,this._def)}nullable(){return te.create(this,this._def)}nullish(){return this.nullable().optional()}array(){return se.create(this)}promise(){return pe.create(this,this._def)}or(e){return $e.create([this,e],this._def)}and(e){return je.create(this,e,this._def)}transform(e){return new Z({...b(this._def),schema:this,typeName:g.ZodEffects,effect:{type:"transform",transform:e}})}default(e){let t=typeof e=="function"?e:()=>e;return new De({...b(this._def),innerType:this,defaultValue:t,typeName:g.ZodDefault})}brand(){return new gt({typeName:g.ZodBranded,type:this,...b(this._def)})}catch(e){let t=typeof e=="function"?e:()=>e;return new Ne({...b(this._def),innerType:this,catchValue:t,typeName:g.ZodCatch})}describe(e){let t=this.constructor;return new t({...this._def,description:e})}pipe(e){return _t.create(this,e)}readonly(){return Le.create(this)}isOptional(){return this.safeParse(void 0).success}isNullable(){return this.safeParse(null).success}},go=/^c[^\s-]{8,}$/i,_o=/^[0-9a-z]+$/,bo=/^[0-9A-HJKMNP-TV-Z]{26}$/i,xo=/^[0-9a-fA-F]{8}\b-[0-9a-fA-F]{4}\b-[0-9a-fA-F]{4}\b-[0-9a-fA-F]{4}\b-[0-9a-fA-F]{12}$/i,wo=/^[a-z0-9_-]{21}$/i,So=/^[A-Za-z0-9-_]+\.[A-Za-z0-9-_]+\.[A-Za-z0-9-_]*$/,ko=/^[-+]?P(?!$)(?:(?:[-+]?\d+Y)|(?:[-+]?\d+[.,]\d+Y$))?(?:(?:[-+]?\d+M)|(?:[-+]?\d+[.,]\d+M$))?(?:(?:[-+]?\d+W)|(?:[-+]?\d+[.,]\d+W$))?(?:(?:[-+]?\d+D)|(?:[-+]?\d+[.,]\d+D$))?(?:T(?=[\d+-])(?:(?:[-+]?\d+H)|(?:[-+]?\d+[.,]\d+H$))?(?:(?:[-+]?\d+M)|(?:[-+]?\d+[.,]\d+M$))?(?:[-+]?\d+(?:[.,]\d+)?S)?)??$/,To=/^(?!\.)(?!.*\.\.)([A-Z0-9_'+\-\.]*)[A-Z0-9_+-]@([A-Z0-9][A-Z0-9\-]*\.)+[A-Z]{2,}$/i,Ao="^(\\p{Extended_Pictographic}|\\p{Emoji_Component})+$",zr,Eo=/^(?:(?:25[0-5]|2[0-4][0-9]|1[0-9][0-9]|[1-9][0-9]|[0-9])\.){3}(?:25[0-5]|2[0-4][0-9]|1[0-9][0-9]|[1-9][0-9]|[0-9])$/,Oo=/^(?:(?:25[0-5]|2[0-4][0-9]|1[0-9][0-9]|[1-9][0-9]|[0-9])\.){3}(?:25[0-5]|2[0-4][0-9]|1[0-9][0-9]|[1-9][0-9]|[0-9])\/(3[0-2]|[12]?[0-9])$/,Co=/^(([0-9a-fA-F]{1,4}:){7,7}[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,7}:|([0-9a-fA-F]{1,4}:){1,6}:[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,5}(:[0-9a-fA-F]{1,4}){1,2}|([0-9a-fA-F]{1,4}:){1,4}(:[0-9a-fA-F]{1,4}){1,3}|([0-9a-fA-F]{1,4}:){1,3}(:[0-9a-fA-F]{1,4}){1,4}|([0-9a-fA-F]{1,4}:){1,2}(:[0-9a-fA-F]{1,4}){1,5}|[0-9a-fA-F]{1,4}:((:[0-9a-fA-F]{1,4}){1,6})|:((:[0-9a-fA-F]{1,4}){1,7}|:)|fe80:(:[0-9a-fA-F]{0,4}){0,4}%[0-9a-zA-Z]{1,}|::(ffff(:0{1,4}){0,1}:){0,1}((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])|([0-9a-fA-F]{1,4}:){1,4}:((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9]))$/,$o=/^(([0-9a-fA-F]{1,4}:){7,7}[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,7}:|([0-9a-fA-F]{1,4}:){1,6}:[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,5}(:[0-9a-fA-F]{1,4}){1,2}|([0-9a-fA-F]{1,4}:){1,4}(:[0-9a-fA-F]{1,4}){1,3}|([0-9a-fA-F]{1,4}:){1,3}(:[0-9a-fA-F]{1,4}){1,4}|([0-9a-fA-F]{1,4}:){1,2}(:[0-9a-fA-F]{1,4}){1,5}|[0-9a-fA-F]{1,4}:((:[0-9a-fA-F]{1,4}){1,6})|:((:[0-9a-fA-F]{1,4}){1,7}|:)|fe80:(:[0-9a-fA-F]{0,4}){0,4}%[0-9a-zA-Z]{1,}|::(ffff(:0{1,4}){0,1}:){0,1}((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])|([0-9a-fA-F]{1,4}:){1,4}:((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9]))\/(12[0-8]|1[01][0-9]|[1-9]?[0-9])$/,jo=/^([0-9a-zA-Z+/]{4})*(([0-9a-zA-Z+/]{2}==)|([0-9a-zA-Z+/]{3}=))?$/,Ro=/^([0-9a-zA-Z-_]{4})*(([0-9a-zA-Z-_]{2}(==)?)|([0-9a-zA-Z-_]{3}(=)?))?$/,qn="((\\d\\d[2468][048]|\\d\\d[13579][26]|\\d\\d0[48]|[02468][048]00|[13579][26]00)-02-29|\\d{4}-((0[13578]|1[02])-(0[1-9]|[12]\\d|3[01])|(0[469]|11)-(0[1-9]|[12]\\d|30)|(02)-(0[1-9]|1\\d|2[0-8])))",Io=new RegExp(`^${qn}$`);function Gn(r){let e="([01]\\d|2[0-3]):[0-5]\\d:[0-5]\\d";return r.precision?e=`${e}\\.\\d{${r.precision}}`:r.precision==null&&(e=`${e}(\\.\\d+)?`),e}function Po(r){return new RegExp(`^${Gn(r)}$`)}function Yn(r){let e=`${qn}T${Gn(r)}`,t=[];return t.push(r.local?"Z?":"Z"),r.offset&&t.push("([+-]\\d{2}:?\\d{2})"),e=`${e}(${t.join("|")})`,new RegExp(`^${e}$`)}function Mo(r,e){return!!((e==="v4"||!e)&&Eo.test(r)||(e==="v6"||!e)&&Co.test(r))}function Do(r,e){if(!So.test(r))return!1;try{let[t]=r.split("."),n=t.replace(/-/g,"+").replace(/_/g,"/").padEnd(t.length+(4-t.length%4)%4,"="),i=JSON.parse(atob(n));return!(typeof i!="object"||i===null||!i.typ||!i.alg||e&&i.alg!==e)}catch{return!1}}function No(r,e){return!!((e==="v4"||!e)&&Oo.test(r)||(e==="v6"||!e)&&$o.test(r))}var ue=class r extends x{_parse(e){if(this._def.coerce&&(e.data=String(e.data)),this._getType(e)!==m.string){let o=this._getOrReturnCtx(e);return h(o,{code:f.invalid_type,expected:m.string,received:o.parsedType}),_}let n=new P,i;for(let o of this._def.checks)if(o.kind==="min")e.data.length<o.value&&(i=this._getOrReturnCtx(e,i),h(i,{code:f.too_small,minimum:o.value,type:"string",inclusive:!0,exact:!1,message:o.message}),n.dirty());else if(o.kind==="max")e.data.length>o.value&&(i=this._getOrReturnCtx(e,i),h(i,{code:f.too_big,maximum:o.value,type:"string",inclusive:!0,exact:!1,message:o.message}),n.dirty());else if(o.kind==="length"){let s=e.data.length>o.value,c=e.data.length<o.value;(s||c)&&(i=this._getOrReturnCtx(e,i),s?h(i,{code:f.too_big,maximum:o.value,type:"string",inclusive:!0,exact:!0,message:o.message}):c&&h(i,{code:f.too_small,minimum:o.value,type:"string",inclusive:!0,exact:!0,message:o.message}),n.dirty())}else if(o.kind==="email")To.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"email",code:f.invalid_string,message:o.message}),n.dirty());else if(o.kind==="emoji")zr||(zr=new RegExp(Ao,"u")),zr.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"emoji",code:f.invalid_string,message:o.message}),n.dirty());else if(o.kind==="uuid")xo.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"uuid",code:f.invalid_string,message:o.message}),n.dirty());else if(o.kind==="nanoid")wo.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"nanoid",code:f.invalid_string,message:o.message}),n.dirty());else if(o.kind==="cuid")go.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"cuid",code:f.invalid_string,message:o.message}),n.dirty());else if(o.kind==="cuid2")_o.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"cuid2",code:f.invalid_string,message:o.message}),n.dirty());else if(o.kind==="ulid")bo.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"ulid",code:f.invalid_string,message:o.message}),n.dirty());else if(o.kind==="url")try{new URL(e.data)}catch{i=this._getOrReturnCtx(e,i),h(i,{validation:"url",code:f.invalid_string,message:o.message}),n.dirty()}else o.kind==="regex"?(o.regex.lastIndex=0,o.regex.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"regex",code:f.invalid_string,message:o.message}),n.dirty())):o.kind==="trim"?e.data=e.data.trim():o.kind==="includes"?e.data.includes(o.value,o.position)||(i=this._getOrReturnCtx(e,i),h(i,{code:f.invalid_string,validation:{includes:o.value,position:o.position},message:o.message}),n.dirty()):o.kind==="toLowerCase"?e.data=e.data.toLowerCase():o.kind==="toUpperCase"?e.data=e.data.toUpperCase():o.kind==="startsWith"?e.data.startsWith(o.value)||(i=this._getOrReturnCtx(e,i),h(i,{code:f.invalid_string,validation:{startsWith:o.value},message:o.message}),n.dirty()):o.kind==="endsWith"?e.data.endsWith(o.value)||(i=this._getOrReturnCtx(e,i),h(i,{code:f.invalid_string,validation:{endsWith:o.value},message:o.message}),n.dirty()):o.kind==="datetime"?Yn(o).test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{code:f.invalid_string,validation:"datetime",message:o.message}),n.dirty()):o.kind==="date"?Io.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{code:f.invalid_string,validation:"date",message:o.message}),n.dirty()):o.kind==="time"?Po(o).test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{code:f.invalid_string,validation:"time",message:o.message}),n.dirty()):o.kind==="duration"?ko.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"duration",code:f.invalid_string,message:o.message}),n.dirty()):o.kind==="ip"?Mo(e.data,o.version)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"ip",code:f.invalid_string,message:o.message}),n.dirty()):o.kind==="jwt"?Do(e.data,o.alg)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"jwt",code:f.invalid_string,message:o.message}),n.dirty()):o.kind==="cidr"?No(e.data,o.version)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"cidr",code:f.invalid_string,message:o.message}),n.dirty()):o.kind==="base64"?jo.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"base64",code:f.invalid_string,message:o.message}),n.dirty()):o.kind==="base64url"?Ro.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"base64url",code:f.invalid_string,message:o.message}),n.dirty()):T.assertNever(o);return{status:n.value,value:e.data}}_regex(e,t,n){return this.refinement(i=>e.test(i),{validation:t,code:f.invalid_string,...v.errToObj(n)})}_addCheck(e){return new r({...this._def,checks:[...this._def.checks,e]})}email(e){return this._addCheck({kind:"email",...v.errToObj(e)})}url(e){return this._addCheck({kind:"url",...v.errToObj(e)})}emoji(e){return this._addCheck({kind:"emoji",...v.errToObj(e)})}uuid(e){return this._addCheck({kind:"uuid",...v.errToObj(e)})}nanoid(e){return this._addCheck({kind:"nanoid",...v.errToObj(e)})}cuid(e){return this._addCheck({kind:"cuid",...v.errToObj(e)})}cuid2(e){return this._addCheck({kind:"cuid2",...v.errToObj(e)})}ulid(e){return this._addCheck({kind:"ulid",...v.errToObj(e)})}base64(e){return this._addCheck({kind:"base64",...v.errToObj(e)})}base64url(e){return this._addCheck({kind:"base64url",...v.errToObj(e)})}jwt(e){return this._addCheck({kind:"jwt",...v.errToObj(e)})}ip(e){return this._addCheck({kind:"ip",...v.errToObj(e)})}cidr(e){return this._addCheck({kind:"cidr",...v.errToObj(e)})}datetime(e){var t,n;return typeof e=="string"?this._addCheck({kind:"datetime",precision:null,offset:!1,local:!1,message:e}):this._addCheck({kind:"datetime",precision:typeof e?.precision>"u"?null:e?.precision,offset:(t=e?.offset)!==null&&t!==void 0?t:!1,local:(n=e?.local)!==null&&n!==void 0?n:!1,...v.errToObj(e?.message)})}date(e){return this._addCheck({kind:"date",message:e})}time(e){return typeof e=="string"?this._addCheck({kind:"time",precision:null,message:e}):this._addCheck({kind:"time",precision:typeof e?.precision>"u"?null:e?.precision,...v.errToObj(e?.message)})}duration(e){return this._addCheck({kind:"duration",...v.errToObj(e)})}regex(e,t){return this._addCheck({kind:"regex",regex:e,...v.errToObj(t)})}includes(e,t){return this._addCheck({kind:"includes",value:e,position:t?.position,...v.errToObj(t?.message)})}startsWith(e,t){return this._addCheck({kind:"startsWith",value:e,...v.errToObj(t)})}endsWith(e,t){return this._addCheck({kind:"endsWith",value:e,...v.errToObj(t)})}min(e,t){return this._addCheck({kind:"min",value:e,...v.errToObj(t)})}max(e,t){return this._addCheck({kind:"max",value:e,...v.errToObj(t)})}length(e,t){return this._addCheck({kind:"length",value:e,...v.errToObj(t)})}nonempty(e){return this.min(1,v.errToObj(e))}trim(){return new r({...this._def,checks:[...this._def.checks,{kind:"trim"}]})}toLowerCase(){return new r({...this._def,checks:[...this._def.checks,{kind:"toLowerCase"}]})}toUpperCase(){return new r({...this._def,checks:[...this._def.checks,{kind:"toUpperCase"}]})}get isDatetime(){return!!this._def.checks.find(e=>e.kind==="datetime")}get isDate(){return!!this._def.checks.find(e=>e.kind==="date")}get isTime(){return!!this._def.checks.find(e=>e.kind==="time")}get isDuration(){return!!this._def.checks.find(e=>e.kind==="duration")}get isEmail(){return!!this._def.checks.find(e=>e.kind==="email")}get isURL(){return!!this._def.checks.find(e=>e.kind==="url")}get isEmoji(){return!!this._def.checks.find(e=>e.kind==="emoji")}get isUUID(){return!!this._def.checks.find(e=>e.kind==="uuid")}get isNANOID(){return!!this._def.checks.find(e=>e.kind==="nanoid")}get isCUID(){return!!this._def.checks.find(e=>e.kind==="cuid")}get isCUID2(){return!!this._def.checks.find(e=>e.kind==="cuid2")}get isULID(){return!!this._def.checks.find(e=>e.kind==="ulid")}get isIP(){return!!this._def.checks.find(e=>e.kind==="ip")}get isCIDR(){return!!this._def.checks.find(e=>e.kind==="cidr")}get isBase64(){return!!this._def.checks.find(e=>e.kind==="base64")}get isBase64url(){return!!this._def.checks.find(e=>e.kind==="base64url")}get minLength(){let e=null;for(let t of this._def.checks)t.kind==="min"&&(e===null||t.value>e)&&(e=t.value);return e}get maxLength(){let e=null;for(let t of this._def.checks)t.kind==="max"&&(e===null||t.value<e)&&(e=t.value);return e}};ue.create=r=>{var e;return new ue({checks:[],typeName:g.ZodString,coerce:(e=r?.coerce)!==null&&e!==void 0?e:!1,...b(r)})};function Lo(r,e){let t=(r.toString().split(".")[1]||"").length,n=(e.toString().split(".")[1]||"").length,i=t>n?t:n,o=parseInt(r.toFixed(i).replace(".","")),s=parseInt(e.toFixed(i).replace(".",""));return o%s/Math.pow(10,i)}var ke=class r extends x{constructor(){super(...arguments),this.min=this.gte,this.max=this.lte,this.step=this.multipleOf}_parse(e){if(this._def.coerce&&(e.data=Number(e.data)),this._getType(e)!==m.number){let o=this._getOrReturnCtx(e);return h(o,{code:f.invalid_type,expected:m.number,received:o.parsedType}),_}let n,i=new P;for(let o of this._def.checks)o.kind==="int"?T.isInteger(e.data)||(n=this._getOrReturnCtx(e,n),h(n,{code:f.invalid_type,expected:"integer",received:"float",message:o.message}),i.dirty()):o.kind==="min"?(o.inclusive?e.data<o.value:e.data<=o.value)&&(n=this._getOrReturnCtx(e,n),h(n,{code:f.too_small,minimum:o.value,type:"number",inclusive:o.inclusive,exact:!1,message:o.message}),i.dirty()):o.kind==="max"?(o.inclusive?e.data>o.value:e.data>=o.value)&&(n=this._getOrReturnCtx(e,n),h(n,{code:f.too_big,maximum:o.value,type:"number",inclusive:o.inclusive,exact:!1,message:o.message}),i.dirty()):o.kind==="multipleOf"?Lo(e.data,o.value)!==0&&(n=this._getOrReturnCtx(e,n),h(n,{code:f.not_multiple_of,multipleOf:o.value,message:o.message}),i.dirty()):o.kind==="finite"?Number.isFinite(e.data)||(n=this._getOrReturnCtx(e,n),h(n,{code:f.not_finite,message:o.message}),i.dirty()):T.assertNever(o);return{status:i.value,value:e.data}}gte(e,t){return this.setLimit("min",e,!0,v.toString(t))}gt(e,t){return this.setLimit("min",e,!1,v.toString(t))}lte(e,t){return this.setLimit("max",e,!0,v.toString(t))}lt(e,t){return this.setLimit("max",e,!1,v.toString(t))}setLimit(e,t,n,i){return new r({...this._def,checks:[...this._def.checks,{kind:e,value:t,inclusive:n,message:v.toString(i)}]})}_addCheck(e){return new r({...this._def,checks:[...this._def.checks,e]})}int(e){return this._addCheck({kind:"int",message:v.toString(e)})}positive(e){return this._addCheck({kind:"min",value:0,inclusive:!1,message:v.toString(e)})}negative(e){return this._addCheck({kind:"max",value:0,inclusive:!1,message:v.toString(e)})}nonpositive(e){return this._addCheck({kind:"max",value:0,inclusive:!0,message:v.toString(e)})}nonnegative(e){return this._addCheck({kind:"min",value:0,inclusive:!0,message:v.toString(e)})}multipleOf(e,t){return this._addCheck({kind:"multipleOf",value:e,message:v.toString(t)})}finite(e){return this._addCheck({kind:"finite",message:v.toString(e)})}safe(e){return this._addCheck({kind:"min",inclusive:!0,value:Number.MIN_SAFE_INTEGER,message:v.toString(e)})._addCheck({kind:"max",inclusive:!0,value:Number.MAX_SAFE_INTEGER,message:v.toString(e)})}get minValue(){let e=null;for(let t of this._def.checks)t.kind==="min"&&(e===null||t.value>e)&&(e=t.value);return e}get maxValue(){let e=null;for(let t of this._def.checks)t.kind==="max"&&(e===null||t.value<e)&&(e=t.value);return e}get isInt(){return!!this._def.checks.find(e=>e.kind==="int"||e.kind==="multipleOf"&&T.isInteger(e.value))}get isFinite(){let e=null,t=null;for(let n of this._def.checks){if(n.kind==="finite"||n.kind==="int"||n.kind==="multipleOf")return!0;n.kind==="min"?(t===null||n.value>t)&&(t=n.value):n.kind==="max"&&(e===null||n.value<e)&&(e=n.value)}return Number.isFinite(t)&&Number.isFinite(e)}};ke.create=r=>new ke({checks:[],typeName:g.ZodNumber,coerce:r?.coerce||!1,...b(r)});var Te=class r extends x{constructor(){super(...arguments),this.min=this.gte,this.max=this.lte}_parse(e){if(this._def.coerce)try{e.data=BigInt(e.data)}catch{return this._getInvalidInput(e)}if(this._getType(e)!==m.bigint)return this._getInvalidInput(e);let n,i=new P;for(let o of this._def.checks)o.kind==="min"?(o.inclusive?e.data<o.value:e.data<=o.value)&&(n=this._getOrReturnCtx(e,n),h(n,{code:f.too_small,type:"bigint",minimum:o.value,inclusive:o.inclusive,message:o.message}),i.dirty()):o.kind==="max"?(o.inclusive?e.data>o.value:e.data>=o.value)&&(n=this._getOrReturnCtx(e,n),h(n,{code:f.too_big,type:"bigint",maximum:o.value,inclusive:o.inclusive,message:o.message}),i.dirty()):o.kind==="multipleOf"?e.data%o.value!==BigInt(0)&&(n=this._getOrReturnCtx(e,n),h(n,{code:f.not_multiple_of,multipleOf:o.value,message:o.message}),i.dirty()):T.assertNever(o);return{status:i.value,value:e.data}}_getInvalidInput(e){let t=this._getOrReturnCtx(e);return h(t,{code:f.invalid_type,expected:m.bigint,received:t.parsedType}),_}gte(e,t){return this.setLimit("min",e,!0,v.toString(t))}gt(e,t){return this.setLimit("min",e,!1,v.toString(t))}lte(e,t){return this.setLimit("max",e,!0,v.toString(t))}lt(e,t){return this.setLimit("max",e,!1,v.toString(t))}setLimit(e,t,n,i){return new r({...this._def,checks:[...this._def.checks,{kind:e,value:t,inclusive:n,message:v.toString(i)}]})}_addCheck(e){return new r({...this._def,checks:[...this._def.checks,e]})}positive(e){return this._addCheck({kind:"min",value:BigInt(0),inclusive:!1,message:v.toString(e)})}negative(e){return this._addCheck({kind:"max",value:BigInt(0),inclusive:!1,message:v.toString(e)})}nonpositive(e){return this._addCheck({kind:"max",value:BigInt(0),inclusive:!0,message:v.toString(e)})}nonnegative(e){return this._addCheck({kind:"min",value:BigInt(0),inclusive:!0,message:v.toString(e)})}multipleOf(e,t){return this._addCheck({kind:"multipleOf",value:e,message:v.toString(t)})}get minValue(){let e=null;for(let t of this._def.checks)t.kind==="min"&&(e===null||t.value>e)&&(e=t.value);return e}get maxValue(){let e=null;for(let t of this._def.checks)t.kind==="max"&&(e===null||t.value<e)&&(e=t.value);return e}};Te.create=r=>{var e;return new Te({checks:[],typeName:g.ZodBigInt,coerce:(e=r?.coerce)!==null&&e!==void 0?e:!1,...b(r)})};var Ae=class extends x{_parse(e){if(this._def.coerce&&(e.data=!!e.data),this._getType(e)!==m.boolean){let n=this._getOrReturnCtx(e);return h(n,{code:f.invalid_type,expected:m.boolean,received:n.parsedType}),_}return M(e.data)}};Ae.create=r=>new Ae({typeName:g.ZodBoolean,coerce:r?.coerce||!1,...b(r)});var Ee=class r extends x{_parse(e){if(this._def.coerce&&(e.data=new Date(e.data)),this._getType(e)!==m.date){let o=this._getOrReturnCtx(e);return h(o,{code:f.invalid_type,expected:m.date,received:o.parsedType}),_}if(isNaN(e.data.getTime())){let o=this._getOrReturnCtx(e);return h(o,{code:f.invalid_date}),_}let n=new P,i;for(let o of this._def.checks)o.kind==="min"?e.data.getTime()<o.value&&(i=this._getOrReturnCtx(e,i),h(i,{code:f.too_small,message:o.message,inclusive:!0,exact:!1,minimum:o.value,type:"date"}),n.dirty()):o.kind==="max"?e.data.getTime()>o.value&&(i=this._getOrReturnCtx(e,i),h(i,{code:f.too_big,message:o.message,inclusive:!0,exact:!1,maximum:o.value,type:"date"}),n.dirty()):T.assertNever(o);return{status:n.value,value:new Date(e.data.getTime())}}_addCheck(e){return new r({...this._def,checks:[...this._def.checks,e]})}min(e,t){return this._addCheck({kind:"min",value:e.getTime(),message:v.toString(t)})}max(e,t){return this._addCheck({kind:"max",value:e.getTime(),message:v.toString(t)})}get minDate(){let e=null;for(let t of this._def.checks)t.kind==="min"&&(e===null||t.value>e)&&(e=t.value);return e!=null?new Date(e):null}get maxDate(){let e=null;for(let t of this._def.checks)t.kind==="max"&&(e===null||t.value<e)&&(e=t.value);return e!=null?new Date(e):null}};Ee.create=r=>new Ee({checks:[],coerce:r?.coerce||!1,typeName:g.ZodDate,...b(r)});var Qe=class extends x{_parse(e){if(this._getType(e)!==m.symbol){let n=this._getOrReturnCtx(e);return h(n,{code:f.invalid_type,expected:m.symbol,received:n.parsedType}),_}return M(e.data)}};Qe.create=r=>new Qe({typeName:g.ZodSymbol,...b(r)});var Oe=class extends x{_parse(e){if(this._getType(e)!==m.undefined){let n=this._getOrReturnCtx(e);return h(n,{code:f.invalid_type,expected:m.undefined,received:n.parsedType}),_}return M(e.data)}};Oe.create=r=>new Oe({typeName:g.ZodUndefined,...b(r)});var Ce=class extends x{_parse(e){if(this._getType(e)!==m.null){let n=this._getOrReturnCtx(e);return h(n,{code:f.invalid_type,expected:m.null,received:n.parsedType}),_}return M(e.data)}};Ce.create=r=>new Ce({typeName:g.ZodNull,...b(r)});var fe=class extends x{constructor(){super(...arguments),this._any=!0}_parse(e){return M(e.data)}};fe.create=r=>new fe({typeName:g.ZodAny,...b(r)});var oe=class extends x{constructor(){super(...arguments),this._unknown=!0}_parse(e){return M(e.data)}};oe.create=r=>new oe({typeName:g.ZodUnknown,...b(r)});var J=class extends x{_parse(e){let t=this._getOrReturnCtx(e);return h(t,{code:f.invalid_type,expected:m.never,received:t.parsedType}),_}};J.create=r=>new J({typeName:g.ZodNever,...b(r)});var et=class extends x{_parse(e){if(this._getType(e)!==m.undefined){let n=this._getOrReturnCtx(e);return h(n,{code:f.invalid_type,expected:m.void,received:n.parsedType}),_}return M(e.data)}};et.create=r=>new et({typeName:g.ZodVoid,...b(r)});var se=class r extends x{_parse(e){let{ctx:t,status:n}=this._processInputParams(e),i=this._def;if(t.parsedType!==m.array)return h(t,{code:f.invalid_type,expected:m.array,received:t.parsedType}),_;if(i.exactLength!==null){let s=t.data.length>i.exactLength.value,c=t.data.length<i.exactLength.value;(s||c)&&(h(t,{code:s?f.too_big:f.too_small,minimum:c?i.exactLength.value:void 0,maximum:s?i.exactLength.value:void 0,type:"array",inclusive:!0,exact:!0,message:i.exactLength.message}),n.dirty())}if(i.minLength!==null&&t.data.length<i.minLength.value&&(h(t,{code:f.too_small,minimum:i.minLength.value,type:"array",inclusive:!0,exact:!1,message:i.minLength.message}),n.dirty()),i.maxLength!==null&&t.data.length>i.maxLength.value&&(h(t,{code:f.too_big,maximum:i.maxLength.value,type:"array",inclusive:!0,exact:!1,message:i.maxLength.message}),n.dirty()),t.common.async)return Promise.all([...t.data].map((s,c)=>i.type._parseAsync(new B(t,s,t.path,c)))).then(s=>P.mergeArray(n,s));let o=[...t.data].map((s,c)=>i.type._parseSync(new B(t,s,t.path,c)));return P.mergeArray(n,o)}get element(){return this._def.type}min(e,t){return new r({...this._def,minLength:{value:e,message:v.toString(t)}})}max(e,t){return new r({...this._def,maxLength:{value:e,message:v.toString(t)}})}length(e,t){return new r({...this._def,exactLength:{value:e,message:v.toString(t)}})}nonempty(e){return this.min(1,e)}};se.create=(r,e)=>new se({type:r,minLength:null,maxLength:null,exactLength:null,typeName:g.ZodArray,...b(e)});function Je(r){if(r instanceof D){let e={};for(let t in r.shape){let n=r.shape[t];e[t]=V.create(Je(n))}return new D({...r._def,shape:()=>e})}else return r instanceof se?new se({...r._def,type:Je(r.element)}):r instanceof V?V.create(Je(r.unwrap())):r instanceof te?te.create(Je(r.unwrap())):r instanceof ee?ee.create(r.items.map(e=>Je(e))):r}var D=class r extends x{constructor(){super(...arguments),this._cached=null,this.nonstrict=this.passthrough,this.augment=this.extend}_getCached(){if(this._cached!==null)return this._cached;let e=this._def.shape(),t=T.objectKeys(e);return this._cached={shape:e,keys:t}}_parse(e){if(this._getType(e)!==m.object){let d=this._getOrReturnCtx(e);return h(d,{code:f.invalid_type,expected:m.object,received:d.parsedType}),_}let{status:n,ctx:i}=this._processInputParams(e),{shape:o,keys:s}=this._getCached(),c=[];if(!(this._def.catchall instanceof J&&this._def.unknownKeys==="strip"))for(let d in i.data)s.includes(d)||c.push(d);let l=[];for(let d of s){let u=o[d],p=i.data[d];l.push({key:{status:"valid",value:d},value:u._parse(new B(i,p,i.path,d)),alwaysSet:d in i.data})}if(this._def.catchall instanceof J){let d=this._def.unknownKeys;if(d==="passthrough")for(let u of c)l.push({key:{status:"valid",value:u},value:{status:"valid",value:i.data[u]}});else if(d==="strict")c.length>0&&(h(i,{code:f.unrecognized_keys,keys:c}),n.dirty());else if(d!=="strip")throw new Error("Internal ZodObject error: invalid unknownKeys value.")}else{let d=this._def.catchall;for(let u of c){let p=i.data[u];l.push({key:{status:"valid",value:u},value:d._parse(new B(i,p,i.path,u)),alwaysSet:u in i.data})}}return i.common.async?Promise.resolve().then(async()=>{let d=[];for(let u of l){let p=await u.key,w=await u.value;d.push({key:p,value:w,alwaysSet:u.alwaysSet})}return d}).then(d=>P.mergeObjectSync(n,d)):P.mergeObjectSync(n,l)}get shape(){return this._def.shape()}strict(e){return v.errToObj,new r({...this._def,unknownKeys:"strict",...e!==void 0?{errorMap:(t,n)=>{var i,o,s,c;let l=(s=(o=(i=this._def).errorMap)===null||o===void 0?void 0:o.call(i,t,n).message)!==null&&s!==void 0?s:n.defaultError;return t.code==="unrecognized_keys"?{message:(c=v.errToObj(e).message)!==null&&c!==void 0?c:l}:{message:l}}}:{}})}strip(){return new r({...this._def,unknownKeys:"strip"})}passthrough(){return new r({...this._def,unknownKeys:"passthrough"})}extend(e){return new r({...this._def,shape:()=>({...this._def.shape(),...e})})}merge(e){return new r({unknownKeys:e._def.unknownKeys,catchall:e._def.catchall,shape:()=>({...this._def.shape(),...e._def.shape()}),typeName:g.ZodObject})}setKey(e,t){return this.augment({[e]:t})}catchall(e){return new r({...this._def,catchall:e})}pick(e){let t={};return T.objectKeys(e).forEach(n=>{e[n]&&this.shape[n]&&(t[n]=this.shape[n])}),new r({...this._def,shape:()=>t})}omit(e){let t={};return T.objectKeys(this.shape).forEach(n=>{e[n]||(t[n]=this.shape[n])}),new r({...this._def,shape:()=>t})}deepPartial(){return Je(this)}partial(e){let t={};return T.objectKeys(this.shape).forEach(n=>{let i=this.shape[n];e&&!e[n]?t[n]=i:t[n]=i.optional()}),new r({...this._def,shape:()=>t})}required(e){let t={};return T.objectKeys(this.shape).forEach(n=>{if(e&&!e[n])t[n]=this.shape[n];else{let o=this.shape[n];for(;o instanceof V;)o=o._def.innerType;t[n]=o}}),new r({...this._def,shape:()=>t})}keyof(){return Jn(T.objectKeys(this.shape))}};D.create=(r,e)=>new D({shape:()=>r,unknownKeys:"strip",catchall:J.create(),typeName:g.ZodObject,...b(e)});D.strictCreate=(r,e)=>new D({shape:()=>r,unknownKeys:"strict",catchall:J.create(),typeName:g.ZodObject,...b(e)});D.lazycreate=(r,e)=>new D({shape:r,unknownKeys:"strip",catchall:J.create(),typeName:g.ZodObject,...b(e)});var $e=class extends x{_parse(e){let{ctx:t}=this._processInputParams(e),n=this._def.options;function i(o){for(let c of o)if(c.result.status==="valid")return c.result;for(let c of o)if(c.result.status==="dirty")return t.common.issues.push(...c.ctx.common.issues),c.result;let s=o.map(c=>new z(c.ctx.common.issues));return h(t,{code:f.invalid_union,unionErrors:s}),_}if(t.common.async)return Promise.all(n.map(async o=>{let s={...t,common:{...t.common,issues:[]},parent:null};return{result:await o._parseAsync({data:t.data,path:t.path,parent:s}),ctx:s}})).then(i);{let o,s=[];for(let l of n){let d={...t,common:{...t.common,issues:[]},parent:null},u=l._parseSync({data:t.data,path:t.path,parent:d});if(u.status==="valid")return u;u.status==="dirty"&&!o&&(o={result:u,ctx:d}),d.common.issues.length&&s.push(d.common.issues)}if(o)return t.common.issues.push(...o.ctx.common.issues),o.result;let c=s.map(l=>new z(l));return h(t,{code:f.invalid_union,unionErrors:c}),_}}get options(){return this._def.options}};$e.create=(r,e)=>new $e({options:r,typeName:g.ZodUnion,...b(e)});var ne=r=>r instanceof Re?ne(r.schema):r instanceof Z?ne(r.innerType()):r instanceof Ie?[r.value]:r instanceof Pe?r.options:r instanceof Me?T.objectValues(r.enum):r instanceof De?ne(r._def.innerType):r instanceof Oe?[void 0]:r instanceof Ce?[null]:r instanceof V?[void 0,...ne(r.unwrap())]:r instanceof te?[null,...ne(r.unwrap())]:r instanceof gt||r instanceof Le?ne(r.unwrap()):r instanceof Ne?ne(r._def.innerType):[],nr=class r extends x{_parse(e){let{ctx:t}=this._processInputParams(e);if(t.parsedType!==m.object)return h(t,{code:f.invalid_type,expected:m.object,received:t.parsedType}),_;let n=this.discriminator,i=t.data[n],o=this.optionsMap.get(i);return o?t.common.async?o._parseAsync({data:t.data,path:t.path,parent:t}):o._parseSync({data:t.data,path:t.path,parent:t}):(h(t,{code:f.invalid_union_discriminator,options:Array.from(this.optionsMap.keys()),path:[n]}),_)}get discriminator(){return this._def.discriminator}get options(){return this._def.options}get optionsMap(){return this._def.optionsMap}static create(e,t,n){let i=new Map;for(let o of t){let s=ne(o.shape[e]);if(!s.length)throw new Error(`A discriminator value for key \`${e}\` could not be extracted from all schema options`);for(let c of s){if(i.has(c))throw new Error(`Discriminator property ${String(e)} has duplicate value ${String(c)}`);i.set(c,o)}}return new r({typeName:g.ZodDiscriminatedUnion,discriminator:e,options:t,optionsMap:i,...b(n)})}};function Br(r,e){let t=ie(r),n=ie(e);if(r===e)return{valid:!0,data:r};if(t===m.object&&n===m.object){let i=T.objectKeys(e),o=T.objectKeys(r).filter(c=>i.indexOf(c)!==-1),s={...r,...e};for(let c of o){let l=Br(r[c],e[c]);if(!l.valid)return{valid:!1};s[c]=l.data}return{valid:!0,data:s}}else if(t===m.array&&n===m.array){if(r.length!==e.length)return{valid:!1};let i=[];for(let o=0;o<r.length;o++){let s=r[o],c=e[o],l=Br(s,c);if(!l.valid)return{valid:!1};i.push(l.data)}return{valid:!0,data:i}}else return t===m.date&&n===m.date&&+r==+e?{valid:!0,data:r}:{valid:!1}}var je=class extends x{_parse(e){let{status:t,ctx:n}=this._processInputParams(e),i=(o,s)=>{if(Fr(o)||Fr(s))return _;let c=Br(o.value,s.value);return c.valid?((Vr(o)||Vr(s))&&t.dirty(),{status:t.value,value:c.data}):(h(n,{code:f.invalid_intersection_types}),_)};return n.common.async?Promise.all([this._def.left._parseAsync({data:n.data,path:n.path,parent:n}),this._def.right._parseAsync({data:n.data,path:n.path,parent:n})]).then(([o,s])=>i(o,s)):i(this._def.left._parseSync({data:n.data,path:n.path,parent:n}),this._def.right._parseSync({data:n.data,path:n.path,parent:n}))}};je.create=(r,e,t)=>new je({left:r,right:e,typeName:g.ZodIntersection,...b(t)});var ee=class r extends x{_parse(e){let{status:t,ctx:n}=this._processInputParams(e);if(n.parsedType!==m.array)return h(n,{code:f.invalid_type,expected:m.array,received:n.parsedType}),_;if(n.data.length<this._def.items.length)return h(n,{code:f.too_small,minimum:this._def.items.length,inclusive:!0,exact:!1,type:"array"}),_;!this._def.rest&&n.data.length>this._def.items.length&&(h(n,{code:f.too_big,maximum:this._def.items.length,inclusive:!0,exact:!1,type:"array"}),t.dirty());let o=[...n.data].map((s,c)=>{let l=this._def.items[c]||this._def.rest;return l?l._parse(new B(n,s,n.path,c)):null}).filter(s=>!!s);return n.common.async?Promise.all(o).then(s=>P.mergeArray(t,s)):P.mergeArray(t,o)}get items(){return this._def.items}rest(e){return new r({...this._def,rest:e})}};ee.create=(r,e)=>{if(!Array.isArray(r))throw new Error("You must pass an array of schemas to z.tuple([ ... ])");return new ee({items:r,typeName:g.ZodTuple,rest:null,...b(e)})};var ir=class r extends x{get keySchema(){return this._def.keyType}get valueSchema(){return this._def.valueType}_parse(e){let{status:t,ctx:n}=this._processInputParams(e);if(n.parsedType!==m.object)return h(n,{code:f.invalid_type,expected:m.object,received:n.parsedType}),_;let i=[],o=this._def.keyType,s=this._def.valueType;for(let c in n.data)i.push({key:o._parse(new B(n,c,n.path,c)),value:s._parse(new B(n,n.data[c],n.path,c)),alwaysSet:c in n.data});return n.common.async?P.mergeObjectAsync(t,i):P.mergeObjectSync(t,i)}get element(){return this._def.valueType}static create(e,t,n){return t instanceof x?new r({keyType:e,valueType:t,typeName:g.ZodRecord,...b(n)}):new r({keyType:ue.create(),valueType:e,typeName:g.ZodRecord,...b(t)})}},tt=class extends x{get keySchema(){return this._def.keyType}get valueSchema(){return this._def.valueType}_parse(e){let{status:t,ctx:n}=this._processInputParams(e);if(n.parsedType!==m.map)return h(n,{code:f.invalid_type,expected:m.map,received:n.parsedType}),_;let i=this._def.keyType,o=this._def.valueType,s=[...n.data.entries()].map(([c,l],d)=>({key:i._parse(new B(n,c,n.path,[d,"key"])),value:o._parse(new B(n,l,n.path,[d,"value"]))}));if(n.common.async){let c=new Map;return Promise.resolve().then(async()=>{for(let l of s){let d=await l.key,u=await l.value;if(d.status==="aborted"||u.status==="aborted")return _;(d.status==="dirty"||u.status==="dirty")&&t.dirty(),c.set(d.value,u.value)}return{status:t.value,value:c}})}else{let c=new Map;for(let l of s){let d=l.key,u=l.value;if(d.status==="aborted"||u.status==="aborted")return _;(d.status==="dirty"||u.status==="dirty")&&t.dirty(),c.set(d.value,u.value)}return{status:t.value,value:c}}}};tt.create=(r,e,t)=>new tt({valueType:e,keyType:r,typeName:g.ZodMap,...b(t)});var rt=class r extends x{_parse(e){let{status:t,ctx:n}=this._processInputParams(e);if(n.parsedType!==m.set)return h(n,{code:f.invalid_type,expected:m.set,received:n.parsedType}),_;let i=this._def;i.minSize!==null&&n.data.size<i.minSize.value&&(h(n,{code:f.too_small,minimum:i.minSize.value,type:"set",inclusive:!0,exact:!1,message:i.minSize.message}),t.dirty()),i.maxSize!==null&&n.data.size>i.maxSize.value&&(h(n,{code:f.too_big,maximum:i.maxSize.value,type:"set",inclusive:!0,exact:!1,message:i.maxSize.message}),t.dirty());let o=this._def.valueType;function s(l){let d=new Set;for(let u of l){if(u.status==="aborted")return _;u.status==="dirty"&&t.dirty(),d.add(u.value)}return{status:t.value,value:d}}let c=[...n.data.values()].map((l,d)=>o._parse(new B(n,l,n.path,d)));return n.common.async?Promise.all(c).then(l=>s(l)):s(c)}min(e,t){return new r({...this._def,minSize:{value:e,message:v.toString(t)}})}max(e,t){return new r({...this._def,maxSize:{value:e,message:v.toString(t)}})}size(e,t){return this.min(e,t).max(e,t)}nonempty(e){return this.min(1,e)}};rt.create=(r,e)=>new rt({valueType:r,minSize:null,maxSize:null,typeName:g.ZodSet,...b(e)});var or=class r extends x{constructor(){super(...arguments),this.validate=this.implement}_parse(e){let{ctx:t}=this._processInputParams(e);if(t.parsedType!==m.function)return h(t,{code:f.invalid_type,expected:m.function,received:t.parsedType}),_;function n(c,l){return tr({data:c,path:t.path,errorMaps:[t.common.contextualErrorMap,t.schemaErrorMap,er(),Xe].filter(d=>!!d),issueData:{code:f.invalid_arguments,argumentsError:l}})}function i(c,l){return tr({data:c,path:t.path,errorMaps:[t.common.contextualErrorMap,t.schemaErrorMap,er(),Xe].filter(d=>!!d),issueData:{code:f.invalid_return_type,returnTypeError:l}})}let o={errorMap:t.common.contextualErrorMap},s=t.data;if(this._def.returns instanceof pe){let c=this;return M(async function(...l){let d=new z([]),u=await c._def.args.parseAsync(l,o).catch(y=>{throw d.addIssue(n(l,y)),d}),p=await Reflect.apply(s,this,u);return await c._def.returns._def.type.parseAsync(p,o).catch(y=>{throw d.addIssue(i(p,y)),d})})}else{let c=this;return M(function(...l){let d=c._def.args.safeParse(l,o);if(!d.success)throw new z([n(l,d.error)]);let u=Reflect.apply(s,this,d.data),p=c._def.returns.safeParse(u,o);if(!p.success)throw new z([i(u,p.error)]);return p.data})}}parameters(){return this._def.args}returnType(){return this._def.returns}args(...e){return new r({...this._def,args:ee.create(e).rest(oe.create())})}returns(e){return new r({...this._def,returns:e})}implement(e){return this.parse(e)}strictImplement(e){return this.parse(e)}static create(e,t,n){return new r({args:e||ee.create([]).rest(oe.create()),returns:t||oe.create(),typeName:g.ZodFunction,...b(n)})}},Re=class extends x{get schema(){return this._def.getter()}_parse(e){let{ctx:t}=this._processInputParams(e);return this._def.getter()._parse({data:t.data,path:t.path,parent:t})}};Re.create=(r,e)=>new Re({getter:r,typeName:g.ZodLazy,...b(e)});var Ie=class extends x{_parse(e){if(e.data!==this._def.value){let t=this._getOrReturnCtx(e);return h(t,{received:t.data,code:f.invalid_literal,expected:this._def.value}),_}return{status:"valid",value:e.data}}get value(){return this._def.value}};Ie.create=(r,e)=>new Ie({value:r,typeName:g.ZodLiteral,...b(e)});function Jn(r,e){return new Pe({values:r,typeName:g.ZodEnum,...b(e)})}var Pe=class r extends x{constructor(){super(...arguments),mt.set(this,void 0)}_parse(e){if(typeof e.data!="string"){let t=this._getOrReturnCtx(e),n=this._def.values;return h(t,{expected:T.joinValues(n),received:t.parsedType,code:f.invalid_type}),_}if(rr(this,mt,"f")||Hn(this,mt,new Set(this._def.values),"f"),!rr(this,mt,"f").has(e.data)){let t=this._getOrReturnCtx(e),n=this._def.values;return h(t,{received:t.data,code:f.invalid_enum_value,options:n}),_}return M(e.data)}get options(){return this._def.values}get enum(){let e={};for(let t of this._def.values)e[t]=t;return e}get Values(){let e={};for(let t of this._def.values)e[t]=t;return e}get Enum(){let e={};for(let t of this._def.values)e[t]=t;return e}extract(e,t=this._def){return r.create(e,{...this._def,...t})}exclude(e,t=this._def){return r.create(this.options.filter(n=>!e.includes(n)),{...this._def,...t})}};mt=new WeakMap;Pe.create=Jn;var Me=class extends x{constructor(){super(...arguments),vt.set(this,void 0)}_parse(e){let t=T.getValidEnumValues(this._def.values),n=this._getOrReturnCtx(e);if(n.parsedType!==m.string&&n.parsedType!==m.number){let i=T.objectValues(t);return h(n,{expected:T.joinValues(i),received:n.parsedType,code:f.invalid_type}),_}if(rr(this,vt,"f")||Hn(this,vt,new Set(T.getValidEnumValues(this._def.values)),"f"),!rr(this,vt,"f").has(e.data)){let i=T.objectValues(t);return h(n,{received:n.data,code:f.invalid_enum_value,options:i}),_}return M(e.data)}get enum(){return this._def.values}};vt=new WeakMap;Me.create=(r,e)=>new Me({values:r,typeName:g.ZodNativeEnum,...b(e)});var pe=class extends x{unwrap(){return this._def.type}_parse(e){let{ctx:t}=this._processInputParams(e);if(t.parsedType!==m.promise&&t.common.async===!1)return h(t,{code:f.invalid_type,expected:m.promise,received:t.parsedType}),_;let n=t.parsedType===m.promise?t.data:Promise.resolve(t.data);return M(n.then(i=>this._def.type.parseAsync(i,{path:t.path,errorMap:t.common.contextualErrorMap})))}};pe.create=(r,e)=>new pe({type:r,typeName:g.ZodPromise,...b(e)});var Z=class extends x{innerType(){return this._def.schema}sourceType(){return this._def.schema._def.typeName===g.ZodEffects?this._def.schema.sourceType():this._def.schema}_parse(e){let{status:t,ctx:n}=this._processInputParams(e),i=this._def.effect||null,o={addIssue:s=>{h(n,s),s.fatal?t.abort():t.dirty()},get path(){return n.path}};if(o.addIssue=o.addIssue.bind(o),i.type==="preprocess"){let s=i.transform(n.data,o);if(n.common.async)return Promise.resolve(s).then(async c=>{if(t.value==="aborted")return _;let l=await this._def.schema._parseAsync({data:c,path:n.path,parent:n});return l.status==="aborted"?_:l.status==="dirty"||t.value==="dirty"?Ke(l.value):l});{if(t.value==="aborted")return _;let c=this._def.schema._parseSync({data:s,path:n.path,parent:n});return c.status==="aborted"?_:c.status==="dirty"||t.value==="dirty"?Ke(c.value):c}}if(i.type==="refinement"){let s=c=>{let l=i.refinement(c,o);if(n.common.async)return Promise.resolve(l);if(l instanceof Promise)throw new Error("Async refinement encountered during synchronous parse operation. Use .parseAsync instead.");return c};if(n.common.async===!1){let c=this._def.schema._parseSync({data:n.data,path:n.path,parent:n});return c.status==="aborted"?_:(c.status==="dirty"&&t.dirty(),s(c.value),{status:t.value,value:c.value})}else return this._def.schema._parseAsync({data:n.data,path:n.path,parent:n}).then(c=>c.status==="aborted"?_:(c.status==="dirty"&&t.dirty(),s(c.value).then(()=>({status:t.value,value:c.value}))))}if(i.type==="transform")if(n.common.async===!1){let s=this._def.schema._parseSync({data:n.data,path:n.path,parent:n});if(!Se(s))return s;let c=i.transform(s.value,o);if(c instanceof Promise)throw new Error("Asynchronous transform encountered during synchronous parse operation. Use .parseAsync instead.");return{status:t.value,value:c}}else return this._def.schema._parseAsync({data:n.data,path:n.path,parent:n}).then(s=>Se(s)?Promise.resolve(i.transform(s.value,o)).then(c=>({status:t.value,value:c})):s);T.assertNever(i)}};Z.create=(r,e,t)=>new Z({schema:r,typeName:g.ZodEffects,effect:e,...b(t)});Z.createWithPreprocess=(r,e,t)=>new Z({schema:e,effect:{type:"preprocess",transform:r},typeName:g.ZodEffects,...b(t)});var V=class extends x{_parse(e){return this._getType(e)===m.undefined?M(void 0):this._def.innerType._parse(e)}unwrap(){return this._def.innerType}};V.create=(r,e)=>new V({innerType:r,typeName:g.ZodOptional,...b(e)});var te=class extends x{_parse(e){return this._getType(e)===m.null?M(null):this._def.innerType._parse(e)}unwrap(){return this._def.innerType}};te.create=(r,e)=>new te({innerType:r,typeName:g.ZodNullable,...b(e)});var De=class extends x{_parse(e){let{ctx:t}=this._processInputParams(e),n=t.data;return t.parsedType===m.undefined&&(n=this._def.defaultValue()),this._def.innerType._parse({data:n,path:t.path,parent:t})}removeDefault(){return this._def.innerType}};De.create=(r,e)=>new De({innerType:r,typeName:g.ZodDefault,defaultValue:typeof e.default=="function"?e.default:()=>e.default,...b(e)});var Ne=class extends x{_parse(e){let{ctx:t}=this._processInputParams(e),n={...t,common:{...t.common,issues:[]}},i=this._def.innerType._parse({data:n.data,path:n.path,parent:{...n}});return yt(i)?i.then(o=>({status:"valid",value:o.status==="valid"?o.value:this._def.catchValue({get error(){return new z(n.common.issues)},input:n.data})})):{status:"valid",value:i.status==="valid"?i.value:this._def.catchValue({get error(){return new z(n.common.issues)},input:n.data})}}removeCatch(){return this._def.innerType}};Ne.create=(r,e)=>new Ne({innerType:r,typeName:g.ZodCatch,catchValue:typeof e.catch=="function"?e.catch:()=>e.catch,...b(e)});var nt=class extends x{_parse(e){if(this._getType(e)!==m.nan){let n=this._getOrReturnCtx(e);return h(n,{code:f.invalid_type,expected:m.nan,received:n.parsedType}),_}return{status:"valid",value:e.data}}};nt.create=r=>new nt({typeName:g.ZodNaN,...b(r)});var Uo=Symbol("zod_brand"),gt=class extends x{_parse(e){let{ctx:t}=this._processInputParams(e),n=t.data;return this._def.type._parse({data:n,path:t.path,parent:t})}unwrap(){return this._def.type}},_t=class r extends x{_parse(e){let{status:t,ctx:n}=this._processInputParams(e);if(n.common.async)return(async()=>{let o=await this._def.in._parseAsync({data:n.data,path:n.path,parent:n});return o.status==="aborted"?_:o.status==="dirty"?(t.dirty(),Ke(o.value)):this._def.out._parseAsync({data:o.value,path:n.path,parent:n})})();{let i=this._def.in._parseSync({data:n.data,path:n.path,parent:n});return i.status==="aborted"?_:i.status==="dirty"?(t.dirty(),{status:"dirty",value:i.value}):this._def.out._parseSync({data:i.value,path:n.path,parent:n})}}static create(e,t){return new r({in:e,out:t,typeName:g.ZodPipeline})}},Le=class extends x{_parse(e){let t=this._def.innerType._parse(e),n=i=>(Se(i)&&(i.value=Object.freeze(i.value)),i);return yt(t)?t.then(i=>n(i)):n(t)}unwrap(){return this._def.innerType}};Le.create=(r,e)=>new Le({innerType:r,typeName:g.ZodReadonly,...b(e)});function Vn(r,e){let t=typeof r=="function"?r(e):typeof r=="string"?{message:r}:r;return typeof t=="string"?{message:t}:t}function Kn(r,e={},t){return r?fe.create().superRefine((n,i)=>{var o,s;let c=r(n);if(c instanceof Promise)return c.then(l=>{var d,u;if(!l){let p=Vn(e,n),w=(u=(d=p.fatal)!==null&&d!==void 0?d:t)!==null&&u!==void 0?u:!0;i.addIssue({code:"custom",...p,fatal:w})}});if(!c){let l=Vn(e,n),d=(s=(o=l.fatal)!==null&&o!==void 0?o:t)!==null&&s!==void 0?s:!0;i.addIssue({code:"custom",...l,fatal:d})}}):fe.create()}var zo={object:D.lazycreate},g;(function(r){r.ZodString="ZodString",r.ZodNumber="ZodNumber",r.ZodNaN="ZodNaN",r.ZodBigInt="ZodBigInt",r.ZodBoolean="ZodBoolean",r.ZodDate="ZodDate",r.ZodSymbol="ZodSymbol",r.ZodUndefined="ZodUndefined",r.ZodNull="ZodNull",r.ZodAny="ZodAny",r.ZodUnknown="ZodUnknown",r.ZodNever="ZodNever",r.ZodVoid="ZodVoid",r.ZodArray="ZodArray",r.ZodObject="ZodObject",r.ZodUnion="ZodUnion",r.ZodDiscriminatedUnion="ZodDiscriminatedUnion",r.ZodIntersection="ZodIntersection",r.ZodTuple="ZodTuple",r.ZodRecord="ZodRecord",r.ZodMap="ZodMap",r.ZodSet="ZodSet",r.ZodFunction="ZodFunction",r.ZodLazy="ZodLazy",r.ZodLiteral="ZodLiteral",r.ZodEnum="ZodEnum",r.ZodEffects="ZodEffects",r.ZodNativeEnum="ZodNativeEnum",r.ZodOptional="ZodOptional",r.ZodNullable="ZodNullable",r.ZodDefault="ZodDefault",r.ZodCatch="ZodCatch",r.ZodPromise="ZodPromise",r.ZodBranded="ZodBranded",r.ZodPipeline="ZodPipeline",r.ZodReadonly="ZodReadonly"})(g||(g={}));var Zo=(r,e={message:`Input not instance of ${r.name}`})=>Kn(t=>t instanceof r,e),Xn=ue.create,Qn=ke.create,Fo=nt.create,Vo=Te.create,ei=Ae.create,Bo=Ee.create,Wo=Qe.create,Ho=Oe.create,qo=Ce.create,Go=fe.create,Yo=oe.create,Jo=J.create,Ko=et.create,Xo=se.create,Qo=D.create,es=D.strictCreate,ts=$e.create,rs=nr.create,ns=je.create,is=ee.create,os=ir.create,ss=tt.create,as=rt.create,cs=or.create,ls=Re.create,ds=Ie.create,us=Pe.create,fs=Me.create,ps=pe.create,Bn=Z.create,hs=V.create,ms=te.create,vs=Z.createWithPreprocess,ys=_t.create,gs=()=>Xn().optional(),_s=()=>Qn().optional(),bs=()=>ei().optional(),xs={string:(r=>ue.create({...r,coerce:!0})),number:(r=>ke.create({...r,coerce:!0})),boolean:(r=>Ae.create({...r,coerce:!0})),bigint:(r=>Te.create({...r,coerce:!0})),date:(r=>Ee.create({...r,coerce:!0}))},ws=_,a=Object.freeze({__proto__:null,defaultErrorMap:Xe,setErrorMap:vo,getErrorMap:er,makeIssue:tr,EMPTY_PATH:yo,addIssueToContext:h,ParseStatus:P,INVALID:_,DIRTY:Ke,OK:M,isAborted:Fr,isDirty:Vr,isValid:Se,isAsync:yt,get util(){return T},get objectUtil(){return Zr},ZodParsedType:m,getParsedType:ie,ZodType:x,datetimeRegex:Yn,ZodString:ue,ZodNumber:ke,ZodBigInt:Te,ZodBoolean:Ae,ZodDate:Ee,ZodSymbol:Qe,ZodUndefined:Oe,ZodNull:Ce,ZodAny:fe,ZodUnknown:oe,ZodNever:J,ZodVoid:et,ZodArray:se,ZodObject:D,ZodUnion:$e,ZodDiscriminatedUnion:nr,ZodIntersection:je,ZodTuple:ee,ZodRecord:ir,ZodMap:tt,ZodSet:rt,ZodFunction:or,ZodLazy:Re,ZodLiteral:Ie,ZodEnum:Pe,ZodNativeEnum:Me,ZodPromise:pe,ZodEffects:Z,ZodTransformer:Z,ZodOptional:V,ZodNullable:te,ZodDefault:De,ZodCatch:Ne,ZodNaN:nt,BRAND:Uo,ZodBranded:gt,ZodPipeline:_t,ZodReadonly:Le,custom:Kn,Schema:x,ZodSchema:x,late:zo,get ZodFirstPartyTypeKind(){return g},coerce:xs,any:Go,array:Xo,bigint:Vo,boolean:ei,date:Bo,discriminatedUnion:rs,effect:Bn,enum:us,function:cs,instanceof:Zo,intersection:ns,lazy:ls,literal:ds,map:ss,nan:Fo,nativeEnum:fs,never:Jo,null:qo,nullable:ms,number:Qn,object:Qo,oboolean:bs,onumber:_s,optional:hs,ostring:gs,pipeline:ys,preprocess:vs,promise:ps,record:os,set:as,strictObject:es,string:Xn,symbol:Wo,transformer:Bn,tuple:is,undefined:Ho,union:ts,unknown:Yo,void:Ko,NEVER:ws,ZodIssueCode:f,quotelessJson:mo,ZodError:z});var ni=(r=>(r.Info="info",r.Debug="debug",r.Trace="trace",r.Error="error",r))(ni||{}),ii=(r=>(r.Changed="Changed",r.Added="Added",r.Removed="Removed",r))(ii||{}),oi=(r=>(r.External="BSLIVE_EXTERNAL",r))(oi||{}),Ss=a.string(),it=a.lazy(()=>a.object({id:a.string(),label:a.string(),nodes:a.array(it)})),ks=a.nativeEnum(ni),ti=a.object({log_level:ks}),Ts=a.object({ws_path:a.string(),host:a.string().optional()}),As=a.object({kind:a.string(),ms:a.string()}),Es=a.object({message:a.string(),reason:a.string().optional()}),ri=a.object({path:a.string()}),Os=a.object({paths:a.array(a.string())}),si=a.discriminatedUnion("kind",[a.object({kind:a.literal("Both"),payload:a.object({name:a.string(),bind_address:a.string()})}),a.object({kind:a.literal("Address"),payload:a.object({bind_address:a.string()})}),a.object({kind:a.literal("Named"),payload:a.object({name:a.string()})}),a.object({kind:a.literal("Port"),payload:a.object({port:a.number()})}),a.object({kind:a.literal("PortNamed"),payload:a.object({port:a.number(),name:a.string()})})]),Cs=a.object({id:a.string(),identity:si,socket_addr:a.string()}),ai=a.object({servers:a.array(Cs)}),ci=a.object({connect:Ts,ctx_message:a.string()}),$s=a.object({path:a.string()}),js=a.object({body:a.string()}),Rs=a.discriminatedUnion("kind",[a.object({kind:a.literal("Html"),payload:a.object({html:a.string()})}),a.object({kind:a.literal("Json"),payload:a.object({json_str:a.string()})}),a.object({kind:a.literal("Raw"),payload:a.object({raw:a.string()})}),a.object({kind:a.literal("Sse"),payload:a.object({sse:js})}),a.object({kind:a.literal("Proxy"),payload:a.object({proxy:a.string()})}),a.object({kind:a.literal("Dir"),payload:a.object({dir:a.string(),base:a.string().optional()})})]),Is=a.discriminatedUnion("kind",[a.object({kind:a.literal("Stopped"),payload:a.object({bind_address:a.string()})}),a.object({kind:a.literal("Started"),payload:a.undefined().optional()}),a.object({kind:a.literal("Patched"),payload:a.undefined().optional()}),a.object({kind:a.literal("Errored"),payload:a.object({error:a.string()})})]),Ps=a.object({identity:si,change:Is}),Ku=a.object({items:a.array(Ps)}),Ms=a.object({path:a.string(),kind:Rs}),Ds=a.object({servers_resp:ai}),Ns=a.object({line:a.string(),prefix:a.string().optional()}),Ls=a.object({line:a.string(),prefix:a.string().optional()}),Us=a.object({paths:a.array(a.string())}),zs=a.union([a.object({kind:a.literal("Ok"),payload:a.undefined().optional()}),a.object({kind:a.literal("Err"),payload:a.string()}),a.object({kind:a.literal("Cancelled"),payload:a.undefined().optional()})]),Zs=a.object({tree:it,will_exec:a.boolean()}),Fs=a.object({paths:a.array(a.string()),debounce:As}),Vs=a.nativeEnum(ii),ar=a.lazy(()=>a.discriminatedUnion("kind",[a.object({kind:a.literal("Fs"),payload:a.object({path:a.string(),change_kind:Vs})}),a.object({kind:a.literal("FsMany"),payload:a.array(ar)})])),Xu=a.discriminatedUnion("kind",[a.object({kind:a.literal("Change"),payload:ar}),a.object({kind:a.literal("WsConnection"),payload:ti}),a.object({kind:a.literal("Config"),payload:ti}),a.object({kind:a.literal("DisplayMessage"),payload:Es})]),Qu=a.nativeEnum(oi),Bs=a.discriminatedUnion("kind",[a.object({kind:a.literal("Stdout"),payload:Ls}),a.object({kind:a.literal("Stderr"),payload:Ns})]),ef=a.discriminatedUnion("kind",[a.object({kind:a.literal("MissingInputs"),payload:a.string()}),a.object({kind:a.literal("InvalidInput"),payload:a.string()}),a.object({kind:a.literal("NotFound"),payload:a.string()}),a.object({kind:a.literal("InputWriteError"),payload:a.string()}),a.object({kind:a.literal("PathError"),payload:a.string()}),a.object({kind:a.literal("PortError"),payload:a.string()}),a.object({kind:a.literal("DirError"),payload:a.string()}),a.object({kind:a.literal("YamlError"),payload:a.string()}),a.object({kind:a.literal("MarkdownError"),payload:a.string()}),a.object({kind:a.literal("HtmlError"),payload:a.string()}),a.object({kind:a.literal("Io"),payload:a.string()}),a.object({kind:a.literal("UnsupportedExtension"),payload:a.string()}),a.object({kind:a.literal("MissingExtension"),payload:a.string()}),a.object({kind:a.literal("EmptyInput"),payload:a.string()}),a.object({kind:a.literal("BsLiveRules"),payload:a.string()})]),tf=a.discriminatedUnion("kind",[a.object({kind:a.literal("ServersChanged"),payload:ai}),a.object({kind:a.literal("TaskReport"),payload:a.object({id:a.string()})}),a.object({kind:a.literal("TaskTreeDisplay"),payload:a.object({tree:it})})]),rf=a.discriminatedUnion("kind",[a.object({kind:a.literal("Started"),payload:a.undefined().optional()}),a.object({kind:a.literal("FailedStartup"),payload:a.string()})]),nf=a.object({routes:a.array(Ms),id:a.string()}),Ws=a.lazy(()=>a.discriminatedUnion("kind",[a.object({kind:a.literal("Started"),payload:a.object({tree:it})}),a.object({kind:a.literal("Ended"),payload:a.object({tree:it,report:sr,report_map:a.record(sr)})}),a.object({kind:a.literal("Error"),payload:a.undefined().optional()})])),sr=a.lazy(()=>a.object({result:qs})),Hs=a.lazy(()=>a.object({stage:Ws})),qs=a.lazy(()=>a.object({conclusion:zs,invocation_id:Ss,task_reports:a.array(sr)})),Gs=a.lazy(()=>a.object({tree:it,report_map:a.record(sr)})),of=a.lazy(()=>a.discriminatedUnion("kind",[a.object({kind:a.literal("ServersChanged"),payload:Ds}),a.object({kind:a.literal("Watching"),payload:Fs}),a.object({kind:a.literal("WatchingStopped"),payload:Us}),a.object({kind:a.literal("FileChanged"),payload:ri}),a.object({kind:a.literal("FilesChanged"),payload:Os}),a.object({kind:a.literal("InputFileChanged"),payload:ri}),a.object({kind:a.literal("InputAccepted"),payload:$s}),a.object({kind:a.literal("OutputLine"),payload:Bs}),a.object({kind:a.literal("TaskAction"),payload:Hs}),a.object({kind:a.literal("TaskTreePreview"),payload:Zs}),a.object({kind:a.literal("TaskTreeSummary"),payload:Gs})]));var li=[{selector:"background",styleNames:["backgroundImage"]},{selector:"border",styleNames:["borderImage","webkitBorderImage","MozBorderImage"]}],cr={stylesheetReloadTimeout:15e3},Ys=/\.(jpe?g|png|gif|svg)$/i,lr=class{constructor(e,t,n){this.window=e,this.console=t,this.Timer=n,this.document=this.window.document,this.importCacheWaitPeriod=200,this.plugins=[]}addPlugin(e){return this.plugins.push(e)}analyze(e){}reload(e,t={}){if(this.options={...cr,...t},!(t.liveCSS&&e.match(/\.css(?:\.map)?$/i)&&this.reloadStylesheet(e))){if(t.liveImg&&e.match(Ys)){this.reloadImages(e);return}if(t.isChromeExtension){this.reloadChromeExtension();return}return this.reloadPage()}}reloadPage(){return this.window.document.location.reload()}reloadChromeExtension(){return this.window.chrome.runtime.reload()}reloadImages(e){let t,n=this.generateUniqueString();for(t of Array.from(this.document.images))di(e,Wr(t.src))&&(t.src=this.generateCacheBustUrl(t.src,n));if(this.document.querySelectorAll)for(let{selector:i,styleNames:o}of li)for(t of Array.from(this.document.querySelectorAll(`[style*=${i}]`)))this.reloadStyleImages(t.style,o,e,n);if(this.document.styleSheets)return Array.from(this.document.styleSheets).map(i=>this.reloadStylesheetImages(i,e,n))}reloadStylesheetImages(e,t,n){let i;try{i=(e||{}).cssRules}catch{}if(i)for(let o of Array.from(i))switch(o.type){case CSSRule.IMPORT_RULE:this.reloadStylesheetImages(o.styleSheet,t,n);break;case CSSRule.STYLE_RULE:for(let{styleNames:s}of li)this.reloadStyleImages(o.style,s,t,n);break;case CSSRule.MEDIA_RULE:this.reloadStylesheetImages(o,t,n);break}}reloadStyleImages(e,t,n,i){for(let o of t){let s=e[o];if(typeof s=="string"){let c=s.replace(new RegExp("\\burl\\s*\\(([^)]*)\\)"),(l,d)=>di(n,Wr(d))?`url(${this.generateCacheBustUrl(d,i)})`:l);c!==s&&(e[o]=c)}}}reloadStylesheet(e){let t=this.options||cr,n,i,o=(()=>{let l=[];for(i of Array.from(this.document.getElementsByTagName("link")))i.rel.match(/^stylesheet$/i)&&!i.__LiveReload_pendingRemoval&&l.push(i);return l})(),s=[];for(n of Array.from(this.document.getElementsByTagName("style")))n.sheet&&this.collectImportedStylesheets(n,n.sheet,s);for(i of Array.from(o))this.collectImportedStylesheets(i,i.sheet,s);if(this.window.StyleFix&&this.document.querySelectorAll)for(n of Array.from(this.document.querySelectorAll("style[data-href]")))o.push(n);this.console.debug(`found ${o.length} LINKed stylesheets, ${s.length} @imported stylesheets`);let c=Js(e,o.concat(s),l=>Wr(this.linkHref(l)));if(c)c.object.rule?(this.console.debug(`is reloading imported stylesheet: ${c.object.href}`),this.reattachImportedRule(c.object)):(this.console.debug(`is reloading stylesheet: ${this.linkHref(c.object)}`),this.reattachStylesheetLink(c.object));else if(t.reloadMissingCSS){this.console.debug(`will reload all stylesheets because path '${e}' did not match any specific one. To disable this behavior, set 'options.reloadMissingCSS' to 'false'.`);for(i of Array.from(o))this.reattachStylesheetLink(i)}else this.console.debug(`will not reload path '${e}' because the stylesheet was not found on the page and 'options.reloadMissingCSS' was set to 'false'.`);return!0}collectImportedStylesheets(e,t,n){let i;try{i=(t||{}).cssRules}catch{}if(i&&i.length)for(let o=0;o<i.length;o++){let s=i[o];switch(s.type){case CSSRule.CHARSET_RULE:continue;case CSSRule.IMPORT_RULE:n.push({link:e,rule:s,index:o,href:s.href}),this.collectImportedStylesheets(e,s.styleSheet,n);break;default:break}}}waitUntilCssLoads(e,t){let n=this.options||cr,i=!1,o=()=>{if(!i)return i=!0,t()};if(e.onload=()=>(this.console.debug("the new stylesheet has finished loading"),this.knownToSupportCssOnLoad=!0,o()),!this.knownToSupportCssOnLoad){let s;(s=()=>e.sheet?(this.console.debug("is polling until the new CSS finishes loading..."),o()):this.Timer.start(50,s))()}return this.Timer.start(n.stylesheetReloadTimeout,o)}linkHref(e){return e.href||e.getAttribute&&e.getAttribute("data-href")}reattachStylesheetLink(e){let t;if(e.__LiveReload_pendingRemoval)return;e.__LiveReload_pendingRemoval=!0,e.tagName==="STYLE"?(t=this.document.createElement("link"),t.rel="stylesheet",t.media=e.media,t.disabled=e.disabled):t=e.cloneNode(!1),t.href=this.generateCacheBustUrl(this.linkHref(e));let n=e.parentNode;return n.lastChild===e?n.appendChild(t):n.insertBefore(t,e.nextSibling),this.waitUntilCssLoads(t,()=>{let i;return/AppleWebKit/.test(this.window.navigator.userAgent)?i=5:i=200,this.Timer.start(i,()=>{if(e.parentNode)return e.parentNode.removeChild(e),t.onreadystatechange=null,this.window.StyleFix?this.window.StyleFix.link(t):void 0})})}reattachImportedRule({rule:e,index:t,link:n}){let i=e.parentStyleSheet,o=this.generateCacheBustUrl(e.href),s=e.media.length?[].join.call(e.media,", "):"",c=`@import url("${o}") ${s};`;e.__LiveReload_newHref=o;let l=this.document.createElement("link");return l.rel="stylesheet",l.href=o,l.__LiveReload_pendingRemoval=!0,n.parentNode&&n.parentNode.insertBefore(l,n),this.Timer.start(this.importCacheWaitPeriod,()=>{if(l.parentNode&&l.parentNode.removeChild(l),e.__LiveReload_newHref===o)return i.insertRule(c,t),i.deleteRule(t+1),e=i.cssRules[t],e.__LiveReload_newHref=o,this.Timer.start(this.importCacheWaitPeriod,()=>{if(e.__LiveReload_newHref===o)return i.insertRule(c,t),i.deleteRule(t+1)})})}generateUniqueString(){return`livereload=${Date.now()}`}generateCacheBustUrl(e,t){let n=this.options||cr,i,o;if(t||(t=this.generateUniqueString()),{url:e,hash:i,params:o}=ui(e),n.overrideURL&&e.indexOf(n.serverURL)<0){let c=e;e=n.serverURL+n.overrideURL+"?url="+encodeURIComponent(e),this.console.debug(`is overriding source URL ${c} with ${e}`)}let s=o.replace(/(\?|&)livereload=(\d+)/,(c,l)=>`${l}${t}`);return s===o&&(o.length===0?s=`?${t}`:s=`${o}&${t}`),e+s+i}};function ui(r){let e="",t="",n=r.indexOf("#");n>=0&&(e=r.slice(n),r=r.slice(0,n));let i=r.indexOf("??");return i>=0?i+1!==r.lastIndexOf("?")&&(n=r.lastIndexOf("?")):n=r.indexOf("?"),n>=0&&(t=r.slice(n),r=r.slice(0,n)),{url:r,params:t,hash:e}}function Wr(r){if(!r)return"";let e;return{url:r}=ui(r),r.indexOf("file://")===0?e=r.replace(new RegExp("^file://(localhost)?"),""):e=r.replace(new RegExp("^([^:]+:)?//([^:/]+)(:\\d*)?/"),"/"),decodeURIComponent(e)}function fi(r,e){if(r=r.replace(/^\/+/,"").toLowerCase(),e=e.replace(/^\/+/,"").toLowerCase(),r===e)return 1e4;let t=r.split(/\/|\\/).reverse(),n=e.split(/\/|\\/).reverse(),i=Math.min(t.length,n.length),o=0;for(;o<i&&t[o]===n[o];)++o;return o}function Js(r,e,t=n=>n){let n,i={score:0};for(let o of e)n=fi(r,t(o)),n>i.score&&(i={object:o,score:n});return i.score===0?null:i}function di(r,e){return fi(r,e)>0}var mi=Yi(hi(),1);var Ks=/\.(jpe?g|png|gif|svg)$/i;function Hr(r,e,t){switch(r.kind){case"FsMany":{if(r.payload.some(i=>{switch(i.kind){case"Fs":return!(i.payload.path.match(/\.css(?:\.map)?$/i)||i.payload.path.match(Ks));case"FsMany":throw new Error("unreachable")}}))return window.__playwright?.record?window.__playwright?.record({kind:"reloadPage"}):t.reloadPage();for(let i of r.payload)Hr(i,e,t);break}case"Fs":{let n=r.payload.path,i={liveCSS:!0,liveImg:!0,reloadMissingCSS:!0,originalPath:"",overrideURL:"",serverURL:""};window.__playwright?.record?window.__playwright?.record({kind:"reload",args:{path:n,opts:i}}):(e.trace("will reload a file with path ",n),t.reload(n,i))}}}var qr={name:"dom plugin",globalSetup:(r,e)=>{let t=new lr(window,e,mi.Timer);return[r,[e,t]]},resetSink(r,e,t){let[n,i]=e;return r.pipe(de(o=>o.kind==="Change"),Q(o=>o.payload),we(o=>{n.trace("incoming message",JSON.stringify({change:o,config:t},null,2));let s=ar.parse(o);Hr(s,n,i)}),xe())}};var ur=globalThis,fr=ur.ShadowRoot&&(ur.ShadyCSS===void 0||ur.ShadyCSS.nativeShadow)&&"adoptedStyleSheets"in Document.prototype&&"replace"in CSSStyleSheet.prototype,Gr=Symbol(),vi=new WeakMap,bt=class{constructor(e,t,n){if(this._$cssResult$=!0,n!==Gr)throw Error("CSSResult is not constructable. Use `unsafeCSS` or `css` instead.");this.cssText=e,this.t=t}get styleSheet(){let e=this.o,t=this.t;if(fr&&e===void 0){let n=t!==void 0&&t.length===1;n&&(e=vi.get(t)),e===void 0&&((this.o=e=new CSSStyleSheet).replaceSync(this.cssText),n&&vi.set(t,e))}return e}toString(){return this.cssText}},yi=r=>new bt(typeof r=="string"?r:r+"",void 0,Gr),K=(r,...e)=>{let t=r.length===1?r[0]:e.reduce((n,i,o)=>n+(s=>{if(s._$cssResult$===!0)return s.cssText;if(typeof s=="number")return s;throw Error("Value passed to 'css' function must be a 'css' function result: "+s+". Use 'unsafeCSS' to pass non-literal values, but take care to ensure page security.")})(i)+r[o+1],r[0]);return new bt(t,r,Gr)},gi=(r,e)=>{if(fr)r.adoptedStyleSheets=e.map(t=>t instanceof CSSStyleSheet?t:t.styleSheet);else for(let t of e){let n=document.createElement("style"),i=ur.litNonce;i!==void 0&&n.setAttribute("nonce",i),n.textContent=t.cssText,r.appendChild(n)}},Yr=fr?r=>r:r=>r instanceof CSSStyleSheet?(e=>{let t="";for(let n of e.cssRules)t+=n.cssText;return yi(t)})(r):r;var{is:Xs,defineProperty:Qs,getOwnPropertyDescriptor:ea,getOwnPropertyNames:ta,getOwnPropertySymbols:ra,getPrototypeOf:na}=Object,pr=globalThis,_i=pr.trustedTypes,ia=_i?_i.emptyScript:"",oa=pr.reactiveElementPolyfillSupport,xt=(r,e)=>r,wt={toAttribute(r,e){switch(e){case Boolean:r=r?ia:null;break;case Object:case Array:r=r==null?r:JSON.stringify(r)}return r},fromAttribute(r,e){let t=r;switch(e){case Boolean:t=r!==null;break;case Number:t=r===null?null:Number(r);break;case Object:case Array:try{t=JSON.parse(r)}catch{t=null}}return t}},hr=(r,e)=>!Xs(r,e),bi={attribute:!0,type:String,converter:wt,reflect:!1,useDefault:!1,hasChanged:hr};Symbol.metadata??=Symbol("metadata"),pr.litPropertyMetadata??=new WeakMap;var ae=class extends HTMLElement{static addInitializer(e){this._$Ei(),(this.l??=[]).push(e)}static get observedAttributes(){return this.finalize(),this._$Eh&&[...this._$Eh.keys()]}static createProperty(e,t=bi){if(t.state&&(t.attribute=!1),this._$Ei(),this.prototype.hasOwnProperty(e)&&((t=Object.create(t)).wrapped=!0),this.elementProperties.set(e,t),!t.noAccessor){let n=Symbol(),i=this.getPropertyDescriptor(e,n,t);i!==void 0&&Qs(this.prototype,e,i)}}static getPropertyDescriptor(e,t,n){let{get:i,set:o}=ea(this.prototype,e)??{get(){return this[t]},set(s){this[t]=s}};return{get:i,set(s){let c=i?.call(this);o?.call(this,s),this.requestUpdate(e,c,n)},configurable:!0,enumerable:!0}}static getPropertyOptions(e){return this.elementProperties.get(e)??bi}static _$Ei(){if(this.hasOwnProperty(xt("elementProperties")))return;let e=na(this);e.finalize(),e.l!==void 0&&(this.l=[...e.l]),this.elementProperties=new Map(e.elementProperties)}static finalize(){if(this.hasOwnProperty(xt("finalized")))return;if(this.finalized=!0,this._$Ei(),this.hasOwnProperty(xt("properties"))){let t=this.properties,n=[...ta(t),...ra(t)];for(let i of n)this.createProperty(i,t[i])}let e=this[Symbol.metadata];if(e!==null){let t=litPropertyMetadata.get(e);if(t!==void 0)for(let[n,i]of t)this.elementProperties.set(n,i)}this._$Eh=new Map;for(let[t,n]of this.elementProperties){let i=this._$Eu(t,n);i!==void 0&&this._$Eh.set(i,t)}this.elementStyles=this.finalizeStyles(this.styles)}static finalizeStyles(e){let t=[];if(Array.isArray(e)){let n=new Set(e.flat(1/0).reverse());for(let i of n)t.unshift(Yr(i))}else e!==void 0&&t.push(Yr(e));return t}static _$Eu(e,t){let n=t.attribute;return n===!1?void 0:typeof n=="string"?n:typeof e=="string"?e.toLowerCase():void 0}constructor(){super(),this._$Ep=void 0,this.isUpdatePending=!1,this.hasUpdated=!1,this._$Em=null,this._$Ev()}_$Ev(){this._$ES=new Promise(e=>this.enableUpdating=e),this._$AL=new Map,this._$E_(),this.requestUpdate(),this.constructor.l?.forEach(e=>e(this))}addController(e){(this._$EO??=new Set).add(e),this.renderRoot!==void 0&&this.isConnected&&e.hostConnected?.()}removeController(e){this._$EO?.delete(e)}_$E_(){let e=new Map,t=this.constructor.elementProperties;for(let n of t.keys())this.hasOwnProperty(n)&&(e.set(n,this[n]),delete this[n]);e.size>0&&(this._$Ep=e)}createRenderRoot(){let e=this.shadowRoot??this.attachShadow(this.constructor.shadowRootOptions);return gi(e,this.constructor.elementStyles),e}connectedCallback(){this.renderRoot??=this.createRenderRoot(),this.enableUpdating(!0),this._$EO?.forEach(e=>e.hostConnected?.())}enableUpdating(e){}disconnectedCallback(){this._$EO?.forEach(e=>e.hostDisconnected?.())}attributeChangedCallback(e,t,n){this._$AK(e,n)}_$ET(e,t){let n=this.constructor.elementProperties.get(e),i=this.constructor._$Eu(e,n);if(i!==void 0&&n.reflect===!0){let o=(n.converter?.toAttribute!==void 0?n.converter:wt).toAttribute(t,n.type);this._$Em=e,o==null?this.removeAttribute(i):this.setAttribute(i,o),this._$Em=null}}_$AK(e,t){let n=this.constructor,i=n._$Eh.get(e);if(i!==void 0&&this._$Em!==i){let o=n.getPropertyOptions(i),s=typeof o.converter=="function"?{fromAttribute:o.converter}:o.converter?.fromAttribute!==void 0?o.converter:wt;this._$Em=i;let c=s.fromAttribute(t,o.type);this[i]=c??this._$Ej?.get(i)??c,this._$Em=null}}requestUpdate(e,t,n,i=!1,o){if(e!==void 0){let s=this.constructor;if(i===!1&&(o=this[e]),n??=s.getPropertyOptions(e),!((n.hasChanged??hr)(o,t)||n.useDefault&&n.reflect&&o===this._$Ej?.get(e)&&!this.hasAttribute(s._$Eu(e,n))))return;this.C(e,t,n)}this.isUpdatePending===!1&&(this._$ES=this._$EP())}C(e,t,{useDefault:n,reflect:i,wrapped:o},s){n&&!(this._$Ej??=new Map).has(e)&&(this._$Ej.set(e,s??t??this[e]),o!==!0||s!==void 0)||(this._$AL.has(e)||(this.hasUpdated||n||(t=void 0),this._$AL.set(e,t)),i===!0&&this._$Em!==e&&(this._$Eq??=new Set).add(e))}async _$EP(){this.isUpdatePending=!0;try{await this._$ES}catch(t){Promise.reject(t)}let e=this.scheduleUpdate();return e!=null&&await e,!this.isUpdatePending}scheduleUpdate(){return this.performUpdate()}performUpdate(){if(!this.isUpdatePending)return;if(!this.hasUpdated){if(this.renderRoot??=this.createRenderRoot(),this._$Ep){for(let[i,o]of this._$Ep)this[i]=o;this._$Ep=void 0}let n=this.constructor.elementProperties;if(n.size>0)for(let[i,o]of n){let{wrapped:s}=o,c=this[i];s!==!0||this._$AL.has(i)||c===void 0||this.C(i,void 0,o,c)}}let e=!1,t=this._$AL;try{e=this.shouldUpdate(t),e?(this.willUpdate(t),this._$EO?.forEach(n=>n.hostUpdate?.()),this.update(t)):this._$EM()}catch(n){throw e=!1,this._$EM(),n}e&&this._$AE(t)}willUpdate(e){}_$AE(e){this._$EO?.forEach(t=>t.hostUpdated?.()),this.hasUpdated||(this.hasUpdated=!0,this.firstUpdated(e)),this.updated(e)}_$EM(){this._$AL=new Map,this.isUpdatePending=!1}get updateComplete(){return this.getUpdateComplete()}getUpdateComplete(){return this._$ES}shouldUpdate(e){return!0}update(e){this._$Eq&&=this._$Eq.forEach(t=>this._$ET(t,this[t])),this._$EM()}updated(e){}firstUpdated(e){}};ae.elementStyles=[],ae.shadowRootOptions={mode:"open"},ae[xt("elementProperties")]=new Map,ae[xt("finalized")]=new Map,oa?.({ReactiveElement:ae}),(pr.reactiveElementVersions??=[]).push("2.1.2");var Kr=globalThis,xi=r=>r,mr=Kr.trustedTypes,wi=mr?mr.createPolicy("lit-html",{createHTML:r=>r}):void 0,Xr="$lit$",ce=`lit$${Math.random().toFixed(9).slice(2)}$`,Qr="?"+ce,sa=`<${Qr}>`,Ze=document,kt=()=>Ze.createComment(""),Tt=r=>r===null||typeof r!="object"&&typeof r!="function",en=Array.isArray,Oi=r=>en(r)||typeof r?.[Symbol.iterator]=="function",Jr=`[ 	
+  `):"",this.name="UnsubscriptionError",this.errors=t}});function ye(r,e){if(r){var t=r.indexOf(e);0<=t&&r.splice(t,1)}}var X=(function(){function r(e){this.initialTeardown=e,this.closed=!1,this._parentage=null,this._finalizers=null}return r.prototype.unsubscribe=function(){var e,t,n,i,o;if(!this.closed){this.closed=!0;var s=this._parentage;if(s)if(this._parentage=null,Array.isArray(s))try{for(var c=re(s),l=c.next();!l.done;l=c.next()){var d=l.value;d.remove(this)}}catch(S){e={error:S}}finally{try{l&&!l.done&&(t=c.return)&&t.call(c)}finally{if(e)throw e.error}}else s.remove(this);var u=this.initialTeardown;if(k(u))try{u()}catch(S){o=S instanceof Pt?S.errors:[S]}var p=this._finalizers;if(p){this._finalizers=null;try{for(var w=re(p),y=w.next();!y.done;y=w.next()){var A=y.value;try{fn(A)}catch(S){o=o??[],S instanceof Pt?o=q(q([],H(o)),H(S.errors)):o.push(S)}}}catch(S){n={error:S}}finally{try{y&&!y.done&&(i=w.return)&&i.call(w)}finally{if(n)throw n.error}}}if(o)throw new Pt(o)}},r.prototype.add=function(e){var t;if(e&&e!==this)if(this.closed)fn(e);else{if(e instanceof r){if(e.closed||e._hasParent(this))return;e._addParent(this)}(this._finalizers=(t=this._finalizers)!==null&&t!==void 0?t:[]).push(e)}},r.prototype._hasParent=function(e){var t=this._parentage;return t===e||Array.isArray(t)&&t.includes(e)},r.prototype._addParent=function(e){var t=this._parentage;this._parentage=Array.isArray(t)?(t.push(e),t):t?[t,e]:e},r.prototype._removeParent=function(e){var t=this._parentage;t===e?this._parentage=null:Array.isArray(t)&&ye(t,e)},r.prototype.remove=function(e){var t=this._finalizers;t&&ye(t,e),e instanceof r&&e._removeParent(this)},r.EMPTY=(function(){var e=new r;return e.closed=!0,e})(),r})();var Cr=X.EMPTY;function Mt(r){return r instanceof X||r&&"closed"in r&&k(r.remove)&&k(r.add)&&k(r.unsubscribe)}function fn(r){k(r)?r():r.unsubscribe()}var G={onUnhandledError:null,onStoppedNotification:null,Promise:void 0,useDeprecatedSynchronousErrorHandling:!1,useDeprecatedNextContext:!1};var qe={setTimeout:function(r,e){for(var t=[],n=2;n<arguments.length;n++)t[n-2]=arguments[n];var i=qe.delegate;return i?.setTimeout?i.setTimeout.apply(i,q([r,e],H(t))):setTimeout.apply(void 0,q([r,e],H(t)))},clearTimeout:function(r){var e=qe.delegate;return(e?.clearTimeout||clearTimeout)(r)},delegate:void 0};function Dt(r){qe.setTimeout(function(){var e=G.onUnhandledError;if(e)e(r);else throw r})}function dt(){}var pn=(function(){return $r("C",void 0,void 0)})();function hn(r){return $r("E",void 0,r)}function mn(r){return $r("N",r,void 0)}function $r(r,e,t){return{kind:r,value:e,error:t}}var ge=null;function Ge(r){if(G.useDeprecatedSynchronousErrorHandling){var e=!ge;if(e&&(ge={errorThrown:!1,error:null}),r(),e){var t=ge,n=t.errorThrown,i=t.error;if(ge=null,n)throw i}}else r()}function vn(r){G.useDeprecatedSynchronousErrorHandling&&ge&&(ge.errorThrown=!0,ge.error=r)}var _e=(function(r){R(e,r);function e(t){var n=r.call(this)||this;return n.isStopped=!1,t?(n.destination=t,Mt(t)&&t.add(n)):n.destination=Qi,n}return e.create=function(t,n,i){return new be(t,n,i)},e.prototype.next=function(t){this.isStopped?Rr(mn(t),this):this._next(t)},e.prototype.error=function(t){this.isStopped?Rr(hn(t),this):(this.isStopped=!0,this._error(t))},e.prototype.complete=function(){this.isStopped?Rr(pn,this):(this.isStopped=!0,this._complete())},e.prototype.unsubscribe=function(){this.closed||(this.isStopped=!0,r.prototype.unsubscribe.call(this),this.destination=null)},e.prototype._next=function(t){this.destination.next(t)},e.prototype._error=function(t){try{this.destination.error(t)}finally{this.unsubscribe()}},e.prototype._complete=function(){try{this.destination.complete()}finally{this.unsubscribe()}},e})(X);var Ji=Function.prototype.bind;function jr(r,e){return Ji.call(r,e)}var Ki=(function(){function r(e){this.partialObserver=e}return r.prototype.next=function(e){var t=this.partialObserver;if(t.next)try{t.next(e)}catch(n){Nt(n)}},r.prototype.error=function(e){var t=this.partialObserver;if(t.error)try{t.error(e)}catch(n){Nt(n)}else Nt(e)},r.prototype.complete=function(){var e=this.partialObserver;if(e.complete)try{e.complete()}catch(t){Nt(t)}},r})(),be=(function(r){R(e,r);function e(t,n,i){var o=r.call(this)||this,s;if(k(t)||!t)s={next:t??void 0,error:n??void 0,complete:i??void 0};else{var c;o&&G.useDeprecatedNextContext?(c=Object.create(t),c.unsubscribe=function(){return o.unsubscribe()},s={next:t.next&&jr(t.next,c),error:t.error&&jr(t.error,c),complete:t.complete&&jr(t.complete,c)}):s=t}return o.destination=new Ki(s),o}return e})(_e);function Nt(r){G.useDeprecatedSynchronousErrorHandling?vn(r):Dt(r)}function Xi(r){throw r}function Rr(r,e){var t=G.onStoppedNotification;t&&qe.setTimeout(function(){return t(r,e)})}var Qi={closed:!0,next:dt,error:Xi,complete:dt};var Ye=(function(){return typeof Symbol=="function"&&Symbol.observable||"@@observable"})();function le(r){return r}function yn(r){return r.length===0?le:r.length===1?r[0]:function(t){return r.reduce(function(n,i){return i(n)},t)}}var E=(function(){function r(e){e&&(this._subscribe=e)}return r.prototype.lift=function(e){var t=new r;return t.source=this,t.operator=e,t},r.prototype.subscribe=function(e,t,n){var i=this,o=to(e)?e:new be(e,t,n);return Ge(function(){var s=i,c=s.operator,l=s.source;o.add(c?c.call(o,l):l?i._subscribe(o):i._trySubscribe(o))}),o},r.prototype._trySubscribe=function(e){try{return this._subscribe(e)}catch(t){e.error(t)}},r.prototype.forEach=function(e,t){var n=this;return t=gn(t),new t(function(i,o){var s=new be({next:function(c){try{e(c)}catch(l){o(l),s.unsubscribe()}},error:o,complete:i});n.subscribe(s)})},r.prototype._subscribe=function(e){var t;return(t=this.source)===null||t===void 0?void 0:t.subscribe(e)},r.prototype[Ye]=function(){return this},r.prototype.pipe=function(){for(var e=[],t=0;t<arguments.length;t++)e[t]=arguments[t];return yn(e)(this)},r.prototype.toPromise=function(e){var t=this;return e=gn(e),new e(function(n,i){var o;t.subscribe(function(s){return o=s},function(s){return i(s)},function(){return n(o)})})},r.create=function(e){return new r(e)},r})();function gn(r){var e;return(e=r??G.Promise)!==null&&e!==void 0?e:Promise}function eo(r){return r&&k(r.next)&&k(r.error)&&k(r.complete)}function to(r){return r&&r instanceof _e||eo(r)&&Mt(r)}function ro(r){return k(r?.lift)}function C(r){return function(e){if(ro(e))return e.lift(function(t){try{return r(t,this)}catch(n){this.error(n)}});throw new TypeError("Unable to lift unknown Observable type")}}function $(r,e,t,n,i){return new no(r,e,t,n,i)}var no=(function(r){R(e,r);function e(t,n,i,o,s,c){var l=r.call(this,t)||this;return l.onFinalize=s,l.shouldUnsubscribe=c,l._next=n?function(d){try{n(d)}catch(u){t.error(u)}}:r.prototype._next,l._error=o?function(d){try{o(d)}catch(u){t.error(u)}finally{this.unsubscribe()}}:r.prototype._error,l._complete=i?function(){try{i()}catch(d){t.error(d)}finally{this.unsubscribe()}}:r.prototype._complete,l}return e.prototype.unsubscribe=function(){var t;if(!this.shouldUnsubscribe||this.shouldUnsubscribe()){var n=this.closed;r.prototype.unsubscribe.call(this),!n&&((t=this.onFinalize)===null||t===void 0||t.call(this))}},e})(_e);var _n=It(function(r){return function(){r(this),this.name="ObjectUnsubscribedError",this.message="object unsubscribed"}});var Y=(function(r){R(e,r);function e(){var t=r.call(this)||this;return t.closed=!1,t.currentObservers=null,t.observers=[],t.isStopped=!1,t.hasError=!1,t.thrownError=null,t}return e.prototype.lift=function(t){var n=new Lt(this,this);return n.operator=t,n},e.prototype._throwIfClosed=function(){if(this.closed)throw new _n},e.prototype.next=function(t){var n=this;Ge(function(){var i,o;if(n._throwIfClosed(),!n.isStopped){n.currentObservers||(n.currentObservers=Array.from(n.observers));try{for(var s=re(n.currentObservers),c=s.next();!c.done;c=s.next()){var l=c.value;l.next(t)}}catch(d){i={error:d}}finally{try{c&&!c.done&&(o=s.return)&&o.call(s)}finally{if(i)throw i.error}}}})},e.prototype.error=function(t){var n=this;Ge(function(){if(n._throwIfClosed(),!n.isStopped){n.hasError=n.isStopped=!0,n.thrownError=t;for(var i=n.observers;i.length;)i.shift().error(t)}})},e.prototype.complete=function(){var t=this;Ge(function(){if(t._throwIfClosed(),!t.isStopped){t.isStopped=!0;for(var n=t.observers;n.length;)n.shift().complete()}})},e.prototype.unsubscribe=function(){this.isStopped=this.closed=!0,this.observers=this.currentObservers=null},Object.defineProperty(e.prototype,"observed",{get:function(){var t;return((t=this.observers)===null||t===void 0?void 0:t.length)>0},enumerable:!1,configurable:!0}),e.prototype._trySubscribe=function(t){return this._throwIfClosed(),r.prototype._trySubscribe.call(this,t)},e.prototype._subscribe=function(t){return this._throwIfClosed(),this._checkFinalizedStatuses(t),this._innerSubscribe(t)},e.prototype._innerSubscribe=function(t){var n=this,i=this,o=i.hasError,s=i.isStopped,c=i.observers;return o||s?Cr:(this.currentObservers=null,c.push(t),new X(function(){n.currentObservers=null,ye(c,t)}))},e.prototype._checkFinalizedStatuses=function(t){var n=this,i=n.hasError,o=n.thrownError,s=n.isStopped;i?t.error(o):s&&t.complete()},e.prototype.asObservable=function(){var t=new E;return t.source=this,t},e.create=function(t,n){return new Lt(t,n)},e})(E);var Lt=(function(r){R(e,r);function e(t,n){var i=r.call(this)||this;return i.destination=t,i.source=n,i}return e.prototype.next=function(t){var n,i;(i=(n=this.destination)===null||n===void 0?void 0:n.next)===null||i===void 0||i.call(n,t)},e.prototype.error=function(t){var n,i;(i=(n=this.destination)===null||n===void 0?void 0:n.error)===null||i===void 0||i.call(n,t)},e.prototype.complete=function(){var t,n;(n=(t=this.destination)===null||t===void 0?void 0:t.complete)===null||n===void 0||n.call(t)},e.prototype._subscribe=function(t){var n,i;return(i=(n=this.source)===null||n===void 0?void 0:n.subscribe(t))!==null&&i!==void 0?i:Cr},e})(Y);var ut={now:function(){return(ut.delegate||Date).now()},delegate:void 0};var Ut=(function(r){R(e,r);function e(t,n,i){t===void 0&&(t=1/0),n===void 0&&(n=1/0),i===void 0&&(i=ut);var o=r.call(this)||this;return o._bufferSize=t,o._windowTime=n,o._timestampProvider=i,o._buffer=[],o._infiniteTimeWindow=!0,o._infiniteTimeWindow=n===1/0,o._bufferSize=Math.max(1,t),o._windowTime=Math.max(1,n),o}return e.prototype.next=function(t){var n=this,i=n.isStopped,o=n._buffer,s=n._infiniteTimeWindow,c=n._timestampProvider,l=n._windowTime;i||(o.push(t),!s&&o.push(c.now()+l)),this._trimBuffer(),r.prototype.next.call(this,t)},e.prototype._subscribe=function(t){this._throwIfClosed(),this._trimBuffer();for(var n=this._innerSubscribe(t),i=this,o=i._infiniteTimeWindow,s=i._buffer,c=s.slice(),l=0;l<c.length&&!t.closed;l+=o?1:2)t.next(c[l]);return this._checkFinalizedStatuses(t),n},e.prototype._trimBuffer=function(){var t=this,n=t._bufferSize,i=t._timestampProvider,o=t._buffer,s=t._infiniteTimeWindow,c=(s?1:2)*n;if(n<1/0&&c<o.length&&o.splice(0,o.length-c),!s){for(var l=i.now(),d=0,u=1;u<o.length&&o[u]<=l;u+=2)d=u;d&&o.splice(0,d+1)}},e})(Y);var bn=(function(r){R(e,r);function e(t,n){return r.call(this)||this}return e.prototype.schedule=function(t,n){return n===void 0&&(n=0),this},e})(X);var ft={setInterval:function(r,e){for(var t=[],n=2;n<arguments.length;n++)t[n-2]=arguments[n];var i=ft.delegate;return i?.setInterval?i.setInterval.apply(i,q([r,e],H(t))):setInterval.apply(void 0,q([r,e],H(t)))},clearInterval:function(r){var e=ft.delegate;return(e?.clearInterval||clearInterval)(r)},delegate:void 0};var xn=(function(r){R(e,r);function e(t,n){var i=r.call(this,t,n)||this;return i.scheduler=t,i.work=n,i.pending=!1,i}return e.prototype.schedule=function(t,n){var i;if(n===void 0&&(n=0),this.closed)return this;this.state=t;var o=this.id,s=this.scheduler;return o!=null&&(this.id=this.recycleAsyncId(s,o,n)),this.pending=!0,this.delay=n,this.id=(i=this.id)!==null&&i!==void 0?i:this.requestAsyncId(s,this.id,n),this},e.prototype.requestAsyncId=function(t,n,i){return i===void 0&&(i=0),ft.setInterval(t.flush.bind(t,this),i)},e.prototype.recycleAsyncId=function(t,n,i){if(i===void 0&&(i=0),i!=null&&this.delay===i&&this.pending===!1)return n;n!=null&&ft.clearInterval(n)},e.prototype.execute=function(t,n){if(this.closed)return new Error("executing a cancelled action");this.pending=!1;var i=this._execute(t,n);if(i)return i;this.pending===!1&&this.id!=null&&(this.id=this.recycleAsyncId(this.scheduler,this.id,null))},e.prototype._execute=function(t,n){var i=!1,o;try{this.work(t)}catch(s){i=!0,o=s||new Error("Scheduled action threw falsy error")}if(i)return this.unsubscribe(),o},e.prototype.unsubscribe=function(){if(!this.closed){var t=this,n=t.id,i=t.scheduler,o=i.actions;this.work=this.state=this.scheduler=null,this.pending=!1,ye(o,this),n!=null&&(this.id=this.recycleAsyncId(i,n,null)),this.delay=null,r.prototype.unsubscribe.call(this)}},e})(bn);var Ir=(function(){function r(e,t){t===void 0&&(t=r.now),this.schedulerActionCtor=e,this.now=t}return r.prototype.schedule=function(e,t,n){return t===void 0&&(t=0),new this.schedulerActionCtor(this,e).schedule(n,t)},r.now=ut.now,r})();var wn=(function(r){R(e,r);function e(t,n){n===void 0&&(n=Ir.now);var i=r.call(this,t,n)||this;return i.actions=[],i._active=!1,i}return e.prototype.flush=function(t){var n=this.actions;if(this._active){n.push(t);return}var i;this._active=!0;do if(i=t.execute(t.state,t.delay))break;while(t=n.shift());if(this._active=!1,i){for(;t=n.shift();)t.unsubscribe();throw i}},e})(Ir);var io=new wn(xn),Sn=io;var kn=new E(function(r){return r.complete()});function zt(r){return r&&k(r.schedule)}function Tn(r){return r[r.length-1]}function An(r){return zt(Tn(r))?r.pop():void 0}function En(r,e){return typeof Tn(r)=="number"?r.pop():e}var Zt=(function(r){return r&&typeof r.length=="number"&&typeof r!="function"});function Ft(r){return k(r?.then)}function Vt(r){return k(r[Ye])}function Bt(r){return Symbol.asyncIterator&&k(r?.[Symbol.asyncIterator])}function Wt(r){return new TypeError("You provided "+(r!==null&&typeof r=="object"?"an invalid object":"'"+r+"'")+" where a stream was expected. You can provide an Observable, Promise, ReadableStream, Array, AsyncIterable, or Iterable.")}function oo(){return typeof Symbol!="function"||!Symbol.iterator?"@@iterator":Symbol.iterator}var Ht=oo();function qt(r){return k(r?.[Ht])}function Gt(r){return dn(this,arguments,function(){var t,n,i,o;return Rt(this,function(s){switch(s.label){case 0:t=r.getReader(),s.label=1;case 1:s.trys.push([1,,9,10]),s.label=2;case 2:return[4,ve(t.read())];case 3:return n=s.sent(),i=n.value,o=n.done,o?[4,ve(void 0)]:[3,5];case 4:return[2,s.sent()];case 5:return[4,ve(i)];case 6:return[4,s.sent()];case 7:return s.sent(),[3,2];case 8:return[3,10];case 9:return t.releaseLock(),[7];case 10:return[2]}})})}function Yt(r){return k(r?.getReader)}function j(r){if(r instanceof E)return r;if(r!=null){if(Vt(r))return so(r);if(Zt(r))return ao(r);if(Ft(r))return co(r);if(Bt(r))return On(r);if(qt(r))return lo(r);if(Yt(r))return uo(r)}throw Wt(r)}function so(r){return new E(function(e){var t=r[Ye]();if(k(t.subscribe))return t.subscribe(e);throw new TypeError("Provided object does not correctly implement Symbol.observable")})}function ao(r){return new E(function(e){for(var t=0;t<r.length&&!e.closed;t++)e.next(r[t]);e.complete()})}function co(r){return new E(function(e){r.then(function(t){e.closed||(e.next(t),e.complete())},function(t){return e.error(t)}).then(null,Dt)})}function lo(r){return new E(function(e){var t,n;try{for(var i=re(r),o=i.next();!o.done;o=i.next()){var s=o.value;if(e.next(s),e.closed)return}}catch(c){t={error:c}}finally{try{o&&!o.done&&(n=i.return)&&n.call(i)}finally{if(t)throw t.error}}e.complete()})}function On(r){return new E(function(e){fo(r,e).catch(function(t){return e.error(t)})})}function uo(r){return On(Gt(r))}function fo(r,e){var t,n,i,o;return ln(this,void 0,void 0,function(){var s,c;return Rt(this,function(l){switch(l.label){case 0:l.trys.push([0,5,6,11]),t=un(r),l.label=1;case 1:return[4,t.next()];case 2:if(n=l.sent(),!!n.done)return[3,4];if(s=n.value,e.next(s),e.closed)return[2];l.label=3;case 3:return[3,1];case 4:return[3,11];case 5:return c=l.sent(),i={error:c},[3,11];case 6:return l.trys.push([6,,9,10]),n&&!n.done&&(o=t.return)?[4,o.call(t)]:[3,8];case 7:l.sent(),l.label=8;case 8:return[3,10];case 9:if(i)throw i.error;return[7];case 10:return[7];case 11:return e.complete(),[2]}})})}function F(r,e,t,n,i){n===void 0&&(n=0),i===void 0&&(i=!1);var o=e.schedule(function(){t(),i?r.add(this.schedule(null,n)):this.unsubscribe()},n);if(r.add(o),!i)return o}function Jt(r,e){return e===void 0&&(e=0),C(function(t,n){t.subscribe($(n,function(i){return F(n,r,function(){return n.next(i)},e)},function(){return F(n,r,function(){return n.complete()},e)},function(i){return F(n,r,function(){return n.error(i)},e)}))})}function Kt(r,e){return e===void 0&&(e=0),C(function(t,n){n.add(r.schedule(function(){return t.subscribe(n)},e))})}function Cn(r,e){return j(r).pipe(Kt(e),Jt(e))}function $n(r,e){return j(r).pipe(Kt(e),Jt(e))}function jn(r,e){return new E(function(t){var n=0;return e.schedule(function(){n===r.length?t.complete():(t.next(r[n++]),t.closed||this.schedule())})})}function Rn(r,e){return new E(function(t){var n;return F(t,e,function(){n=r[Ht](),F(t,e,function(){var i,o,s;try{i=n.next(),o=i.value,s=i.done}catch(c){t.error(c);return}s?t.complete():t.next(o)},0,!0)}),function(){return k(n?.return)&&n.return()}})}function Xt(r,e){if(!r)throw new Error("Iterable cannot be null");return new E(function(t){F(t,e,function(){var n=r[Symbol.asyncIterator]();F(t,e,function(){n.next().then(function(i){i.done?t.complete():t.next(i.value)})},0,!0)})})}function In(r,e){return Xt(Gt(r),e)}function Pn(r,e){if(r!=null){if(Vt(r))return Cn(r,e);if(Zt(r))return jn(r,e);if(Ft(r))return $n(r,e);if(Bt(r))return Xt(r,e);if(qt(r))return Rn(r,e);if(Yt(r))return In(r,e)}throw Wt(r)}function Mn(r,e){return e?Pn(r,e):j(r)}function Dn(r){return r instanceof Date&&!isNaN(r)}function Q(r,e){return C(function(t,n){var i=0;t.subscribe($(n,function(o){n.next(r.call(e,o,i++))}))})}function Nn(r,e,t,n,i,o,s,c){var l=[],d=0,u=0,p=!1,w=function(){p&&!l.length&&!d&&e.complete()},y=function(S){return d<n?A(S):l.push(S)},A=function(S){o&&e.next(S),d++;var I=!1;j(t(S,u++)).subscribe($(e,function(U){i?.(U),o?y(U):e.next(U)},function(){I=!0},void 0,function(){if(I)try{d--;for(var U=function(){var He=l.shift();s?F(e,s,function(){return A(He)}):A(He)};l.length&&d<n;)U();w()}catch(He){e.error(He)}}))};return r.subscribe($(e,y,function(){p=!0,w()})),function(){c?.()}}function Pr(r,e,t){return t===void 0&&(t=1/0),k(e)?Pr(function(n,i){return Q(function(o,s){return e(n,o,i,s)})(j(r(n,i)))},t):(typeof e=="number"&&(t=e),C(function(n,i){return Nn(n,i,r,t)}))}function Ln(r){return r===void 0&&(r=1/0),Pr(le,r)}function pt(r,e,t){r===void 0&&(r=0),t===void 0&&(t=Sn);var n=-1;return e!=null&&(zt(e)?t=e:n=e),new E(function(i){var o=Dn(r)?+r-t.now():r;o<0&&(o=0);var s=0;return t.schedule(function(){i.closed||(i.next(s++),0<=n?this.schedule(void 0,n):i.complete())},o)})}function Qt(){for(var r=[],e=0;e<arguments.length;e++)r[e]=arguments[e];var t=An(r),n=En(r,1/0),i=r;return i.length?i.length===1?j(i[0]):Ln(n)(Mn(i,t)):kn}function de(r,e){return C(function(t,n){var i=0;t.subscribe($(n,function(o){return r.call(e,o,i++)&&n.next(o)}))})}function xe(){return C(function(r,e){r.subscribe($(e,dt))})}function Mr(r){r===void 0&&(r=1/0);var e;r&&typeof r=="object"?e=r:e={count:r};var t=e.count,n=t===void 0?1/0:t,i=e.delay,o=e.resetOnSuccess,s=o===void 0?!1:o;return n<=0?le:C(function(c,l){var d=0,u,p=function(){var w=!1;u=c.subscribe($(l,function(y){s&&(d=0),l.next(y)},void 0,function(y){if(d++<n){var A=function(){u?(u.unsubscribe(),u=null,p()):w=!0};if(i!=null){var S=typeof i=="number"?pt(i):j(i(y,d)),I=$(l,function(){I.unsubscribe(),A()},function(){l.complete()});S.subscribe(I)}else A()}else l.error(y)})),w&&(u.unsubscribe(),u=null,p())};p()})}function Nr(r){r===void 0&&(r={});var e=r.connector,t=e===void 0?function(){return new Y}:e,n=r.resetOnError,i=n===void 0?!0:n,o=r.resetOnComplete,s=o===void 0?!0:o,c=r.resetOnRefCountZero,l=c===void 0?!0:c;return function(d){var u,p,w,y=0,A=!1,S=!1,I=function(){p?.unsubscribe(),p=void 0},U=function(){I(),u=w=void 0,A=S=!1},He=function(){var lt=u;U(),lt?.unsubscribe()};return C(function(lt,an){y++,!S&&!A&&I();var Ct=w=w??t();an.add(function(){y--,y===0&&!S&&!A&&(p=Dr(He,l))}),Ct.subscribe(an),!u&&y>0&&(u=new be({next:function($t){return Ct.next($t)},error:function($t){S=!0,I(),p=Dr(U,i,$t),Ct.error($t)},complete:function(){A=!0,I(),p=Dr(U,s),Ct.complete()}}),j(lt).subscribe(u))})(d)}}function Dr(r,e){for(var t=[],n=2;n<arguments.length;n++)t[n-2]=arguments[n];if(e===!0){r();return}if(e!==!1){var i=new be({next:function(){i.unsubscribe(),r()}});return j(e.apply(void 0,q([],H(t)))).subscribe(i)}}function ht(r,e){return C(function(t,n){var i=null,o=0,s=!1,c=function(){return s&&!i&&n.complete()};t.subscribe($(n,function(l){i?.unsubscribe();var d=0,u=o++;j(r(l,u)).subscribe(i=$(n,function(p){return n.next(e?e(l,p,u,d++):p)},function(){i=null,c()}))},function(){s=!0,c()}))})}function we(r,e,t){var n=k(r)||e||t?{next:r,error:e,complete:t}:r;return n?C(function(i,o){var s;(s=n.subscribe)===null||s===void 0||s.call(n);var c=!0;i.subscribe($(o,function(l){var d;(d=n.next)===null||d===void 0||d.call(n,l),o.next(l)},function(){var l;c=!1,(l=n.complete)===null||l===void 0||l.call(n),o.complete()},function(l){var d;c=!1,(d=n.error)===null||d===void 0||d.call(n,l),o.error(l)},function(){var l,d;c&&((l=n.unsubscribe)===null||l===void 0||l.call(n)),(d=n.finalize)===null||d===void 0||d.call(n)}))}):le}var po={url:"",deserializer:function(r){return JSON.parse(r.data)},serializer:function(r){return JSON.stringify(r)}},ho="WebSocketSubject.error must be called with an object with an error code, and an optional reason: { code: number, reason: string }",Un=(function(r){R(e,r);function e(t,n){var i=r.call(this)||this;if(i._socket=null,t instanceof E)i.destination=n,i.source=t;else{var o=i._config=jt({},po);if(i._output=new Y,typeof t=="string")o.url=t;else for(var s in t)t.hasOwnProperty(s)&&(o[s]=t[s]);if(!o.WebSocketCtor&&WebSocket)o.WebSocketCtor=WebSocket;else if(!o.WebSocketCtor)throw new Error("no WebSocket constructor can be found");i.destination=new Ut}return i}return e.prototype.lift=function(t){var n=new e(this._config,this.destination);return n.operator=t,n.source=this,n},e.prototype._resetState=function(){this._socket=null,this.source||(this.destination=new Ut),this._output=new Y},e.prototype.multiplex=function(t,n,i){var o=this;return new E(function(s){try{o.next(t())}catch(l){s.error(l)}var c=o.subscribe({next:function(l){try{i(l)&&s.next(l)}catch(d){s.error(d)}},error:function(l){return s.error(l)},complete:function(){return s.complete()}});return function(){try{o.next(n())}catch(l){s.error(l)}c.unsubscribe()}})},e.prototype._connectSocket=function(){var t=this,n=this._config,i=n.WebSocketCtor,o=n.protocol,s=n.url,c=n.binaryType,l=this._output,d=null;try{d=o?new i(s,o):new i(s),this._socket=d,c&&(this._socket.binaryType=c)}catch(p){l.error(p);return}var u=new X(function(){t._socket=null,d&&d.readyState===1&&d.close()});d.onopen=function(p){var w=t._socket;if(!w){d.close(),t._resetState();return}var y=t._config.openObserver;y&&y.next(p);var A=t.destination;t.destination=_e.create(function(S){if(d.readyState===1)try{var I=t._config.serializer;d.send(I(S))}catch(U){t.destination.error(U)}},function(S){var I=t._config.closingObserver;I&&I.next(void 0),S&&S.code?d.close(S.code,S.reason):l.error(new TypeError(ho)),t._resetState()},function(){var S=t._config.closingObserver;S&&S.next(void 0),d.close(),t._resetState()}),A&&A instanceof Ut&&u.add(A.subscribe(t.destination))},d.onerror=function(p){t._resetState(),l.error(p)},d.onclose=function(p){d===t._socket&&t._resetState();var w=t._config.closeObserver;w&&w.next(p),p.wasClean?l.complete():l.error(p)},d.onmessage=function(p){try{var w=t._config.deserializer;l.next(w(p))}catch(y){l.error(y)}}},e.prototype._subscribe=function(t){var n=this,i=this.source;return i?i.subscribe(t):(this._socket||this._connectSocket(),this._output.subscribe(t),t.add(function(){var o=n._socket;n._output.observers.length===0&&(o&&(o.readyState===1||o.readyState===0)&&o.close(),n._resetState())}),t)},e.prototype.unsubscribe=function(){var t=this._socket;t&&(t.readyState===1||t.readyState===0)&&t.close(),this._resetState(),r.prototype.unsubscribe.call(this)},e})(Lt);function Lr(r){return new Un(r)}function zn(){let r;return{create:e=>{let t=new URL(window.location.href),n=t.protocol==="https:"?"wss":"ws",i;if(e.host)i=new URL(e.ws_path,n+"://"+e.host);else{let d=new URL(t);d.protocol=n,d.pathname=e.ws_path,i=d}let o=aP();i.searchParams.set("bslive.url",o),e.token&&i.searchParams.set("bslive.token",e.token);let s=!1,c=o,l=()=>{if(!s)return;let d=aP();d!==c&&(c=d,r?.next({kind:"Location",payload:{path:d}}))};r=Lr({url:i.toString(),openObserver:{next:()=>{s=!0,c=o,l()}},closeObserver:{next:()=>{s=!1}}}),window.addEventListener("popstate",l),window.addEventListener("hashchange",l);for(let d of["pushState","replaceState"]){let u=history[d];history[d]=function(...p){u.apply(this,p),l()}}return r.pipe(Mr({delay:5e3}))},send:e=>{r?.next(e)}}}function aP(){let{pathname:r,search:e,hash:t}=window.location;return r+e+t}var aG=console.log.bind(console),Zn={debug(...r){},error(...r){},info(...r){},trace(...r){}},Ur={name:"console",globalSetup:r=>{let e=new Y;return[e,{debug:function(...n){e.next({level:"debug",args:n})},info:function(...n){e.next({level:"info",args:n})},trace:function(...n){e.next({level:"trace",args:n})},error:function(...n){e.next({level:"error",args:n})}}]},resetSink:(r,e,t)=>r.pipe(we(n=>{let i=["trace","debug","info","error"],o=i.indexOf(n.level),s=i.indexOf(t.log_level);o>=s&&aG(`[${n.level}]`,...n.args)}),xe())};var T;(function(r){r.assertEqual=i=>i;function e(i){}r.assertIs=e;function t(i){throw new Error}r.assertNever=t,r.arrayToEnum=i=>{let o={};for(let s of i)o[s]=s;return o},r.getValidEnumValues=i=>{let o=r.objectKeys(i).filter(c=>typeof i[i[c]]!="number"),s={};for(let c of o)s[c]=i[c];return r.objectValues(s)},r.objectValues=i=>r.objectKeys(i).map(function(o){return i[o]}),r.objectKeys=typeof Object.keys=="function"?i=>Object.keys(i):i=>{let o=[];for(let s in i)Object.prototype.hasOwnProperty.call(i,s)&&o.push(s);return o},r.find=(i,o)=>{for(let s of i)if(o(s))return s},r.isInteger=typeof Number.isInteger=="function"?i=>Number.isInteger(i):i=>typeof i=="number"&&isFinite(i)&&Math.floor(i)===i;function n(i,o=" | "){return i.map(s=>typeof s=="string"?`'${s}'`:s).join(o)}r.joinValues=n,r.jsonStringifyReplacer=(i,o)=>typeof o=="bigint"?o.toString():o})(T||(T={}));var Zr;(function(r){r.mergeShapes=(e,t)=>({...e,...t})})(Zr||(Zr={}));var m=T.arrayToEnum(["string","nan","number","integer","float","boolean","date","bigint","symbol","function","undefined","null","array","object","unknown","promise","void","never","map","set"]),ie=r=>{switch(typeof r){case"undefined":return m.undefined;case"string":return m.string;case"number":return isNaN(r)?m.nan:m.number;case"boolean":return m.boolean;case"function":return m.function;case"bigint":return m.bigint;case"symbol":return m.symbol;case"object":return Array.isArray(r)?m.array:r===null?m.null:r.then&&typeof r.then=="function"&&r.catch&&typeof r.catch=="function"?m.promise:typeof Map<"u"&&r instanceof Map?m.map:typeof Set<"u"&&r instanceof Set?m.set:typeof Date<"u"&&r instanceof Date?m.date:m.object;default:return m.unknown}},f=T.arrayToEnum(["invalid_type","invalid_literal","custom","invalid_union","invalid_union_discriminator","invalid_enum_value","unrecognized_keys","invalid_arguments","invalid_return_type","invalid_date","invalid_string","too_small","too_big","invalid_intersection_types","not_multiple_of","not_finite"]),mo=r=>JSON.stringify(r,null,2).replace(/"([^"]+)":/g,"$1:"),z=class r extends Error{get errors(){return this.issues}constructor(e){super(),this.issues=[],this.addIssue=n=>{this.issues=[...this.issues,n]},this.addIssues=(n=[])=>{this.issues=[...this.issues,...n]};let t=new.target.prototype;Object.setPrototypeOf?Object.setPrototypeOf(this,t):this.__proto__=t,this.name="ZodError",this.issues=e}format(e){let t=e||function(o){return o.message},n={_errors:[]},i=o=>{for(let s of o.issues)if(s.code==="invalid_union")s.unionErrors.map(i);else if(s.code==="invalid_return_type")i(s.returnTypeError);else if(s.code==="invalid_arguments")i(s.argumentsError);else if(s.path.length===0)n._errors.push(t(s));else{let c=n,l=0;for(;l<s.path.length;){let d=s.path[l];l===s.path.length-1?(c[d]=c[d]||{_errors:[]},c[d]._errors.push(t(s))):c[d]=c[d]||{_errors:[]},c=c[d],l++}}};return i(this),n}static assert(e){if(!(e instanceof r))throw new Error(`Not a ZodError: ${e}`)}toString(){return this.message}get message(){return JSON.stringify(this.issues,T.jsonStringifyReplacer,2)}get isEmpty(){return this.issues.length===0}flatten(e=t=>t.message){let t={},n=[];for(let i of this.issues)i.path.length>0?(t[i.path[0]]=t[i.path[0]]||[],t[i.path[0]].push(e(i))):n.push(e(i));return{formErrors:n,fieldErrors:t}}get formErrors(){return this.flatten()}};z.create=r=>new z(r);var Xe=(r,e)=>{let t;switch(r.code){case f.invalid_type:r.received===m.undefined?t="Required":t=`Expected ${r.expected}, received ${r.received}`;break;case f.invalid_literal:t=`Invalid literal value, expected ${JSON.stringify(r.expected,T.jsonStringifyReplacer)}`;break;case f.unrecognized_keys:t=`Unrecognized key(s) in object: ${T.joinValues(r.keys,", ")}`;break;case f.invalid_union:t="Invalid input";break;case f.invalid_union_discriminator:t=`Invalid discriminator value. Expected ${T.joinValues(r.options)}`;break;case f.invalid_enum_value:t=`Invalid enum value. Expected ${T.joinValues(r.options)}, received '${r.received}'`;break;case f.invalid_arguments:t="Invalid function arguments";break;case f.invalid_return_type:t="Invalid function return type";break;case f.invalid_date:t="Invalid date";break;case f.invalid_string:typeof r.validation=="object"?"includes"in r.validation?(t=`Invalid input: must include "${r.validation.includes}"`,typeof r.validation.position=="number"&&(t=`${t} at one or more positions greater than or equal to ${r.validation.position}`)):"startsWith"in r.validation?t=`Invalid input: must start with "${r.validation.startsWith}"`:"endsWith"in r.validation?t=`Invalid input: must end with "${r.validation.endsWith}"`:T.assertNever(r.validation):r.validation!=="regex"?t=`Invalid ${r.validation}`:t="Invalid";break;case f.too_small:r.type==="array"?t=`Array must contain ${r.exact?"exactly":r.inclusive?"at least":"more than"} ${r.minimum} element(s)`:r.type==="string"?t=`String must contain ${r.exact?"exactly":r.inclusive?"at least":"over"} ${r.minimum} character(s)`:r.type==="number"?t=`Number must be ${r.exact?"exactly equal to ":r.inclusive?"greater than or equal to ":"greater than "}${r.minimum}`:r.type==="date"?t=`Date must be ${r.exact?"exactly equal to ":r.inclusive?"greater than or equal to ":"greater than "}${new Date(Number(r.minimum))}`:t="Invalid input";break;case f.too_big:r.type==="array"?t=`Array must contain ${r.exact?"exactly":r.inclusive?"at most":"less than"} ${r.maximum} element(s)`:r.type==="string"?t=`String must contain ${r.exact?"exactly":r.inclusive?"at most":"under"} ${r.maximum} character(s)`:r.type==="number"?t=`Number must be ${r.exact?"exactly":r.inclusive?"less than or equal to":"less than"} ${r.maximum}`:r.type==="bigint"?t=`BigInt must be ${r.exact?"exactly":r.inclusive?"less than or equal to":"less than"} ${r.maximum}`:r.type==="date"?t=`Date must be ${r.exact?"exactly":r.inclusive?"smaller than or equal to":"smaller than"} ${new Date(Number(r.maximum))}`:t="Invalid input";break;case f.custom:t="Invalid input";break;case f.invalid_intersection_types:t="Intersection results could not be merged";break;case f.not_multiple_of:t=`Number must be a multiple of ${r.multipleOf}`;break;case f.not_finite:t="Number must be finite";break;default:t=e.defaultError,T.assertNever(r)}return{message:t}},Wn=Xe;function vo(r){Wn=r}function er(){return Wn}var tr=r=>{let{data:e,path:t,errorMaps:n,issueData:i}=r,o=[...t,...i.path||[]],s={...i,path:o};if(i.message!==void 0)return{...i,path:o,message:i.message};let c="",l=n.filter(d=>!!d).slice().reverse();for(let d of l)c=d(s,{data:e,defaultError:c}).message;return{...i,path:o,message:c}},yo=[];function h(r,e){let t=er(),n=tr({issueData:e,data:r.data,path:r.path,errorMaps:[r.common.contextualErrorMap,r.schemaErrorMap,t,t===Xe?void 0:Xe].filter(i=>!!i)});r.common.issues.push(n)}var P=class r{constructor(){this.value="valid"}dirty(){this.value==="valid"&&(this.value="dirty")}abort(){this.value!=="aborted"&&(this.value="aborted")}static mergeArray(e,t){let n=[];for(let i of t){if(i.status==="aborted")return _;i.status==="dirty"&&e.dirty(),n.push(i.value)}return{status:e.value,value:n}}static async mergeObjectAsync(e,t){let n=[];for(let i of t){let o=await i.key,s=await i.value;n.push({key:o,value:s})}return r.mergeObjectSync(e,n)}static mergeObjectSync(e,t){let n={};for(let i of t){let{key:o,value:s}=i;if(o.status==="aborted"||s.status==="aborted")return _;o.status==="dirty"&&e.dirty(),s.status==="dirty"&&e.dirty(),o.value!=="__proto__"&&(typeof s.value<"u"||i.alwaysSet)&&(n[o.value]=s.value)}return{status:e.value,value:n}}},_=Object.freeze({status:"aborted"}),Ke=r=>({status:"dirty",value:r}),M=r=>({status:"valid",value:r}),Fr=r=>r.status==="aborted",Vr=r=>r.status==="dirty",Se=r=>r.status==="valid",yt=r=>typeof Promise<"u"&&r instanceof Promise;function rr(r,e,t,n){if(t==="a"&&!n)throw new TypeError("Private accessor was defined without a getter");if(typeof e=="function"?r!==e||!n:!e.has(r))throw new TypeError("Cannot read private member from an object whose class did not declare it");return t==="m"?n:t==="a"?n.call(r):n?n.value:e.get(r)}function Hn(r,e,t,n,i){if(n==="m")throw new TypeError("Private method is not writable");if(n==="a"&&!i)throw new TypeError("Private accessor was defined without a setter");if(typeof e=="function"?r!==e||!i:!e.has(r))throw new TypeError("Cannot write private member to an object whose class did not declare it");return n==="a"?i.call(r,t):i?i.value=t:e.set(r,t),t}var v;(function(r){r.errToObj=e=>typeof e=="string"?{message:e}:e||{},r.toString=e=>typeof e=="string"?e:e?.message})(v||(v={}));var mt,vt,B=class{constructor(e,t,n,i){this._cachedPath=[],this.parent=e,this.data=t,this._path=n,this._key=i}get path(){return this._cachedPath.length||(this._key instanceof Array?this._cachedPath.push(...this._path,...this._key):this._cachedPath.push(...this._path,this._key)),this._cachedPath}},Fn=(r,e)=>{if(Se(e))return{success:!0,data:e.value};if(!r.common.issues.length)throw new Error("Validation failed but no issues detected.");return{success:!1,get error(){if(this._error)return this._error;let t=new z(r.common.issues);return this._error=t,this._error}}};function b(r){if(!r)return{};let{errorMap:e,invalid_type_error:t,required_error:n,description:i}=r;if(e&&(t||n))throw new Error(`Can't use "invalid_type_error" or "required_error" in conjunction with custom error map.`);return e?{errorMap:e,description:i}:{errorMap:(s,c)=>{var l,d;let{message:u}=r;return s.code==="invalid_enum_value"?{message:u??c.defaultError}:typeof c.data>"u"?{message:(l=u??n)!==null&&l!==void 0?l:c.defaultError}:s.code!=="invalid_type"?{message:c.defaultError}:{message:(d=u??t)!==null&&d!==void 0?d:c.defaultError}},description:i}}var x=class{get description(){return this._def.description}_getType(e){return ie(e.data)}_getOrReturnCtx(e,t){return t||{common:e.parent.common,data:e.data,parsedType:ie(e.data),schemaErrorMap:this._def.errorMap,path:e.path,parent:e.parent}}_processInputParams(e){return{status:new P,ctx:{common:e.parent.common,data:e.data,parsedType:ie(e.data),schemaErrorMap:this._def.errorMap,path:e.path,parent:e.parent}}}_parseSync(e){let t=this._parse(e);if(yt(t))throw new Error("Synchronous parse encountered promise.");return t}_parseAsync(e){let t=this._parse(e);return Promise.resolve(t)}parse(e,t){let n=this.safeParse(e,t);if(n.success)return n.data;throw n.error}safeParse(e,t){var n;let i={common:{issues:[],async:(n=t?.async)!==null&&n!==void 0?n:!1,contextualErrorMap:t?.errorMap},path:t?.path||[],schemaErrorMap:this._def.errorMap,parent:null,data:e,parsedType:ie(e)},o=this._parseSync({data:e,path:i.path,parent:i});return Fn(i,o)}"~validate"(e){var t,n;let i={common:{issues:[],async:!!this["~standard"].async},path:[],schemaErrorMap:this._def.errorMap,parent:null,data:e,parsedType:ie(e)};if(!this["~standard"].async)try{let o=this._parseSync({data:e,path:[],parent:i});return Se(o)?{value:o.value}:{issues:i.common.issues}}catch(o){!((n=(t=o?.message)===null||t===void 0?void 0:t.toLowerCase())===null||n===void 0)&&n.includes("encountered")&&(this["~standard"].async=!0),i.common={issues:[],async:!0}}return this._parseAsync({data:e,path:[],parent:i}).then(o=>Se(o)?{value:o.value}:{issues:i.common.issues})}async parseAsync(e,t){let n=await this.safeParseAsync(e,t);if(n.success)return n.data;throw n.error}async safeParseAsync(e,t){let n={common:{issues:[],contextualErrorMap:t?.errorMap,async:!0},path:t?.path||[],schemaErrorMap:this._def.errorMap,parent:null,data:e,parsedType:ie(e)},i=this._parse({data:e,path:n.path,parent:n}),o=await(yt(i)?i:Promise.resolve(i));return Fn(n,o)}refine(e,t){let n=i=>typeof t=="string"||typeof t>"u"?{message:t}:typeof t=="function"?t(i):t;return this._refinement((i,o)=>{let s=e(i),c=()=>o.addIssue({code:f.custom,...n(i)});return typeof Promise<"u"&&s instanceof Promise?s.then(l=>l?!0:(c(),!1)):s?!0:(c(),!1)})}refinement(e,t){return this._refinement((n,i)=>e(n)?!0:(i.addIssue(typeof t=="function"?t(n,i):t),!1))}_refinement(e){return new Z({schema:this,typeName:g.ZodEffects,effect:{type:"refinement",refinement:e}})}superRefine(e){return this._refinement(e)}constructor(e){this.spa=this.safeParseAsync,this._def=e,this.parse=this.parse.bind(this),this.safeParse=this.safeParse.bind(this),this.parseAsync=this.parseAsync.bind(this),this.safeParseAsync=this.safeParseAsync.bind(this),this.spa=this.spa.bind(this),this.refine=this.refine.bind(this),this.refinement=this.refinement.bind(this),this.superRefine=this.superRefine.bind(this),this.optional=this.optional.bind(this),this.nullable=this.nullable.bind(this),this.nullish=this.nullish.bind(this),this.array=this.array.bind(this),this.promise=this.promise.bind(this),this.or=this.or.bind(this),this.and=this.and.bind(this),this.transform=this.transform.bind(this),this.brand=this.brand.bind(this),this.default=this.default.bind(this),this.catch=this.catch.bind(this),this.describe=this.describe.bind(this),this.pipe=this.pipe.bind(this),this.readonly=this.readonly.bind(this),this.isNullable=this.isNullable.bind(this),this.isOptional=this.isOptional.bind(this),this["~standard"]={version:1,vendor:"zod",validate:t=>this["~validate"](t)}}optional(){return V.create(this,this._def)}nullable(){return te.create(this,this._def)}nullish(){return this.nullable().optional()}array(){return se.create(this)}promise(){return pe.create(this,this._def)}or(e){return $e.create([this,e],this._def)}and(e){return je.create(this,e,this._def)}transform(e){return new Z({...b(this._def),schema:this,typeName:g.ZodEffects,effect:{type:"transform",transform:e}})}default(e){let t=typeof e=="function"?e:()=>e;return new De({...b(this._def),innerType:this,defaultValue:t,typeName:g.ZodDefault})}brand(){return new gt({typeName:g.ZodBranded,type:this,...b(this._def)})}catch(e){let t=typeof e=="function"?e:()=>e;return new Ne({...b(this._def),innerType:this,catchValue:t,typeName:g.ZodCatch})}describe(e){let t=this.constructor;return new t({...this._def,description:e})}pipe(e){return _t.create(this,e)}readonly(){return Le.create(this)}isOptional(){return this.safeParse(void 0).success}isNullable(){return this.safeParse(null).success}},go=/^c[^\s-]{8,}$/i,_o=/^[0-9a-z]+$/,bo=/^[0-9A-HJKMNP-TV-Z]{26}$/i,xo=/^[0-9a-fA-F]{8}\b-[0-9a-fA-F]{4}\b-[0-9a-fA-F]{4}\b-[0-9a-fA-F]{4}\b-[0-9a-fA-F]{12}$/i,wo=/^[a-z0-9_-]{21}$/i,So=/^[A-Za-z0-9-_]+\.[A-Za-z0-9-_]+\.[A-Za-z0-9-_]*$/,ko=/^[-+]?P(?!$)(?:(?:[-+]?\d+Y)|(?:[-+]?\d+[.,]\d+Y$))?(?:(?:[-+]?\d+M)|(?:[-+]?\d+[.,]\d+M$))?(?:(?:[-+]?\d+W)|(?:[-+]?\d+[.,]\d+W$))?(?:(?:[-+]?\d+D)|(?:[-+]?\d+[.,]\d+D$))?(?:T(?=[\d+-])(?:(?:[-+]?\d+H)|(?:[-+]?\d+[.,]\d+H$))?(?:(?:[-+]?\d+M)|(?:[-+]?\d+[.,]\d+M$))?(?:[-+]?\d+(?:[.,]\d+)?S)?)??$/,To=/^(?!\.)(?!.*\.\.)([A-Z0-9_'+\-\.]*)[A-Z0-9_+-]@([A-Z0-9][A-Z0-9\-]*\.)+[A-Z]{2,}$/i,Ao="^(\\p{Extended_Pictographic}|\\p{Emoji_Component})+$",zr,Eo=/^(?:(?:25[0-5]|2[0-4][0-9]|1[0-9][0-9]|[1-9][0-9]|[0-9])\.){3}(?:25[0-5]|2[0-4][0-9]|1[0-9][0-9]|[1-9][0-9]|[0-9])$/,Oo=/^(?:(?:25[0-5]|2[0-4][0-9]|1[0-9][0-9]|[1-9][0-9]|[0-9])\.){3}(?:25[0-5]|2[0-4][0-9]|1[0-9][0-9]|[1-9][0-9]|[0-9])\/(3[0-2]|[12]?[0-9])$/,Co=/^(([0-9a-fA-F]{1,4}:){7,7}[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,7}:|([0-9a-fA-F]{1,4}:){1,6}:[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,5}(:[0-9a-fA-F]{1,4}){1,2}|([0-9a-fA-F]{1,4}:){1,4}(:[0-9a-fA-F]{1,4}){1,3}|([0-9a-fA-F]{1,4}:){1,3}(:[0-9a-fA-F]{1,4}){1,4}|([0-9a-fA-F]{1,4}:){1,2}(:[0-9a-fA-F]{1,4}){1,5}|[0-9a-fA-F]{1,4}:((:[0-9a-fA-F]{1,4}){1,6})|:((:[0-9a-fA-F]{1,4}){1,7}|:)|fe80:(:[0-9a-fA-F]{0,4}){0,4}%[0-9a-zA-Z]{1,}|::(ffff(:0{1,4}){0,1}:){0,1}((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])|([0-9a-fA-F]{1,4}:){1,4}:((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9]))$/,$o=/^(([0-9a-fA-F]{1,4}:){7,7}[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,7}:|([0-9a-fA-F]{1,4}:){1,6}:[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,5}(:[0-9a-fA-F]{1,4}){1,2}|([0-9a-fA-F]{1,4}:){1,4}(:[0-9a-fA-F]{1,4}){1,3}|([0-9a-fA-F]{1,4}:){1,3}(:[0-9a-fA-F]{1,4}){1,4}|([0-9a-fA-F]{1,4}:){1,2}(:[0-9a-fA-F]{1,4}){1,5}|[0-9a-fA-F]{1,4}:((:[0-9a-fA-F]{1,4}){1,6})|:((:[0-9a-fA-F]{1,4}){1,7}|:)|fe80:(:[0-9a-fA-F]{0,4}){0,4}%[0-9a-zA-Z]{1,}|::(ffff(:0{1,4}){0,1}:){0,1}((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])|([0-9a-fA-F]{1,4}:){1,4}:((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9]))\/(12[0-8]|1[01][0-9]|[1-9]?[0-9])$/,jo=/^([0-9a-zA-Z+/]{4})*(([0-9a-zA-Z+/]{2}==)|([0-9a-zA-Z+/]{3}=))?$/,Ro=/^([0-9a-zA-Z-_]{4})*(([0-9a-zA-Z-_]{2}(==)?)|([0-9a-zA-Z-_]{3}(=)?))?$/,qn="((\\d\\d[2468][048]|\\d\\d[13579][26]|\\d\\d0[48]|[02468][048]00|[13579][26]00)-02-29|\\d{4}-((0[13578]|1[02])-(0[1-9]|[12]\\d|3[01])|(0[469]|11)-(0[1-9]|[12]\\d|30)|(02)-(0[1-9]|1\\d|2[0-8])))",Io=new RegExp(`^${qn}$`);function Gn(r){let e="([01]\\d|2[0-3]):[0-5]\\d:[0-5]\\d";return r.precision?e=`${e}\\.\\d{${r.precision}}`:r.precision==null&&(e=`${e}(\\.\\d+)?`),e}function Po(r){return new RegExp(`^${Gn(r)}$`)}function Yn(r){let e=`${qn}T${Gn(r)}`,t=[];return t.push(r.local?"Z?":"Z"),r.offset&&t.push("([+-]\\d{2}:?\\d{2})"),e=`${e}(${t.join("|")})`,new RegExp(`^${e}$`)}function Mo(r,e){return!!((e==="v4"||!e)&&Eo.test(r)||(e==="v6"||!e)&&Co.test(r))}function Do(r,e){if(!So.test(r))return!1;try{let[t]=r.split("."),n=t.replace(/-/g,"+").replace(/_/g,"/").padEnd(t.length+(4-t.length%4)%4,"="),i=JSON.parse(atob(n));return!(typeof i!="object"||i===null||!i.typ||!i.alg||e&&i.alg!==e)}catch{return!1}}function No(r,e){return!!((e==="v4"||!e)&&Oo.test(r)||(e==="v6"||!e)&&$o.test(r))}var ue=class r extends x{_parse(e){if(this._def.coerce&&(e.data=String(e.data)),this._getType(e)!==m.string){let o=this._getOrReturnCtx(e);return h(o,{code:f.invalid_type,expected:m.string,received:o.parsedType}),_}let n=new P,i;for(let o of this._def.checks)if(o.kind==="min")e.data.length<o.value&&(i=this._getOrReturnCtx(e,i),h(i,{code:f.too_small,minimum:o.value,type:"string",inclusive:!0,exact:!1,message:o.message}),n.dirty());else if(o.kind==="max")e.data.length>o.value&&(i=this._getOrReturnCtx(e,i),h(i,{code:f.too_big,maximum:o.value,type:"string",inclusive:!0,exact:!1,message:o.message}),n.dirty());else if(o.kind==="length"){let s=e.data.length>o.value,c=e.data.length<o.value;(s||c)&&(i=this._getOrReturnCtx(e,i),s?h(i,{code:f.too_big,maximum:o.value,type:"string",inclusive:!0,exact:!0,message:o.message}):c&&h(i,{code:f.too_small,minimum:o.value,type:"string",inclusive:!0,exact:!0,message:o.message}),n.dirty())}else if(o.kind==="email")To.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"email",code:f.invalid_string,message:o.message}),n.dirty());else if(o.kind==="emoji")zr||(zr=new RegExp(Ao,"u")),zr.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"emoji",code:f.invalid_string,message:o.message}),n.dirty());else if(o.kind==="uuid")xo.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"uuid",code:f.invalid_string,message:o.message}),n.dirty());else if(o.kind==="nanoid")wo.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"nanoid",code:f.invalid_string,message:o.message}),n.dirty());else if(o.kind==="cuid")go.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"cuid",code:f.invalid_string,message:o.message}),n.dirty());else if(o.kind==="cuid2")_o.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"cuid2",code:f.invalid_string,message:o.message}),n.dirty());else if(o.kind==="ulid")bo.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"ulid",code:f.invalid_string,message:o.message}),n.dirty());else if(o.kind==="url")try{new URL(e.data)}catch{i=this._getOrReturnCtx(e,i),h(i,{validation:"url",code:f.invalid_string,message:o.message}),n.dirty()}else o.kind==="regex"?(o.regex.lastIndex=0,o.regex.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"regex",code:f.invalid_string,message:o.message}),n.dirty())):o.kind==="trim"?e.data=e.data.trim():o.kind==="includes"?e.data.includes(o.value,o.position)||(i=this._getOrReturnCtx(e,i),h(i,{code:f.invalid_string,validation:{includes:o.value,position:o.position},message:o.message}),n.dirty()):o.kind==="toLowerCase"?e.data=e.data.toLowerCase():o.kind==="toUpperCase"?e.data=e.data.toUpperCase():o.kind==="startsWith"?e.data.startsWith(o.value)||(i=this._getOrReturnCtx(e,i),h(i,{code:f.invalid_string,validation:{startsWith:o.value},message:o.message}),n.dirty()):o.kind==="endsWith"?e.data.endsWith(o.value)||(i=this._getOrReturnCtx(e,i),h(i,{code:f.invalid_string,validation:{endsWith:o.value},message:o.message}),n.dirty()):o.kind==="datetime"?Yn(o).test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{code:f.invalid_string,validation:"datetime",message:o.message}),n.dirty()):o.kind==="date"?Io.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{code:f.invalid_string,validation:"date",message:o.message}),n.dirty()):o.kind==="time"?Po(o).test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{code:f.invalid_string,validation:"time",message:o.message}),n.dirty()):o.kind==="duration"?ko.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"duration",code:f.invalid_string,message:o.message}),n.dirty()):o.kind==="ip"?Mo(e.data,o.version)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"ip",code:f.invalid_string,message:o.message}),n.dirty()):o.kind==="jwt"?Do(e.data,o.alg)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"jwt",code:f.invalid_string,message:o.message}),n.dirty()):o.kind==="cidr"?No(e.data,o.version)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"cidr",code:f.invalid_string,message:o.message}),n.dirty()):o.kind==="base64"?jo.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"base64",code:f.invalid_string,message:o.message}),n.dirty()):o.kind==="base64url"?Ro.test(e.data)||(i=this._getOrReturnCtx(e,i),h(i,{validation:"base64url",code:f.invalid_string,message:o.message}),n.dirty()):T.assertNever(o);return{status:n.value,value:e.data}}_regex(e,t,n){return this.refinement(i=>e.test(i),{validation:t,code:f.invalid_string,...v.errToObj(n)})}_addCheck(e){return new r({...this._def,checks:[...this._def.checks,e]})}email(e){return this._addCheck({kind:"email",...v.errToObj(e)})}url(e){return this._addCheck({kind:"url",...v.errToObj(e)})}emoji(e){return this._addCheck({kind:"emoji",...v.errToObj(e)})}uuid(e){return this._addCheck({kind:"uuid",...v.errToObj(e)})}nanoid(e){return this._addCheck({kind:"nanoid",...v.errToObj(e)})}cuid(e){return this._addCheck({kind:"cuid",...v.errToObj(e)})}cuid2(e){return this._addCheck({kind:"cuid2",...v.errToObj(e)})}ulid(e){return this._addCheck({kind:"ulid",...v.errToObj(e)})}base64(e){return this._addCheck({kind:"base64",...v.errToObj(e)})}base64url(e){return this._addCheck({kind:"base64url",...v.errToObj(e)})}jwt(e){return this._addCheck({kind:"jwt",...v.errToObj(e)})}ip(e){return this._addCheck({kind:"ip",...v.errToObj(e)})}cidr(e){return this._addCheck({kind:"cidr",...v.errToObj(e)})}datetime(e){var t,n;return typeof e=="string"?this._addCheck({kind:"datetime",precision:null,offset:!1,local:!1,message:e}):this._addCheck({kind:"datetime",precision:typeof e?.precision>"u"?null:e?.precision,offset:(t=e?.offset)!==null&&t!==void 0?t:!1,local:(n=e?.local)!==null&&n!==void 0?n:!1,...v.errToObj(e?.message)})}date(e){return this._addCheck({kind:"date",message:e})}time(e){return typeof e=="string"?this._addCheck({kind:"time",precision:null,message:e}):this._addCheck({kind:"time",precision:typeof e?.precision>"u"?null:e?.precision,...v.errToObj(e?.message)})}duration(e){return this._addCheck({kind:"duration",...v.errToObj(e)})}regex(e,t){return this._addCheck({kind:"regex",regex:e,...v.errToObj(t)})}includes(e,t){return this._addCheck({kind:"includes",value:e,position:t?.position,...v.errToObj(t?.message)})}startsWith(e,t){return this._addCheck({kind:"startsWith",value:e,...v.errToObj(t)})}endsWith(e,t){return this._addCheck({kind:"endsWith",value:e,...v.errToObj(t)})}min(e,t){return this._addCheck({kind:"min",value:e,...v.errToObj(t)})}max(e,t){return this._addCheck({kind:"max",value:e,...v.errToObj(t)})}length(e,t){return this._addCheck({kind:"length",value:e,...v.errToObj(t)})}nonempty(e){return this.min(1,v.errToObj(e))}trim(){return new r({...this._def,checks:[...this._def.checks,{kind:"trim"}]})}toLowerCase(){return new r({...this._def,checks:[...this._def.checks,{kind:"toLowerCase"}]})}toUpperCase(){return new r({...this._def,checks:[...this._def.checks,{kind:"toUpperCase"}]})}get isDatetime(){return!!this._def.checks.find(e=>e.kind==="datetime")}get isDate(){return!!this._def.checks.find(e=>e.kind==="date")}get isTime(){return!!this._def.checks.find(e=>e.kind==="time")}get isDuration(){return!!this._def.checks.find(e=>e.kind==="duration")}get isEmail(){return!!this._def.checks.find(e=>e.kind==="email")}get isURL(){return!!this._def.checks.find(e=>e.kind==="url")}get isEmoji(){return!!this._def.checks.find(e=>e.kind==="emoji")}get isUUID(){return!!this._def.checks.find(e=>e.kind==="uuid")}get isNANOID(){return!!this._def.checks.find(e=>e.kind==="nanoid")}get isCUID(){return!!this._def.checks.find(e=>e.kind==="cuid")}get isCUID2(){return!!this._def.checks.find(e=>e.kind==="cuid2")}get isULID(){return!!this._def.checks.find(e=>e.kind==="ulid")}get isIP(){return!!this._def.checks.find(e=>e.kind==="ip")}get isCIDR(){return!!this._def.checks.find(e=>e.kind==="cidr")}get isBase64(){return!!this._def.checks.find(e=>e.kind==="base64")}get isBase64url(){return!!this._def.checks.find(e=>e.kind==="base64url")}get minLength(){let e=null;for(let t of this._def.checks)t.kind==="min"&&(e===null||t.value>e)&&(e=t.value);return e}get maxLength(){let e=null;for(let t of this._def.checks)t.kind==="max"&&(e===null||t.value<e)&&(e=t.value);return e}};ue.create=r=>{var e;return new ue({checks:[],typeName:g.ZodString,coerce:(e=r?.coerce)!==null&&e!==void 0?e:!1,...b(r)})};function Lo(r,e){let t=(r.toString().split(".")[1]||"").length,n=(e.toString().split(".")[1]||"").length,i=t>n?t:n,o=parseInt(r.toFixed(i).replace(".","")),s=parseInt(e.toFixed(i).replace(".",""));return o%s/Math.pow(10,i)}var ke=class r extends x{constructor(){super(...arguments),this.min=this.gte,this.max=this.lte,this.step=this.multipleOf}_parse(e){if(this._def.coerce&&(e.data=Number(e.data)),this._getType(e)!==m.number){let o=this._getOrReturnCtx(e);return h(o,{code:f.invalid_type,expected:m.number,received:o.parsedType}),_}let n,i=new P;for(let o of this._def.checks)o.kind==="int"?T.isInteger(e.data)||(n=this._getOrReturnCtx(e,n),h(n,{code:f.invalid_type,expected:"integer",received:"float",message:o.message}),i.dirty()):o.kind==="min"?(o.inclusive?e.data<o.value:e.data<=o.value)&&(n=this._getOrReturnCtx(e,n),h(n,{code:f.too_small,minimum:o.value,type:"number",inclusive:o.inclusive,exact:!1,message:o.message}),i.dirty()):o.kind==="max"?(o.inclusive?e.data>o.value:e.data>=o.value)&&(n=this._getOrReturnCtx(e,n),h(n,{code:f.too_big,maximum:o.value,type:"number",inclusive:o.inclusive,exact:!1,message:o.message}),i.dirty()):o.kind==="multipleOf"?Lo(e.data,o.value)!==0&&(n=this._getOrReturnCtx(e,n),h(n,{code:f.not_multiple_of,multipleOf:o.value,message:o.message}),i.dirty()):o.kind==="finite"?Number.isFinite(e.data)||(n=this._getOrReturnCtx(e,n),h(n,{code:f.not_finite,message:o.message}),i.dirty()):T.assertNever(o);return{status:i.value,value:e.data}}gte(e,t){return this.setLimit("min",e,!0,v.toString(t))}gt(e,t){return this.setLimit("min",e,!1,v.toString(t))}lte(e,t){return this.setLimit("max",e,!0,v.toString(t))}lt(e,t){return this.setLimit("max",e,!1,v.toString(t))}setLimit(e,t,n,i){return new r({...this._def,checks:[...this._def.checks,{kind:e,value:t,inclusive:n,message:v.toString(i)}]})}_addCheck(e){return new r({...this._def,checks:[...this._def.checks,e]})}int(e){return this._addCheck({kind:"int",message:v.toString(e)})}positive(e){return this._addCheck({kind:"min",value:0,inclusive:!1,message:v.toString(e)})}negative(e){return this._addCheck({kind:"max",value:0,inclusive:!1,message:v.toString(e)})}nonpositive(e){return this._addCheck({kind:"max",value:0,inclusive:!0,message:v.toString(e)})}nonnegative(e){return this._addCheck({kind:"min",value:0,inclusive:!0,message:v.toString(e)})}multipleOf(e,t){return this._addCheck({kind:"multipleOf",value:e,message:v.toString(t)})}finite(e){return this._addCheck({kind:"finite",message:v.toString(e)})}safe(e){return this._addCheck({kind:"min",inclusive:!0,value:Number.MIN_SAFE_INTEGER,message:v.toString(e)})._addCheck({kind:"max",inclusive:!0,value:Number.MAX_SAFE_INTEGER,message:v.toString(e)})}get minValue(){let e=null;for(let t of this._def.checks)t.kind==="min"&&(e===null||t.value>e)&&(e=t.value);return e}get maxValue(){let e=null;for(let t of this._def.checks)t.kind==="max"&&(e===null||t.value<e)&&(e=t.value);return e}get isInt(){return!!this._def.checks.find(e=>e.kind==="int"||e.kind==="multipleOf"&&T.isInteger(e.value))}get isFinite(){let e=null,t=null;for(let n of this._def.checks){if(n.kind==="finite"||n.kind==="int"||n.kind==="multipleOf")return!0;n.kind==="min"?(t===null||n.value>t)&&(t=n.value):n.kind==="max"&&(e===null||n.value<e)&&(e=n.value)}return Number.isFinite(t)&&Number.isFinite(e)}};ke.create=r=>new ke({checks:[],typeName:g.ZodNumber,coerce:r?.coerce||!1,...b(r)});var Te=class r extends x{constructor(){super(...arguments),this.min=this.gte,this.max=this.lte}_parse(e){if(this._def.coerce)try{e.data=BigInt(e.data)}catch{return this._getInvalidInput(e)}if(this._getType(e)!==m.bigint)return this._getInvalidInput(e);let n,i=new P;for(let o of this._def.checks)o.kind==="min"?(o.inclusive?e.data<o.value:e.data<=o.value)&&(n=this._getOrReturnCtx(e,n),h(n,{code:f.too_small,type:"bigint",minimum:o.value,inclusive:o.inclusive,message:o.message}),i.dirty()):o.kind==="max"?(o.inclusive?e.data>o.value:e.data>=o.value)&&(n=this._getOrReturnCtx(e,n),h(n,{code:f.too_big,type:"bigint",maximum:o.value,inclusive:o.inclusive,message:o.message}),i.dirty()):o.kind==="multipleOf"?e.data%o.value!==BigInt(0)&&(n=this._getOrReturnCtx(e,n),h(n,{code:f.not_multiple_of,multipleOf:o.value,message:o.message}),i.dirty()):T.assertNever(o);return{status:i.value,value:e.data}}_getInvalidInput(e){let t=this._getOrReturnCtx(e);return h(t,{code:f.invalid_type,expected:m.bigint,received:t.parsedType}),_}gte(e,t){return this.setLimit("min",e,!0,v.toString(t))}gt(e,t){return this.setLimit("min",e,!1,v.toString(t))}lte(e,t){return this.setLimit("max",e,!0,v.toString(t))}lt(e,t){return this.setLimit("max",e,!1,v.toString(t))}setLimit(e,t,n,i){return new r({...this._def,checks:[...this._def.checks,{kind:e,value:t,inclusive:n,message:v.toString(i)}]})}_addCheck(e){return new r({...this._def,checks:[...this._def.checks,e]})}positive(e){return this._addCheck({kind:"min",value:BigInt(0),inclusive:!1,message:v.toString(e)})}negative(e){return this._addCheck({kind:"max",value:BigInt(0),inclusive:!1,message:v.toString(e)})}nonpositive(e){return this._addCheck({kind:"max",value:BigInt(0),inclusive:!0,message:v.toString(e)})}nonnegative(e){return this._addCheck({kind:"min",value:BigInt(0),inclusive:!0,message:v.toString(e)})}multipleOf(e,t){return this._addCheck({kind:"multipleOf",value:e,message:v.toString(t)})}get minValue(){let e=null;for(let t of this._def.checks)t.kind==="min"&&(e===null||t.value>e)&&(e=t.value);return e}get maxValue(){let e=null;for(let t of this._def.checks)t.kind==="max"&&(e===null||t.value<e)&&(e=t.value);return e}};Te.create=r=>{var e;return new Te({checks:[],typeName:g.ZodBigInt,coerce:(e=r?.coerce)!==null&&e!==void 0?e:!1,...b(r)})};var Ae=class extends x{_parse(e){if(this._def.coerce&&(e.data=!!e.data),this._getType(e)!==m.boolean){let n=this._getOrReturnCtx(e);return h(n,{code:f.invalid_type,expected:m.boolean,received:n.parsedType}),_}return M(e.data)}};Ae.create=r=>new Ae({typeName:g.ZodBoolean,coerce:r?.coerce||!1,...b(r)});var Ee=class r extends x{_parse(e){if(this._def.coerce&&(e.data=new Date(e.data)),this._getType(e)!==m.date){let o=this._getOrReturnCtx(e);return h(o,{code:f.invalid_type,expected:m.date,received:o.parsedType}),_}if(isNaN(e.data.getTime())){let o=this._getOrReturnCtx(e);return h(o,{code:f.invalid_date}),_}let n=new P,i;for(let o of this._def.checks)o.kind==="min"?e.data.getTime()<o.value&&(i=this._getOrReturnCtx(e,i),h(i,{code:f.too_small,message:o.message,inclusive:!0,exact:!1,minimum:o.value,type:"date"}),n.dirty()):o.kind==="max"?e.data.getTime()>o.value&&(i=this._getOrReturnCtx(e,i),h(i,{code:f.too_big,message:o.message,inclusive:!0,exact:!1,maximum:o.value,type:"date"}),n.dirty()):T.assertNever(o);return{status:n.value,value:new Date(e.data.getTime())}}_addCheck(e){return new r({...this._def,checks:[...this._def.checks,e]})}min(e,t){return this._addCheck({kind:"min",value:e.getTime(),message:v.toString(t)})}max(e,t){return this._addCheck({kind:"max",value:e.getTime(),message:v.toString(t)})}get minDate(){let e=null;for(let t of this._def.checks)t.kind==="min"&&(e===null||t.value>e)&&(e=t.value);return e!=null?new Date(e):null}get maxDate(){let e=null;for(let t of this._def.checks)t.kind==="max"&&(e===null||t.value<e)&&(e=t.value);return e!=null?new Date(e):null}};Ee.create=r=>new Ee({checks:[],coerce:r?.coerce||!1,typeName:g.ZodDate,...b(r)});var Qe=class extends x{_parse(e){if(this._getType(e)!==m.symbol){let n=this._getOrReturnCtx(e);return h(n,{code:f.invalid_type,expected:m.symbol,received:n.parsedType}),_}return M(e.data)}};Qe.create=r=>new Qe({typeName:g.ZodSymbol,...b(r)});var Oe=class extends x{_parse(e){if(this._getType(e)!==m.undefined){let n=this._getOrReturnCtx(e);return h(n,{code:f.invalid_type,expected:m.undefined,received:n.parsedType}),_}return M(e.data)}};Oe.create=r=>new Oe({typeName:g.ZodUndefined,...b(r)});var Ce=class extends x{_parse(e){if(this._getType(e)!==m.null){let n=this._getOrReturnCtx(e);return h(n,{code:f.invalid_type,expected:m.null,received:n.parsedType}),_}return M(e.data)}};Ce.create=r=>new Ce({typeName:g.ZodNull,...b(r)});var fe=class extends x{constructor(){super(...arguments),this._any=!0}_parse(e){return M(e.data)}};fe.create=r=>new fe({typeName:g.ZodAny,...b(r)});var oe=class extends x{constructor(){super(...arguments),this._unknown=!0}_parse(e){return M(e.data)}};oe.create=r=>new oe({typeName:g.ZodUnknown,...b(r)});var J=class extends x{_parse(e){let t=this._getOrReturnCtx(e);return h(t,{code:f.invalid_type,expected:m.never,received:t.parsedType}),_}};J.create=r=>new J({typeName:g.ZodNever,...b(r)});var et=class extends x{_parse(e){if(this._getType(e)!==m.undefined){let n=this._getOrReturnCtx(e);return h(n,{code:f.invalid_type,expected:m.void,received:n.parsedType}),_}return M(e.data)}};et.create=r=>new et({typeName:g.ZodVoid,...b(r)});var se=class r extends x{_parse(e){let{ctx:t,status:n}=this._processInputParams(e),i=this._def;if(t.parsedType!==m.array)return h(t,{code:f.invalid_type,expected:m.array,received:t.parsedType}),_;if(i.exactLength!==null){let s=t.data.length>i.exactLength.value,c=t.data.length<i.exactLength.value;(s||c)&&(h(t,{code:s?f.too_big:f.too_small,minimum:c?i.exactLength.value:void 0,maximum:s?i.exactLength.value:void 0,type:"array",inclusive:!0,exact:!0,message:i.exactLength.message}),n.dirty())}if(i.minLength!==null&&t.data.length<i.minLength.value&&(h(t,{code:f.too_small,minimum:i.minLength.value,type:"array",inclusive:!0,exact:!1,message:i.minLength.message}),n.dirty()),i.maxLength!==null&&t.data.length>i.maxLength.value&&(h(t,{code:f.too_big,maximum:i.maxLength.value,type:"array",inclusive:!0,exact:!1,message:i.maxLength.message}),n.dirty()),t.common.async)return Promise.all([...t.data].map((s,c)=>i.type._parseAsync(new B(t,s,t.path,c)))).then(s=>P.mergeArray(n,s));let o=[...t.data].map((s,c)=>i.type._parseSync(new B(t,s,t.path,c)));return P.mergeArray(n,o)}get element(){return this._def.type}min(e,t){return new r({...this._def,minLength:{value:e,message:v.toString(t)}})}max(e,t){return new r({...this._def,maxLength:{value:e,message:v.toString(t)}})}length(e,t){return new r({...this._def,exactLength:{value:e,message:v.toString(t)}})}nonempty(e){return this.min(1,e)}};se.create=(r,e)=>new se({type:r,minLength:null,maxLength:null,exactLength:null,typeName:g.ZodArray,...b(e)});function Je(r){if(r instanceof D){let e={};for(let t in r.shape){let n=r.shape[t];e[t]=V.create(Je(n))}return new D({...r._def,shape:()=>e})}else return r instanceof se?new se({...r._def,type:Je(r.element)}):r instanceof V?V.create(Je(r.unwrap())):r instanceof te?te.create(Je(r.unwrap())):r instanceof ee?ee.create(r.items.map(e=>Je(e))):r}var D=class r extends x{constructor(){super(...arguments),this._cached=null,this.nonstrict=this.passthrough,this.augment=this.extend}_getCached(){if(this._cached!==null)return this._cached;let e=this._def.shape(),t=T.objectKeys(e);return this._cached={shape:e,keys:t}}_parse(e){if(this._getType(e)!==m.object){let d=this._getOrReturnCtx(e);return h(d,{code:f.invalid_type,expected:m.object,received:d.parsedType}),_}let{status:n,ctx:i}=this._processInputParams(e),{shape:o,keys:s}=this._getCached(),c=[];if(!(this._def.catchall instanceof J&&this._def.unknownKeys==="strip"))for(let d in i.data)s.includes(d)||c.push(d);let l=[];for(let d of s){let u=o[d],p=i.data[d];l.push({key:{status:"valid",value:d},value:u._parse(new B(i,p,i.path,d)),alwaysSet:d in i.data})}if(this._def.catchall instanceof J){let d=this._def.unknownKeys;if(d==="passthrough")for(let u of c)l.push({key:{status:"valid",value:u},value:{status:"valid",value:i.data[u]}});else if(d==="strict")c.length>0&&(h(i,{code:f.unrecognized_keys,keys:c}),n.dirty());else if(d!=="strip")throw new Error("Internal ZodObject error: invalid unknownKeys value.")}else{let d=this._def.catchall;for(let u of c){let p=i.data[u];l.push({key:{status:"valid",value:u},value:d._parse(new B(i,p,i.path,u)),alwaysSet:u in i.data})}}return i.common.async?Promise.resolve().then(async()=>{let d=[];for(let u of l){let p=await u.key,w=await u.value;d.push({key:p,value:w,alwaysSet:u.alwaysSet})}return d}).then(d=>P.mergeObjectSync(n,d)):P.mergeObjectSync(n,l)}get shape(){return this._def.shape()}strict(e){return v.errToObj,new r({...this._def,unknownKeys:"strict",...e!==void 0?{errorMap:(t,n)=>{var i,o,s,c;let l=(s=(o=(i=this._def).errorMap)===null||o===void 0?void 0:o.call(i,t,n).message)!==null&&s!==void 0?s:n.defaultError;return t.code==="unrecognized_keys"?{message:(c=v.errToObj(e).message)!==null&&c!==void 0?c:l}:{message:l}}}:{}})}strip(){return new r({...this._def,unknownKeys:"strip"})}passthrough(){return new r({...this._def,unknownKeys:"passthrough"})}extend(e){return new r({...this._def,shape:()=>({...this._def.shape(),...e})})}merge(e){return new r({unknownKeys:e._def.unknownKeys,catchall:e._def.catchall,shape:()=>({...this._def.shape(),...e._def.shape()}),typeName:g.ZodObject})}setKey(e,t){return this.augment({[e]:t})}catchall(e){return new r({...this._def,catchall:e})}pick(e){let t={};return T.objectKeys(e).forEach(n=>{e[n]&&this.shape[n]&&(t[n]=this.shape[n])}),new r({...this._def,shape:()=>t})}omit(e){let t={};return T.objectKeys(this.shape).forEach(n=>{e[n]||(t[n]=this.shape[n])}),new r({...this._def,shape:()=>t})}deepPartial(){return Je(this)}partial(e){let t={};return T.objectKeys(this.shape).forEach(n=>{let i=this.shape[n];e&&!e[n]?t[n]=i:t[n]=i.optional()}),new r({...this._def,shape:()=>t})}required(e){let t={};return T.objectKeys(this.shape).forEach(n=>{if(e&&!e[n])t[n]=this.shape[n];else{let o=this.shape[n];for(;o instanceof V;)o=o._def.innerType;t[n]=o}}),new r({...this._def,shape:()=>t})}keyof(){return Jn(T.objectKeys(this.shape))}};D.create=(r,e)=>new D({shape:()=>r,unknownKeys:"strip",catchall:J.create(),typeName:g.ZodObject,...b(e)});D.strictCreate=(r,e)=>new D({shape:()=>r,unknownKeys:"strict",catchall:J.create(),typeName:g.ZodObject,...b(e)});D.lazycreate=(r,e)=>new D({shape:r,unknownKeys:"strip",catchall:J.create(),typeName:g.ZodObject,...b(e)});var $e=class extends x{_parse(e){let{ctx:t}=this._processInputParams(e),n=this._def.options;function i(o){for(let c of o)if(c.result.status==="valid")return c.result;for(let c of o)if(c.result.status==="dirty")return t.common.issues.push(...c.ctx.common.issues),c.result;let s=o.map(c=>new z(c.ctx.common.issues));return h(t,{code:f.invalid_union,unionErrors:s}),_}if(t.common.async)return Promise.all(n.map(async o=>{let s={...t,common:{...t.common,issues:[]},parent:null};return{result:await o._parseAsync({data:t.data,path:t.path,parent:s}),ctx:s}})).then(i);{let o,s=[];for(let l of n){let d={...t,common:{...t.common,issues:[]},parent:null},u=l._parseSync({data:t.data,path:t.path,parent:d});if(u.status==="valid")return u;u.status==="dirty"&&!o&&(o={result:u,ctx:d}),d.common.issues.length&&s.push(d.common.issues)}if(o)return t.common.issues.push(...o.ctx.common.issues),o.result;let c=s.map(l=>new z(l));return h(t,{code:f.invalid_union,unionErrors:c}),_}}get options(){return this._def.options}};$e.create=(r,e)=>new $e({options:r,typeName:g.ZodUnion,...b(e)});var ne=r=>r instanceof Re?ne(r.schema):r instanceof Z?ne(r.innerType()):r instanceof Ie?[r.value]:r instanceof Pe?r.options:r instanceof Me?T.objectValues(r.enum):r instanceof De?ne(r._def.innerType):r instanceof Oe?[void 0]:r instanceof Ce?[null]:r instanceof V?[void 0,...ne(r.unwrap())]:r instanceof te?[null,...ne(r.unwrap())]:r instanceof gt||r instanceof Le?ne(r.unwrap()):r instanceof Ne?ne(r._def.innerType):[],nr=class r extends x{_parse(e){let{ctx:t}=this._processInputParams(e);if(t.parsedType!==m.object)return h(t,{code:f.invalid_type,expected:m.object,received:t.parsedType}),_;let n=this.discriminator,i=t.data[n],o=this.optionsMap.get(i);return o?t.common.async?o._parseAsync({data:t.data,path:t.path,parent:t}):o._parseSync({data:t.data,path:t.path,parent:t}):(h(t,{code:f.invalid_union_discriminator,options:Array.from(this.optionsMap.keys()),path:[n]}),_)}get discriminator(){return this._def.discriminator}get options(){return this._def.options}get optionsMap(){return this._def.optionsMap}static create(e,t,n){let i=new Map;for(let o of t){let s=ne(o.shape[e]);if(!s.length)throw new Error(`A discriminator value for key \`${e}\` could not be extracted from all schema options`);for(let c of s){if(i.has(c))throw new Error(`Discriminator property ${String(e)} has duplicate value ${String(c)}`);i.set(c,o)}}return new r({typeName:g.ZodDiscriminatedUnion,discriminator:e,options:t,optionsMap:i,...b(n)})}};function Br(r,e){let t=ie(r),n=ie(e);if(r===e)return{valid:!0,data:r};if(t===m.object&&n===m.object){let i=T.objectKeys(e),o=T.objectKeys(r).filter(c=>i.indexOf(c)!==-1),s={...r,...e};for(let c of o){let l=Br(r[c],e[c]);if(!l.valid)return{valid:!1};s[c]=l.data}return{valid:!0,data:s}}else if(t===m.array&&n===m.array){if(r.length!==e.length)return{valid:!1};let i=[];for(let o=0;o<r.length;o++){let s=r[o],c=e[o],l=Br(s,c);if(!l.valid)return{valid:!1};i.push(l.data)}return{valid:!0,data:i}}else return t===m.date&&n===m.date&&+r==+e?{valid:!0,data:r}:{valid:!1}}var je=class extends x{_parse(e){let{status:t,ctx:n}=this._processInputParams(e),i=(o,s)=>{if(Fr(o)||Fr(s))return _;let c=Br(o.value,s.value);return c.valid?((Vr(o)||Vr(s))&&t.dirty(),{status:t.value,value:c.data}):(h(n,{code:f.invalid_intersection_types}),_)};return n.common.async?Promise.all([this._def.left._parseAsync({data:n.data,path:n.path,parent:n}),this._def.right._parseAsync({data:n.data,path:n.path,parent:n})]).then(([o,s])=>i(o,s)):i(this._def.left._parseSync({data:n.data,path:n.path,parent:n}),this._def.right._parseSync({data:n.data,path:n.path,parent:n}))}};je.create=(r,e,t)=>new je({left:r,right:e,typeName:g.ZodIntersection,...b(t)});var ee=class r extends x{_parse(e){let{status:t,ctx:n}=this._processInputParams(e);if(n.parsedType!==m.array)return h(n,{code:f.invalid_type,expected:m.array,received:n.parsedType}),_;if(n.data.length<this._def.items.length)return h(n,{code:f.too_small,minimum:this._def.items.length,inclusive:!0,exact:!1,type:"array"}),_;!this._def.rest&&n.data.length>this._def.items.length&&(h(n,{code:f.too_big,maximum:this._def.items.length,inclusive:!0,exact:!1,type:"array"}),t.dirty());let o=[...n.data].map((s,c)=>{let l=this._def.items[c]||this._def.rest;return l?l._parse(new B(n,s,n.path,c)):null}).filter(s=>!!s);return n.common.async?Promise.all(o).then(s=>P.mergeArray(t,s)):P.mergeArray(t,o)}get items(){return this._def.items}rest(e){return new r({...this._def,rest:e})}};ee.create=(r,e)=>{if(!Array.isArray(r))throw new Error("You must pass an array of schemas to z.tuple([ ... ])");return new ee({items:r,typeName:g.ZodTuple,rest:null,...b(e)})};var ir=class r extends x{get keySchema(){return this._def.keyType}get valueSchema(){return this._def.valueType}_parse(e){let{status:t,ctx:n}=this._processInputParams(e);if(n.parsedType!==m.object)return h(n,{code:f.invalid_type,expected:m.object,received:n.parsedType}),_;let i=[],o=this._def.keyType,s=this._def.valueType;for(let c in n.data)i.push({key:o._parse(new B(n,c,n.path,c)),value:s._parse(new B(n,n.data[c],n.path,c)),alwaysSet:c in n.data});return n.common.async?P.mergeObjectAsync(t,i):P.mergeObjectSync(t,i)}get element(){return this._def.valueType}static create(e,t,n){return t instanceof x?new r({keyType:e,valueType:t,typeName:g.ZodRecord,...b(n)}):new r({keyType:ue.create(),valueType:e,typeName:g.ZodRecord,...b(t)})}},tt=class extends x{get keySchema(){return this._def.keyType}get valueSchema(){return this._def.valueType}_parse(e){let{status:t,ctx:n}=this._processInputParams(e);if(n.parsedType!==m.map)return h(n,{code:f.invalid_type,expected:m.map,received:n.parsedType}),_;let i=this._def.keyType,o=this._def.valueType,s=[...n.data.entries()].map(([c,l],d)=>({key:i._parse(new B(n,c,n.path,[d,"key"])),value:o._parse(new B(n,l,n.path,[d,"value"]))}));if(n.common.async){let c=new Map;return Promise.resolve().then(async()=>{for(let l of s){let d=await l.key,u=await l.value;if(d.status==="aborted"||u.status==="aborted")return _;(d.status==="dirty"||u.status==="dirty")&&t.dirty(),c.set(d.value,u.value)}return{status:t.value,value:c}})}else{let c=new Map;for(let l of s){let d=l.key,u=l.value;if(d.status==="aborted"||u.status==="aborted")return _;(d.status==="dirty"||u.status==="dirty")&&t.dirty(),c.set(d.value,u.value)}return{status:t.value,value:c}}}};tt.create=(r,e,t)=>new tt({valueType:e,keyType:r,typeName:g.ZodMap,...b(t)});var rt=class r extends x{_parse(e){let{status:t,ctx:n}=this._processInputParams(e);if(n.parsedType!==m.set)return h(n,{code:f.invalid_type,expected:m.set,received:n.parsedType}),_;let i=this._def;i.minSize!==null&&n.data.size<i.minSize.value&&(h(n,{code:f.too_small,minimum:i.minSize.value,type:"set",inclusive:!0,exact:!1,message:i.minSize.message}),t.dirty()),i.maxSize!==null&&n.data.size>i.maxSize.value&&(h(n,{code:f.too_big,maximum:i.maxSize.value,type:"set",inclusive:!0,exact:!1,message:i.maxSize.message}),t.dirty());let o=this._def.valueType;function s(l){let d=new Set;for(let u of l){if(u.status==="aborted")return _;u.status==="dirty"&&t.dirty(),d.add(u.value)}return{status:t.value,value:d}}let c=[...n.data.values()].map((l,d)=>o._parse(new B(n,l,n.path,d)));return n.common.async?Promise.all(c).then(l=>s(l)):s(c)}min(e,t){return new r({...this._def,minSize:{value:e,message:v.toString(t)}})}max(e,t){return new r({...this._def,maxSize:{value:e,message:v.toString(t)}})}size(e,t){return this.min(e,t).max(e,t)}nonempty(e){return this.min(1,e)}};rt.create=(r,e)=>new rt({valueType:r,minSize:null,maxSize:null,typeName:g.ZodSet,...b(e)});var or=class r extends x{constructor(){super(...arguments),this.validate=this.implement}_parse(e){let{ctx:t}=this._processInputParams(e);if(t.parsedType!==m.function)return h(t,{code:f.invalid_type,expected:m.function,received:t.parsedType}),_;function n(c,l){return tr({data:c,path:t.path,errorMaps:[t.common.contextualErrorMap,t.schemaErrorMap,er(),Xe].filter(d=>!!d),issueData:{code:f.invalid_arguments,argumentsError:l}})}function i(c,l){return tr({data:c,path:t.path,errorMaps:[t.common.contextualErrorMap,t.schemaErrorMap,er(),Xe].filter(d=>!!d),issueData:{code:f.invalid_return_type,returnTypeError:l}})}let o={errorMap:t.common.contextualErrorMap},s=t.data;if(this._def.returns instanceof pe){let c=this;return M(async function(...l){let d=new z([]),u=await c._def.args.parseAsync(l,o).catch(y=>{throw d.addIssue(n(l,y)),d}),p=await Reflect.apply(s,this,u);return await c._def.returns._def.type.parseAsync(p,o).catch(y=>{throw d.addIssue(i(p,y)),d})})}else{let c=this;return M(function(...l){let d=c._def.args.safeParse(l,o);if(!d.success)throw new z([n(l,d.error)]);let u=Reflect.apply(s,this,d.data),p=c._def.returns.safeParse(u,o);if(!p.success)throw new z([i(u,p.error)]);return p.data})}}parameters(){return this._def.args}returnType(){return this._def.returns}args(...e){return new r({...this._def,args:ee.create(e).rest(oe.create())})}returns(e){return new r({...this._def,returns:e})}implement(e){return this.parse(e)}strictImplement(e){return this.parse(e)}static create(e,t,n){return new r({args:e||ee.create([]).rest(oe.create()),returns:t||oe.create(),typeName:g.ZodFunction,...b(n)})}},Re=class extends x{get schema(){return this._def.getter()}_parse(e){let{ctx:t}=this._processInputParams(e);return this._def.getter()._parse({data:t.data,path:t.path,parent:t})}};Re.create=(r,e)=>new Re({getter:r,typeName:g.ZodLazy,...b(e)});var Ie=class extends x{_parse(e){if(e.data!==this._def.value){let t=this._getOrReturnCtx(e);return h(t,{received:t.data,code:f.invalid_literal,expected:this._def.value}),_}return{status:"valid",value:e.data}}get value(){return this._def.value}};Ie.create=(r,e)=>new Ie({value:r,typeName:g.ZodLiteral,...b(e)});function Jn(r,e){return new Pe({values:r,typeName:g.ZodEnum,...b(e)})}var Pe=class r extends x{constructor(){super(...arguments),mt.set(this,void 0)}_parse(e){if(typeof e.data!="string"){let t=this._getOrReturnCtx(e),n=this._def.values;return h(t,{expected:T.joinValues(n),received:t.parsedType,code:f.invalid_type}),_}if(rr(this,mt,"f")||Hn(this,mt,new Set(this._def.values),"f"),!rr(this,mt,"f").has(e.data)){let t=this._getOrReturnCtx(e),n=this._def.values;return h(t,{received:t.data,code:f.invalid_enum_value,options:n}),_}return M(e.data)}get options(){return this._def.values}get enum(){let e={};for(let t of this._def.values)e[t]=t;return e}get Values(){let e={};for(let t of this._def.values)e[t]=t;return e}get Enum(){let e={};for(let t of this._def.values)e[t]=t;return e}extract(e,t=this._def){return r.create(e,{...this._def,...t})}exclude(e,t=this._def){return r.create(this.options.filter(n=>!e.includes(n)),{...this._def,...t})}};mt=new WeakMap;Pe.create=Jn;var Me=class extends x{constructor(){super(...arguments),vt.set(this,void 0)}_parse(e){let t=T.getValidEnumValues(this._def.values),n=this._getOrReturnCtx(e);if(n.parsedType!==m.string&&n.parsedType!==m.number){let i=T.objectValues(t);return h(n,{expected:T.joinValues(i),received:n.parsedType,code:f.invalid_type}),_}if(rr(this,vt,"f")||Hn(this,vt,new Set(T.getValidEnumValues(this._def.values)),"f"),!rr(this,vt,"f").has(e.data)){let i=T.objectValues(t);return h(n,{received:n.data,code:f.invalid_enum_value,options:i}),_}return M(e.data)}get enum(){return this._def.values}};vt=new WeakMap;Me.create=(r,e)=>new Me({values:r,typeName:g.ZodNativeEnum,...b(e)});var pe=class extends x{unwrap(){return this._def.type}_parse(e){let{ctx:t}=this._processInputParams(e);if(t.parsedType!==m.promise&&t.common.async===!1)return h(t,{code:f.invalid_type,expected:m.promise,received:t.parsedType}),_;let n=t.parsedType===m.promise?t.data:Promise.resolve(t.data);return M(n.then(i=>this._def.type.parseAsync(i,{path:t.path,errorMap:t.common.contextualErrorMap})))}};pe.create=(r,e)=>new pe({type:r,typeName:g.ZodPromise,...b(e)});var Z=class extends x{innerType(){return this._def.schema}sourceType(){return this._def.schema._def.typeName===g.ZodEffects?this._def.schema.sourceType():this._def.schema}_parse(e){let{status:t,ctx:n}=this._processInputParams(e),i=this._def.effect||null,o={addIssue:s=>{h(n,s),s.fatal?t.abort():t.dirty()},get path(){return n.path}};if(o.addIssue=o.addIssue.bind(o),i.type==="preprocess"){let s=i.transform(n.data,o);if(n.common.async)return Promise.resolve(s).then(async c=>{if(t.value==="aborted")return _;let l=await this._def.schema._parseAsync({data:c,path:n.path,parent:n});return l.status==="aborted"?_:l.status==="dirty"||t.value==="dirty"?Ke(l.value):l});{if(t.value==="aborted")return _;let c=this._def.schema._parseSync({data:s,path:n.path,parent:n});return c.status==="aborted"?_:c.status==="dirty"||t.value==="dirty"?Ke(c.value):c}}if(i.type==="refinement"){let s=c=>{let l=i.refinement(c,o);if(n.common.async)return Promise.resolve(l);if(l instanceof Promise)throw new Error("Async refinement encountered during synchronous parse operation. Use .parseAsync instead.");return c};if(n.common.async===!1){let c=this._def.schema._parseSync({data:n.data,path:n.path,parent:n});return c.status==="aborted"?_:(c.status==="dirty"&&t.dirty(),s(c.value),{status:t.value,value:c.value})}else return this._def.schema._parseAsync({data:n.data,path:n.path,parent:n}).then(c=>c.status==="aborted"?_:(c.status==="dirty"&&t.dirty(),s(c.value).then(()=>({status:t.value,value:c.value}))))}if(i.type==="transform")if(n.common.async===!1){let s=this._def.schema._parseSync({data:n.data,path:n.path,parent:n});if(!Se(s))return s;let c=i.transform(s.value,o);if(c instanceof Promise)throw new Error("Asynchronous transform encountered during synchronous parse operation. Use .parseAsync instead.");return{status:t.value,value:c}}else return this._def.schema._parseAsync({data:n.data,path:n.path,parent:n}).then(s=>Se(s)?Promise.resolve(i.transform(s.value,o)).then(c=>({status:t.value,value:c})):s);T.assertNever(i)}};Z.create=(r,e,t)=>new Z({schema:r,typeName:g.ZodEffects,effect:e,...b(t)});Z.createWithPreprocess=(r,e,t)=>new Z({schema:e,effect:{type:"preprocess",transform:r},typeName:g.ZodEffects,...b(t)});var V=class extends x{_parse(e){return this._getType(e)===m.undefined?M(void 0):this._def.innerType._parse(e)}unwrap(){return this._def.innerType}};V.create=(r,e)=>new V({innerType:r,typeName:g.ZodOptional,...b(e)});var te=class extends x{_parse(e){return this._getType(e)===m.null?M(null):this._def.innerType._parse(e)}unwrap(){return this._def.innerType}};te.create=(r,e)=>new te({innerType:r,typeName:g.ZodNullable,...b(e)});var De=class extends x{_parse(e){let{ctx:t}=this._processInputParams(e),n=t.data;return t.parsedType===m.undefined&&(n=this._def.defaultValue()),this._def.innerType._parse({data:n,path:t.path,parent:t})}removeDefault(){return this._def.innerType}};De.create=(r,e)=>new De({innerType:r,typeName:g.ZodDefault,defaultValue:typeof e.default=="function"?e.default:()=>e.default,...b(e)});var Ne=class extends x{_parse(e){let{ctx:t}=this._processInputParams(e),n={...t,common:{...t.common,issues:[]}},i=this._def.innerType._parse({data:n.data,path:n.path,parent:{...n}});return yt(i)?i.then(o=>({status:"valid",value:o.status==="valid"?o.value:this._def.catchValue({get error(){return new z(n.common.issues)},input:n.data})})):{status:"valid",value:i.status==="valid"?i.value:this._def.catchValue({get error(){return new z(n.common.issues)},input:n.data})}}removeCatch(){return this._def.innerType}};Ne.create=(r,e)=>new Ne({innerType:r,typeName:g.ZodCatch,catchValue:typeof e.catch=="function"?e.catch:()=>e.catch,...b(e)});var nt=class extends x{_parse(e){if(this._getType(e)!==m.nan){let n=this._getOrReturnCtx(e);return h(n,{code:f.invalid_type,expected:m.nan,received:n.parsedType}),_}return{status:"valid",value:e.data}}};nt.create=r=>new nt({typeName:g.ZodNaN,...b(r)});var Uo=Symbol("zod_brand"),gt=class extends x{_parse(e){let{ctx:t}=this._processInputParams(e),n=t.data;return this._def.type._parse({data:n,path:t.path,parent:t})}unwrap(){return this._def.type}},_t=class r extends x{_parse(e){let{status:t,ctx:n}=this._processInputParams(e);if(n.common.async)return(async()=>{let o=await this._def.in._parseAsync({data:n.data,path:n.path,parent:n});return o.status==="aborted"?_:o.status==="dirty"?(t.dirty(),Ke(o.value)):this._def.out._parseAsync({data:o.value,path:n.path,parent:n})})();{let i=this._def.in._parseSync({data:n.data,path:n.path,parent:n});return i.status==="aborted"?_:i.status==="dirty"?(t.dirty(),{status:"dirty",value:i.value}):this._def.out._parseSync({data:i.value,path:n.path,parent:n})}}static create(e,t){return new r({in:e,out:t,typeName:g.ZodPipeline})}},Le=class extends x{_parse(e){let t=this._def.innerType._parse(e),n=i=>(Se(i)&&(i.value=Object.freeze(i.value)),i);return yt(t)?t.then(i=>n(i)):n(t)}unwrap(){return this._def.innerType}};Le.create=(r,e)=>new Le({innerType:r,typeName:g.ZodReadonly,...b(e)});function Vn(r,e){let t=typeof r=="function"?r(e):typeof r=="string"?{message:r}:r;return typeof t=="string"?{message:t}:t}function Kn(r,e={},t){return r?fe.create().superRefine((n,i)=>{var o,s;let c=r(n);if(c instanceof Promise)return c.then(l=>{var d,u;if(!l){let p=Vn(e,n),w=(u=(d=p.fatal)!==null&&d!==void 0?d:t)!==null&&u!==void 0?u:!0;i.addIssue({code:"custom",...p,fatal:w})}});if(!c){let l=Vn(e,n),d=(s=(o=l.fatal)!==null&&o!==void 0?o:t)!==null&&s!==void 0?s:!0;i.addIssue({code:"custom",...l,fatal:d})}}):fe.create()}var zo={object:D.lazycreate},g;(function(r){r.ZodString="ZodString",r.ZodNumber="ZodNumber",r.ZodNaN="ZodNaN",r.ZodBigInt="ZodBigInt",r.ZodBoolean="ZodBoolean",r.ZodDate="ZodDate",r.ZodSymbol="ZodSymbol",r.ZodUndefined="ZodUndefined",r.ZodNull="ZodNull",r.ZodAny="ZodAny",r.ZodUnknown="ZodUnknown",r.ZodNever="ZodNever",r.ZodVoid="ZodVoid",r.ZodArray="ZodArray",r.ZodObject="ZodObject",r.ZodUnion="ZodUnion",r.ZodDiscriminatedUnion="ZodDiscriminatedUnion",r.ZodIntersection="ZodIntersection",r.ZodTuple="ZodTuple",r.ZodRecord="ZodRecord",r.ZodMap="ZodMap",r.ZodSet="ZodSet",r.ZodFunction="ZodFunction",r.ZodLazy="ZodLazy",r.ZodLiteral="ZodLiteral",r.ZodEnum="ZodEnum",r.ZodEffects="ZodEffects",r.ZodNativeEnum="ZodNativeEnum",r.ZodOptional="ZodOptional",r.ZodNullable="ZodNullable",r.ZodDefault="ZodDefault",r.ZodCatch="ZodCatch",r.ZodPromise="ZodPromise",r.ZodBranded="ZodBranded",r.ZodPipeline="ZodPipeline",r.ZodReadonly="ZodReadonly"})(g||(g={}));var Zo=(r,e={message:`Input not instance of ${r.name}`})=>Kn(t=>t instanceof r,e),Xn=ue.create,Qn=ke.create,Fo=nt.create,Vo=Te.create,ei=Ae.create,Bo=Ee.create,Wo=Qe.create,Ho=Oe.create,qo=Ce.create,Go=fe.create,Yo=oe.create,Jo=J.create,Ko=et.create,Xo=se.create,Qo=D.create,es=D.strictCreate,ts=$e.create,rs=nr.create,ns=je.create,is=ee.create,os=ir.create,ss=tt.create,as=rt.create,cs=or.create,ls=Re.create,ds=Ie.create,us=Pe.create,fs=Me.create,ps=pe.create,Bn=Z.create,hs=V.create,ms=te.create,vs=Z.createWithPreprocess,ys=_t.create,gs=()=>Xn().optional(),_s=()=>Qn().optional(),bs=()=>ei().optional(),xs={string:(r=>ue.create({...r,coerce:!0})),number:(r=>ke.create({...r,coerce:!0})),boolean:(r=>Ae.create({...r,coerce:!0})),bigint:(r=>Te.create({...r,coerce:!0})),date:(r=>Ee.create({...r,coerce:!0}))},ws=_,a=Object.freeze({__proto__:null,defaultErrorMap:Xe,setErrorMap:vo,getErrorMap:er,makeIssue:tr,EMPTY_PATH:yo,addIssueToContext:h,ParseStatus:P,INVALID:_,DIRTY:Ke,OK:M,isAborted:Fr,isDirty:Vr,isValid:Se,isAsync:yt,get util(){return T},get objectUtil(){return Zr},ZodParsedType:m,getParsedType:ie,ZodType:x,datetimeRegex:Yn,ZodString:ue,ZodNumber:ke,ZodBigInt:Te,ZodBoolean:Ae,ZodDate:Ee,ZodSymbol:Qe,ZodUndefined:Oe,ZodNull:Ce,ZodAny:fe,ZodUnknown:oe,ZodNever:J,ZodVoid:et,ZodArray:se,ZodObject:D,ZodUnion:$e,ZodDiscriminatedUnion:nr,ZodIntersection:je,ZodTuple:ee,ZodRecord:ir,ZodMap:tt,ZodSet:rt,ZodFunction:or,ZodLazy:Re,ZodLiteral:Ie,ZodEnum:Pe,ZodNativeEnum:Me,ZodPromise:pe,ZodEffects:Z,ZodTransformer:Z,ZodOptional:V,ZodNullable:te,ZodDefault:De,ZodCatch:Ne,ZodNaN:nt,BRAND:Uo,ZodBranded:gt,ZodPipeline:_t,ZodReadonly:Le,custom:Kn,Schema:x,ZodSchema:x,late:zo,get ZodFirstPartyTypeKind(){return g},coerce:xs,any:Go,array:Xo,bigint:Vo,boolean:ei,date:Bo,discriminatedUnion:rs,effect:Bn,enum:us,function:cs,instanceof:Zo,intersection:ns,lazy:ls,literal:ds,map:ss,nan:Fo,nativeEnum:fs,never:Jo,null:qo,nullable:ms,number:Qn,object:Qo,oboolean:bs,onumber:_s,optional:hs,ostring:gs,pipeline:ys,preprocess:vs,promise:ps,record:os,set:as,strictObject:es,string:Xn,symbol:Wo,transformer:Bn,tuple:is,undefined:Ho,union:ts,unknown:Yo,void:Ko,NEVER:ws,ZodIssueCode:f,quotelessJson:mo,ZodError:z});var ni=(r=>(r.Info="info",r.Debug="debug",r.Trace="trace",r.Error="error",r))(ni||{}),aC=(r=>(r.Debug="debug",r.Log="log",r.Info="info",r.Warn="warn",r.Error="error",r))(aC||{}),ab=(r=>(r.ConnectTimeout="connect_timeout",r.ResponseTimeout="response_timeout",r.Connect="connect",r.Other="other",r))(ab||{}),aT=(r=>(r.InjectCss="InjectCss",r.SwapImage="SwapImage",r.ReloadPage="ReloadPage",r))(aT||{}),ii=(r=>(r.Changed="Changed",r.Added="Added",r.Removed="Removed",r))(ii||{}),oi=(r=>(r.External="BSLIVE_EXTERNAL",r))(oi||{}),Ss=a.string(),it=a.lazy(()=>a.object({id:a.string(),label:a.string(),nodes:a.array(it)})),ks=a.nativeEnum(ni),aD=a.nativeEnum(aC),aE=a.object({level:aD,message:a.string(),stack:a.string().optional(),user_agent:a.string().optional(),addr:a.string()}),ac=a.object({scroll:a.boolean(),click:a.boolean(),input:a.boolean(),submit:a.boolean(),location:a.boolean()}),ti=a.object({log_level:ks,ghost:ac,console:a.array(aD)}),Ts=a.object({ws_path:a.string(),host:a.string().optional(),token:a.string().optional()}),aF=a.object({level:aD,message:a.string(),stack:a.string().optional()}),As=a.object({kind:a.string(),ms:a.string()}),Es=a.object({message:a.string(),reason:a.string().optional()}),ri=a.object({path:a.string()}),Os=a.object({paths:a.array(a.string())}),si=a.discriminatedUnion("kind",[a.object({kind:a.literal("Both"),payload:a.object({name:a.string(),bind_address:a.string()})}),a.object({kind:a.literal("Address"),payload:a.object({bind_address:a.string()})}),a.object({kind:a.literal("Named"),payload:a.object({name:a.string()})}),a.object({kind:a.literal("Port"),payload:a.object({port:a.number()})}),a.object({kind:a.literal("PortNamed"),payload:a.object({port:a.number(),name:a.string()})})]),Cs=a.object({id:a.string(),identity:si,socket_addr:a.string(),url:a.string()}),ai=a.object({servers:a.array(Cs)}),ci=a.object({connect:Ts,ctx_message:a.string()}),$s=a.object({path:a.string()}),js=a.object({body:a.string()}),Rs=a.discriminatedUnion("kind",[a.object({kind:a.literal("Html"),payload:a.object({html:a.string()})}),a.object({kind:a.literal("Json"),payload:a.object({json_str:a.string()})}),a.object({kind:a.literal("Raw"),payload:a.object({raw:a.string()})}),a.object({kind:a.literal("Sse"),payload:a.object({sse:js})}),a.object({kind:a.literal("Redirect"),payload:a.object({to:a.string(),status:a.number()})}),a.object({kind:a.literal("NoContent"),payload:a.object({no_content:a.boolean()})}),a.object({kind:a.literal("Proxy"),payload:a.object({proxy:a.string()})}),a.object({kind:a.literal("Dir"),payload:a.object({dir:a.string(),base:a.string().optional()})})]),Is=a.discriminatedUnion("kind",[a.object({kind:a.literal("Stopped"),payload:a.object({bind_address:a.string()})}),a.object({kind:a.literal("Started"),payload:a.undefined().optional()}),a.object({kind:a.literal("Patched"),payload:a.undefined().optional()}),a.object({kind:a.literal("Errored"),payload:a.object({error:a.string()})})]),Ps=a.object({identity:si,change:Is}),Ku=a.object({items:a.array(Ps)}),Ms=a.object({path:a.string(),kind:Rs,status:a.number().optional()}),Ds=a.object({servers_resp:ai}),Ns=a.object({line:a.string(),prefix:a.string().optional(),node_path:a.string().optional()}),Ls=a.object({line:a.string(),prefix:a.string().optional(),node_path:a.string().optional()}),aM=a.object({id:a.string(),addr:a.string(),user_agent:a.string().optional(),url:a.string().optional(),connected_at:a.string()}),aN=a.object({clients:a.array(aM)}),ad=a.nativeEnum(ab),af=a.object({path:a.string(),target:a.string(),kind:ad,message:a.string(),attempts:a.number()}),ag=a.object({path:a.string(),target:a.string()}),Us=a.object({paths:a.array(a.string())}),zs=a.union([a.object({kind:a.literal("Ok"),payload:a.undefined().optional()}),a.object({kind:a.literal("Err"),payload:a.string()}),a.object({kind:a.literal("Cancelled"),payload:a.undefined().optional()})]),Zs=a.object({tree:it,will_exec:a.boolean()}),Fs=a.object({paths:a.array(a.string()),debounce:As}),Vs=a.nativeEnum(ii),aU=a.nativeEnum(aT),ar=a.lazy(()=>a.discriminatedUnion("kind",[a.object({kind:a.literal("Fs"),payload:a.object({path:a.string(),change_kind:Vs,effect:aU.optional(),urls:a.array(a.string()).optional()})}),a.object({kind:a.literal("FsMany"),payload:a.array(ar)})])),ah=a.object({tag:a.string(),index:a.number()}),aQ=a.object({path:a.string()}),aj=a.object({element:ah,value:a.string(),checked:a.boolean().optional()}),ak=a.object({path:a.string()}),al=a.object({x:a.number(),y:a.number()}),aO=a.lazy(()=>a.object({clients:a.array(a.string()),event:Xu})),Xu=a.lazy(()=>a.discriminatedUnion("kind",[a.object({kind:a.literal("Change"),payload:ar}),a.object({kind:a.literal("WsConnection"),payload:ti}),a.object({kind:a.literal("Config"),payload:ti}),a.object({kind:a.literal("DisplayMessage"),payload:Es}),a.object({kind:a.literal("Scroll"),payload:al}),a.object({kind:a.literal("Click"),payload:ah}),a.object({kind:a.literal("Input"),payload:aj}),a.object({kind:a.literal("Submit"),payload:ah}),a.object({kind:a.literal("Location"),payload:ak}),a.object({kind:a.literal("Console"),payload:aF}),a.object({kind:a.literal("Addressed"),payload:aO}),a.object({kind:a.literal("Reload"),payload:a.undefined().optional()}),a.object({kind:a.literal("InjectCss"),payload:aQ})])),Qu=a.nativeEnum(oi),Bs=a.discriminatedUnion("kind",[a.object({kind:a.literal("Stdout"),payload:Ls}),a.object({kind:a.literal("Stderr"),payload:Ns})]),ef=a.discriminatedUnion("kind",[a.object({kind:a.literal("MissingInputs"),payload:a.string()}),a.object({kind:a.literal("InvalidInput"),payload:a.string()}),a.object({kind:a.literal("NotFound"),payload:a.string()}),a.object({kind:a.literal("InputWriteError"),payload:a.string()}),a.object({kind:a.literal("PathError"),payload:a.string()}),a.object({kind:a.literal("PortError"),payload:a.string()}),a.object({kind:a.literal("DirError"),payload:a.string()}),a.object({kind:a.literal("YamlError"),payload:a.string()}),a.object({kind:a.literal("TomlError"),payload:a.string()}),a.object({kind:a.literal("MarkdownError"),payload:a.string()}),a.object({kind:a.literal("HtmlError"),payload:a.string()}),a.object({kind:a.literal("Io"),payload:a.string()}),a.object({kind:a.literal("UnsupportedExtension"),payload:a.string()}),a.object({kind:a.literal("MissingExtension"),payload:a.string()}),a.object({kind:a.literal("EmptyInput"),payload:a.string()}),a.object({kind:a.literal("BsLiveRules"),payload:a.string()})]),tf=a.discriminatedUnion("kind",[a.object({kind:a.literal("ServersChanged"),payload:ai}),a.object({kind:a.literal("TaskReport"),payload:a.object({id:a.string()})}),a.object({kind:a.literal("TaskTreeDisplay"),payload:a.object({tree:it})})]),rf=a.discriminatedUnion("kind",[a.object({kind:a.literal("Started"),payload:a.undefined().optional()}),a.object({kind:a.literal("FailedStartup"),payload:a.string()})]),nf=a.object({routes:a.array(Ms),id:a.string()}),Ws=a.lazy(()=>a.discriminatedUnion("kind",[a.object({kind:a.literal("Started"),payload:a.object({tree:it})}),a.object({kind:a.literal("Ended"),payload:a.object({tree:it,report:sr,report_map:a.record(sr)})}),a.object({kind:a.literal("Error"),payload:a.undefined().optional()})])),sr=a.lazy(()=>a.object({result:qs})),Hs=a.lazy(()=>a.object({stage:Ws})),qs=a.lazy(()=>a.object({conclusion:zs,invocation_id:Ss,task_reports:a.array(sr)})),Gs=a.lazy(()=>a.object({tree:it,report_map:a.record(sr)})),of=a.lazy(()=>a.discriminatedUnion("kind",[a.object({kind:a.literal("ServersChanged"),payload:Ds}),a.object({kind:a.literal("Watching"),payload:Fs}),a.object({kind:a.literal("WatchingStopped"),payload:Us}),a.object({kind:a.literal("FileChanged"),payload:ri}),a.object({kind:a.literal("FilesChanged"),payload:Os}),a.object({kind:a.literal("InputFileChanged"),payload:ri}),a.object({kind:a.literal("InputAccepted"),payload:$s}),a.object({kind:a.literal("OutputLine"),payload:Bs}),a.object({kind:a.literal("TaskAction"),payload:Hs}),a.object({kind:a.literal("TaskTreePreview"),payload:Zs}),a.object({kind:a.literal("TaskTreeSummary"),payload:Gs}),a.object({kind:a.literal("ProxyWsOpened"),payload:ag}),a.object({kind:a.literal("ProxyWsClosed"),payload:ag}),a.object({kind:a.literal("ProxyError"),payload:af}),a.object({kind:a.literal("BrowserConsole"),payload:aE}),a.object({kind:a.literal("ClientConnected"),payload:aM}),a.object({kind:a.literal("ClientDisconnected"),payload:aM})]));var li=[{selector:"background",styleNames:["backgroundImage"]},{selector:"border",styleNames:["borderImage","webkitBorderImage","MozBorderImage"]}],cr={stylesheetReloadTimeout:15e3},Ys=/\.(jpe?g|png|gif|svg)$/i,lr=class{constructor(e,t,n){this.window=e,this.console=t,this.Timer=n,this.document=this.window.document,this.importCacheWaitPeriod=200,this.plugins=[]}addPlugin(e){return this.plugins.push(e)}analyze(e){}reload(e,t={}){if(this.options={...cr,...t},!(t.liveCSS&&e.match(/\.css(?:\.map)?$/i)&&this.reloadStylesheet(e))){if(t.liveImg&&e.match(Ys)){this.reloadImages(e);return}if(t.isChromeExtension){this.reloadChromeExtension();return}return this.reloadPage()}}reloadPage(){return this.window.document.location.reload()}reloadChromeExtension(){return this.window.chrome.runtime.reload()}reloadImages(e){let t,n=this.generateUniqueString();for(t of Array.from(this.document.images))di(e,Wr(t.src))&&(t.src=this.generateCacheBustUrl(t.src,n));if(this.document.querySelectorAll)for(let{selector:i,styleNames:o}of li)for(t of Array.from(this.document.querySelectorAll(`[style*=${i}]`)))this.reloadStyleImages(t.style,o,e,n);if(this.document.styleSheets)return Array.from(this.document.styleSheets).map(i=>this.reloadStylesheetImages(i,e,n))}reloadStylesheetImages(e,t,n){let i;try{i=(e||{}).cssRules}catch{}if(i)for(let o of Array.from(i))switch(o.type){case CSSRule.IMPORT_RULE:this.reloadStylesheetImages(o.styleSheet,t,n);break;case CSSRule.STYLE_RULE:for(let{styleNames:s}of li)this.reloadStyleImages(o.style,s,t,n);break;case CSSRule.MEDIA_RULE:this.reloadStylesheetImages(o,t,n);break}}reloadStyleImages(e,t,n,i){for(let o of t){let s=e[o];if(typeof s=="string"){let c=s.replace(new RegExp("\\burl\\s*\\(([^)]*)\\)"),(l,d)=>di(n,Wr(d))?`url(${this.generateCacheBustUrl(d,i)})`:l);c!==s&&(e[o]=c)}}}reloadStylesheet(e){let t=this.options||cr,n,i,o=(()=>{let l=[];for(i of Array.from(this.document.getElementsByTagName("link")))i.rel.match(/^stylesheet$/i)&&!i.__LiveReload_pendingRemoval&&l.push(i);return l})(),s=[];for(n of Array.from(this.document.getElementsByTagName("style")))n.sheet&&this.collectImportedStylesheets(n,n.sheet,s);for(i of Array.from(o))this.collectImportedStylesheets(i,i.sheet,s);if(this.window.StyleFix&&this.document.querySelectorAll)for(n of Array.from(this.document.querySelectorAll("style[data-href]")))o.push(n);this.console.debug(`found ${o.length} LINKed stylesheets, ${s.length} @imported stylesheets`);let c=Js(e,o.concat(s),l=>Wr(this.linkHref(l)));if(c)c.object.rule?(this.console.debug(`is reloading imported stylesheet: ${c.object.href}`),this.reattachImportedRule(c.object)):(this.console.debug(`is reloading stylesheet: ${this.linkHref(c.object)}`),this.reattachStylesheetLink(c.object));else if(t.reloadMissingCSS){this.console.debug(`will reload all stylesheets because path '${e}' did not match any specific one. To disable this behavior, set 'options.reloadMissingCSS' to 'false'.`);for(i of Array.from(o))this.reattachStylesheetLink(i)}else this.console.debug(`will not reload path '${e}' because the stylesheet was not found on the page and 'options.reloadMissingCSS' was set to 'false'.`);return!0}collectImportedStylesheets(e,t,n){let i;try{i=(t||{}).cssRules}catch{}if(i&&i.length)for(let o=0;o<i.length;o++){let s=i[o];switch(s.type){case CSSRule.CHARSET_RULE:continue;case CSSRule.IMPORT_RULE:n.push({link:e,rule:s,index:o,href:s.href}),this.collectImportedStylesheets(e,s.styleSheet,n);break;default:break}}}waitUntilCssLoads(e,t){let n=this.options||cr,i=!1,o=()=>{if(!i)return i=!0,t()};if(e.onload=()=>(this.console.debug("the new stylesheet has finished loading"),this.knownToSupportCssOnLoad=!0,o()),!this.knownToSupportCssOnLoad){let s;(s=()=>e.sheet?(this.console.debug("is polling until the new CSS finishes loading..."),o()):this.Timer.start(50,s))()}return this.Timer.start(n.stylesheetReloadTimeout,o)}linkHref(e){return e.href||e.getAttribute&&e.getAttribute("data-href")}reattachStylesheetLink(e){let t;if(e.__LiveReload_pendingRemoval)return;e.__LiveReload_pendingRemoval=!0,e.tagName==="STYLE"?(t=this.document.createElement("link"),t.rel="stylesheet",t.media=e.media,t.disabled=e.disabled):t=e.cloneNode(!1),t.href=this.generateCacheBustUrl(this.linkHref(e));let n=e.parentNode;return n.lastChild===e?n.appendChild(t):n.insertBefore(t,e.nextSibling),this.waitUntilCssLoads(t,()=>{let i;return/AppleWebKit/.test(this.window.navigator.userAgent)?i=5:i=200,this.Timer.start(i,()=>{if(e.parentNode)return e.parentNode.removeChild(e),t.onreadystatechange=null,this.window.StyleFix?this.window.StyleFix.link(t):void 0})})}reattachImportedRule({rule:e,index:t,link:n}){let i=e.parentStyleSheet,o=this.generateCacheBustUrl(e.href),s=e.media.length?[].join.call(e.media,", "):"",c=`@import url("${o}") ${s};`;e.__LiveReload_newHref=o;let l=this.document.createElement("link");return l.rel="stylesheet",l.href=o,l.__LiveReload_pendingRemoval=!0,n.parentNode&&n.parentNode.insertBefore(l,n),this.Timer.start(this.importCacheWaitPeriod,()=>{if(l.parentNode&&l.parentNode.removeChild(l),e.__LiveReload_newHref===o)return i.insertRule(c,t),i.deleteRule(t+1),e=i.cssRules[t],e.__LiveReload_newHref=o,this.Timer.start(this.importCacheWaitPeriod,()=>{if(e.__LiveReload_newHref===o)return i.insertRule(c,t),i.deleteRule(t+1)})})}generateUniqueString(){return`livereload=${Date.now()}`}generateCacheBustUrl(e,t){let n=this.options||cr,i,o;if(t||(t=this.generateUniqueString()),{url:e,hash:i,params:o}=ui(e),n.overrideURL&&e.indexOf(n.serverURL)<0){let c=e;e=n.serverURL+n.overrideURL+"?url="+encodeURIComponent(e),this.console.debug(`is overriding source URL ${c} with ${e}`)}let s=o.replace(/(\?|&)livereload=(\d+)/,(c,l)=>`${l}${t}`);return s===o&&(o.length===0?s=`?${t}`:s=`${o}&${t}`),e+s+i}};function ui(r){let e="",t="",n=r.indexOf("#");n>=0&&(e=r.slice(n),r=r.slice(0,n));let i=r.indexOf("??");return i>=0?i+1!==r.lastIndexOf("?")&&(n=r.lastIndexOf("?")):n=r.indexOf("?"),n>=0&&(t=r.slice(n),r=r.slice(0,n)),{url:r,params:t,hash:e}}function Wr(r){if(!r)return"";let e;return{url:r}=ui(r),r.indexOf("file://")===0?e=r.replace(new RegExp("^file://(localhost)?"),""):e=r.replace(new RegExp("^([^:]+:)?//([^:/]+)(:\\d*)?/"),"/"),decodeURIComponent(e)}function fi(r,e){if(r=r.replace(/^\/+/,"").toLowerCase(),e=e.replace(/^\/+/,"").toLowerCase(),r===e)return 1e4;let t=r.split(/\/|\\/).reverse(),n=e.split(/\/|\\/).reverse(),i=Math.min(t.length,n.length),o=0;for(;o<i&&t[o]===n[o];)++o;return o}function Js(r,e,t=n=>n){let n,i={score:0};for(let o of e)n=fi(r,t(o)),n>i.score&&(i={object:o,score:n});return i.score===0?null:i}function di(r,e){return fi(r,e)>0}var mi=Yi(hi(),1);var Ks=/\.(jpe?g|png|gif|svg)$/i,aV=/\.css(?:\.map)?$/i;function Hr(r,e,t){switch(r.kind){case"FsMany":{if(r.payload.some(i=>aW(i)==="ReloadPage"))return aR(t);for(let i of r.payload)Hr(i,e,t);break}case"Fs":{let{path:n,effect:i,urls:o}=r.payload;if(i==="ReloadPage")return aR(t);for(let s of o?.length?o:[n])aS(s,e,t)}}}function aW(r){switch(r.kind){case"Fs":{let{path:e,effect:t}=r.payload;return t||(e.match(aV)?"InjectCss":e.match(Ks)?"SwapImage":"ReloadPage")}case"FsMany":throw new Error("unreachable")}}function aR(r){return window.__playwright?.record?window.__playwright?.record({kind:"reloadPage"}):r.reloadPage()}function aS(r,e,t){let n={liveCSS:!0,liveImg:!0,reloadMissingCSS:!0,originalPath:"",overrideURL:"",serverURL:""};window.__playwright?.record?window.__playwright?.record({kind:"reload",args:{path:r,opts:n}}):(e.trace("will reload a file with path ",r),t.reload(r,n))}var qr={name:"dom plugin",globalSetup:(r,e)=>{let t=new lr(window,e,mi.Timer);return[r,[e,t]]},resetSink(r,e,t){let[n,i]=e;return r.pipe(we(o=>{switch(o.kind){case"Change":{let s=o.payload;n.trace("incoming message",JSON.stringify({change:s,config:t},null,2));let c=ar.parse(s);Hr(c,n,i);break}case"Reload":{aR(i);break}case"InjectCss":{aS(o.payload.path,n,i);break}}}),xe())}};var ur=globalThis,fr=ur.ShadowRoot&&(ur.ShadyCSS===void 0||ur.ShadyCSS.nativeShadow)&&"adoptedStyleSheets"in Document.prototype&&"replace"in CSSStyleSheet.prototype,Gr=Symbol(),vi=new WeakMap,bt=class{constructor(e,t,n){if(this._$cssResult$=!0,n!==Gr)throw Error("CSSResult is not constructable. Use `unsafeCSS` or `css` instead.");this.cssText=e,this.t=t}get styleSheet(){let e=this.o,t=this.t;if(fr&&e===void 0){let n=t!==void 0&&t.length===1;n&&(e=vi.get(t)),e===void 0&&((this.o=e=new CSSStyleSheet).replaceSync(this.cssText),n&&vi.set(t,e))}return e}toString(){return this.cssText}},yi=r=>new bt(typeof r=="string"?r:r+"",void 0,Gr),K=(r,...e)=>{let t=r.length===1?r[0]:e.reduce((n,i,o)=>n+(s=>{if(s._$cssResult$===!0)return s.cssText;if(typeof s=="number")return s;throw Error("Value passed to 'css' function must be a 'css' function result: "+s+". Use 'unsafeCSS' to pass non-literal values, but take care to ensure page security.")})(i)+r[o+1],r[0]);return new bt(t,r,Gr)},gi=(r,e)=>{if(fr)r.adoptedStyleSheets=e.map(t=>t instanceof CSSStyleSheet?t:t.styleSheet);else for(let t of e){let n=document.createElement("style"),i=ur.litNonce;i!==void 0&&n.setAttribute("nonce",i),n.textContent=t.cssText,r.appendChild(n)}},Yr=fr?r=>r:r=>r instanceof CSSStyleSheet?(e=>{let t="";for(let n of e.cssRules)t+=n.cssText;return yi(t)})(r):r;var{is:Xs,defineProperty:Qs,getOwnPropertyDescriptor:ea,getOwnPropertyNames:ta,getOwnPropertySymbols:ra,getPrototypeOf:na}=Object,pr=globalThis,_i=pr.trustedTypes,ia=_i?_i.emptyScript:"",oa=pr.reactiveElementPolyfillSupport,xt=(r,e)=>r,wt={toAttribute(r,e){switch(e){case Boolean:r=r?ia:null;break;case Object:case Array:r=r==null?r:JSON.stringify(r)}return r},fromAttribute(r,e){let t=r;switch(e){case Boolean:t=r!==null;break;case Number:t=r===null?null:Number(r);break;case Object:case Array:try{t=JSON.parse(r)}catch{t=null}}return t}},hr=(r,e)=>!Xs(r,e),bi={attribute:!0,type:String,converter:wt,reflect:!1,useDefault:!1,hasChanged:hr};Symbol.metadata??=Symbol("metadata"),pr.litPropertyMetadata??=new WeakMap;var ae=class extends HTMLElement{static addInitializer(e){this._$Ei(),(this.l??=[]).push(e)}static get observedAttributes(){return this.finalize(),this._$Eh&&[...this._$Eh.keys()]}static createProperty(e,t=bi){if(t.state&&(t.attribute=!1),this._$Ei(),this.prototype.hasOwnProperty(e)&&((t=Object.create(t)).wrapped=!0),this.elementProperties.set(e,t),!t.noAccessor){let n=Symbol(),i=this.getPropertyDescriptor(e,n,t);i!==void 0&&Qs(this.prototype,e,i)}}static getPropertyDescriptor(e,t,n){let{get:i,set:o}=ea(this.prototype,e)??{get(){return this[t]},set(s){this[t]=s}};return{get:i,set(s){let c=i?.call(this);o?.call(this,s),this.requestUpdate(e,c,n)},configurable:!0,enumerable:!0}}static getPropertyOptions(e){return this.elementProperties.get(e)??bi}static _$Ei(){if(this.hasOwnProperty(xt("elementProperties")))return;let e=na(this);e.finalize(),e.l!==void 0&&(this.l=[...e.l]),this.elementProperties=new Map(e.elementProperties)}static finalize(){if(this.hasOwnProperty(xt("finalized")))return;if(this.finalized=!0,this._$Ei(),this.hasOwnProperty(xt("properties"))){let t=this.properties,n=[...ta(t),...ra(t)];for(let i of n)this.createProperty(i,t[i])}let e=this[Symbol.metadata];if(e!==null){let t=litPropertyMetadata.get(e);if(t!==void 0)for(let[n,i]of t)this.elementProperties.set(n,i)}this._$Eh=new Map;for(let[t,n]of this.elementProperties){let i=this._$Eu(t,n);i!==void 0&&this._$Eh.set(i,t)}this.elementStyles=this.finalizeStyles(this.styles)}static finalizeStyles(e){let t=[];if(Array.isArray(e)){let n=new Set(e.flat(1/0).reverse());for(let i of n)t.unshift(Yr(i))}else e!==void 0&&t.push(Yr(e));return t}static _$Eu(e,t){let n=t.attribute;return n===!1?void 0:typeof n=="string"?n:typeof e=="string"?e.toLowerCase():void 0}constructor(){super(),this._$Ep=void 0,this.isUpdatePending=!1,this.hasUpdated=!1,this._$Em=null,this._$Ev()}_$Ev(){this._$ES=new Promise(e=>this.enableUpdating=e),this._$AL=new Map,this._$E_(),this.requestUpdate(),this.constructor.l?.forEach(e=>e(this))}addController(e){(this._$EO??=new Set).add(e),this.renderRoot!==void 0&&this.isConnected&&e.hostConnected?.()}removeController(e){this._$EO?.delete(e)}_$E_(){let e=new Map,t=this.constructor.elementProperties;for(let n of t.keys())this.hasOwnProperty(n)&&(e.set(n,this[n]),delete this[n]);e.size>0&&(this._$Ep=e)}createRenderRoot(){let e=this.shadowRoot??this.attachShadow(this.constructor.shadowRootOptions);return gi(e,this.constructor.elementStyles),e}connectedCallback(){this.renderRoot??=this.createRenderRoot(),this.enableUpdating(!0),this._$EO?.forEach(e=>e.hostConnected?.())}enableUpdating(e){}disconnectedCallback(){this._$EO?.forEach(e=>e.hostDisconnected?.())}attributeChangedCallback(e,t,n){this._$AK(e,n)}_$ET(e,t){let n=this.constructor.elementProperties.get(e),i=this.constructor._$Eu(e,n);if(i!==void 0&&n.reflect===!0){let o=(n.converter?.toAttribute!==void 0?n.converter:wt).toAttribute(t,n.type);this._$Em=e,o==null?this.removeAttribute(i):this.setAttribute(i,o),this._$Em=null}}_$AK(e,t){let n=this.constructor,i=n._$Eh.get(e);if(i!==void 0&&this._$Em!==i){let o=n.getPropertyOptions(i),s=typeof o.converter=="function"?{fromAttribute:o.converter}:o.converter?.fromAttribute!==void 0?o.converter:wt;this._$Em=i;let c=s.fromAttribute(t,o.type);this[i]=c??this._$Ej?.get(i)??c,this._$Em=null}}requestUpdate(e,t,n,i=!1,o){if(e!==void 0){let s=this.constructor;if(i===!1&&(o=this[e]),n??=s.getPropertyOptions(e),!((n.hasChanged??hr)(o,t)||n.useDefault&&n.reflect&&o===this._$Ej?.get(e)&&!this.hasAttribute(s._$Eu(e,n))))return;this.C(e,t,n)}this.isUpdatePending===!1&&(this._$ES=this._$EP())}C(e,t,{useDefault:n,reflect:i,wrapped:o},s){n&&!(this._$Ej??=new Map).has(e)&&(this._$Ej.set(e,s??t??this[e]),o!==!0||s!==void 0)||(this._$AL.has(e)||(this.hasUpdated||n||(t=void 0),this._$AL.set(e,t)),i===!0&&this._$Em!==e&&(this._$Eq??=new Set).add(e))}async _$EP(){this.isUpdatePending=!0;try{await this._$ES}catch(t){Promise.reject(t)}let e=this.scheduleUpdate();return e!=null&&await e,!this.isUpdatePending}scheduleUpdate(){return this.performUpdate()}performUpdate(){if(!this.isUpdatePending)return;if(!this.hasUpdated){if(this.renderRoot??=this.createRenderRoot(),this._$Ep){for(let[i,o]of this._$Ep)this[i]=o;this._$Ep=void 0}let n=this.constructor.elementProperties;if(n.size>0)for(let[i,o]of n){let{wrapped:s}=o,c=this[i];s!==!0||this._$AL.has(i)||c===void 0||this.C(i,void 0,o,c)}}let e=!1,t=this._$AL;try{e=this.shouldUpdate(t),e?(this.willUpdate(t),this._$EO?.forEach(n=>n.hostUpdate?.()),this.update(t)):this._$EM()}catch(n){throw e=!1,this._$EM(),n}e&&this._$AE(t)}willUpdate(e){}_$AE(e){this._$EO?.forEach(t=>t.hostUpdated?.()),this.hasUpdated||(this.hasUpdated=!0,this.firstUpdated(e)),this.updated(e)}_$EM(){this._$AL=new Map,this.isUpdatePending=!1}get updateComplete(){return this.getUpdateComplete()}getUpdateComplete(){return this._$ES}shouldUpdate(e){return!0}update(e){this._$Eq&&=this._$Eq.forEach(t=>this._$ET(t,this[t])),this._$EM()}updated(e){}firstUpdated(e){}};ae.elementStyles=[],ae.shadowRootOptions={mode:"open"},ae[xt("elementProperties")]=new Map,ae[xt("finalized")]=new Map,oa?.({ReactiveElement:ae}),(pr.reactiveElementVersions??=[]).push("2.1.2");var Kr=globalThis,xi=r=>r,mr=Kr.trustedTypes,wi=mr?mr.createPolicy("lit-html",{createHTML:r=>r}):void 0,Xr="$lit$",ce=`lit$${Math.random().toFixed(9).slice(2)}$`,Qr="?"+ce,sa=`<${Qr}>`,Ze=document,kt=()=>Ze.createComment(""),Tt=r=>r===null||typeof r!="object"&&typeof r!="function",en=Array.isArray,Oi=r=>en(r)||typeof r?.[Symbol.iterator]=="function",Jr=`[ 	
 \f\r]`,St=/<(?:(!--|\/[^a-zA-Z])|(\/?[a-zA-Z][^>\s]*)|(\/?$))/g,Si=/-->/g,ki=/>/g,Ue=RegExp(`>|${Jr}(?:([^\\s"'>=/]+)(${Jr}*=${Jr}*(?:[^ 	
 \f\r"'\`<>=]|("|')|))|$)`,"g"),Ti=/'/g,Ai=/"/g,Ci=/^(?:script|style|textarea|title)$/i,tn=r=>(e,...t)=>({_$litType$:r,strings:e,values:t}),N=tn(1),bf=tn(2),xf=tn(3),Fe=Symbol.for("lit-noChange"),O=Symbol.for("lit-nothing"),Ei=new WeakMap,ze=Ze.createTreeWalker(Ze,129);function $i(r,e){if(!en(r)||!r.hasOwnProperty("raw"))throw Error("invalid template strings array");return wi!==void 0?wi.createHTML(e):e}var ji=(r,e)=>{let t=r.length-1,n=[],i,o=e===2?"<svg>":e===3?"<math>":"",s=St;for(let c=0;c<t;c++){let l=r[c],d,u,p=-1,w=0;for(;w<l.length&&(s.lastIndex=w,u=s.exec(l),u!==null);)w=s.lastIndex,s===St?u[1]==="!--"?s=Si:u[1]!==void 0?s=ki:u[2]!==void 0?(Ci.test(u[2])&&(i=RegExp("</"+u[2],"g")),s=Ue):u[3]!==void 0&&(s=Ue):s===Ue?u[0]===">"?(s=i??St,p=-1):u[1]===void 0?p=-2:(p=s.lastIndex-u[2].length,d=u[1],s=u[3]===void 0?Ue:u[3]==='"'?Ai:Ti):s===Ai||s===Ti?s=Ue:s===Si||s===ki?s=St:(s=Ue,i=void 0);let y=s===Ue&&r[c+1].startsWith("/>")?" ":"";o+=s===St?l+sa:p>=0?(n.push(d),l.slice(0,p)+Xr+l.slice(p)+ce+y):l+ce+(p===-2?c:y)}return[$i(r,o+(r[t]||"<?>")+(e===2?"</svg>":e===3?"</math>":"")),n]},At=class r{constructor({strings:e,_$litType$:t},n){let i;this.parts=[];let o=0,s=0,c=e.length-1,l=this.parts,[d,u]=ji(e,t);if(this.el=r.createElement(d,n),ze.currentNode=this.el.content,t===2||t===3){let p=this.el.content.firstChild;p.replaceWith(...p.childNodes)}for(;(i=ze.nextNode())!==null&&l.length<c;){if(i.nodeType===1){if(i.hasAttributes())for(let p of i.getAttributeNames())if(p.endsWith(Xr)){let w=u[s++],y=i.getAttribute(p).split(ce),A=/([.?@])?(.*)/.exec(w);l.push({type:1,index:o,name:A[2],strings:y,ctor:A[1]==="."?yr:A[1]==="?"?gr:A[1]==="@"?_r:Be}),i.removeAttribute(p)}else p.startsWith(ce)&&(l.push({type:6,index:o}),i.removeAttribute(p));if(Ci.test(i.tagName)){let p=i.textContent.split(ce),w=p.length-1;if(w>0){i.textContent=mr?mr.emptyScript:"";for(let y=0;y<w;y++)i.append(p[y],kt()),ze.nextNode(),l.push({type:2,index:++o});i.append(p[w],kt())}}}else if(i.nodeType===8)if(i.data===Qr)l.push({type:2,index:o});else{let p=-1;for(;(p=i.data.indexOf(ce,p+1))!==-1;)l.push({type:7,index:o}),p+=ce.length-1}o++}}static createElement(e,t){let n=Ze.createElement("template");return n.innerHTML=e,n}};function Ve(r,e,t=r,n){if(e===Fe)return e;let i=n!==void 0?t._$Co?.[n]:t._$Cl,o=Tt(e)?void 0:e._$litDirective$;return i?.constructor!==o&&(i?._$AO?.(!1),o===void 0?i=void 0:(i=new o(r),i._$AT(r,t,n)),n!==void 0?(t._$Co??=[])[n]=i:t._$Cl=i),i!==void 0&&(e=Ve(r,i._$AS(r,e.values),i,n)),e}var vr=class{constructor(e,t){this._$AV=[],this._$AN=void 0,this._$AD=e,this._$AM=t}get parentNode(){return this._$AM.parentNode}get _$AU(){return this._$AM._$AU}u(e){let{el:{content:t},parts:n}=this._$AD,i=(e?.creationScope??Ze).importNode(t,!0);ze.currentNode=i;let o=ze.nextNode(),s=0,c=0,l=n[0];for(;l!==void 0;){if(s===l.index){let d;l.type===2?d=new ot(o,o.nextSibling,this,e):l.type===1?d=new l.ctor(o,l.name,l.strings,this,e):l.type===6&&(d=new br(o,this,e)),this._$AV.push(d),l=n[++c]}s!==l?.index&&(o=ze.nextNode(),s++)}return ze.currentNode=Ze,i}p(e){let t=0;for(let n of this._$AV)n!==void 0&&(n.strings!==void 0?(n._$AI(e,n,t),t+=n.strings.length-2):n._$AI(e[t])),t++}},ot=class r{get _$AU(){return this._$AM?._$AU??this._$Cv}constructor(e,t,n,i){this.type=2,this._$AH=O,this._$AN=void 0,this._$AA=e,this._$AB=t,this._$AM=n,this.options=i,this._$Cv=i?.isConnected??!0}get parentNode(){let e=this._$AA.parentNode,t=this._$AM;return t!==void 0&&e?.nodeType===11&&(e=t.parentNode),e}get startNode(){return this._$AA}get endNode(){return this._$AB}_$AI(e,t=this){e=Ve(this,e,t),Tt(e)?e===O||e==null||e===""?(this._$AH!==O&&this._$AR(),this._$AH=O):e!==this._$AH&&e!==Fe&&this._(e):e._$litType$!==void 0?this.$(e):e.nodeType!==void 0?this.T(e):Oi(e)?this.k(e):this._(e)}O(e){return this._$AA.parentNode.insertBefore(e,this._$AB)}T(e){this._$AH!==e&&(this._$AR(),this._$AH=this.O(e))}_(e){this._$AH!==O&&Tt(this._$AH)?this._$AA.nextSibling.data=e:this.T(Ze.createTextNode(e)),this._$AH=e}$(e){let{values:t,_$litType$:n}=e,i=typeof n=="number"?this._$AC(e):(n.el===void 0&&(n.el=At.createElement($i(n.h,n.h[0]),this.options)),n);if(this._$AH?._$AD===i)this._$AH.p(t);else{let o=new vr(i,this),s=o.u(this.options);o.p(t),this.T(s),this._$AH=o}}_$AC(e){let t=Ei.get(e.strings);return t===void 0&&Ei.set(e.strings,t=new At(e)),t}k(e){en(this._$AH)||(this._$AH=[],this._$AR());let t=this._$AH,n,i=0;for(let o of e)i===t.length?t.push(n=new r(this.O(kt()),this.O(kt()),this,this.options)):n=t[i],n._$AI(o),i++;i<t.length&&(this._$AR(n&&n._$AB.nextSibling,i),t.length=i)}_$AR(e=this._$AA.nextSibling,t){for(this._$AP?.(!1,!0,t);e!==this._$AB;){let n=xi(e).nextSibling;xi(e).remove(),e=n}}setConnected(e){this._$AM===void 0&&(this._$Cv=e,this._$AP?.(e))}},Be=class{get tagName(){return this.element.tagName}get _$AU(){return this._$AM._$AU}constructor(e,t,n,i,o){this.type=1,this._$AH=O,this._$AN=void 0,this.element=e,this.name=t,this._$AM=i,this.options=o,n.length>2||n[0]!==""||n[1]!==""?(this._$AH=Array(n.length-1).fill(new String),this.strings=n):this._$AH=O}_$AI(e,t=this,n,i){let o=this.strings,s=!1;if(o===void 0)e=Ve(this,e,t,0),s=!Tt(e)||e!==this._$AH&&e!==Fe,s&&(this._$AH=e);else{let c=e,l,d;for(e=o[0],l=0;l<o.length-1;l++)d=Ve(this,c[n+l],t,l),d===Fe&&(d=this._$AH[l]),s||=!Tt(d)||d!==this._$AH[l],d===O?e=O:e!==O&&(e+=(d??"")+o[l+1]),this._$AH[l]=d}s&&!i&&this.j(e)}j(e){e===O?this.element.removeAttribute(this.name):this.element.setAttribute(this.name,e??"")}},yr=class extends Be{constructor(){super(...arguments),this.type=3}j(e){this.element[this.name]=e===O?void 0:e}},gr=class extends Be{constructor(){super(...arguments),this.type=4}j(e){this.element.toggleAttribute(this.name,!!e&&e!==O)}},_r=class extends Be{constructor(e,t,n,i,o){super(e,t,n,i,o),this.type=5}_$AI(e,t=this){if((e=Ve(this,e,t,0)??O)===Fe)return;let n=this._$AH,i=e===O&&n!==O||e.capture!==n.capture||e.once!==n.once||e.passive!==n.passive,o=e!==O&&(n===O||i);i&&this.element.removeEventListener(this.name,this,n),o&&this.element.addEventListener(this.name,this,e),this._$AH=e}handleEvent(e){typeof this._$AH=="function"?this._$AH.call(this.options?.host??this.element,e):this._$AH.handleEvent(e)}},br=class{constructor(e,t,n){this.element=e,this.type=6,this._$AN=void 0,this._$AM=t,this.options=n}get _$AU(){return this._$AM._$AU}_$AI(e){Ve(this,e)}},Ri={M:Xr,P:ce,A:Qr,C:1,L:ji,R:vr,D:Oi,V:Ve,I:ot,H:Be,N:gr,U:_r,B:yr,F:br},aa=Kr.litHtmlPolyfillSupport;aa?.(At,ot),(Kr.litHtmlVersions??=[]).push("3.3.2");var xr=(r,e,t)=>{let n=t?.renderBefore??e,i=n._$litPart$;if(i===void 0){let o=t?.renderBefore??null;n._$litPart$=i=new ot(e.insertBefore(kt(),o),o,void 0,t??{})}return i._$AI(r),i};var rn=globalThis,L=class extends ae{constructor(){super(...arguments),this.renderOptions={host:this},this._$Do=void 0}createRenderRoot(){let e=super.createRenderRoot();return this.renderOptions.renderBefore??=e.firstChild,e}update(e){let t=this.render();this.hasUpdated||(this.renderOptions.isConnected=this.isConnected),super.update(e),this._$Do=xr(t,this.renderRoot,this.renderOptions)}connectedCallback(){super.connectedCallback(),this._$Do?.setConnected(!0)}disconnectedCallback(){super.disconnectedCallback(),this._$Do?.setConnected(!1)}render(){return Fe}};L._$litElement$=!0,L.finalized=!0,rn.litElementHydrateSupport?.({LitElement:L});var ca=rn.litElementPolyfillSupport;ca?.({LitElement:L});(rn.litElementVersions??=[]).push("4.2.2");var st=r=>(e,t)=>{t!==void 0?t.addInitializer(()=>{customElements.define(r,e)}):customElements.define(r,e)};var la={attribute:!0,type:String,converter:wt,reflect:!1,hasChanged:hr},da=(r=la,e,t)=>{let{kind:n,metadata:i}=t,o=globalThis.litPropertyMetadata.get(i);if(o===void 0&&globalThis.litPropertyMetadata.set(i,o=new Map),n==="setter"&&((r=Object.create(r)).wrapped=!0),o.set(t.name,r),n==="accessor"){let{name:s}=t;return{set(c){let l=e.get.call(this);e.set.call(this,c),this.requestUpdate(s,l,r,!0,c)},init(c){return c!==void 0&&this.C(s,void 0,r,c),c}}}if(n==="setter"){let{name:s}=t;return function(c){let l=this[s];e.call(this,c),this.requestUpdate(s,l,r,!0,c)}}throw Error("Unsupported decorator location: "+n)};function he(r){return(e,t)=>typeof t=="object"?da(r,e,t):((n,i,o)=>{let s=i.hasOwnProperty(o);return i.constructor.createProperty(o,n),s?Object.getOwnPropertyDescriptor(i,o):void 0})(r,e,t)}var{I:cp}=Ri;var Ii=r=>r.strings===void 0;var Pi={ATTRIBUTE:1,CHILD:2,PROPERTY:3,BOOLEAN_ATTRIBUTE:4,EVENT:5,ELEMENT:6},nn=r=>(...e)=>({_$litDirective$:r,values:e}),Sr=class{constructor(e){}get _$AU(){return this._$AM._$AU}_$AT(e,t,n){this._$Ct=e,this._$AM=t,this._$Ci=n}_$AS(e,t){return this.update(e,t)}update(e,t){return this.render(...t)}};var Et=(r,e)=>{let t=r._$AN;if(t===void 0)return!1;for(let n of t)n._$AO?.(e,!1),Et(n,e);return!0},kr=r=>{let e,t;do{if((e=r._$AM)===void 0)break;t=e._$AN,t.delete(r),r=e}while(t?.size===0)},Mi=r=>{for(let e;e=r._$AM;r=e){let t=e._$AN;if(t===void 0)e._$AN=t=new Set;else if(t.has(r))break;t.add(r),pa(e)}};function ua(r){this._$AN!==void 0?(kr(this),this._$AM=r,Mi(this)):this._$AM=r}function fa(r,e=!1,t=0){let n=this._$AH,i=this._$AN;if(i!==void 0&&i.size!==0)if(e)if(Array.isArray(n))for(let o=t;o<n.length;o++)Et(n[o],!1),kr(n[o]);else n!=null&&(Et(n,!1),kr(n));else Et(this,r)}var pa=r=>{r.type==Pi.CHILD&&(r._$AP??=fa,r._$AQ??=ua)},Tr=class extends Sr{constructor(){super(...arguments),this._$AN=void 0}_$AT(e,t,n){super._$AT(e,t,n),Mi(this),this.isConnected=e._$AU}_$AO(e,t=!0){e!==this.isConnected&&(this.isConnected=e,e?this.reconnected?.():this.disconnected?.()),t&&(Et(this,e),kr(this))}setValue(e){if(Ii(this._$Ct))this._$Ct._$AI(e,this);else{let t=[...this._$Ct._$AH];t[this._$Ci]=e,this._$Ct._$AI(t,this,0)}}disconnected(){}reconnected(){}};var Di=()=>new sn,sn=class{},on=new WeakMap,Ni=nn(class extends Tr{render(r){return O}update(r,[e]){let t=e!==this.G;return t&&this.G!==void 0&&this.rt(void 0),(t||this.lt!==this.ct)&&(this.G=e,this.ht=r.options?.host,this.rt(this.ct=r.element)),O}rt(r){if(this.isConnected||(r=void 0),typeof this.G=="function"){let e=this.ht??globalThis,t=on.get(e);t===void 0&&(t=new WeakMap,on.set(e,t)),t.get(this.G)!==void 0&&this.G.call(this.ht,void 0),t.set(this.G,r),r!==void 0&&this.G.call(this.ht,r)}else this.G.value=r}get lt(){return typeof this.G=="function"?on.get(this.ht??globalThis)?.get(this.G):this.G?.value}disconnected(){this.lt===this.ct&&this.rt(void 0)}reconnected(){this.rt(this.ct)}});var me=K`
     * {
@@ -294,7 +294,7 @@ var Vi=Object.create;var Er=Object.defineProperty;var cn=Object.getOwnPropertyDe
                 </bs-panel>
             </bs-overlay>
         </bs-token-env>
-    `,n=document.createElement("bs-overlay");return xr(t,document.body),()=>{n.isConnected&&n.remove()}}var Fi={name:"overlay plugin",globalSetup:(r,e)=>[r,[e]],resetSink(r,e,t){let[n]=e;return r.pipe(de(i=>i.kind==="DisplayMessage"),Q(i=>i.payload),ht(i=>{let o=Zi({displayMessage:i});return pt(2e3).pipe(we(()=>o()))}),xe())}};(r=>{ci.parse(r);let t=zn().create(r.connect),[n,i]=Ur.globalSetup(t,Zn),[o,s]=qr.globalSetup(t,i),c=t.pipe(de(d=>d.kind==="WsConnection"),Q(d=>d.payload),Nr()),l=t.pipe(de(d=>d.kind==="Config"),Q(d=>d.payload));Qt(l,c).pipe(ht(d=>{let u=[qr.resetSink(o,s,d),Ur.resetSink(n,i,d),Fi.resetSink(t,[i],d)];return Qt(...u)})).subscribe(),c.subscribe(d=>{i.info("\u{1F7E2} Browsersync Live connected",{config:d})})})(window.$BSLIVE_INJECT_CONFIG$);
+    `,n=document.createElement("bs-overlay");return xr(t,document.body),()=>{n.isConnected&&n.remove()}}var Fi={name:"overlay plugin",globalSetup:(r,e)=>[r,[e]],resetSink(r,e,t){let[n]=e;return r.pipe(de(i=>i.kind==="DisplayMessage"),Q(i=>i.payload),ht(i=>{let o=Zi({displayMessage:i});return pt(2e3).pipe(we(()=>o()))}),xe())}};function am(r){return{name:"ghost plugin",globalSetup:(e,t)=>[e,[t,r]],resetSink(e,t,n){let[i,o]=t,s=n.ghost,c=0,h=!1,g=w=>{h=!0;try{w()}finally{h=!1}},l=[];s.scroll&&l.push(ap(window,"scroll").pipe(de(()=>Date.now()-c>200),aq(100),Q(()=>({kind:"Scroll",payload:au()})))),s.click&&l.push(ap(document,"click",{capture:!0}).pipe(de(f=>f.isTrusted),Q(f=>av(f.target)),de(f=>f!==null),Q(f=>({kind:"Click",payload:f})))),s.input&&l.push(ap(document,"input",{capture:!0}).pipe(de(f=>f.isTrusted),Q(f=>aw(f.target)),de(f=>f!==null))),s.submit&&l.push(ap(document,"submit",{capture:!0}).pipe(de(f=>f.isTrusted&&!h),Q(f=>av(f.target)),de(f=>f!==null),Q(f=>({kind:"Submit",payload:f}))));let d=Qt(...l).pipe(we(f=>{i.trace("ghost: sending",f),o(f)})),u=e.pipe(we(f=>{switch(f.kind){case"Scroll":{if(!s.scroll)return;c=Date.now(),ay(f.payload);break}case"Click":{if(!s.click)return;let p=az(f.payload);p instanceof HTMLElement&&g(()=>p.click());break}case"Input":{if(!s.input)return;let p=az(f.payload.element);if(!aA(p))return;f.payload.checked!=null&&p instanceof HTMLInputElement?p.checked=f.payload.checked:p.value=f.payload.value,p.dispatchEvent(new Event("input",{bubbles:!0})),p.dispatchEvent(new Event("change",{bubbles:!0}));break}case"Submit":{if(!s.submit)return;let p=az(f.payload);p instanceof HTMLFormElement&&g(()=>p.requestSubmit());break}case"Location":{if(!s.location)return;if(!aB(f.payload.path)){i.debug("ghost: ignoring location",f.payload);return}f.payload.path!==ax()&&window.location.assign(f.payload.path);break}}}));return Qt(d,u).pipe(xe())}}}function aA(r){return r instanceof HTMLInputElement||r instanceof HTMLTextAreaElement||r instanceof HTMLSelectElement}function aw(r){if(!(r instanceof Element)||!aA(r))return null;let e=av(r);if(!e)return null;let t=r instanceof HTMLInputElement&&(r.type==="checkbox"||r.type==="radio");return{kind:"Input",payload:{element:e,value:r.value,checked:t?r.checked:void 0}}}function av(r){if(!(r instanceof Element))return null;let e=r.tagName.toLowerCase(),t=Array.prototype.indexOf.call(document.getElementsByTagName(e),r);return t===-1?null:{tag:e,index:t}}function az(r){return document.getElementsByTagName(r.tag)[r.index]??null}function au(){let r=document.scrollingElement??document.documentElement,e=r.scrollWidth-window.innerWidth,t=r.scrollHeight-window.innerHeight;return{x:e>0?window.scrollX/e:0,y:t>0?window.scrollY/t:0}}function ay({x:r,y:e}){let t=document.scrollingElement??document.documentElement;window.scrollTo(r*(t.scrollWidth-window.innerWidth),e*(t.scrollHeight-window.innerHeight))}function ax(){let{pathname:r,search:e,hash:t}=window.location;return r+e+t}function aB(r){try{return new URL(r,window.location.origin).origin===window.location.origin}catch{return!1}}function ap(r,e,t){return new E(n=>{let i=o=>n.next(o);return r.addEventListener(e,i,t),()=>r.removeEventListener(e,i,t)})}function aq(r){return C((e,t)=>{let n,i=!1,o,s=()=>{i?(i=!1,t.next(o),n=setTimeout(s,r)):n=void 0};e.subscribe($(t,c=>{n?(i=!0,o=c):(t.next(c),n=setTimeout(s,r))},void 0,void 0,()=>{clearTimeout(n)}))})}var aH=[["debug","debug"],["log","log"],["info","info"],["warn","warn"],["error","error"]];function aI(r){let e=[],t=!1;return{name:"browser console plugin",globalSetup:()=>{let n=new Y,i=o=>{!t&&e.length<100&&e.push(o),n.next(o)};for(let[o,s]of aH){let c=console[o];console[o]=(...l)=>{c.apply(console,l),i(aJ(s,l))}}return window.addEventListener("error",o=>{i({level:"error",message:`Uncaught ${o.error??o.message}`,stack:aK(o.error)})}),window.addEventListener("unhandledrejection",o=>{i({level:"error",message:`Uncaught (in promise) ${aL(o.reason)}`,stack:aK(o.reason)})}),[n,r]},resetSink(n,i,o){let s=t?[]:e.splice(0);return t=!0,Qt(s,n).pipe(de(c=>o.console.includes(c.level)),we(c=>i({kind:"Console",payload:c})),xe())}}}function aJ(r,e){let t=e.find(n=>n instanceof Error);return{level:r,message:e.map(aL).join(" "),stack:aK(t)}}function aK(r){return r instanceof Error?r.stack:void 0}function aL(r){if(typeof r=="string")return r;if(r instanceof Error)return`${r.name}: ${r.message}`;try{return JSON.stringify(r)??String(r)}catch{return String(r)}}(r=>{ci.parse(r);let e=zn(),t=e.create(r.connect),[n,i]=Ur.globalSetup(t,Zn),[o,s]=qr.globalSetup(t,i),c=am(e.send),[l,d]=c.globalSetup(t,i),p=aI(e.send),[w,y]=p.globalSetup(t,i),u=t.pipe(de(h=>h.kind==="WsConnection"),Q(h=>h.payload),Nr()),f=t.pipe(de(h=>h.kind==="Config"),Q(h=>h.payload));Qt(f,u).pipe(ht(h=>{let A=[qr.resetSink(o,s,h),Ur.resetSink(n,i,h),Fi.resetSink(t,[i],h),c.resetSink(l,d,h),p.resetSink(w,y,h)];return Qt(...A)})).subscribe(),u.subscribe(h=>{i.info("\u{1F7E2} Browsersync Live connected",{config:h})})})(window.$BSLIVE_INJECT_CONFIG$);
 /*! Bundled license information:
 
 @lit/reactive-element/css-tag.js:
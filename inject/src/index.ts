@@ -6,6 +6,8 @@ import { domPlugin } from "./sinks/dom.js";
 import { InjectConfig } from "@browsersync/generated/dto.js";
 import { injectConfigSchema } from "@browsersync/generated/schema.js";
 import { overlayPlugin } from "./sinks/overlay.js";
+import { ghostPlugin } from "./sinks/ghost.js";
+import { browserConsolePlugin } from "./sinks/browser_console.js";
 
 ((injectConfig) => {
     injectConfigSchema.parse(injectConfig);
@@ -18,6 +20,13 @@ import { overlayPlugin } from "./sinks/overlay.js";
         NULL_CONSOLE,
     );
     const [domEvents$, domApis] = domPlugin.globalSetup(clientEvent$, log);
+    const ghost = ghostPlugin(producer.send);
+    const [ghostEvents$, ghostApis] = ghost.globalSetup(clientEvent$, log);
+    const browserConsole = browserConsolePlugin(producer.send);
+    const [consoleEntries$, consoleApis] = browserConsole.globalSetup(
+        clientEvent$,
+        log,
+    );
 
     // prettier-ignore
     const connection$ = clientEvent$.pipe(
@@ -42,6 +51,8 @@ import { overlayPlugin } from "./sinks/overlay.js";
                     domPlugin.resetSink(domEvents$, domApis, config),
                     consolePlugin.resetSink(logEvent$, log, config),
                     overlayPlugin.resetSink(clientEvent$, [log], config),
+                    ghost.resetSink(ghostEvents$, ghostApis, config),
+                    browserConsole.resetSink(consoleEntries$, consoleApis, config),
                 ];
                 return merge(...sinks);
             }),
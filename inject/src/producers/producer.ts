@@ -3,4 +3,5 @@ import { Observable } from "rxjs";
 
 export interface Producer {
     create(connectInfo: ConnectInfo): Observable<ClientEvent>;
+    send(event: ClientEvent): void;
 }
@@ -1,9 +1,10 @@
 import { Producer } from "./producer.js";
-import { webSocket } from "rxjs/webSocket";
+import { webSocket, WebSocketSubject } from "rxjs/webSocket";
 import { ClientEvent } from "@browsersync/generated/dto.js";
 import { retry } from "rxjs";
 
 export function ws(): Producer {
+    let socket: WebSocketSubject<ClientEvent> | undefined;
     return {
         create: (connectInfo) => {
             const current = new URL(window.location.href);
@@ -24,8 +25,63 @@ export function ws(): Producer {
                 ws_url = clone;
             }
 
-            const socket = webSocket<ClientEvent>(ws_url.toString());
+            // so the server knows which page each connected client is on
+            const initialPath = currentPath();
+            ws_url.searchParams.set("bslive.url", initialPath);
+
+            // servers protected by a token need it on the websocket too
+            if (connectInfo.token) {
+                ws_url.searchParams.set("bslive.token", connectInfo.token);
+            }
+
+            // navigation is reported too - ghost mode passes it on to other browsers, but
+            // it's always kept in the client registry
+            let connected = false;
+            let sentPath = initialPath;
+            const sendLocation = () => {
+                if (!connected) return;
+                const path = currentPath();
+                if (path === sentPath) return;
+                sentPath = path;
+                socket?.next({ kind: "Location", payload: { path } });
+            };
+
+            socket = webSocket<ClientEvent>({
+                url: ws_url.toString(),
+                // reconnecting reuses the same url, so catch up on anything since page load
+                openObserver: {
+                    next: () => {
+                        connected = true;
+                        sentPath = initialPath;
+                        sendLocation();
+                    },
+                },
+                closeObserver: {
+                    next: () => {
+                        connected = false;
+                    },
+                },
+            });
+
+            window.addEventListener("popstate", sendLocation);
+            window.addEventListener("hashchange", sendLocation);
+            for (const method of ["pushState", "replaceState"] as const) {
+                const original = history[method];
+                history[method] = function (...args) {
+                    original.apply(this, args);
+                    sendLocation();
+                };
+            }
+
             return socket.pipe(retry({ delay: 5000 }));
         },
+        send: (event) => {
+            socket?.next(event);
+        },
     };
 }
+
+function currentPath(): string {
+    const { pathname, search, hash } = window.location;
+    return pathname + search + hash;
+}
@@ -0,0 +1,112 @@
+import {
+    ClientConfigDTO,
+    ClientEvent,
+    ConsoleDTO,
+    ConsoleLevelDTO,
+} from "@browsersync/generated/dto.js";
+import {
+    filter,
+    from,
+    ignoreElements,
+    merge,
+    Observable,
+    Subject,
+    tap,
+} from "rxjs";
+import { Sink } from "./sink.js";
+
+type Send = (event: ClientEvent) => void;
+
+/**
+ * Entries logged before the first config arrives are kept, so that errors during page load
+ * still make it to the terminal
+ */
+const MAX_PENDING = 100;
+
+const METHODS = [
+    ["debug", ConsoleLevelDTO.Debug],
+    ["log", ConsoleLevelDTO.Log],
+    ["info", ConsoleLevelDTO.Info],
+    ["warn", ConsoleLevelDTO.Warn],
+    ["error", ConsoleLevelDTO.Error],
+] as const;
+
+/**
+ * Forwards `console.*` calls, uncaught errors and unhandled rejections to the server, which
+ * prints them in the terminal. Levels are chosen with `clients.console` in the config.
+ */
+export function browserConsolePlugin(send: Send): Sink<ConsoleDTO, Send> {
+    const pending: ConsoleDTO[] = [];
+    let flushed = false;
+    return {
+        name: "browser console plugin",
+        globalSetup: () => {
+            const entries$ = new Subject<ConsoleDTO>();
+            const next = (entry: ConsoleDTO) => {
+                if (!flushed && pending.length < MAX_PENDING) pending.push(entry);
+                entries$.next(entry);
+            };
+
+            for (const [method, level] of METHODS) {
+                const original = console[method];
+                console[method] = (...args: any[]) => {
+                    original.apply(console, args);
+                    next(consoleEntry(level, args));
+                };
+            }
+
+            window.addEventListener("error", (e) => {
+                next({
+                    level: ConsoleLevelDTO.Error,
+                    message: `Uncaught ${e.error ?? e.message}`,
+                    stack: stackOf(e.error),
+                });
+            });
+            window.addEventListener("unhandledrejection", (e) => {
+                next({
+                    level: ConsoleLevelDTO.Error,
+                    message: `Uncaught (in promise) ${stringify(e.reason)}`,
+                    stack: stackOf(e.reason),
+                });
+            });
+
+            return [entries$, send];
+        },
+        resetSink(
+            entries$: Observable<ConsoleDTO>,
+            send: Send,
+            config: ClientConfigDTO,
+        ): Observable<unknown> {
+            const backlog = flushed ? [] : pending.splice(0);
+            flushed = true;
+            return merge(from(backlog), entries$).pipe(
+                filter((entry) => config.console.includes(entry.level)),
+                tap((entry) => send({ kind: "Console", payload: entry })),
+                ignoreElements(),
+            );
+        },
+    };
+}
+
+function consoleEntry(level: ConsoleLevelDTO, args: any[]): ConsoleDTO {
+    const error = args.find((arg) => arg instanceof Error);
+    return {
+        level,
+        message: args.map(stringify).join(" "),
+        stack: stackOf(error),
+    };
+}
+
+function stackOf(value: unknown): string | undefined {
+    return value instanceof Error ? value.stack : undefined;
+}
+
+function stringify(value: unknown): string {
+    if (typeof value === "string") return value;
+    if (value instanceof Error) return `${value.name}: ${value.message}`;
+    try {
+        return JSON.stringify(value) ?? String(value);
+    } catch {
+        return String(value);
+    }
+}
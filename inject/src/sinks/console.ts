@@ -12,6 +12,9 @@ export type ConsoleApi = Pick<
     "trace" | "debug" | "info" | "error"
 >;
 
+// bound up front, so that this output isn't forwarded by the browser console plugin
+const print = console.log.bind(console);
+
 export const NULL_CONSOLE: ConsoleApi = {
     debug(...data: any[]): void {},
     error(...data: any[]): void {},
@@ -68,7 +71,7 @@ export const consolePlugin: Sink<ConsoleEvent, ConsoleApi> = {
                 const configLevelIndex = levelOrder.indexOf(config.log_level);
 
                 if (currentLevelIndex >= configLevelIndex) {
-                    console.log(`[${evt.level}]`, ...evt.args);
+                    print(`[${evt.level}]`, ...evt.args);
                 }
             }),
             ignoreElements(),
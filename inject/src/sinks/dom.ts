@@ -1,11 +1,11 @@
-import { filter, ignoreElements, map, Observable, tap } from "rxjs";
+import { ignoreElements, Observable, tap } from "rxjs";
 import { changeDTOSchema } from "@browsersync/generated/schema.js";
 import { ClientConfigDTO, ClientEvent } from "@browsersync/generated/dto.js";
 import { Sink } from "./sink.js";
 import { Reloader } from "../../vendor/live-reload/src/reloader.js";
 import { Timer } from "../../vendor/live-reload/src/timer.js";
 import { ConsoleApi } from "./console.js";
-import { changedPath } from "./dom/changed-path.js";
+import { changedPath, reloadPage, reloadPath } from "./dom/changed-path.js";
 
 export const domPlugin: Sink<ClientEvent, [ConsoleApi, Reloader]> = {
     name: "dom plugin",
@@ -20,15 +20,28 @@ export const domPlugin: Sink<ClientEvent, [ConsoleApi, Reloader]> = {
     ): Observable<unknown> {
         const [log, reloader] = api;
         return clientEvent$.pipe(
-            filter((x) => x.kind === "Change"),
-            map((x) => x.payload),
-            tap((change) => {
-                log.trace(
-                    "incoming message",
-                    JSON.stringify({ change, config }, null, 2),
-                );
-                const parsed = changeDTOSchema.parse(change);
-                changedPath(parsed, log, reloader);
+            tap((event) => {
+                switch (event.kind) {
+                    case "Change": {
+                        const change = event.payload;
+                        log.trace(
+                            "incoming message",
+                            JSON.stringify({ change, config }, null, 2),
+                        );
+                        const parsed = changeDTOSchema.parse(change);
+                        changedPath(parsed, log, reloader);
+                        break;
+                    }
+                    case "Reload": {
+                        reloadPage(reloader);
+                        break;
+                    }
+                    case "InjectCss": {
+                        // the reloader swaps `.css` paths in place, anything else reloads the page
+                        reloadPath(event.payload.path, log, reloader);
+                        break;
+                    }
+                }
             }),
             ignoreElements(),
         );
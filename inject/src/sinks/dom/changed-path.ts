@@ -1,39 +1,21 @@
 // todo: the checks are lifted directly from live reload, we should not use them, but are a good starting point
-import { ChangeDTO } from "@browsersync/generated/dto.js";
+import { ChangeDTO, ChangeEffect } from "@browsersync/generated/dto.js";
 import { ConsoleApi } from "../console.js";
 import { Reloader } from "../../../vendor/live-reload/src/reloader.js";
 
 export const IMAGES_REGEX = /\.(jpe?g|png|gif|svg)$/i;
+export const CSS_REGEX = /\.css(?:\.map)?$/i;
 
 export function changedPath(change: ChangeDTO, log: ConsoleApi, api: Reloader) {
     switch (change.kind) {
         case "FsMany": {
-            const hasNoneInjectable = change.payload.some((changeDTO) => {
-                switch (changeDTO.kind) {
-                    case "Fs":
-                        if (changeDTO.payload.path.match(/\.css(?:\.map)?$/i)) {
-                            return false;
-                        }
-                        if (changeDTO.payload.path.match(IMAGES_REGEX)) {
-                            return false;
-                        }
-
-                        // if we get here, we're not going to live inject anything
-                        return true;
-                    case "FsMany":
-                        throw new Error("unreachable");
-                }
-            });
+            const hasNoneInjectable = change.payload.some(
+                (changeDTO) => effectOf(changeDTO) === ChangeEffect.ReloadPage,
+            );
 
             // if any path will cause a reload anyway, don't both hot-reloading anything.
             if (hasNoneInjectable) {
-                if (window.__playwright?.record) {
-                    return window.__playwright?.record({
-                        kind: "reloadPage",
-                    });
-                } else {
-                    return api.reloadPage();
-                }
+                return reloadPage(api);
             }
 
             // if we get here, every path given was injectable, so try to inject them all
@@ -43,27 +25,69 @@ export function changedPath(change: ChangeDTO, log: ConsoleApi, api: Reloader) {
             break;
         }
         case "Fs": {
-            let path = change.payload.path;
-            const opts = {
-                liveCSS: true,
-                liveImg: true,
-                reloadMissingCSS: true,
-                originalPath: "",
-                overrideURL: "",
-                serverURL: ``,
-            };
-            if (window.__playwright?.record) {
-                window.__playwright?.record({
-                    kind: "reload",
-                    args: {
-                        path,
-                        opts,
-                    },
-                });
-            } else {
-                log.trace("will reload a file with path ", path);
-                api.reload(path, opts);
+            const { path, effect, urls } = change.payload;
+            if (effect === ChangeEffect.ReloadPage) {
+                return reloadPage(api);
+            }
+            // urls come from the server's routes, so they match stylesheets even when the
+            // file on disk lives somewhere else
+            for (const target of urls?.length ? urls : [path]) {
+                reloadPath(target, log, api);
             }
         }
     }
 }
+
+/**
+ * The server classifies each file it reports, the regexes cover changes sent without an effect
+ */
+function effectOf(change: ChangeDTO): ChangeEffect {
+    switch (change.kind) {
+        case "Fs": {
+            const { path, effect } = change.payload;
+            if (effect) return effect;
+            if (path.match(CSS_REGEX)) return ChangeEffect.InjectCss;
+            if (path.match(IMAGES_REGEX)) return ChangeEffect.SwapImage;
+            // if we get here, we're not going to live inject anything
+            return ChangeEffect.ReloadPage;
+        }
+        case "FsMany":
+            throw new Error("unreachable");
+    }
+}
+
+export function reloadPage(api: Reloader) {
+    if (window.__playwright?.record) {
+        return window.__playwright?.record({
+            kind: "reloadPage",
+        });
+    } else {
+        return api.reloadPage();
+    }
+}
+
+/**
+ * Stylesheets and images are swapped in place, anything else reloads the page
+ */
+export function reloadPath(path: string, log: ConsoleApi, api: Reloader) {
+    const opts = {
+        liveCSS: true,
+        liveImg: true,
+        reloadMissingCSS: true,
+        originalPath: "",
+        overrideURL: "",
+        serverURL: ``,
+    };
+    if (window.__playwright?.record) {
+        window.__playwright?.record({
+            kind: "reload",
+            args: {
+                path,
+                opts,
+            },
+        });
+    } else {
+        log.trace("will reload a file with path ", path);
+        api.reload(path, opts);
+    }
+}
@@ -0,0 +1,231 @@
+import {
+    ClientConfigDTO,
+    ClientEvent,
+    ElementDTO,
+    ScrollDTO,
+} from "@browsersync/generated/dto.js";
+import {
+    filter,
+    fromEvent,
+    ignoreElements,
+    map,
+    merge,
+    Observable,
+    tap,
+    throttleTime,
+} from "rxjs";
+import { Sink } from "./sink.js";
+import { ConsoleApi } from "./console.js";
+
+type Send = (event: ClientEvent) => void;
+
+/**
+ * Ghost mode: interactions in this browser are sent to the server, which passes them on to
+ * every other connected browser. Each kind is toggled with `clients.ghost` in the config.
+ *
+ * Navigation is reported by the websocket producer (it's tracked without ghost mode too), so
+ * only incoming `Location` events are handled here.
+ *
+ * Replayed clicks and inputs are untrusted (`isTrusted === false`), which is how they're kept
+ * from being sent straight back. A replayed submit is fired by the browser itself, so it's
+ * trusted - outgoing submits are dropped while replaying instead. Scrolling is ignored for a
+ * moment after a remote scroll.
+ */
+export function ghostPlugin(send: Send): Sink<ClientEvent, [ConsoleApi, Send]> {
+    return {
+        name: "ghost plugin",
+        globalSetup: (clientEvents$, log) => {
+            return [clientEvents$, [log, send]];
+        },
+        resetSink(
+            clientEvent$: Observable<ClientEvent>,
+            api: [ConsoleApi, Send],
+            config: ClientConfigDTO,
+        ): Observable<unknown> {
+            const [log, send] = api;
+            const ghost = config.ghost;
+            let remoteScrollAt = 0;
+            let replaying = false;
+            const replay = (action: () => void) => {
+                replaying = true;
+                try {
+                    action();
+                } finally {
+                    replaying = false;
+                }
+            };
+
+            const outgoing: Observable<ClientEvent>[] = [];
+            if (ghost.scroll) {
+                // prettier-ignore
+                outgoing.push(fromEvent(window, "scroll").pipe(
+                    filter(() => Date.now() - remoteScrollAt > 200),
+                    throttleTime(100, undefined, { leading: true, trailing: true }),
+                    map((): ClientEvent => ({ kind: "Scroll", payload: scrollPosition() })),
+                ));
+            }
+            if (ghost.click) {
+                // prettier-ignore
+                outgoing.push(fromEvent<MouseEvent>(document, "click", { capture: true }).pipe(
+                    filter((e) => e.isTrusted),
+                    map((e) => elementDTO(e.target)),
+                    filter((el): el is ElementDTO => el !== null),
+                    map((el): ClientEvent => ({ kind: "Click", payload: el })),
+                ));
+            }
+            if (ghost.input) {
+                // prettier-ignore
+                outgoing.push(fromEvent<InputEvent>(document, "input", { capture: true }).pipe(
+                    filter((e) => e.isTrusted),
+                    map((e) => inputEvent(e.target)),
+                    filter((evt): evt is ClientEvent => evt !== null),
+                ));
+            }
+            if (ghost.submit) {
+                // prettier-ignore
+                outgoing.push(fromEvent<SubmitEvent>(document, "submit", { capture: true }).pipe(
+                    filter((e) => e.isTrusted && !replaying),
+                    map((e) => elementDTO(e.target)),
+                    filter((el): el is ElementDTO => el !== null),
+                    map((el): ClientEvent => ({ kind: "Submit", payload: el })),
+                ));
+            }
+
+            const send$ = merge(...outgoing).pipe(
+                tap((event) => {
+                    log.trace("ghost: sending", event);
+                    send(event);
+                }),
+            );
+
+            const receive$ = clientEvent$.pipe(
+                tap((event) => {
+                    switch (event.kind) {
+                        case "Scroll": {
+                            if (!ghost.scroll) return;
+                            remoteScrollAt = Date.now();
+                            applyScroll(event.payload);
+                            break;
+                        }
+                        case "Click": {
+                            if (!ghost.click) return;
+                            const el = findElement(event.payload);
+                            if (el instanceof HTMLElement) replay(() => el.click());
+                            break;
+                        }
+                        case "Input": {
+                            if (!ghost.input) return;
+                            const el = findElement(event.payload.element);
+                            if (!isField(el)) return;
+                            if (event.payload.checked != null && el instanceof HTMLInputElement) {
+                                el.checked = event.payload.checked;
+                            } else {
+                                el.value = event.payload.value;
+                            }
+                            el.dispatchEvent(new Event("input", { bubbles: true }));
+                            el.dispatchEvent(new Event("change", { bubbles: true }));
+                            break;
+                        }
+                        case "Submit": {
+                            if (!ghost.submit) return;
+                            const el = findElement(event.payload);
+                            if (el instanceof HTMLFormElement) replay(() => el.requestSubmit());
+                            break;
+                        }
+                        case "Location": {
+                            if (!ghost.location) return;
+                            if (!isSameOrigin(event.payload.path)) {
+                                log.debug("ghost: ignoring location", event.payload);
+                                return;
+                            }
+                            if (event.payload.path !== currentPath()) {
+                                window.location.assign(event.payload.path);
+                            }
+                            break;
+                        }
+                    }
+                }),
+            );
+
+            return merge(send$, receive$).pipe(ignoreElements());
+        },
+    };
+}
+
+type Field = HTMLInputElement | HTMLTextAreaElement | HTMLSelectElement;
+
+function isField(el: Element | null): el is Field {
+    return (
+        el instanceof HTMLInputElement ||
+        el instanceof HTMLTextAreaElement ||
+        el instanceof HTMLSelectElement
+    );
+}
+
+function inputEvent(target: EventTarget | null): ClientEvent | null {
+    if (!(target instanceof Element) || !isField(target)) return null;
+    const element = elementDTO(target);
+    if (!element) return null;
+    const checkable =
+        target instanceof HTMLInputElement &&
+        (target.type === "checkbox" || target.type === "radio");
+    return {
+        kind: "Input",
+        payload: {
+            element,
+            value: target.value,
+            checked: checkable ? target.checked : undefined,
+        },
+    };
+}
+
+/**
+ * Elements are found by tag name + position, since the same page is loaded everywhere
+ */
+function elementDTO(target: EventTarget | null): ElementDTO | null {
+    if (!(target instanceof Element)) return null;
+    const tag = target.tagName.toLowerCase();
+    const index = Array.prototype.indexOf.call(
+        document.getElementsByTagName(tag),
+        target,
+    );
+    return index === -1 ? null : { tag, index };
+}
+
+function findElement(element: ElementDTO): Element | null {
+    return document.getElementsByTagName(element.tag)[element.index] ?? null;
+}
+
+function scrollPosition(): ScrollDTO {
+    const root = document.scrollingElement ?? document.documentElement;
+    const maxX = root.scrollWidth - window.innerWidth;
+    const maxY = root.scrollHeight - window.innerHeight;
+    return {
+        x: maxX > 0 ? window.scrollX / maxX : 0,
+        y: maxY > 0 ? window.scrollY / maxY : 0,
+    };
+}
+
+function applyScroll({ x, y }: ScrollDTO) {
+    const root = document.scrollingElement ?? document.documentElement;
+    window.scrollTo(
+        x * (root.scrollWidth - window.innerWidth),
+        y * (root.scrollHeight - window.innerHeight),
+    );
+}
+
+function currentPath(): string {
+    const { pathname, search, hash } = window.location;
+    return pathname + search + hash;
+}
+
+/**
+ * Only paths on this origin are followed - `//host`, or anything with a scheme, goes elsewhere
+ */
+function isSameOrigin(path: string): boolean {
+    try {
+        return new URL(path, window.location.origin).origin === window.location.origin;
+    } catch {
+        return false;
+    }
+}
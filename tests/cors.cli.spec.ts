@@ -72,3 +72,41 @@ test.describe(
         });
     },
 );
+test.describe(
+    "with structured cors cli args",
+    {
+        annotation: {
+            type: cli({
+                args: [
+                    "start",
+                    "examples/basic/public",
+                    "--cors={origins: [http://localhost:5173], credentials: true}",
+                ],
+            }),
+            description: "",
+        },
+    },
+    () => {
+        test("only allows the given origin, with credentials", async ({
+            request,
+            bs,
+        }) => {
+            const allowed = await request.get(bs.path("/"), {
+                headers: { origin: "http://localhost:5173" },
+            });
+            expect(allowed.headers()["access-control-allow-origin"]).toBe(
+                "http://localhost:5173",
+            );
+            expect(allowed.headers()["access-control-allow-credentials"]).toBe(
+                "true",
+            );
+
+            const other = await request.get(bs.path("/"), {
+                headers: { origin: "http://example.com" },
+            });
+            expect(
+                other.headers()["access-control-allow-origin"],
+            ).toBeUndefined();
+        });
+    },
+);
@@ -18,6 +18,24 @@ test.describe(
                 "API response from /abc[1,2,3]",
             );
         });
+        test("serves index.html for client-side routes", async ({
+            request,
+            bs,
+        }) => {
+            const response = await request.get(bs.path("/users/1"), {
+                headers: { accept: "text/html" },
+            });
+            expect(response.status()).toBe(200);
+            expect(await response.text()).toContain('<div id="root">');
+        });
+        test("still 404s missing assets", async ({ request, bs }) => {
+            const js = await request.get(bs.path("/assets/missing.js"));
+            expect(js.status()).toBe(404);
+            const css = await request.get(bs.path("/assets/missing.css"), {
+                headers: { accept: "text/html" },
+            });
+            expect(css.status()).toBe(404);
+        });
         test("supports compressed responses", async ({ page, bs }) => {
             // Navigate to the page and wait until the network becomes idle
             const load = page.goto(
@@ -308,7 +308,7 @@ export const test = base.extend<{
             }, 100);
         });
         const servers = data.servers.map((s) => {
-            return { url: "http://" + s.socket_addr, identity: s.identity };
+            return { url: s.url, identity: s.identity };
         });
         await use({
             url: "msg.urls.local",
@@ -12,7 +12,7 @@ class BsServerList extends LitElement {
     render() {
         return html`
             ${this.servers.map((server) => {
-                const display_addr = "http://" + server.socket_addr;
+                const display_addr = server.url;
                 let url = new URL(display_addr);
                 let bs_url = new URL("./__bslive", display_addr);
                 return html`